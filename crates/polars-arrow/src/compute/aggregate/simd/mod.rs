@@ -1,7 +1,7 @@
 use std::ops::Add;
 
 use super::Sum;
-use crate::types::simd::{i128x8, NativeSimd};
+use crate::types::simd::{i128x8, u128x8, NativeSimd};
 
 macro_rules! simd_add {
     ($simd:tt, $type:ty, $lanes:expr, $add:tt) => {
@@ -44,6 +44,7 @@ macro_rules! simd_add {
 // pub(super) use simd_add;
 
 simd_add!(i128x8, i128, 8, add);
+simd_add!(u128x8, u128, 8, add);
 
 #[cfg(not(feature = "simd"))]
 mod native;
@@ -44,6 +44,11 @@ pub enum ArrowDataType {
     Int32,
     /// An [`i64`]
     Int64,
+    /// An [`i128`]
+    ///
+    /// This type is not part of the Arrow specification. It is exchanged as the
+    /// [`INT128_EXTENSION_NAME`] extension type over a `Decimal(38, 0)`.
+    Int128,
     /// An [`u8`]
     UInt8,
     /// An [`u16`]
@@ -52,6 +57,11 @@ pub enum ArrowDataType {
     UInt32,
     /// An [`u64`]
     UInt64,
+    /// An [`u128`]
+    ///
+    /// This type is not part of the Arrow specification. It is exchanged as the
+    /// [`UINT128_EXTENSION_NAME`] extension type over a `FixedSizeBinary(16)`.
+    UInt128,
     /// An 16-bit float
     Float16,
     /// A [`f32`]
@@ -250,12 +260,13 @@ impl ArrowDataType {
             Int64 | Date64 | Timestamp(_, _) | Time64(_) | Duration(_) => {
                 PhysicalType::Primitive(PrimitiveType::Int64)
             },
-            Decimal(_, _) => PhysicalType::Primitive(PrimitiveType::Int128),
+            Int128 | Decimal(_, _) => PhysicalType::Primitive(PrimitiveType::Int128),
             Decimal256(_, _) => PhysicalType::Primitive(PrimitiveType::Int256),
             UInt8 => PhysicalType::Primitive(PrimitiveType::UInt8),
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
             UInt64 => PhysicalType::Primitive(PrimitiveType::UInt64),
+            UInt128 => PhysicalType::Primitive(PrimitiveType::UInt128),
             Float16 => PhysicalType::Primitive(PrimitiveType::Float16),
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
@@ -374,10 +385,12 @@ impl ArrowDataType {
                 | D::Int16
                 | D::Int32
                 | D::Int64
+                | D::Int128
                 | D::UInt8
                 | D::UInt16
                 | D::UInt32
                 | D::UInt64
+                | D::UInt128
                 | D::Float32
                 | D::Float64
                 | D::Decimal(_, _)
@@ -406,10 +419,12 @@ impl ArrowDataType {
             | D::Int16
             | D::Int32
             | D::Int64
+            | D::Int128
             | D::UInt8
             | D::UInt16
             | D::UInt32
             | D::UInt64
+            | D::UInt128
             | D::Float16
             | D::Float32
             | D::Float64
@@ -469,18 +484,23 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::UInt16 => ArrowDataType::UInt16,
             PrimitiveType::UInt32 => ArrowDataType::UInt32,
             PrimitiveType::UInt64 => ArrowDataType::UInt64,
-            PrimitiveType::Int128 => ArrowDataType::Decimal(32, 32),
+            PrimitiveType::Int128 => ArrowDataType::Int128,
             PrimitiveType::Int256 => ArrowDataType::Decimal256(32, 32),
             PrimitiveType::Float16 => ArrowDataType::Float16,
             PrimitiveType::Float32 => ArrowDataType::Float32,
             PrimitiveType::Float64 => ArrowDataType::Float64,
             PrimitiveType::DaysMs => ArrowDataType::Interval(IntervalUnit::DayTime),
             PrimitiveType::MonthDayNano => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
-            PrimitiveType::UInt128 => unimplemented!(),
+            PrimitiveType::UInt128 => ArrowDataType::UInt128,
         }
     }
 }
 
+/// Name of the extension type used to exchange [`ArrowDataType::Int128`].
+pub const INT128_EXTENSION_NAME: &str = "polars.int128";
+/// Name of the extension type used to exchange [`ArrowDataType::UInt128`].
+pub const UINT128_EXTENSION_NAME: &str = "polars.uint128";
+
 /// typedef for [`Arc<ArrowSchema>`].
 pub type SchemaRef = Arc<ArrowSchema>;
 
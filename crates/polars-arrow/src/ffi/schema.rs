@@ -8,6 +8,7 @@ use polars_utils::pl_str::PlSmallStr;
 use super::ArrowSchema;
 use crate::datatypes::{
     ArrowDataType, Extension, Field, IntegerType, IntervalUnit, Metadata, TimeUnit, UnionMode,
+    INT128_EXTENSION_NAME, UINT128_EXTENSION_NAME,
 };
 
 #[allow(dead_code)]
@@ -82,8 +83,16 @@ impl ArrowSchema {
 
         let metadata = &field.metadata;
 
-        let metadata = if let ArrowDataType::Extension(name, _, extension_metadata) = field.dtype()
-        {
+        let extension = match field.dtype() {
+            ArrowDataType::Extension(name, _, extension_metadata) => {
+                Some((name.clone(), extension_metadata.as_ref()))
+            },
+            ArrowDataType::Int128 => Some((PlSmallStr::from_static(INT128_EXTENSION_NAME), None)),
+            ArrowDataType::UInt128 => Some((PlSmallStr::from_static(UINT128_EXTENSION_NAME), None)),
+            _ => None,
+        };
+
+        let metadata = if let Some((name, extension_metadata)) = extension {
             // append extension information.
             let mut metadata = metadata.clone();
 
@@ -95,10 +104,7 @@ impl ArrowSchema {
                 );
             }
 
-            metadata.insert(
-                PlSmallStr::from_static("ARROW:extension:name"),
-                name.clone(),
-            );
+            metadata.insert(PlSmallStr::from_static("ARROW:extension:name"), name);
 
             Some(metadata_to_bytes(&metadata))
         } else if !metadata.is_empty() {
@@ -213,10 +219,17 @@ pub(crate) unsafe fn to_field(schema: &ArrowSchema) -> PolarsResult<Field> {
     };
     let (metadata, extension) = unsafe { metadata_from_bytes(schema.metadata) };
 
-    let dtype = if let Some((name, extension_metadata)) = extension {
-        ArrowDataType::Extension(name, Box::new(dtype), extension_metadata)
-    } else {
-        dtype
+    let dtype = match (extension, &dtype) {
+        (Some((name, _)), ArrowDataType::Decimal(_, _)) if name == INT128_EXTENSION_NAME => {
+            ArrowDataType::Int128
+        },
+        (Some((name, _)), ArrowDataType::FixedSizeBinary(16)) if name == UINT128_EXTENSION_NAME => {
+            ArrowDataType::UInt128
+        },
+        (Some((name, extension_metadata)), _) => {
+            ArrowDataType::Extension(name, Box::new(dtype), extension_metadata)
+        },
+        (None, _) => dtype,
     };
 
     Ok(Field::new(
@@ -278,6 +291,7 @@ unsafe fn to_dtype(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
         "tDn" => ArrowDataType::Duration(TimeUnit::Nanosecond),
         "tiM" => ArrowDataType::Interval(IntervalUnit::YearMonth),
         "tiD" => ArrowDataType::Interval(IntervalUnit::DayTime),
+        "tin" => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
         "vu" => ArrowDataType::Utf8View,
         "vz" => ArrowDataType::BinaryView,
         "+l" => {
@@ -450,9 +464,7 @@ fn to_format(dtype: &ArrowDataType) -> String {
         ArrowDataType::Duration(TimeUnit::Nanosecond) => "tDn".to_string(),
         ArrowDataType::Interval(IntervalUnit::YearMonth) => "tiM".to_string(),
         ArrowDataType::Interval(IntervalUnit::DayTime) => "tiD".to_string(),
-        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
-            todo!("Spec for FFI for MonthDayNano still not defined.")
-        },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => "tin".to_string(),
         ArrowDataType::Timestamp(unit, tz) => {
             let unit = match unit {
                 TimeUnit::Second => "s",
@@ -468,6 +480,8 @@ fn to_format(dtype: &ArrowDataType) -> String {
         },
         ArrowDataType::Utf8View => "vu".to_string(),
         ArrowDataType::BinaryView => "vz".to_string(),
+        ArrowDataType::Int128 => "d:38,0".to_string(),
+        ArrowDataType::UInt128 => "w:16".to_string(),
         ArrowDataType::Decimal(precision, scale) => format!("d:{precision},{scale}"),
         ArrowDataType::Decimal256(precision, scale) => format!("d:{precision},{scale},256"),
         ArrowDataType::List(_) => "+l".to_string(),
@@ -685,7 +699,7 @@ mod tests {
         for interval_type in [
             IntervalUnit::DayTime,
             IntervalUnit::YearMonth,
-            //IntervalUnit::MonthDayNano, // not yet defined on the C data interface
+            IntervalUnit::MonthDayNano,
         ] {
             dts.push(ArrowDataType::Interval(interval_type));
         }
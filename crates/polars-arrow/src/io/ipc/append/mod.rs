@@ -64,8 +64,10 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries,
                 cannot_replace: true,
+                emit_deltas: options.dictionary_deltas,
             },
             encoded_message: Default::default(),
+            custom_schema_metadata: metadata.custom_schema_metadata,
         })
     }
 }
@@ -265,12 +265,9 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        polars_bail!(ComputeError: "delta dictionary batches not supported")
-    }
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
@@ -313,7 +310,17 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let values = chunk.into_arrays().pop().unwrap();
+    let values = if is_delta {
+        // A delta appends its values to the dictionary that was read before.
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            polars_err!(oos = "delta dictionary batch without a preceding dictionary batch")
+        })?;
+        crate::compute::concatenate::concatenate(&[existing.as_ref(), values.as_ref()])?
+    } else {
+        values
+    };
+    dictionaries.insert(id, values);
 
     Ok(())
 }
@@ -28,7 +28,9 @@ pub use file::{
 use polars_utils::aliases::PlHashMap;
 pub use reader::FileReader;
 pub use schema::deserialize_schema;
-pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
+pub use stream::{
+    get_stream_row_count, read_stream_metadata, StreamMetadata, StreamReader, StreamState,
+};
 
 /// how dictionaries are tracked in this crate
 pub type Dictionaries = PlHashMap<i64, Box<dyn Array>>;
@@ -167,8 +167,8 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = PolarsResult<RecordBatchT<Box<dyn Array>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // get current block
-        if self.current_block == self.metadata.blocks.len() {
+        // get current block, unless the limit has been reached
+        if self.current_block == self.metadata.blocks.len() || self.remaining == 0 {
             return None;
         }
 
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow_format::ipc::planus::ReadAsRoot;
 use arrow_format::ipc::{FieldRef, FixedSizeListRef, MapRef, TimeRef, TimestampRef, UnionRef};
 use polars_error::{polars_bail, polars_err, PolarsResult};
@@ -7,7 +9,7 @@ use super::super::{IpcField, IpcSchema};
 use super::{OutOfSpecKind, StreamMetadata};
 use crate::datatypes::{
     get_extension, ArrowDataType, ArrowSchema, Extension, Field, IntegerType, IntervalUnit,
-    Metadata, TimeUnit, UnionMode,
+    Metadata, TimeUnit, UnionMode, INT128_EXTENSION_NAME, UINT128_EXTENSION_NAME,
 };
 
 fn try_unzip_vec<A, B, I: Iterator<Item = PolarsResult<(A, B)>>>(
@@ -46,7 +48,18 @@ fn deserialize_field(ipc_field: arrow_format::ipc::FieldRef) -> PolarsResult<(Fi
 }
 
 fn read_metadata(field: &arrow_format::ipc::FieldRef) -> PolarsResult<Metadata> {
-    Ok(if let Some(list) = field.custom_metadata()? {
+    read_key_values(field.custom_metadata()?)
+}
+
+fn read_key_values(
+    list: Option<
+        arrow_format::ipc::planus::Vector<
+            '_,
+            arrow_format::ipc::planus::Result<arrow_format::ipc::KeyValueRef<'_>>,
+        >,
+    >,
+) -> PolarsResult<Metadata> {
+    Ok(if let Some(list) = list {
         let mut metadata_map = Metadata::new();
         for kv in list {
             let kv = kv?;
@@ -60,6 +73,14 @@ fn read_metadata(field: &arrow_format::ipc::FieldRef) -> PolarsResult<Metadata>
     })
 }
 
+/// Reads the custom metadata of the IPC schema. Returns `None` if the schema has no metadata.
+pub(super) fn read_custom_schema_metadata(
+    schema: arrow_format::ipc::SchemaRef,
+) -> PolarsResult<Option<Arc<Metadata>>> {
+    let metadata = read_key_values(schema.custom_metadata()?)?;
+    Ok((!metadata.is_empty()).then(|| Arc::new(metadata)))
+}
+
 fn deserialize_integer(int: arrow_format::ipc::IntRef) -> PolarsResult<IntegerType> {
     Ok(match (int.bit_width()?, int.is_signed()?) {
         (8, true) => IntegerType::Int8,
@@ -254,10 +275,12 @@ fn get_dtype(
     if let Some(extension) = extension {
         let (name, metadata) = extension;
         let (dtype, fields) = get_dtype(field, None, false)?;
-        return Ok((
-            ArrowDataType::Extension(name, Box::new(dtype), metadata),
-            fields,
-        ));
+        let dtype = match (name.as_str(), &dtype) {
+            (INT128_EXTENSION_NAME, ArrowDataType::Decimal(_, _)) => ArrowDataType::Int128,
+            (UINT128_EXTENSION_NAME, ArrowDataType::FixedSizeBinary(16)) => ArrowDataType::UInt128,
+            _ => ArrowDataType::Extension(name, Box::new(dtype), metadata),
+        };
+        return Ok((dtype, fields));
     }
 
     let type_ = field
@@ -415,11 +438,13 @@ pub(super) fn deserialize_stream_metadata(meta: &[u8]) -> PolarsResult<StreamMet
     } else {
         polars_bail!(oos = "The first IPC message of the stream must be a schema")
     };
+    let custom_schema_metadata = read_custom_schema_metadata(schema)?;
     let (schema, ipc_schema) = fb_to_schema(schema)?;
 
     Ok(StreamMetadata {
         schema,
         version,
         ipc_schema,
+        custom_schema_metadata,
     })
 }
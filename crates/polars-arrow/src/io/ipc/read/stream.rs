@@ -1,4 +1,5 @@
-use std::io::Read;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
 
 use arrow_format::ipc::planus::ReadAsRoot;
 use polars_error::{polars_bail, polars_err, PolarsError, PolarsResult};
@@ -8,7 +9,7 @@ use super::common::*;
 use super::schema::deserialize_stream_metadata;
 use super::{Dictionaries, OutOfSpecKind};
 use crate::array::Array;
-use crate::datatypes::ArrowSchema;
+use crate::datatypes::{ArrowSchema, Metadata};
 use crate::io::ipc::IpcSchema;
 use crate::record_batch::RecordBatchT;
 
@@ -23,6 +24,9 @@ pub struct StreamMetadata {
 
     /// The IPC fields tracking dictionaries
     pub ipc_schema: IpcSchema,
+
+    /// The custom metadata of the schema
+    pub custom_schema_metadata: Option<Arc<Metadata>>,
 }
 
 /// Reads the metadata of the stream
@@ -53,6 +57,55 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> PolarsResult<StreamMetad
     deserialize_stream_metadata(&buffer)
 }
 
+/// Get the number of rows of an Arrow stream by only reading the headers of its messages.
+///
+/// The reader must be positioned after the schema message, e.g. by calling
+/// [`read_stream_metadata`] first.
+pub fn get_stream_row_count<R: Read + Seek>(reader: &mut R) -> PolarsResult<i64> {
+    let mut message_buffer = vec![];
+    let mut num_rows = 0;
+    loop {
+        let mut meta_length: [u8; 4] = [0; 4];
+        match reader.read_exact(&mut meta_length) {
+            Ok(()) => (),
+            // A stream may end without the end-of-stream marker.
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(num_rows),
+            Err(e) => return Err(e.into()),
+        }
+        if meta_length == CONTINUATION_MARKER {
+            reader.read_exact(&mut meta_length)?;
+        }
+        let meta_length: usize = i32::from_le_bytes(meta_length)
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::NegativeFooterLength))?;
+        if meta_length == 0 {
+            return Ok(num_rows);
+        }
+
+        message_buffer.clear();
+        message_buffer.try_reserve(meta_length)?;
+        reader
+            .by_ref()
+            .take(meta_length as u64)
+            .read_to_end(&mut message_buffer)?;
+
+        let message = arrow_format::ipc::MessageRef::read_as_root(message_buffer.as_ref())
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+        let header = message
+            .header()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferHeader(err)))?
+            .ok_or_else(|| polars_err!(oos = OutOfSpecKind::MissingMessageHeader))?;
+        let block_length = message
+            .body_length()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?;
+
+        if let arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) = header {
+            num_rows += batch.length().map_err(PolarsError::from)?;
+        }
+        reader.seek(SeekFrom::Current(block_length))?;
+    }
+}
+
 /// Encodes the stream's status after each read.
 ///
 /// A stream is an iterator, and an iterator returns `Option<Item>`. The `Item`
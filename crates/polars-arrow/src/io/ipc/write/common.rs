@@ -28,14 +28,18 @@ pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// Whether a dictionary that extends a previously written dictionary is written as a delta
+    /// containing only the new values, instead of replacing the whole dictionary.
+    pub dictionary_deltas: bool,
 }
 
-/// Find the dictionary that are new and need to be encoded.
+/// Find the dictionary that are new and need to be encoded, together with whether they are
+/// deltas of a previously written dictionary.
 pub fn dictionaries_to_encode(
     field: &IpcField,
     array: &dyn Array,
     dictionary_tracker: &mut DictionaryTracker,
-    dicts_to_encode: &mut Vec<(i64, Box<dyn Array>)>,
+    dicts_to_encode: &mut Vec<(i64, Box<dyn Array>, bool)>,
 ) -> PolarsResult<()> {
     use PhysicalType::*;
     match array.dtype().to_physical_type() {
@@ -45,11 +49,26 @@ pub fn dictionaries_to_encode(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| polars_err!(InvalidOperation: "Dictionaries must have an associated id"))?;
 
-            if dictionary_tracker.insert(dict_id, array)? {
-                dicts_to_encode.push((dict_id, array.to_boxed()));
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+
+            match dictionary_tracker.insert(dict_id, array)? {
+                Some(DictionaryUpdate::Full) => {
+                    dicts_to_encode.push((dict_id, array.to_boxed(), false))
+                },
+                Some(DictionaryUpdate::Delta(offset)) => {
+                    // Only the new values of a delta are written.
+                    let values = array.values();
+                    let values = values.sliced(offset, values.len() - offset);
+                    let delta = DictionaryArray::<$T>::try_new(
+                        array.dtype().clone(),
+                        PrimitiveArray::new_empty(array.keys().dtype().clone()),
+                        values,
+                    )?;
+                    dicts_to_encode.push((dict_id, delta.boxed(), true));
+                },
+                None => {},
             }
 
-            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
             let values = array.values();
             // @Q? Should this not pick fields[0]?
             dictionaries_to_encode(field,
@@ -147,6 +166,7 @@ pub fn dictionaries_to_encode(
 pub fn encode_dictionary(
     dict_id: i64,
     array: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     encoded_dictionaries: &mut Vec<EncodedData>,
 ) -> PolarsResult<()> {
@@ -159,6 +179,7 @@ pub fn encode_dictionary(
         encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
             dict_id,
             array,
+            is_delta,
             options,
             is_native_little_endian(),
         ));
@@ -176,8 +197,14 @@ pub fn encode_new_dictionaries(
 ) -> PolarsResult<()> {
     let mut dicts_to_encode = Vec::new();
     dictionaries_to_encode(field, array, dictionary_tracker, &mut dicts_to_encode)?;
-    for (dict_id, dict_array) in dicts_to_encode {
-        encode_dictionary(dict_id, dict_array.as_ref(), options, encoded_dictionaries)?;
+    for (dict_id, dict_array, is_delta) in dicts_to_encode {
+        encode_dictionary(
+            dict_id,
+            dict_array.as_ref(),
+            is_delta,
+            options,
+            encoded_dictionaries,
+        )?;
     }
     Ok(())
 }
@@ -265,6 +292,10 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
         },
+        ArrowDataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            set_variadic_buffer_counts(counts, array.field().as_ref())
+        },
         // Don't traverse dictionary values as those are set when the `Dictionary` IPC struct
         // is read.
         ArrowDataType::Dictionary(_, _, _) => (),
@@ -364,6 +395,7 @@ pub fn encode_record_batch(
 fn dictionary_batch_to_bytes<K: DictionaryKey>(
     dict_id: i64,
     array: &DictionaryArray<K>,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -404,7 +436,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     compression,
                     variadic_buffer_counts,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -426,19 +458,28 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
 pub struct DictionaryTracker {
     pub dictionaries: Dictionaries,
     pub cannot_replace: bool,
+    /// Whether an update that only appends values to an existing dictionary is emitted as a delta.
+    pub emit_deltas: bool,
 }
 
 impl DictionaryTracker {
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
+    /// * If this ID has been written already and has the same data, return `Ok(None)` to indicate
     ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already, this tracker emits deltas and the data only appends
+    ///   values to it, return `Ok(Some(DictionaryUpdate::Delta(_)))` with the number of values
+    ///   that were written before.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
-    ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<bool> {
+    ///   has never been seen before, return `Ok(Some(DictionaryUpdate::Full))` to indicate that
+    ///   the dictionary was just inserted.
+    pub fn insert(
+        &mut self,
+        dict_id: i64,
+        array: &dyn Array,
+    ) -> PolarsResult<Option<DictionaryUpdate>> {
         let values = match array.dtype() {
             ArrowDataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -456,7 +497,14 @@ impl DictionaryTracker {
         if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(None);
+            } else if self.emit_deltas
+                && values.len() > last.len()
+                && values.sliced(0, last.len()).as_ref() == last.as_ref()
+            {
+                let offset = last.len();
+                self.dictionaries.insert(dict_id, values.clone());
+                return Ok(Some(DictionaryUpdate::Delta(offset)));
             } else if self.cannot_replace {
                 polars_bail!(InvalidOperation:
                     "Dictionary replacement detected when writing IPC file format. \
@@ -467,10 +515,19 @@ impl DictionaryTracker {
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(Some(DictionaryUpdate::Full))
     }
 }
 
+/// A dictionary that must be written by a [`DictionaryTracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryUpdate {
+    /// All values of the dictionary.
+    Full,
+    /// Only the values after the given number of values that were written before.
+    Delta(usize),
+}
+
 /// Stores the encoded data, which is an ipc::Schema::Message, and optional Arrow data
 #[derive(Debug, Default)]
 pub struct EncodedData {
@@ -7,7 +7,7 @@ pub(crate) mod writer;
 
 pub use common::{
     dictionaries_to_encode, encode_dictionary, encode_new_dictionaries, encode_record_batch,
-    Compression, DictionaryTracker, EncodedData, Record, WriteOptions,
+    Compression, DictionaryTracker, DictionaryUpdate, EncodedData, Record, WriteOptions,
 };
 pub use schema::schema_to_bytes;
 pub use serialize::write;
@@ -3,12 +3,17 @@ use arrow_format::ipc::planus::Builder;
 use super::super::IpcField;
 use crate::datatypes::{
     ArrowDataType, ArrowSchema, Field, IntegerType, IntervalUnit, Metadata, TimeUnit, UnionMode,
+    INT128_EXTENSION_NAME, UINT128_EXTENSION_NAME,
 };
 use crate::io::ipc::endianness::is_native_little_endian;
 
 /// Converts a [ArrowSchema] and [IpcField]s to a flatbuffers-encoded [arrow_format::ipc::Message].
-pub fn schema_to_bytes(schema: &ArrowSchema, ipc_fields: &[IpcField]) -> Vec<u8> {
-    let schema = serialize_schema(schema, ipc_fields);
+pub fn schema_to_bytes(
+    schema: &ArrowSchema,
+    ipc_fields: &[IpcField],
+    custom_metadata: Option<&Metadata>,
+) -> Vec<u8> {
+    let schema = serialize_schema(schema, ipc_fields, custom_metadata);
 
     let message = arrow_format::ipc::Message {
         version: arrow_format::ipc::MetadataVersion::V5,
@@ -24,6 +29,7 @@ pub fn schema_to_bytes(schema: &ArrowSchema, ipc_fields: &[IpcField]) -> Vec<u8>
 pub fn serialize_schema(
     schema: &ArrowSchema,
     ipc_fields: &[IpcField],
+    custom_metadata: Option<&Metadata>,
 ) -> arrow_format::ipc::Schema {
     let endianness = if is_native_little_endian() {
         arrow_format::ipc::Endianness::Little
@@ -37,7 +43,11 @@ pub fn serialize_schema(
         .map(|(field, ipc_field)| serialize_field(field, ipc_field))
         .collect::<Vec<_>>();
 
-    let custom_metadata = None;
+    let custom_metadata = custom_metadata.filter(|m| !m.is_empty()).map(|metadata| {
+        let mut kv_vec = vec![];
+        write_metadata(metadata, &mut kv_vec);
+        kv_vec
+    });
 
     arrow_format::ipc::Schema {
         endianness,
@@ -78,12 +88,15 @@ fn write_extension(
 pub(crate) fn serialize_field(field: &Field, ipc_field: &IpcField) -> arrow_format::ipc::Field {
     // custom metadata.
     let mut kv_vec = vec![];
-    if let ArrowDataType::Extension(name, _, metadata) = field.dtype() {
-        write_extension(
+    match field.dtype() {
+        ArrowDataType::Extension(name, _, metadata) => write_extension(
             name.as_str(),
             metadata.as_ref().map(|x| x.as_str()),
             &mut kv_vec,
-        );
+        ),
+        ArrowDataType::Int128 => write_extension(INT128_EXTENSION_NAME, None, &mut kv_vec),
+        ArrowDataType::UInt128 => write_extension(UINT128_EXTENSION_NAME, None, &mut kv_vec),
+        _ => {},
     }
 
     let type_ = serialize_type(field.dtype());
@@ -174,6 +187,14 @@ fn serialize_type(dtype: &ArrowDataType) -> arrow_format::ipc::Type {
             bit_width: 64,
             is_signed: true,
         })),
+        // 128-bit integers are not part of the IPC format; they are stored with the same layout
+        // as a decimal and fixed-size binary respectively and marked as an extension type.
+        Int128 => ipc::Type::Decimal(Box::new(ipc::Decimal {
+            precision: 38,
+            scale: 0,
+            bit_width: 128,
+        })),
+        UInt128 => ipc::Type::FixedSizeBinary(Box::new(ipc::FixedSizeBinary { byte_width: 16 })),
         Float16 => ipc::Type::FloatingPoint(Box::new(ipc::FloatingPoint {
             precision: ipc::Precision::Half,
         })),
@@ -264,10 +285,12 @@ fn serialize_children(
         | Int16
         | Int32
         | Int64
+        | Int128
         | UInt8
         | UInt16
         | UInt32
         | UInt64
+        | UInt128
         | Float16
         | Float32
         | Float64
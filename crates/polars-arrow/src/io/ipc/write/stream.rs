@@ -4,6 +4,7 @@
 //! however the `FileWriter` expects a reader that supports `Seek`ing
 
 use std::io::Write;
+use std::sync::Arc;
 
 use polars_error::{PolarsError, PolarsResult};
 
@@ -32,6 +33,9 @@ pub struct StreamWriter<W: Write> {
     dictionary_tracker: DictionaryTracker,
 
     ipc_fields: Option<Vec<IpcField>>,
+
+    /// Custom metadata of the schema
+    custom_schema_metadata: Option<Arc<Metadata>>,
 }
 
 impl<W: Write> StreamWriter<W> {
@@ -44,11 +48,18 @@ impl<W: Write> StreamWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                emit_deltas: write_options.dictionary_deltas,
             },
             ipc_fields: None,
+            custom_schema_metadata: None,
         }
     }
 
+    /// Sets the custom metadata of the schema. This must be called before the stream is started.
+    pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
+        self.custom_schema_metadata = Some(custom_metadata);
+    }
+
     /// Starts the stream by writing a Schema message to it.
     /// Use `ipc_fields` to declare dictionary ids in the schema, for dictionary-reuse
     pub fn start(
@@ -63,7 +74,11 @@ impl<W: Write> StreamWriter<W> {
         });
 
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(schema, self.ipc_fields.as_ref().unwrap()),
+            ipc_message: schema_to_bytes(
+                schema,
+                self.ipc_fields.as_ref().unwrap(),
+                self.custom_schema_metadata.as_deref(),
+            ),
             arrow_data: vec![],
         };
         write_message(&mut self.writer, &encoded_message)?;
@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::sync::Arc;
 
 use arrow_format::ipc::planus::Builder;
 use polars_error::{polars_bail, PolarsResult};
@@ -40,6 +41,8 @@ pub struct FileWriter<W: Write> {
     pub(crate) dictionary_tracker: DictionaryTracker,
     /// Buffer/scratch that is reused between writes
     pub(crate) encoded_message: EncodedData,
+    /// Custom metadata of the schema
+    pub(crate) custom_schema_metadata: Option<Arc<Metadata>>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -81,8 +84,10 @@ impl<W: Write> FileWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: true,
+                emit_deltas: options.dictionary_deltas,
             },
             encoded_message: Default::default(),
+            custom_schema_metadata: None,
         }
     }
 
@@ -91,6 +96,11 @@ impl<W: Write> FileWriter<W> {
         self.writer
     }
 
+    /// Sets the custom metadata of the schema. This must be called before the file is started.
+    pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
+        self.custom_schema_metadata = Some(custom_metadata);
+    }
+
     /// Get the inner memory scratches so they can be reused in a new writer.
     /// This can be utilized to save memory allocations for performance reasons.
     pub fn get_scratches(&mut self) -> EncodedData {
@@ -116,7 +126,11 @@ impl<W: Write> FileWriter<W> {
         // write the schema, set the written bytes to the schema
 
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(&self.schema, &self.ipc_fields),
+            ipc_message: schema_to_bytes(
+                &self.schema,
+                &self.ipc_fields,
+                self.custom_schema_metadata.as_deref(),
+            ),
             arrow_data: vec![],
         };
 
@@ -210,7 +224,11 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, 0)?;
 
-        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields);
+        let schema = schema::serialize_schema(
+            &self.schema,
+            &self.ipc_fields,
+            self.custom_schema_metadata.as_deref(),
+        );
 
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
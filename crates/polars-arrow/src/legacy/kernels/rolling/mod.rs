@@ -1,5 +1,6 @@
 pub mod no_nulls;
 pub mod nulls;
+pub mod order_statistics;
 pub mod quantile_filter;
 mod window;
 
@@ -29,6 +30,8 @@ type Len = usize;
 pub enum RollingFnParams {
     Quantile(RollingQuantileParams),
     Var(RollingVarParams),
+    Rank(RollingRankParams),
+    Skew(RollingSkewParams),
 }
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
@@ -89,6 +92,18 @@ pub struct RollingVarParams {
     pub ddof: u8,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingRankParams {
+    pub method: order_statistics::RollingRankMethod,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingSkewParams {
+    pub bias: bool,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RollingQuantileParams {
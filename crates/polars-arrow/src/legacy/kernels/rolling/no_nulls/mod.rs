@@ -1,6 +1,7 @@
 mod mean;
 mod min_max;
 mod quantile;
+mod skew;
 mod sum;
 mod variance;
 use std::fmt::Debug;
@@ -11,6 +12,7 @@ use num_traits::{Float, Num, NumCast};
 pub use quantile::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+pub use skew::*;
 use strum_macros::IntoStaticStr;
 pub use sum::*;
 pub use variance::*;
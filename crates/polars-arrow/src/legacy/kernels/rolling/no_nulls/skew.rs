@@ -0,0 +1,40 @@
+use super::*;
+
+/// Sample skewness of the values in the window, see `MomentSeries::skew` for the definition.
+pub struct SkewWindow<'a, T> {
+    slice: &'a [T],
+    bias: bool,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNoNulls<'a, T> for SkewWindow<'a, T> {
+    fn new(slice: &'a [T], _start: usize, _end: usize, params: Option<RollingFnParams>) -> Self {
+        let bias = match params {
+            Some(RollingFnParams::Skew(params)) => params.bias,
+            _ => true,
+        };
+        Self { slice, bias }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let values = self.slice.get_unchecked(start..end);
+        if values.is_empty() {
+            return None;
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().map(|v| v.to_f64().unwrap()).sum::<f64>() / n;
+        let (m2, m3) = values.iter().fold((0.0, 0.0), |(m2, m3), v| {
+            let d = v.to_f64().unwrap() - mean;
+            (m2 + d * d, m3 + d * d * d)
+        });
+        let (m2, m3) = (m2 / n, m3 / n);
+
+        let zero = m2 <= (f64::EPSILON * mean).powf(2.0);
+        let skew = if zero { f64::NAN } else { m3 / m2.powf(1.5) };
+        let skew = if !self.bias && !zero && n > 3.0 {
+            ((n - 1.0) * n).sqrt() / (n - 2.0) * skew
+        } else {
+            skew
+        };
+        NumCast::from(skew)
+    }
+}
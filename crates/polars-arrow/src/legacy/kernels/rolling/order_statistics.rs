@@ -0,0 +1,457 @@
+//! Rolling order statistics: median, median absolute deviation and rank.
+//!
+//! All values are ranked once up front, which sorts the whole array: `O(n log n)` time and `O(n)`
+//! memory for `n` values. The values that are in the window are then tracked in a Fenwick tree
+//! over the distinct ranks, so that moving the window by one value and selecting the k-th smallest
+//! value are both `O(log n)`, independent of the window size. The median absolute deviation
+//! binary searches over such selections and takes `O(log² n)` per window.
+use num_traits::{Float, NumCast};
+use polars_utils::total_ord::TotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+use super::{det_offsets, det_offsets_center};
+use crate::array::{ArrayRef, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::legacy::utils::CustomIterTools;
+use crate::types::NativeType;
+
+/// How ties are resolved when computing the rank of a value within its window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum RollingRankMethod {
+    /// The average of the ranks that would have been assigned to the tied values.
+    #[default]
+    Average,
+    /// The lowest rank of the tied values.
+    Min,
+    /// The highest rank of the tied values.
+    Max,
+}
+
+struct FenwickTree {
+    tree: Vec<u32>,
+    top_bit: usize,
+}
+
+impl FenwickTree {
+    fn new(n: usize) -> Self {
+        let top_bit = if n == 0 { 0 } else { 1 << n.ilog2() };
+        Self {
+            tree: vec![0; n + 1],
+            top_bit,
+        }
+    }
+
+    fn insert(&mut self, rank: usize) {
+        let mut i = rank + 1;
+        while i < self.tree.len() {
+            self.tree[i] += 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn remove(&mut self, rank: usize) {
+        let mut i = rank + 1;
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Number of inserted values with a rank strictly below `rank`.
+    fn count_less(&self, rank: usize) -> usize {
+        let mut i = rank;
+        let mut count = 0;
+        while i > 0 {
+            count += self.tree[i] as usize;
+            i &= i - 1;
+        }
+        count
+    }
+
+    /// Rank of the `k`-th (0-based) smallest inserted value.
+    fn select(&self, mut k: usize) -> usize {
+        let mut pos = 0;
+        let mut step = self.top_bit;
+        while step > 0 {
+            let next = pos + step;
+            if next < self.tree.len() && (self.tree[next] as usize) <= k {
+                pos = next;
+                k -= self.tree[next] as usize;
+            }
+            step >>= 1;
+        }
+        pos
+    }
+}
+
+struct RankedWindow<'a, T> {
+    validity: Option<&'a Bitmap>,
+    // Dense rank of every valid value in `values`.
+    ranks: Vec<u32>,
+    // The distinct values, sorted.
+    distinct: Vec<T>,
+    tree: FenwickTree,
+    start: usize,
+    end: usize,
+    n_valid: usize,
+}
+
+impl<'a, T: NativeType + TotalOrd> RankedWindow<'a, T> {
+    fn new(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        let is_valid = |i: usize| validity.map_or(true, |v| v.get_bit(i));
+        let mut idx = (0..values.len())
+            .filter(|&i| is_valid(i))
+            .collect::<Vec<_>>();
+        idx.sort_unstable_by(|&a, &b| values[a].tot_cmp(&values[b]));
+
+        let mut ranks = vec![0; values.len()];
+        let mut distinct: Vec<T> = Vec::new();
+        for i in idx {
+            let v = values[i];
+            if distinct.last().map_or(true, |last| last.tot_ne(&v)) {
+                distinct.push(v);
+            }
+            ranks[i] = (distinct.len() - 1) as u32;
+        }
+        let tree = FenwickTree::new(distinct.len());
+
+        Self {
+            validity,
+            ranks,
+            distinct,
+            tree,
+            start: 0,
+            end: 0,
+            n_valid: 0,
+        }
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity.map_or(true, |v| v.get_bit(i))
+    }
+
+    fn insert(&mut self, i: usize) {
+        if self.is_valid(i) {
+            self.tree.insert(self.ranks[i] as usize);
+            self.n_valid += 1;
+        }
+    }
+
+    fn remove(&mut self, i: usize) {
+        if self.is_valid(i) {
+            self.tree.remove(self.ranks[i] as usize);
+            self.n_valid -= 1;
+        }
+    }
+
+    fn update(&mut self, start: usize, end: usize) {
+        if start >= self.end {
+            for i in self.start..self.end {
+                self.remove(i);
+            }
+            for i in start..end {
+                self.insert(i);
+            }
+        } else {
+            for i in self.start..start {
+                self.remove(i);
+            }
+            for i in self.end..end {
+                self.insert(i);
+            }
+        }
+        self.start = start;
+        self.end = end;
+    }
+
+    /// The `k`-th (0-based) smallest value in the window.
+    fn kth(&self, k: usize) -> T {
+        self.distinct[self.tree.select(k)]
+    }
+
+    /// Number of values in the window that are smaller than `value`.
+    fn count_less_than(&self, value: T) -> usize {
+        let rank = self.distinct.partition_point(|v| v.tot_lt(&value));
+        self.tree.count_less(rank)
+    }
+}
+
+impl<T: NativeType + TotalOrd + Float> RankedWindow<'_, T> {
+    fn median(&self) -> T {
+        let n = self.n_valid;
+        let lower = self.kth((n - 1) / 2);
+        let upper = self.kth(n / 2);
+        midpoint(lower, upper)
+    }
+
+    fn median_absolute_deviation(&self) -> T {
+        let n = self.n_valid;
+        let median = self.median();
+        let n_below = self.count_less_than(median);
+
+        // The absolute deviations of the values below the median and of the values at or above
+        // the median are both sorted sequences. Select from their union without materializing it.
+        let below = |j: usize| median - self.kth(n_below - 1 - j);
+        let above = |j: usize| self.kth(n_below + j) - median;
+        let kth_deviation = |k: usize| {
+            // Find how many of the `k + 1` smallest deviations come from `below`.
+            let mut lo = (k + 1).saturating_sub(n - n_below);
+            let mut hi = std::cmp::min(k + 1, n_below);
+            while lo < hi {
+                let take_below = lo + (hi - lo) / 2;
+                let take_above = k + 1 - take_below;
+                if take_above > 0 && below(take_below).tot_lt(&above(take_above - 1)) {
+                    lo = take_below + 1;
+                } else {
+                    hi = take_below;
+                }
+            }
+            let take_below = lo;
+            let take_above = k + 1 - take_below;
+            match (take_below, take_above) {
+                (0, _) => above(take_above - 1),
+                (_, 0) => below(take_below - 1),
+                _ => {
+                    let (b, a) = (below(take_below - 1), above(take_above - 1));
+                    if b.tot_gt(&a) {
+                        b
+                    } else {
+                        a
+                    }
+                },
+            }
+        };
+
+        let lower = kth_deviation((n - 1) / 2);
+        let upper = kth_deviation(n / 2);
+        midpoint(lower, upper)
+    }
+
+    fn rank(&self, i: usize, method: RollingRankMethod) -> T {
+        let rank = self.ranks[i] as usize;
+        let less = self.tree.count_less(rank);
+        let equal = self.tree.count_less(rank + 1) - less;
+        let position = match method {
+            RollingRankMethod::Average => less as f64 + (equal as f64 + 1.0) / 2.0,
+            RollingRankMethod::Min => (less + 1) as f64,
+            RollingRankMethod::Max => (less + equal) as f64,
+        };
+        T::from(position / self.n_valid as f64).unwrap()
+    }
+}
+
+// Same as linear interpolation at the 0.5 quantile.
+fn midpoint<T: Float>(lower: T, upper: T) -> T {
+    if lower == upper {
+        lower
+    } else {
+        <T as NumCast>::from(0.5).unwrap() * (upper - lower) + lower
+    }
+}
+
+fn rolling_apply_ranked<T, F>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    mut f: F,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd,
+    F: FnMut(&RankedWindow<T>, usize) -> Option<T>,
+{
+    let len = values.len();
+    let det_offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    let min_periods = std::cmp::max(min_periods, 1);
+    let mut window = RankedWindow::new(values, validity);
+
+    let out: PrimitiveArray<T> = (0..len)
+        .map(|i| {
+            let (start, end) = det_offsets_fn(i, window_size, len);
+            window.update(start, end);
+            if window.n_valid < min_periods {
+                None
+            } else {
+                f(&window, i)
+            }
+        })
+        .collect_trusted();
+    Box::new(out)
+}
+
+/// Rolling median in `O(n log n)` time and `O(n)` memory for `n` values, ignoring null values.
+pub fn rolling_median<T>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd + Float,
+{
+    rolling_apply_ranked(
+        values,
+        validity,
+        window_size,
+        min_periods,
+        center,
+        |w, _| Some(w.median()),
+    )
+}
+
+/// Rolling median absolute deviation (the median of `|x - median|`), ignoring null values.
+///
+/// Takes `O(n log² n)` time and `O(n)` memory for `n` values.
+pub fn rolling_mad<T>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd + Float,
+{
+    rolling_apply_ranked(
+        values,
+        validity,
+        window_size,
+        min_periods,
+        center,
+        |w, _| Some(w.median_absolute_deviation()),
+    )
+}
+
+/// Rolling rank of every value within its window, expressed as a fraction in `(0, 1]` of the
+/// number of non-null values in the window. Null values get a null rank.
+pub fn rolling_rank<T>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    method: RollingRankMethod,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd + Float,
+{
+    rolling_apply_ranked(
+        values,
+        validity,
+        window_size,
+        min_periods,
+        center,
+        |w, i| w.is_valid(i).then(|| w.rank(i, method)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_vec(arr: ArrayRef) -> Vec<Option<f64>> {
+        let arr = arr.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        arr.into_iter().map(|v| v.copied()).collect()
+    }
+
+    fn naive_median(window: &[f64]) -> f64 {
+        let mut w = window.to_vec();
+        w.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = w.len();
+        midpoint(w[(n - 1) / 2], w[n / 2])
+    }
+
+    #[test]
+    fn test_rolling_median_mad() {
+        let values = &[1.0, 2.0, 3.0, 4.0];
+        let out = to_vec(rolling_median(values, None, 2, 2, false));
+        assert_eq!(out, &[None, Some(1.5), Some(2.5), Some(3.5)]);
+        let out = to_vec(rolling_median(values, None, 4, 1, true));
+        assert_eq!(out, &[Some(1.5), Some(2.0), Some(2.5), Some(3.0)]);
+
+        let values = &[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0];
+        let out = to_vec(rolling_mad(values, None, 7, 7, false));
+        assert_eq!(out[6], Some(1.0));
+
+        let validity = Bitmap::from([true, false, true, true]);
+        let values = &[1.0, 100.0, 3.0, 7.0];
+        let out = to_vec(rolling_median(values, Some(&validity), 3, 2, false));
+        assert_eq!(out, &[None, None, Some(2.0), Some(5.0)]);
+        let out = to_vec(rolling_mad(values, Some(&validity), 3, 1, false));
+        assert_eq!(out, &[Some(0.0), Some(0.0), Some(1.0), Some(2.0)]);
+    }
+
+    #[test]
+    fn test_rolling_median_mad_against_naive() {
+        let values = (0..200)
+            .map(|i| ((i * 7919) % 31) as f64 - 15.0)
+            .collect::<Vec<_>>();
+        for window_size in [1, 2, 5, 10] {
+            let medians = to_vec(rolling_median(&values, None, window_size, 1, false));
+            let mads = to_vec(rolling_mad(&values, None, window_size, 1, false));
+            for i in 0..values.len() {
+                let window = &values[i.saturating_sub(window_size - 1)..i + 1];
+                let median = naive_median(window);
+                let deviations = window
+                    .iter()
+                    .map(|v| (v - median).abs())
+                    .collect::<Vec<_>>();
+                assert_eq!(medians[i], Some(median));
+                assert_eq!(mads[i], Some(naive_median(&deviations)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_rank() {
+        let values = &[3.0, 1.0, 1.0, 2.0];
+        let out = to_vec(rolling_rank(
+            values,
+            None,
+            3,
+            1,
+            false,
+            RollingRankMethod::Average,
+        ));
+        assert_eq!(out, &[Some(1.0), Some(0.5), Some(0.5), Some(1.0)]);
+        let out = to_vec(rolling_rank(
+            values,
+            None,
+            3,
+            1,
+            false,
+            RollingRankMethod::Min,
+        ));
+        assert_eq!(out, &[Some(1.0), Some(0.5), Some(1.0 / 3.0), Some(1.0)]);
+        let out = to_vec(rolling_rank(
+            values,
+            None,
+            3,
+            1,
+            false,
+            RollingRankMethod::Max,
+        ));
+        assert_eq!(out, &[Some(1.0), Some(0.5), Some(2.0 / 3.0), Some(1.0)]);
+
+        let validity = Bitmap::from([true, false, true, true]);
+        let out = to_vec(rolling_rank(
+            values,
+            Some(&validity),
+            2,
+            1,
+            false,
+            RollingRankMethod::Average,
+        ));
+        assert_eq!(out, &[Some(1.0), None, Some(1.0), Some(1.0)]);
+    }
+}
@@ -11,6 +11,9 @@ use polars_error::{polars_bail, PolarsError};
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
 pub enum Ambiguous {
     Earliest,
     Latest,
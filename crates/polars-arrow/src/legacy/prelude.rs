@@ -3,8 +3,9 @@ pub use crate::legacy::array::default_arrays::*;
 pub use crate::legacy::array::*;
 pub use crate::legacy::index::*;
 pub use crate::legacy::kernels::rolling::no_nulls::QuantileMethod;
+pub use crate::legacy::kernels::rolling::order_statistics::RollingRankMethod;
 pub use crate::legacy::kernels::rolling::{
-    RollingFnParams, RollingQuantileParams, RollingVarParams,
+    RollingFnParams, RollingQuantileParams, RollingRankParams, RollingSkewParams, RollingVarParams,
 };
 pub use crate::legacy::kernels::{Ambiguous, NonExistent};
 
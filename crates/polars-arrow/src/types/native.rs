@@ -289,6 +289,21 @@ impl TotalOrd for months_days_ns {
     }
 }
 
+impl ToTotalOrd for months_days_ns {
+    type TotalOrdItem = months_days_ns;
+    type SourceItem = months_days_ns;
+
+    #[inline]
+    fn to_total_ord(&self) -> Self::TotalOrdItem {
+        *self
+    }
+
+    #[inline]
+    fn peel_total_ord(ord_item: Self::TotalOrdItem) -> Self::SourceItem {
+        ord_item
+    }
+}
+
 impl MinMax for months_days_ns {
     fn nan_min_lt(&self, other: &Self) -> bool {
         self < other
@@ -149,10 +149,12 @@ impl_bitwise_kernel! {
     (i16, identity, identity),
     (i32, identity, identity),
     (i64, identity, identity),
+    (i128, identity, identity),
     (u8, identity, identity),
     (u16, identity, identity),
     (u32, identity, identity),
     (u64, identity, identity),
+    (u128, identity, identity),
     (f32, f32::to_bits, f32::from_bits),
     (f64, f64::to_bits, f64::from_bits),
 }
@@ -402,6 +402,8 @@ pub fn cast(
                 Int16 => binview_to_primitive_dyn::<i16>(&arr.to_binview(), to_type, options),
                 Int32 => binview_to_primitive_dyn::<i32>(&arr.to_binview(), to_type, options),
                 Int64 => binview_to_primitive_dyn::<i64>(&arr.to_binview(), to_type, options),
+                Int128 => binview_to_primitive_dyn::<i128>(&arr.to_binview(), to_type, options),
+                UInt128 => binview_to_primitive_dyn::<u128>(&arr.to_binview(), to_type, options),
                 Float32 => binview_to_primitive_dyn::<f32>(&arr.to_binview(), to_type, options),
                 Float64 => binview_to_primitive_dyn::<f64>(&arr.to_binview(), to_type, options),
                 Timestamp(time_unit, None) => {
@@ -430,6 +432,8 @@ pub fn cast(
             Int16 => primitive_to_boolean_dyn::<i16>(array, to_type.clone()),
             Int32 => primitive_to_boolean_dyn::<i32>(array, to_type.clone()),
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
+            Int128 => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
+            UInt128 => primitive_to_boolean_dyn::<u128>(array, to_type.clone()),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
             Decimal(_, _) => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
@@ -446,6 +450,8 @@ pub fn cast(
             Int16 => boolean_to_primitive_dyn::<i16>(array),
             Int32 => boolean_to_primitive_dyn::<i32>(array),
             Int64 => boolean_to_primitive_dyn::<i64>(array),
+            Int128 => boolean_to_primitive_dyn::<i128>(array),
+            UInt128 => boolean_to_primitive_dyn::<u128>(array),
             Float32 => boolean_to_primitive_dyn::<f32>(array),
             Float64 => boolean_to_primitive_dyn::<f64>(array),
             Utf8View => boolean_to_utf8view_dyn(array),
@@ -516,6 +522,8 @@ pub fn cast(
             Int16 => binary_to_primitive_dyn::<i64, i16>(array, to_type, options),
             Int32 => binary_to_primitive_dyn::<i64, i32>(array, to_type, options),
             Int64 => binary_to_primitive_dyn::<i64, i64>(array, to_type, options),
+            Int128 => binary_to_primitive_dyn::<i64, i128>(array, to_type, options),
+            UInt128 => binary_to_primitive_dyn::<i64, u128>(array, to_type, options),
             Float32 => binary_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => binary_to_primitive_dyn::<i64, f64>(array, to_type, options),
             Binary => {
@@ -638,6 +646,18 @@ pub fn cast(
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         },
+        (Float16, Float64) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f16_to_f64(from).boxed())
+        },
+        (Float32, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f32_to_f16(from).boxed())
+        },
+        (Float64, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f64_to_f16(from).boxed())
+        },
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
@@ -672,6 +692,56 @@ pub fn cast(
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+
+        (UInt8, Int128) => primitive_to_primitive_dyn::<u8, i128>(array, to_type, as_options),
+        (UInt16, Int128) => primitive_to_primitive_dyn::<u16, i128>(array, to_type, as_options),
+        (UInt32, Int128) => primitive_to_primitive_dyn::<u32, i128>(array, to_type, as_options),
+        (UInt64, Int128) => primitive_to_primitive_dyn::<u64, i128>(array, to_type, as_options),
+        (Int8, Int128) => primitive_to_primitive_dyn::<i8, i128>(array, to_type, as_options),
+        (Int16, Int128) => primitive_to_primitive_dyn::<i16, i128>(array, to_type, as_options),
+        (Int32, Int128) => primitive_to_primitive_dyn::<i32, i128>(array, to_type, as_options),
+        (Int64, Int128) => primitive_to_primitive_dyn::<i64, i128>(array, to_type, as_options),
+        (Float32, Int128) => primitive_to_primitive_dyn::<f32, i128>(array, to_type, options),
+        (Float64, Int128) => primitive_to_primitive_dyn::<f64, i128>(array, to_type, options),
+        (UInt8, UInt128) => primitive_to_primitive_dyn::<u8, u128>(array, to_type, as_options),
+        (UInt16, UInt128) => primitive_to_primitive_dyn::<u16, u128>(array, to_type, as_options),
+        (UInt32, UInt128) => primitive_to_primitive_dyn::<u32, u128>(array, to_type, as_options),
+        (UInt64, UInt128) => primitive_to_primitive_dyn::<u64, u128>(array, to_type, as_options),
+        (Int8, UInt128) => primitive_to_primitive_dyn::<i8, u128>(array, to_type, options),
+        (Int16, UInt128) => primitive_to_primitive_dyn::<i16, u128>(array, to_type, options),
+        (Int32, UInt128) => primitive_to_primitive_dyn::<i32, u128>(array, to_type, options),
+        (Int64, UInt128) => primitive_to_primitive_dyn::<i64, u128>(array, to_type, options),
+        (Float32, UInt128) => primitive_to_primitive_dyn::<f32, u128>(array, to_type, options),
+        (Float64, UInt128) => primitive_to_primitive_dyn::<f64, u128>(array, to_type, options),
+
+        (Int128, UInt8) => primitive_to_primitive_dyn::<i128, u8>(array, to_type, options),
+        (Int128, UInt16) => primitive_to_primitive_dyn::<i128, u16>(array, to_type, options),
+        (Int128, UInt32) => primitive_to_primitive_dyn::<i128, u32>(array, to_type, options),
+        (Int128, UInt64) => primitive_to_primitive_dyn::<i128, u64>(array, to_type, options),
+        (Int128, Int8) => primitive_to_primitive_dyn::<i128, i8>(array, to_type, options),
+        (Int128, Int16) => primitive_to_primitive_dyn::<i128, i16>(array, to_type, options),
+        (Int128, Int32) => primitive_to_primitive_dyn::<i128, i32>(array, to_type, options),
+        (Int128, Int64) => primitive_to_primitive_dyn::<i128, i64>(array, to_type, options),
+        (Int128, Float32) => primitive_to_primitive_dyn::<i128, f32>(array, to_type, as_options),
+        (Int128, Float64) => primitive_to_primitive_dyn::<i128, f64>(array, to_type, as_options),
+        (Int128, UInt128) => primitive_to_primitive_dyn::<i128, u128>(array, to_type, options),
+        (Int128, Decimal(p, s)) => integer_to_decimal_dyn::<i128>(array, *p, *s),
+
+        (UInt128, UInt8) => primitive_to_primitive_dyn::<u128, u8>(array, to_type, options),
+        (UInt128, UInt16) => primitive_to_primitive_dyn::<u128, u16>(array, to_type, options),
+        (UInt128, UInt32) => primitive_to_primitive_dyn::<u128, u32>(array, to_type, options),
+        (UInt128, UInt64) => primitive_to_primitive_dyn::<u128, u64>(array, to_type, options),
+        (UInt128, Int8) => primitive_to_primitive_dyn::<u128, i8>(array, to_type, options),
+        (UInt128, Int16) => primitive_to_primitive_dyn::<u128, i16>(array, to_type, options),
+        (UInt128, Int32) => primitive_to_primitive_dyn::<u128, i32>(array, to_type, options),
+        (UInt128, Int64) => primitive_to_primitive_dyn::<u128, i64>(array, to_type, options),
+        (UInt128, Float32) => primitive_to_primitive_dyn::<u128, f32>(array, to_type, as_options),
+        (UInt128, Float64) => primitive_to_primitive_dyn::<u128, f64>(array, to_type, as_options),
+        (UInt128, Int128) => primitive_to_primitive_dyn::<u128, i128>(array, to_type, options),
+        (UInt128, Decimal(p, s)) => integer_to_decimal_dyn::<u128>(array, *p, *s),
+
+        (Decimal(_, _), Int128) => decimal_to_integer_dyn::<i128>(array),
+        (Decimal(_, _), UInt128) => decimal_to_integer_dyn::<u128>(array),
         // end numeric casts
 
         // temporal casts
@@ -788,6 +858,8 @@ fn from_to_binview(
         Int16 => primitive_to_binview_dyn::<i16>(array),
         Int32 => primitive_to_binview_dyn::<i32>(array),
         Int64 => primitive_to_binview_dyn::<i64>(array),
+        Int128 => primitive_to_binview_dyn::<i128>(array),
+        UInt128 => primitive_to_binview_dyn::<u128>(array),
         Float32 => primitive_to_binview_dyn::<f32>(array),
         Float64 => primitive_to_binview_dyn::<f64>(array),
         Binary => binary_to_binview::<i32>(array.as_any().downcast_ref().unwrap()),
@@ -39,10 +39,12 @@ impl_ser_primitive!(i8);
 impl_ser_primitive!(i16);
 impl_ser_primitive!(i32);
 impl_ser_primitive!(i64);
+impl_ser_primitive!(i128);
 impl_ser_primitive!(u8);
 impl_ser_primitive!(u16);
 impl_ser_primitive!(u32);
 impl_ser_primitive!(u64);
+impl_ser_primitive!(u128);
 
 impl SerPrimitive for f32 {
     fn write(f: &mut Vec<u8>, val: Self) -> usize
@@ -173,7 +175,7 @@ where
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
-pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
+pub fn integer_to_decimal<T: NativeType + num_traits::NumCast>(
     from: &PrimitiveArray<T>,
     to_precision: usize,
     to_scale: usize,
@@ -187,13 +189,15 @@ pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
 
     let values = from.iter().map(|x| {
         x.and_then(|x| {
-            x.as_().checked_mul(multiplier).and_then(|x| {
-                if x > max_for_precision || x < min_for_precision {
-                    None
-                } else {
-                    Some(x)
-                }
-            })
+            <i128 as num_traits::NumCast>::from(*x)?
+                .checked_mul(multiplier)
+                .and_then(|x| {
+                    if x > max_for_precision || x < min_for_precision {
+                        None
+                    } else {
+                        Some(x)
+                    }
+                })
         })
     });
 
@@ -207,7 +211,7 @@ pub(super) fn integer_to_decimal_dyn<T>(
     scale: usize,
 ) -> PolarsResult<Box<dyn Array>>
 where
-    T: NativeType + AsPrimitive<i128>,
+    T: NativeType + num_traits::NumCast,
 {
     let from = from.as_any().downcast_ref().unwrap();
     Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
@@ -530,6 +534,21 @@ pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), ArrowDataType::Float32)
 }
 
+/// Casts f16 into f64
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f32() as f64, ArrowDataType::Float64)
+}
+
+/// Casts f32 into f16, rounding to the nearest representable value.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, ArrowDataType::Float16)
+}
+
+/// Casts f64 into f16, rounding to the nearest representable value.
+pub fn f64_to_f16(from: &PrimitiveArray<f64>) -> PrimitiveArray<f16> {
+    unary(from, |x| f16::from_f32(x as f32), ArrowDataType::Float16)
+}
+
 /// Returns a [`Utf8Array`] where every element is the utf8 representation of the number.
 pub(super) fn primitive_to_binview<T: NativeType + SerPrimitive>(
     from: &PrimitiveArray<T>,
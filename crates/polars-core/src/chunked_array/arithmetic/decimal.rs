@@ -1,13 +1,55 @@
 use super::*;
+use crate::prelude::arity::broadcast_try_binary_elementwise;
+
+/// Apply `op` to the physical values, checking the results fit in `dtype`.
+///
+/// `op` returns `None` on overflow and `Some(None)` for a null result.
+fn checked_decimal_arithmetic<F>(
+    lhs: &DecimalChunked,
+    rhs: &DecimalChunked,
+    dtype: DataType,
+    op: F,
+) -> PolarsResult<DecimalChunked>
+where
+    F: Fn(i128, i128) -> Option<Option<i128>>,
+{
+    let DataType::Decimal(precision, Some(scale)) = dtype else {
+        unreachable!()
+    };
+    let ca: Int128Chunked =
+        broadcast_try_binary_elementwise(&lhs.0, &rhs.0, |l, r| match (l, r) {
+            (Some(l), Some(r)) => op(l, r)
+                .and_then(|v| match v {
+                    Some(v) => decimal_fit_precision(v, precision).map(Some),
+                    None => Some(None),
+                })
+                .ok_or_else(|| decimal_overflow_err(&dtype)),
+            _ => Ok(None),
+        })?;
+    Ok(ca.into_decimal_unchecked(precision, scale))
+}
+
+/// Multiply by `10^exp`, returning `None` on overflow.
+#[inline]
+fn checked_rescale(v: i128, exp: usize) -> Option<i128> {
+    if v == 0 {
+        return Some(0);
+    }
+    v.checked_mul(checked_pow10(exp)?)
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        let (s1, s2) = (self.scale(), rhs.scale());
+        let dtype = decimal_add_dtype(self.precision(), s1, rhs.precision(), s2);
+        let scale = s1.max(s2);
+        checked_decimal_arithmetic(self, rhs, dtype, |l, r| {
+            let l = checked_rescale(l, scale - s1)?;
+            let r = checked_rescale(r, scale - s2)?;
+            l.checked_add(r).map(Some)
+        })
     }
 }
 
@@ -15,10 +57,14 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        let (s1, s2) = (self.scale(), rhs.scale());
+        let dtype = decimal_add_dtype(self.precision(), s1, rhs.precision(), s2);
+        let scale = s1.max(s2);
+        checked_decimal_arithmetic(self, rhs, dtype, |l, r| {
+            let l = checked_rescale(l, scale - s1)?;
+            let r = checked_rescale(r, scale - s2)?;
+            l.checked_sub(r).map(Some)
+        })
     }
 }
 
@@ -26,18 +72,29 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let scale = self.scale() + rhs.scale();
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        let dtype =
+            decimal_mul_dtype(self.precision(), self.scale(), rhs.precision(), rhs.scale())?;
+        checked_decimal_arithmetic(self, rhs, dtype, |l, r| l.checked_mul(r).map(Some))
     }
 }
 
 impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
+    /// Divide, rounding half away from zero. Dividing by zero gives null.
     fn div(self, rhs: Self) -> Self::Output {
-        // Follow postgres and MySQL adding a fixed scale increment of 4
-        let scale = self.scale() + 4;
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(None, scale))
+        let (s1, s2) = (self.scale(), rhs.scale());
+        let dtype = decimal_div_dtype(self.precision(), s1, rhs.precision(), s2);
+        let DataType::Decimal(_, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        // `l / r` is at scale `s1 - s2`, so scale the dividend up to land on `scale`.
+        let shift = scale + s2 - s1;
+        checked_decimal_arithmetic(self, rhs, dtype, |l, r| {
+            if r == 0 {
+                return Some(None);
+            }
+            decimal_div_round(checked_rescale(l, shift)?, r).map(Some)
+        })
     }
 }
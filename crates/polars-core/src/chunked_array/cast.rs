@@ -75,6 +75,13 @@ fn cast_impl_inner(
     dtype: &DataType,
     options: CastOptions,
 ) -> PolarsResult<Series> {
+    // Casting to the physical `UInt16` would truncate, so round through `Float32` instead.
+    #[cfg(feature = "dtype-f16")]
+    if let DataType::Float16 = dtype {
+        let chunks = cast_chunks(chunks, &DataType::Float32, options)?;
+        let chunks = cast_chunks(&chunks, dtype, options)?;
+        return Series::try_from((name, chunks));
+    }
     let chunks = cast_chunks(chunks, &dtype.to_physical(), options)?;
     let out = Series::try_from((name, chunks))?;
     use DataType::*;
@@ -91,6 +98,8 @@ fn cast_impl_inner(
         Duration(tu) => out.into_duration(*tu),
         #[cfg(feature = "dtype-time")]
         Time => out.into_time(),
+        #[cfg(feature = "dtype-interval")]
+        Interval => out.into_interval(),
         _ => out,
     };
 
@@ -255,6 +264,13 @@ where
                     polars_bail!(ComputeError: "cannot cast numeric types to 'Categorical'");
                 }
             },
+            // The physical bits are reinterpreted, not converted.
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 if self.dtype() == &DataType::UInt16 => {
+                // SAFETY: we are guarded by the type system.
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt16Chunked) };
+                Ok(ca.clone().into_float16().into_series())
+            },
             _ => self.cast_impl(dtype, CastOptions::Overflowing),
         }
     }
@@ -469,6 +485,9 @@ impl ChunkCast for ListChunked {
                     },
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            // The struct fields are matched by position, so first reinterpret as a map.
+            Map(_, _) => self.clone().into_map()?.cast_with_options(dtype, options),
             #[cfg(feature = "dtype-array")]
             Array(child_type, width) => {
                 let physical_type = dtype.to_physical();
@@ -6,6 +6,145 @@ use crate::prelude::*;
 
 pub type DecimalChunked = Logical<DecimalType, Int128Type>;
 
+/// The maximum precision of a decimal, bounded by its 128-bit backing integer.
+pub const DEC128_MAX_PRECISION: usize = 38;
+
+/// The scale a division or mean keeps at least, even if that costs precision.
+const DEC128_MIN_DIV_SCALE: usize = 6;
+
+fn decimal_dtype(precision: Option<usize>, scale: usize) -> DataType {
+    DataType::Decimal(precision, Some(scale))
+}
+
+/// Cap a derived precision at 38, giving up fractional digits before integral ones.
+///
+/// This follows the adjustment SQL Server and Spark apply to derived decimal types.
+fn adjust_precision_scale(precision: usize, scale: usize) -> (usize, usize) {
+    if precision <= DEC128_MAX_PRECISION {
+        return (precision, scale);
+    }
+    let int_digits = precision - scale;
+    let min_scale = scale.min(DEC128_MIN_DIV_SCALE);
+    let scale = DEC128_MAX_PRECISION
+        .saturating_sub(int_digits)
+        .max(min_scale);
+    (DEC128_MAX_PRECISION, scale)
+}
+
+/// Output type of adding or subtracting decimals: `(max(p1 - s1, p2 - s2) + s + 1, max(s1, s2))`.
+pub fn decimal_add_dtype(p1: Option<usize>, s1: usize, p2: Option<usize>, s2: usize) -> DataType {
+    let scale = s1.max(s2);
+    let precision = p1.zip(p2).map(|(p1, p2)| {
+        (p1.saturating_sub(s1).max(p2.saturating_sub(s2)) + scale + 1).min(DEC128_MAX_PRECISION)
+    });
+    decimal_dtype(precision, scale)
+}
+
+/// Output type of multiplying decimals: `(p1 + p2 + 1, s1 + s2)`.
+pub fn decimal_mul_dtype(
+    p1: Option<usize>,
+    s1: usize,
+    p2: Option<usize>,
+    s2: usize,
+) -> PolarsResult<DataType> {
+    let scale = s1 + s2;
+    polars_ensure!(
+        scale <= DEC128_MAX_PRECISION,
+        InvalidOperation: "decimal multiplication would need scale {} which exceeds the maximum of {}",
+        scale, DEC128_MAX_PRECISION
+    );
+    let precision = p1
+        .zip(p2)
+        .map(|(p1, p2)| (p1 + p2 + 1).min(DEC128_MAX_PRECISION));
+    Ok(decimal_dtype(precision, scale))
+}
+
+/// Output type of dividing decimals: `(p1 - s1 + s2 + s, max(6, s1 + p2 + 1))`.
+///
+/// A missing precision counts as 38 when deriving the scale.
+pub fn decimal_div_dtype(p1: Option<usize>, s1: usize, p2: Option<usize>, s2: usize) -> DataType {
+    let lhs_precision = p1.unwrap_or(DEC128_MAX_PRECISION);
+    let rhs_precision = p2.unwrap_or(DEC128_MAX_PRECISION);
+    let scale = DEC128_MIN_DIV_SCALE.max(s1 + rhs_precision + 1);
+    let precision = lhs_precision.saturating_sub(s1) + s2 + scale;
+    let (precision, scale) = adjust_precision_scale(precision, scale);
+    decimal_dtype(p1.zip(p2).map(|_| precision), scale)
+}
+
+/// Output type of summing decimals: `(38, s)`.
+pub fn decimal_sum_dtype(precision: Option<usize>, scale: usize) -> DataType {
+    decimal_dtype(precision.map(|_| DEC128_MAX_PRECISION), scale)
+}
+
+/// Output type of averaging decimals: `(38, max(s, 6))`.
+pub fn decimal_mean_dtype(precision: Option<usize>, scale: usize) -> DataType {
+    decimal_dtype(
+        precision.map(|_| DEC128_MAX_PRECISION),
+        scale.max(DEC128_MIN_DIV_SCALE),
+    )
+}
+
+/// Output type of rounding decimals to `decimals` fractional digits.
+///
+/// One integral digit is added to hold a carry, e.g. rounding `9.99` to `10.0`.
+pub fn decimal_round_dtype(precision: Option<usize>, scale: usize, decimals: u32) -> DataType {
+    let decimals = decimals as usize;
+    if decimals >= scale {
+        return decimal_dtype(precision, scale);
+    }
+    let precision =
+        precision.map(|p| (p.saturating_sub(scale) + decimals + 1).min(DEC128_MAX_PRECISION));
+    decimal_dtype(precision, decimals)
+}
+
+/// `10^exp`, or `None` if that doesn't fit in an `i128`.
+#[inline]
+pub fn checked_pow10(exp: usize) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+/// Divide rounding half away from zero, as SQL does. Returns `None` on overflow.
+#[inline]
+pub fn decimal_div_round(n: i128, d: i128) -> Option<i128> {
+    let q = n.checked_div(d)?;
+    let r = n % d;
+    // `|r| >= |d| - |r|` is `2 * |r| >= |d|` without overflowing.
+    if r.unsigned_abs() >= d.unsigned_abs() - r.unsigned_abs() {
+        if (n < 0) == (d < 0) {
+            q.checked_add(1)
+        } else {
+            q.checked_sub(1)
+        }
+    } else {
+        Some(q)
+    }
+}
+
+/// Return `v` if it fits in `precision` digits (38 if unknown), `None` otherwise.
+#[inline]
+pub fn decimal_fit_precision(v: i128, precision: Option<usize>) -> Option<i128> {
+    let precision = precision.unwrap_or(DEC128_MAX_PRECISION);
+    (v.unsigned_abs() < 10u128.pow(precision as u32)).then_some(v)
+}
+
+/// The mean of `count` values that sum to `sum` at `in_scale`, expressed at `out_scale`.
+///
+/// Returns `None` on overflow.
+pub fn decimal_mean(sum: i128, count: usize, in_scale: usize, out_scale: usize) -> Option<i128> {
+    debug_assert!(out_scale >= in_scale);
+    let count = count as i128;
+    let factor = checked_pow10(out_scale - in_scale)?;
+    // Split off the remainder first so the rescale only overflows if the mean itself does.
+    let q = sum / count;
+    let r = sum % count;
+    let frac = decimal_div_round(r.checked_mul(factor)?, count)?;
+    q.checked_mul(factor)?.checked_add(frac)
+}
+
+pub fn decimal_overflow_err(dtype: &DataType) -> PolarsError {
+    polars_err!(ComputeError: "decimal overflow: result does not fit in {}", dtype)
+}
+
 impl Int128Chunked {
     fn update_chunks_dtype(&mut self, precision: Option<usize>, scale: usize) {
         // physical i128 type doesn't exist
@@ -139,4 +278,112 @@ impl DecimalChunked {
         dt.2 = Some(dtype);
         Ok(Cow::Owned(dt))
     }
+
+    /// Sum the values, raising an error if the sum doesn't fit in a decimal.
+    ///
+    /// The sum has the [`decimal_sum_dtype`] of this array.
+    pub fn try_sum(&self) -> PolarsResult<i128> {
+        let dtype = decimal_sum_dtype(self.precision(), self.scale());
+        let mut sum = 0i128;
+        for arr in self.downcast_iter() {
+            for v in arr.non_null_values_iter() {
+                sum = sum
+                    .checked_add(v)
+                    .ok_or_else(|| decimal_overflow_err(&dtype))?;
+            }
+        }
+        decimal_fit_precision(sum, Some(DEC128_MAX_PRECISION))
+            .ok_or_else(|| decimal_overflow_err(&dtype))
+    }
+
+    /// Average the values at the scale of [`decimal_mean_dtype`], rounding half away from zero.
+    pub fn try_mean(&self) -> PolarsResult<Option<i128>> {
+        let count = self.len() - self.null_count();
+        if count == 0 {
+            return Ok(None);
+        }
+        let sum = self.try_sum()?;
+        let dtype = decimal_mean_dtype(self.precision(), self.scale());
+        let DataType::Decimal(_, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        decimal_mean(sum, count, self.scale(), scale)
+            .and_then(|v| decimal_fit_precision(v, Some(DEC128_MAX_PRECISION)))
+            .map(Some)
+            .ok_or_else(|| decimal_overflow_err(&dtype))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn try_agg_helper<F>(&self, groups: &GroupsProxy, dtype: DataType, f: F) -> PolarsResult<Self>
+    where
+        F: Fn(i128, usize) -> Option<Option<i128>> + Send + Sync,
+    {
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let ca = self.0.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let group_sum = |indicator: GroupsIndicator| -> Option<(i128, usize)> {
+            let mut sum = 0i128;
+            let mut count = 0;
+            let mut add = |v: Option<i128>| -> Option<()> {
+                if let Some(v) = v {
+                    sum = sum.checked_add(v)?;
+                    count += 1;
+                }
+                Some(())
+            };
+            match indicator {
+                GroupsIndicator::Idx((_, idx)) => {
+                    for &i in idx.iter() {
+                        add(arr.get(i as usize))?;
+                    }
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    for i in first..first + len {
+                        add(arr.get(i as usize))?;
+                    }
+                },
+            }
+            Some((sum, count))
+        };
+        let out: Int128Chunked = groups
+            .iter()
+            .map(|indicator| {
+                group_sum(indicator)
+                    .and_then(|(sum, count)| f(sum, count))
+                    .ok_or_else(|| decimal_overflow_err(&dtype))
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(out
+            .with_name(self.name().clone())
+            .into_decimal_unchecked(precision, scale))
+    }
+
+    /// Sum every group, raising an error if a sum doesn't fit in a decimal.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn try_agg_sum(&self, groups: &GroupsProxy) -> PolarsResult<Self> {
+        let dtype = decimal_sum_dtype(self.precision(), self.scale());
+        self.try_agg_helper(groups, dtype, |sum, _| {
+            decimal_fit_precision(sum, Some(DEC128_MAX_PRECISION)).map(Some)
+        })
+    }
+
+    /// Average every group, raising an error if a mean doesn't fit in a decimal.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn try_agg_mean(&self, groups: &GroupsProxy) -> PolarsResult<Self> {
+        let in_scale = self.scale();
+        let dtype = decimal_mean_dtype(self.precision(), in_scale);
+        let DataType::Decimal(_, Some(out_scale)) = dtype else {
+            unreachable!()
+        };
+        self.try_agg_helper(groups, dtype, |sum, count| {
+            if count == 0 {
+                return Some(None);
+            }
+            decimal_mean(sum, count, in_scale, out_scale)
+                .and_then(|v| decimal_fit_precision(v, Some(DEC128_MAX_PRECISION)))
+                .map(Some)
+        })
+    }
 }
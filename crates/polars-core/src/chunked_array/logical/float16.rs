@@ -0,0 +1,112 @@
+use arrow::types::f16;
+
+use super::*;
+use crate::prelude::arity::unary_elementwise_values;
+use crate::prelude::*;
+
+pub type Float16Chunked = Logical<Float16Type, UInt16Type>;
+
+/// Map the bits of a half-precision float to a key that orders like the float.
+///
+/// As in the total order of [`DataType::Float32`], `-0.0` and `0.0` get the same key, and so do all NaNs,
+/// which are greater than any other value. Keys can be hashed and grouped in place of the floats.
+#[inline]
+pub(crate) fn total_order_key(bits: u16) -> u16 {
+    let bits = if bits & 0x7FFF > 0x7C00 {
+        0x7E00
+    } else if bits == 0x8000 {
+        0
+    } else {
+        bits
+    };
+    if bits & 0x8000 != 0 {
+        !bits
+    } else {
+        bits | 0x8000
+    }
+}
+
+/// The inverse of [`total_order_key`], which returns the canonical NaN and zero.
+#[inline]
+fn from_total_order_key(key: u16) -> u16 {
+    if key & 0x8000 != 0 {
+        key & 0x7FFF
+    } else {
+        !key
+    }
+}
+
+impl UInt16Chunked {
+    pub fn into_float16(self) -> Float16Chunked {
+        Float16Chunked::new_logical(self)
+    }
+}
+
+impl Float16Chunked {
+    /// Create a [`Float16Chunked`] by rounding 32-bit floats to half precision.
+    pub fn from_f32(ca: &Float32Chunked) -> Self {
+        let out: UInt16Chunked = unary_elementwise_values(ca, |v| f16::from_f32(v).to_bits());
+        out.into_float16()
+    }
+
+    /// Upcast the half-precision values to 32-bit floats.
+    pub fn to_f32(&self) -> Float32Chunked {
+        unary_elementwise_values(&self.0, |v| f16::from_bits(v).to_f32())
+    }
+
+    /// The [`total_order_key`]s of the values.
+    pub(crate) fn to_total_order_keys(&self) -> UInt16Chunked {
+        unary_elementwise_values(&self.0, total_order_key)
+    }
+
+    /// Create a [`Float16Chunked`] from [`total_order_key`]s.
+    pub(crate) fn from_total_order_keys(keys: &UInt16Chunked) -> Self {
+        let out: UInt16Chunked = unary_elementwise_values(keys, from_total_order_key);
+        out.into_float16()
+    }
+
+    /// Convert a chunk to an arrow `Float16` array without copying the values.
+    pub(crate) fn to_arrow_chunk(&self, chunk_idx: usize) -> ArrayRef {
+        let arr = self.downcast_get(chunk_idx).unwrap();
+        arr.clone().transmute::<f16>().boxed()
+    }
+
+    /// Create a [`Float16Chunked`] from arrow `Float16` arrays without copying the values.
+    pub(crate) fn from_arrow_chunks(name: PlSmallStr, chunks: &[ArrayRef]) -> Self {
+        let chunks = chunks.iter().map(|arr| {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap()
+                .clone()
+                .transmute::<u16>()
+        });
+        UInt16Chunked::from_chunk_iter(name, chunks).into_float16()
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(|av| av.as_float16())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i).as_float16()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            // The physical bits are meaningless to other types, so every
+            // cast goes through the upcasted values.
+            dt => self.to_f32().cast_with_options(dt, cast_options),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use arrow::types::months_days_ns;
+
+use super::*;
+use crate::prelude::arity::unary_elementwise;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+/// Pack an interval into its physical 128-bit representation.
+///
+/// The months occupy the upper 32 bits, the days the next 32 bits and the
+/// nanoseconds the lower 64 bits.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96) | ((v.days() as u32 as i128) << 64) | (v.ns() as u64 as i128)
+}
+
+/// Unpack the physical 128-bit representation of an interval.
+#[inline]
+pub fn i128_to_interval(v: i128) -> months_days_ns {
+    months_days_ns::new((v >> 96) as i32, (v >> 64) as i32, v as i64)
+}
+
+impl Int128Chunked {
+    pub fn into_interval(self) -> IntervalChunked {
+        IntervalChunked::new_logical(self)
+    }
+}
+
+impl IntervalChunked {
+    /// Create an [`IntervalChunked`] from an iterator of optional intervals.
+    pub fn from_intervals<I>(name: PlSmallStr, v: I) -> Self
+    where
+        I: IntoIterator<Item = Option<months_days_ns>>,
+    {
+        Int128Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Convert a chunk to an arrow `MonthDayNano` interval array.
+    pub(crate) fn to_arrow_chunk(&self, chunk_idx: usize) -> ArrayRef {
+        let arr = self.downcast_get(chunk_idx).unwrap();
+        let values = arr
+            .values()
+            .iter()
+            .map(|v| i128_to_interval(*v))
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(
+            DataType::Interval.to_arrow(CompatLevel::newest()),
+            values.into(),
+            arr.validity().cloned(),
+        )
+        .boxed()
+    }
+
+    /// Create an [`IntervalChunked`] from arrow `MonthDayNano` interval arrays.
+    pub(crate) fn from_arrow_chunks(name: PlSmallStr, chunks: &[ArrayRef]) -> Self {
+        let chunks = chunks
+            .iter()
+            .map(|arr| {
+                let arr = arr
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                    .unwrap();
+                let values = arr
+                    .values()
+                    .iter()
+                    .map(|v| interval_to_i128(*v))
+                    .collect::<Vec<_>>();
+                PrimitiveArray::new(
+                    ArrowDataType::Int128,
+                    values.into(),
+                    arr.validity().cloned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Int128Chunked::from_chunk_iter(name, chunks).into_interval()
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Interval
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(|av| av.as_interval())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        match dtype {
+            Interval => Ok(self.clone().into_series()),
+            Duration(tu) => {
+                // Only intervals without a month component have a fixed length.
+                let out: Int64Chunked = unary_elementwise(&self.0, |opt_v: Option<i128>| {
+                    let v = i128_to_interval(opt_v?);
+                    if v.months() != 0 {
+                        return None;
+                    }
+                    (v.days() as i64)
+                        .checked_mul(86_400_000_000_000)
+                        .and_then(|days| days.checked_add(v.ns()))
+                });
+                out.into_duration(TimeUnit::Nanoseconds)
+                    .cast_with_options(&Duration(*tu), cast_options)
+            },
+            String => {
+                let out = self.0.apply_into_string_amortized(|v, buf| {
+                    crate::fmt::fmt_interval_string(buf, i128_to_interval(v)).unwrap()
+                });
+                Ok(out.into_series())
+            },
+            dt if dt.is_numeric() => self.0.cast_with_options(dtype, cast_options),
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use arrow::array::{ListArray, MapArray, StructArray};
+use arrow::datatypes::ArrowDataType;
+
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+impl ListChunked {
+    /// Interpret this `List(Struct{key, value})` as a map.
+    ///
+    /// # Safety
+    /// The inner dtype must be a struct with a `key` and a `value` field, in that order.
+    pub unsafe fn into_map_unchecked(self, key: DataType, value: DataType) -> MapChunked {
+        let mut ca = MapChunked::new_logical(self);
+        ca.2 = Some(DataType::Map(Box::new(key), Box::new(value)));
+        ca
+    }
+
+    /// Interpret a list of two-field structs as a map. The first field holds the keys
+    /// and the second field the values.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let (key, value, needs_rename) = match self.inner_dtype() {
+            DataType::Struct(fields) if fields.len() == 2 => (
+                fields[0].dtype().clone(),
+                fields[1].dtype().clone(),
+                fields[0].name() != "key" || fields[1].name() != "value",
+            ),
+            dt => polars_bail!(
+                SchemaMismatch: "expected a list of structs with two fields to create a map, got list[{}]", dt
+            ),
+        };
+
+        let ca = if !needs_rename {
+            self
+        } else {
+            // Rename the struct fields to `key` and `value`.
+            let chunks = self
+                .downcast_iter()
+                .map(|arr| {
+                    let entries = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+                    let ArrowDataType::Struct(fields) = entries.dtype() else {
+                        unreachable!()
+                    };
+                    let fields = fields
+                        .iter()
+                        .zip(["key", "value"])
+                        .map(|(fld, name)| {
+                            ArrowField::new(
+                                PlSmallStr::from_static(name),
+                                fld.dtype().clone(),
+                                true,
+                            )
+                        })
+                        .collect();
+                    let entries = StructArray::new(
+                        ArrowDataType::Struct(fields),
+                        entries.len(),
+                        entries.values().to_vec(),
+                        entries.validity().cloned(),
+                    );
+                    ListArray::<i64>::new(
+                        ListArray::<i64>::default_datatype(entries.dtype().clone()),
+                        arr.offsets().clone(),
+                        entries.boxed(),
+                        arr.validity().cloned(),
+                    )
+                    .boxed()
+                })
+                .collect::<Vec<_>>();
+            // SAFETY: only the field names of the inner struct changed.
+            unsafe {
+                ListChunked::from_chunks_and_dtype_unchecked(
+                    self.name().clone(),
+                    chunks,
+                    DataType::map_entries(key.clone(), value.clone()),
+                )
+            }
+        };
+        // SAFETY: we just checked the layout.
+        Ok(unsafe { ca.into_map_unchecked(key, value) })
+    }
+}
+
+impl MapChunked {
+    /// Wrap a physical array with the same layout as `self` into a map.
+    pub(crate) fn with_physical(&self, ca: ListChunked) -> Self {
+        let mut out = MapChunked::new_logical(ca);
+        out.2.clone_from(&self.2);
+        out
+    }
+
+    pub fn key_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Convert a chunk to an arrow `Map` array.
+    pub(crate) fn to_arrow_chunk(&self, chunk_idx: usize, compat_level: CompatLevel) -> ArrayRef {
+        let s = self.0.clone().into_series();
+        let arr = s.to_arrow(chunk_idx, compat_level);
+        let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        let dtype = self.dtype().to_arrow(compat_level);
+        let ArrowDataType::Map(entries_field, _) = &dtype else {
+            unreachable!()
+        };
+        let entries = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+        let entries = StructArray::new(
+            entries_field.dtype().clone(),
+            entries.len(),
+            entries.values().to_vec(),
+            entries.validity().cloned(),
+        );
+        // Arrow maps use 32-bit offsets.
+        let offsets = arr.offsets().try_into().expect("map offsets overflow i32");
+        MapArray::new(dtype, offsets, entries.boxed(), arr.validity().cloned()).boxed()
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        match dtype {
+            Map(key, value) => {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                let out = self.0.cast_with_options(&entries, cast_options)?;
+                // SAFETY: we casted to the entries layout.
+                Ok(unsafe {
+                    out.list()?
+                        .clone()
+                        .into_map_unchecked(key.as_ref().clone(), value.as_ref().clone())
+                }
+                .into_series())
+            },
+            List(_) => self.0.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
@@ -745,6 +745,7 @@ mod test {
         assert_eq!(
             ca.into_series()
                 .mean_reduce()
+                .unwrap()
                 .value()
                 .extract::<f32>()
                 .unwrap(),
@@ -754,7 +755,11 @@ mod test {
         let ca = Float32Chunked::full_null(PlSmallStr::EMPTY, 3);
         assert_eq!(ca.mean(), None);
         assert_eq!(
-            ca.into_series().mean_reduce().value().extract::<f32>(),
+            ca.into_series()
+                .mean_reduce()
+                .unwrap()
+                .value()
+                .extract::<f32>(),
             None
         );
     }
@@ -44,6 +44,10 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::Int16 => downcast_and_pack!(Int16Array, Int16),
         DataType::Int32 => downcast_and_pack!(Int32Array, Int32),
         DataType::Int64 => downcast_and_pack!(Int64Array, Int64),
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => downcast_and_pack!(Int128Array, Int128),
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => downcast_and_pack!(UInt128Array, UInt128),
         DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
         DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
         DataType::List(dt) => {
@@ -120,6 +124,18 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const UInt16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float32(arrow::types::f16::from_bits(v).to_f32())
+        },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(i128_to_interval(v))
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
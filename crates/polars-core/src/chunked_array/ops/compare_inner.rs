@@ -183,6 +183,36 @@ where
     }
 }
 
+/// Compares half-precision floats by their
+/// [`total_order_key`](crate::chunked_array::logical::total_order_key), as their bits
+/// don't order like the floats.
+#[cfg(feature = "dtype-f16")]
+struct Float16TotalOrder<'a>(&'a UInt16Chunked);
+
+#[cfg(feature = "dtype-f16")]
+impl GetInner for Float16TotalOrder<'_> {
+    type Item = Option<u16>;
+    unsafe fn get_unchecked(&self, idx: usize) -> Self::Item {
+        use crate::chunked_array::logical::total_order_key;
+
+        self.0.get_unchecked(idx).map(total_order_key)
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalEqInner<'a> for &'a Float16Chunked {
+    fn into_total_eq_inner(self) -> Box<dyn TotalEqInner + 'a> {
+        Box::new(Float16TotalOrder(self.physical()))
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalOrdInner<'a> for &'a Float16Chunked {
+    fn into_total_ord_inner(self) -> Box<dyn TotalOrdInner + 'a> {
+        Box::new(Float16TotalOrder(self.physical()))
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 struct LocalCategorical<'a> {
     rev_map: &'a Utf8ViewArray,
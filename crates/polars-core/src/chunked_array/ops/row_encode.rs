@@ -30,6 +30,9 @@ pub(crate) fn convert_series_for_row_encoding(s: &Series) -> PolarsResult<Series
         // we could fallback to default branch, but decimal is not numeric dtype for now, so explicit here
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => s.clone(),
+        // The bit patterns of negative values don't sort like the floats they encode.
+        #[cfg(feature = "dtype-f16")]
+        Float16 => s.float16().unwrap().to_f32().into_series(),
         List(inner) if !inner.is_nested() => s.clone(),
         Null => s.clone(),
         _ => {
@@ -130,10 +133,12 @@ pub fn get_row_encoding_dictionary(dtype: &DataType) -> Option<RowEncodingCatOrd
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
+        | DataType::UInt128
         | DataType::Int8
         | DataType::Int16
         | DataType::Int32
         | DataType::Int64
+        | DataType::Int128
         | DataType::Float32
         | DataType::Float64
         | DataType::String
@@ -146,6 +151,8 @@ pub fn get_row_encoding_dictionary(dtype: &DataType) -> Option<RowEncodingCatOrd
         DataType::Date => None,
         DataType::Datetime(_, _) => None,
         DataType::Duration(_) => None,
+        DataType::Interval => None,
+        DataType::Float16 => None,
 
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, _) => None,
@@ -156,6 +163,11 @@ pub fn get_row_encoding_dictionary(dtype: &DataType) -> Option<RowEncodingCatOrd
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_dictionary(dtype),
         DataType::List(dtype) => get_row_encoding_dictionary(dtype),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => get_row_encoding_dictionary(&DataType::map_entries(
+            key.as_ref().clone(),
+            value.as_ref().clone(),
+        )),
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(revmap, ordering) | DataType::Enum(revmap, ordering) => {
             let revmap = revmap.as_ref().unwrap();
@@ -115,6 +115,12 @@ enum SerializableDataType {
     Enum(Option<Wrap<Utf8ViewArray>>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    Int128,
+    UInt128,
+    Interval,
+    Float16,
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -157,6 +163,15 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name, _) => Self::Object(name.to_string()),
+            Int128 => Self::Int128,
+            UInt128 => Self::UInt128,
+            Interval => Self::Interval,
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
             dt => panic!("{dt:?} not supported"),
         }
     }
@@ -199,6 +214,12 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown", None),
+            Int128 => Self::Int128,
+            UInt128 => Self::UInt128,
+            Interval => Self::Interval,
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
         }
     }
 }
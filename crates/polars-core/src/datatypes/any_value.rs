@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use arrow::types::PrimitiveType;
 use polars_compute::cast::SerPrimitive;
 #[cfg(feature = "dtype-categorical")]
@@ -37,6 +39,9 @@ pub enum AnyValue<'a> {
     UInt32(u32),
     /// An unsigned 64-bit integer number.
     UInt64(u64),
+    /// An unsigned 128-bit integer number.
+    #[cfg(feature = "dtype-u128")]
+    UInt128(u128),
     /// An 8-bit integer number.
     Int8(i8),
     /// A 16-bit integer number.
@@ -45,6 +50,9 @@ pub enum AnyValue<'a> {
     Int32(i32),
     /// A 64-bit integer number.
     Int64(i64),
+    /// A 128-bit integer number.
+    #[cfg(feature = "dtype-i128")]
+    Int128(i128),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -67,6 +75,9 @@ pub enum AnyValue<'a> {
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
     // If syncptr is_null the data is in the rev-map
     // otherwise it is in the array pointer
     #[cfg(feature = "dtype-categorical")]
@@ -135,6 +146,10 @@ impl Serialize for AnyValue<'_> {
             AnyValue::BinaryOwned(v) => {
                 serializer.serialize_newtype_variant(name, 14, "BinaryOwned", v)
             },
+            #[cfg(feature = "dtype-i128")]
+            AnyValue::Int128(v) => serializer.serialize_newtype_variant(name, 15, "Int128", v),
+            #[cfg(feature = "dtype-u128")]
+            AnyValue::UInt128(v) => serializer.serialize_newtype_variant(name, 16, "UInt128", v),
             _ => Err(serde::ser::Error::custom(
                 "Unknown data type. Cannot serialize",
             )),
@@ -165,6 +180,8 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             Bool,
             StringOwned,
             BinaryOwned,
+            Int128,
+            UInt128,
         }
         const VARIANTS: &[&str] = &[
             "Null",
@@ -182,8 +199,10 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             "Boolean",
             "StringOwned",
             "BinaryOwned",
+            "Int128",
+            "UInt128",
         ];
-        const LAST: u8 = unsafe { std::mem::transmute::<_, u8>(AvField::BinaryOwned) };
+        const LAST: u8 = unsafe { std::mem::transmute::<_, u8>(AvField::UInt128) };
 
         struct FieldVisitor;
 
@@ -247,6 +266,8 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                     b"Bool" => AvField::Bool,
                     b"StringOwned" | b"String" => AvField::StringOwned,
                     b"BinaryOwned" | b"Binary" => AvField::BinaryOwned,
+                    b"Int128" => AvField::Int128,
+                    b"UInt128" => AvField::UInt128,
                     _ => {
                         return Err(serde::de::Error::unknown_variant(
                             &String::from_utf8_lossy(v),
@@ -338,6 +359,22 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                         let value = variant.newtype_variant()?;
                         AnyValue::BinaryOwned(value)
                     },
+                    #[cfg(feature = "dtype-i128")]
+                    (AvField::Int128, variant) => {
+                        let value = variant.newtype_variant()?;
+                        AnyValue::Int128(value)
+                    },
+                    #[cfg(feature = "dtype-u128")]
+                    (AvField::UInt128, variant) => {
+                        let value = variant.newtype_variant()?;
+                        AnyValue::UInt128(value)
+                    },
+                    #[allow(unreachable_patterns)]
+                    (AvField::Int128 | AvField::UInt128, _) => {
+                        return Err(serde::de::Error::custom(
+                            "activate the 'dtype-i128' and 'dtype-u128' features to deserialize 128-bit integers",
+                        ))
+                    },
                 };
                 Ok(out)
             }
@@ -388,10 +425,14 @@ impl<'a> AnyValue<'a> {
             Int16(_) => DataType::Int16,
             Int32(_) => DataType::Int32,
             Int64(_) => DataType::Int64,
+            #[cfg(feature = "dtype-i128")]
+            Int128(_) => DataType::Int128,
             UInt8(_) => DataType::UInt8,
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
             UInt64(_) => DataType::UInt64,
+            #[cfg(feature = "dtype-u128")]
+            UInt128(_) => DataType::UInt128,
             Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
             String(_) | StringOwned(_) => DataType::String,
@@ -408,6 +449,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-duration")]
             Duration(_, tu) => DataType::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _, _) | CategoricalOwned(_, _, _) => {
                 DataType::Categorical(None, Default::default())
@@ -440,10 +483,14 @@ impl<'a> AnyValue<'a> {
             Int16(v) => NumCast::from(*v),
             Int32(v) => NumCast::from(*v),
             Int64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-i128")]
+            Int128(v) => NumCast::from(*v),
             UInt8(v) => NumCast::from(*v),
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
             UInt64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-date")]
@@ -503,17 +550,26 @@ impl<'a> AnyValue<'a> {
     }
 
     pub fn is_signed_integer(&self) -> bool {
-        matches!(
-            self,
-            AnyValue::Int8(_) | AnyValue::Int16(_) | AnyValue::Int32(_) | AnyValue::Int64(_)
-        )
+        match self {
+            AnyValue::Int8(_) | AnyValue::Int16(_) | AnyValue::Int32(_) | AnyValue::Int64(_) => {
+                true
+            },
+            #[cfg(feature = "dtype-i128")]
+            AnyValue::Int128(_) => true,
+            _ => false,
+        }
     }
 
     pub fn is_unsigned_integer(&self) -> bool {
-        matches!(
-            self,
-            AnyValue::UInt8(_) | AnyValue::UInt16(_) | AnyValue::UInt32(_) | AnyValue::UInt64(_)
-        )
+        match self {
+            AnyValue::UInt8(_)
+            | AnyValue::UInt16(_)
+            | AnyValue::UInt32(_)
+            | AnyValue::UInt64(_) => true,
+            #[cfg(feature = "dtype-u128")]
+            AnyValue::UInt128(_) => true,
+            _ => false,
+        }
     }
 
     pub fn is_nan(&self) -> bool {
@@ -553,6 +609,10 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int16) => AnyValue::Int16(av.extract::<i16>()?),
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
+            #[cfg(feature = "dtype-i128")]
+            (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            #[cfg(feature = "dtype-u128")]
+            (av, DataType::UInt128) => AnyValue::UInt128(av.extract::<u128>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -565,12 +625,24 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int16(v), DataType::Boolean) => AnyValue::Boolean(*v != i16::default()),
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
+            #[cfg(feature = "dtype-i128")]
+            (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
+            #[cfg(feature = "dtype-u128")]
+            (AnyValue::UInt128(v), DataType::Boolean) => AnyValue::Boolean(*v != u128::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
             // to string
             (AnyValue::String(v), DataType::String) => AnyValue::String(v),
             (AnyValue::StringOwned(v), DataType::String) => AnyValue::StringOwned(v.clone()),
+            #[cfg(feature = "dtype-i128")]
+            (AnyValue::Int128(v), DataType::String) => {
+                AnyValue::StringOwned(polars_utils::format_pl_smallstr!("{v}"))
+            },
+            #[cfg(feature = "dtype-u128")]
+            (AnyValue::UInt128(v), DataType::String) => {
+                AnyValue::StringOwned(polars_utils::format_pl_smallstr!("{v}"))
+            },
 
             (av, DataType::String) => {
                 let mut tmp = vec![];
@@ -675,6 +747,34 @@ impl<'a> AnyValue<'a> {
                 },
                 *tu_r,
             ),
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Interval(v), DataType::Duration(tu)) => {
+                if v.months() != 0 {
+                    return None;
+                }
+                let ns = (v.days() as i64)
+                    .checked_mul(NS_IN_DAY)?
+                    .checked_add(v.ns())?;
+                AnyValue::Duration(
+                    match tu {
+                        TimeUnit::Nanoseconds => ns,
+                        TimeUnit::Microseconds => ns / 1_000i64,
+                        TimeUnit::Milliseconds => ns / 1_000_000i64,
+                    },
+                    *tu,
+                )
+            },
+
+            // to interval
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Duration(v, tu), DataType::Interval) => {
+                let ns = match tu {
+                    TimeUnit::Nanoseconds => *v,
+                    TimeUnit::Microseconds => v.checked_mul(1_000)?,
+                    TimeUnit::Milliseconds => v.checked_mul(1_000_000)?,
+                };
+                AnyValue::Interval(months_days_ns::new(0, 0, ns))
+            },
 
             // to decimal
             #[cfg(feature = "dtype-decimal")]
@@ -785,10 +885,14 @@ impl AnyValue<'_> {
             Int16(v) => v.hash(state),
             Int32(v) => v.hash(state),
             Int64(v) => v.hash(state),
+            #[cfg(feature = "dtype-i128")]
+            Int128(v) => v.hash(state),
             UInt8(v) => v.hash(state),
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
             UInt64(v) => v.hash(state),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(v) => v.hash(state),
             String(v) => v.hash(state),
             StringOwned(v) => v.hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
@@ -829,6 +933,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, _, _)
             | CategoricalOwned(v, _, _)
@@ -916,6 +1022,24 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-f16")]
+    pub(crate) fn as_float16(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::UInt16(v) => AnyValue::Float32(arrow::types::f16::from_bits(*v).to_f32()),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create float16 from other type. dtype: {dt}"),
+        }
+    }
+
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(i128_to_interval(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create interval from other type. dtype: {dt}"),
+        }
+    }
+
     pub(crate) fn to_i128(&self) -> Option<i128> {
         match self {
             AnyValue::UInt8(v) => Some((*v).into()),
@@ -926,6 +1050,10 @@ impl<'a> AnyValue<'a> {
             AnyValue::Int16(v) => Some((*v).into()),
             AnyValue::Int32(v) => Some((*v).into()),
             AnyValue::Int64(v) => Some((*v).into()),
+            #[cfg(feature = "dtype-i128")]
+            AnyValue::Int128(v) => Some(*v),
+            #[cfg(feature = "dtype-u128")]
+            AnyValue::UInt128(v) => (*v).try_into().ok(),
             _ => None,
         }
     }
@@ -948,6 +1076,10 @@ impl<'a> AnyValue<'a> {
             (Int64(l), Int64(r)) => Int64(l + r),
             (UInt32(l), UInt32(r)) => UInt32(l + r),
             (UInt64(l), UInt64(r)) => UInt64(l + r),
+            #[cfg(feature = "dtype-i128")]
+            (Int128(l), Int128(r)) => Int128(l + r),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128(l), UInt128(r)) => UInt128(l + r),
             (Float32(l), Float32(r)) => Float32(l + r),
             (Float64(l), Float64(r)) => Float64(l + r),
             #[cfg(feature = "dtype-duration")]
@@ -1000,10 +1132,14 @@ impl<'a> AnyValue<'a> {
             Int16(v) => Int16(v),
             Int32(v) => Int32(v),
             Int64(v) => Int64(v),
+            #[cfg(feature = "dtype-i128")]
+            Int128(v) => Int128(v),
             UInt8(v) => UInt8(v),
             UInt16(v) => UInt16(v),
             UInt32(v) => UInt32(v),
             UInt64(v) => UInt64(v),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(v) => UInt128(v),
             Boolean(v) => Boolean(v),
             Float32(v) => Float32(v),
             Float64(v) => Float64(v),
@@ -1017,6 +1153,8 @@ impl<'a> AnyValue<'a> {
             Duration(v, tu) => Duration(v, tu),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
             List(v) => List(v),
             #[cfg(feature = "dtype-array")]
             Array(s, size) => Array(s, size),
@@ -1174,6 +1312,10 @@ impl AnyValue<'_> {
             (Int16(l), Int16(r)) => *l == *r,
             (Int32(l), Int32(r)) => *l == *r,
             (Int64(l), Int64(r)) => *l == *r,
+            #[cfg(feature = "dtype-i128")]
+            (Int128(l), Int128(r)) => *l == *r,
+            #[cfg(feature = "dtype-u128")]
+            (UInt128(l), UInt128(r)) => *l == *r,
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
             (String(l), String(r)) => l == r,
@@ -1211,6 +1353,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1355,6 +1499,10 @@ impl PartialOrd for AnyValue<'_> {
             (Int16(l), Int16(r)) => l.partial_cmp(r),
             (Int32(l), Int32(r)) => l.partial_cmp(r),
             (Int64(l), Int64(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-i128")]
+            (Int128(l), Int128(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128(l), UInt128(r)) => l.partial_cmp(r),
             (Float32(l), Float32(r)) => Some(l.tot_cmp(r)),
             (Float64(l), Float64(r)) => Some(l.tot_cmp(r)),
             (String(l), String(r)) => l.partial_cmp(r),
@@ -1381,6 +1529,8 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
             (Categorical(..), Categorical(..)) => {
                 unimplemented!(
@@ -1638,6 +1788,12 @@ impl<K: NumericNative> From<K> for AnyValue<'static> {
                 PrimitiveType::UInt16 => AnyValue::UInt16(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt32 => AnyValue::UInt32(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt64 => AnyValue::UInt64(NumCast::from(value).unwrap_unchecked()),
+                #[cfg(feature = "dtype-i128")]
+                PrimitiveType::Int128 => AnyValue::Int128(NumCast::from(value).unwrap_unchecked()),
+                #[cfg(feature = "dtype-u128")]
+                PrimitiveType::UInt128 => {
+                    AnyValue::UInt128(NumCast::from(value).unwrap_unchecked())
+                },
                 PrimitiveType::Float32 => {
                     AnyValue::Float32(NumCast::from(value).unwrap_unchecked())
                 },
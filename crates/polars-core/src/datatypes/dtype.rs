@@ -48,10 +48,17 @@ pub enum DataType {
     UInt16,
     UInt32,
     UInt64,
+    /// A 128-bit unsigned integer.
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
+    /// A 128-bit signed integer.
+    Int128,
+    /// A 16-bit half-precision float. The bits are stored in an unsigned 16-bit integer
+    /// and computations upcast to [`DataType::Float32`].
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -73,6 +80,9 @@ pub enum DataType {
     Duration(TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
+    /// A calendar interval of months, days and nanoseconds. The components are packed
+    /// into a 128-bit integer.
+    Interval,
     /// A nested list with a fixed size in each row
     #[cfg(feature = "dtype-array")]
     Array(Box<DataType>, usize),
@@ -91,6 +101,10 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A nested list of key/value pairs in each row. Physically this is a
+    /// `List(Struct{key, value})`.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -139,6 +153,10 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(left_key, left_value), Map(right_key, right_value)) => {
+                    left_key == right_key && left_value == right_value
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -177,6 +195,10 @@ impl DataType {
             Int16 => other.extract::<i16>().is_some(),
             Int32 => other.extract::<i32>().is_some(),
             Int64 => other.extract::<i64>().is_some(),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => other.extract::<i128>().is_some(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => other.extract::<u128>().is_some(),
             _ => false,
         }
     }
@@ -189,6 +211,8 @@ impl DataType {
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
             DataType::Unknown(_) => false,
             _ => true,
         }
@@ -219,6 +243,11 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown()?),
+                Box::new(value.materialize_unknown()?),
+            )),
             _ => Ok(self.clone()),
         }
     }
@@ -291,6 +320,15 @@ impl DataType {
         DataType::List(Box::new(self))
     }
 
+    /// The `List(Struct{key, value})` layout that backs a [`DataType::Map`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_entries(key: DataType, value: DataType) -> DataType {
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), key),
+            Field::new(PlSmallStr::from_static("value"), value),
+        ])))
+    }
+
     /// Convert to the physical data type
     #[must_use]
     pub fn to_physical(&self) -> DataType {
@@ -300,6 +338,8 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            Interval => Int128,
+            Float16 => UInt16,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => UInt32,
             #[cfg(feature = "dtype-array")]
@@ -313,6 +353,8 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::map_entries(key.to_physical(), value.to_physical()),
             _ => self.clone(),
         }
     }
@@ -369,8 +411,20 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map()
     }
 
     /// Check if this [`DataType`] is a struct
@@ -419,6 +473,8 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
             _ => false,
         }
     }
@@ -435,6 +491,8 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
             _ => false,
         }
     }
@@ -449,6 +507,8 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
             _ => false,
         }
     }
@@ -495,10 +555,12 @@ impl DataType {
                 | DataType::Int16
                 | DataType::Int32
                 | DataType::Int64
+                | DataType::Int128
                 | DataType::UInt8
                 | DataType::UInt16
                 | DataType::UInt32
                 | DataType::UInt64
+                | DataType::UInt128
                 | DataType::Unknown(UnknownKind::Int(_))
         )
     }
@@ -511,6 +573,8 @@ impl DataType {
             DataType::Int8 => true,
             #[cfg(feature = "dtype-i16")]
             DataType::Int16 => true,
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => true,
             _ => false,
         }
     }
@@ -522,6 +586,8 @@ impl DataType {
             DataType::UInt8 => true,
             #[cfg(feature = "dtype-u16")]
             DataType::UInt16 => true,
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => true,
             _ => false,
         }
     }
@@ -586,12 +652,16 @@ impl DataType {
             Int16 => Scalar::from(i16::MAX),
             Int32 => Scalar::from(i32::MAX),
             Int64 => Scalar::from(i64::MAX),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => Scalar::from(i128::MAX),
             #[cfg(feature = "dtype-u8")]
             UInt8 => Scalar::from(u8::MAX),
             #[cfg(feature = "dtype-u16")]
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => Scalar::from(u128::MAX),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             dt => polars_bail!(ComputeError: "cannot determine upper bound for dtype `{}`", dt),
@@ -609,12 +679,16 @@ impl DataType {
             Int16 => Scalar::from(i16::MIN),
             Int32 => Scalar::from(i32::MIN),
             Int64 => Scalar::from(i64::MIN),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => Scalar::from(i128::MIN),
             #[cfg(feature = "dtype-u8")]
             UInt8 => Scalar::from(u8::MIN),
             #[cfg(feature = "dtype-u16")]
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => Scalar::from(u128::MIN),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             dt => polars_bail!(ComputeError: "cannot determine lower bound for dtype `{}`", dt),
@@ -637,10 +711,13 @@ impl DataType {
             UInt16 => Ok(ArrowDataType::UInt16),
             UInt32 => Ok(ArrowDataType::UInt32),
             UInt64 => Ok(ArrowDataType::UInt64),
+            UInt128 => Ok(ArrowDataType::UInt128),
             Int8 => Ok(ArrowDataType::Int8),
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
+            Int128 => Ok(ArrowDataType::Int128),
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -673,6 +750,9 @@ impl DataType {
             Datetime(unit, tz) => Ok(ArrowDataType::Timestamp(unit.to_arrow(), tz.clone())),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
+            Interval => Ok(ArrowDataType::Interval(
+                arrow::datatypes::IntervalUnit::MonthDayNano,
+            )),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
                 .try_to_arrow(compat_level)?
@@ -711,6 +791,26 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Arrow requires the keys of a map to be non-nullable.
+                let fields = vec![
+                    ArrowField::new(
+                        PlSmallStr::from_static("key"),
+                        key.try_to_arrow(compat_level)?,
+                        false,
+                    ),
+                    value.to_arrow_field(PlSmallStr::from_static("value"), compat_level),
+                ];
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static("entries"),
+                        ArrowDataType::Struct(fields),
+                        false,
+                    )),
+                    false,
+                ))
+            },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -760,6 +860,10 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => Ok(s1 != s2),
@@ -790,10 +894,13 @@ impl Display for DataType {
             DataType::UInt16 => "u16",
             DataType::UInt32 => "u32",
             DataType::UInt64 => "u64",
+            DataType::UInt128 => "u128",
             DataType::Int8 => "i8",
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
+            DataType::Int128 => "i128",
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -818,6 +925,7 @@ impl Display for DataType {
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
+            DataType::Interval => "interval",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let tp = self.array_leaf_dtype().unwrap();
@@ -839,6 +947,8 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
@@ -908,9 +1018,19 @@ pub fn create_enum_dtype(categories: Utf8ViewArray) -> DataType {
     DataType::Enum(Some(Arc::new(rev_map)), Default::default())
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    any(feature = "serde", feature = "serde-lazy"),
+    derive(Serialize, Deserialize)
+)]
 pub struct CompatLevel(pub(crate) u16);
 
+impl Default for CompatLevel {
+    fn default() -> Self {
+        CompatLevel::newest()
+    }
+}
+
 impl CompatLevel {
     pub const fn newest() -> CompatLevel {
         CompatLevel(1)
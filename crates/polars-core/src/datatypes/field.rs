@@ -1,3 +1,4 @@
+use arrow::datatypes::IntervalUnit;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::*;
@@ -141,17 +142,35 @@ impl DataType {
             ArrowDataType::Int16 => DataType::Int16,
             ArrowDataType::Int32 => DataType::Int32,
             ArrowDataType::Int64 => DataType::Int64,
+            ArrowDataType::Int128 => DataType::Int128,
+            ArrowDataType::UInt128 => DataType::UInt128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
+            ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
             ArrowDataType::FixedSizeList(f, size) => DataType::Array(DataType::from_arrow(f.dtype(), bin_to_view).boxed(), *size),
             ArrowDataType::LargeList(f) | ArrowDataType::List(f) => DataType::List(DataType::from_arrow(f.dtype(), bin_to_view).boxed()),
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(f, _) => match f.dtype() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    DataType::from_arrow(fields[0].dtype(), bin_to_view).boxed(),
+                    DataType::from_arrow(fields[1].dtype(), bin_to_view).boxed(),
+                ),
+                dt => panic!("Arrow map with entries of type {dt:?} not supported by Polars"),
+            },
+            // Without the map type we read maps as their `List<Struct<key, value>>` layout.
+            #[cfg(not(feature = "dtype-map"))]
+            ArrowDataType::Map(f, _) => DataType::List(DataType::from_arrow(f.dtype(), bin_to_view).boxed()),
             ArrowDataType::Date32 => DataType::Date,
             ArrowDataType::Timestamp(tu, tz) => DataType::Datetime(tu.into(), DataType::canonical_timezone(tz)),
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
             ArrowDataType::Date64 => DataType::Datetime(TimeUnit::Milliseconds, None),
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
             #[cfg(feature = "dtype-categorical")]
             ArrowDataType::Dictionary(_, _, _) => DataType::Categorical(None,Default::default()),
             #[cfg(feature = "dtype-struct")]
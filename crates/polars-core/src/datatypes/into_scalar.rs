@@ -35,7 +35,6 @@ impl_into_scalar! {
     i16: (T::Int16),
     i32: (T::Int32), // T::Date
     i64: (T::Int64), // T::Datetime, T::Duration, T::Time
-    // i128: (T::Decimal),
     f32: (T::Float32),
     f64: (T::Float64),
     // Vec<u8>: (T::Binary),
@@ -55,3 +54,13 @@ impl_into_scalar! {
     // #[cfg(feature = "dtype-struct")]
     // StructOwned(Box<(Vec<AnyValue<'a>>, Vec<Field>)>),
 }
+
+#[cfg(feature = "dtype-i128")]
+impl_into_scalar! {
+    i128: (T::Int128), // T::Decimal
+}
+
+#[cfg(feature = "dtype-u128")]
+impl_into_scalar! {
+    u128: (T::UInt128),
+}
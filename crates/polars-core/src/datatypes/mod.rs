@@ -190,10 +190,18 @@ impl_polars_num_datatype!(PolarsIntegerType, Int8Type, Int8, i8, i8);
 impl_polars_num_datatype!(PolarsIntegerType, Int16Type, Int16, i16, i16);
 impl_polars_num_datatype!(PolarsIntegerType, Int32Type, Int32, i32, i32);
 impl_polars_num_datatype!(PolarsIntegerType, Int64Type, Int64, i64, i64);
+#[cfg(feature = "dtype-i128")]
+impl_polars_num_datatype!(PolarsIntegerType, Int128Type, Int128, i128, i128);
+#[cfg(feature = "dtype-u128")]
+impl_polars_num_datatype!(PolarsIntegerType, UInt128Type, UInt128, u128, u128);
 impl_polars_num_datatype!(PolarsFloatType, Float32Type, Float32, f32, f32);
 impl_polars_num_datatype!(PolarsFloatType, Float64Type, Float64, f64, f64);
 impl_polars_datatype!(DateType, Date, PrimitiveArray<i32>, 'a, i32, i32, i32);
 impl_polars_datatype!(TimeType, Time, PrimitiveArray<i64>, 'a, i64, i64, i64);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, Float16, PrimitiveArray<u16>, 'a, u16, u16, u16);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, Interval, PrimitiveArray<i128>, 'a, i128, i128, i128);
 impl_polars_binview_datatype!(StringType, String, Utf8ViewArray, 'a, &'a str, Option<&'a str>, String);
 impl_polars_binview_datatype!(BinaryType, Binary, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
 impl_polars_datatype!(BinaryOffsetType, BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
@@ -204,6 +212,8 @@ impl_polars_datatype_pass_dtype!(DecimalType, DataType::Unknown(UnknownKind::Any
 impl_polars_datatype_pass_dtype!(DatetimeType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype_pass_dtype!(DurationType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype_pass_dtype!(CategoricalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
+#[cfg(feature = "dtype-map")]
+impl_polars_datatype_pass_dtype!(MapType, DataType::Unknown(UnknownKind::Any), ListArray<i64>, 'a, Box<dyn Array>, Option<Box<dyn Array>>, Box<dyn Array>, FalseT);
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListType {}
@@ -267,30 +277,6 @@ unsafe impl PolarsDataType for FixedSizeListType {
         DataType::Array(Box::new(DataType::Null), 0)
     }
 }
-#[cfg(feature = "dtype-decimal")]
-pub struct Int128Type {}
-#[cfg(feature = "dtype-decimal")]
-unsafe impl PolarsDataType for Int128Type {
-    type Physical<'a> = i128;
-    type OwnedPhysical = i128;
-    type ZeroablePhysical<'a> = i128;
-    type Array = PrimitiveArray<i128>;
-    type IsNested = FalseT;
-    type HasViews = FalseT;
-    type IsStruct = FalseT;
-    type IsObject = FalseT;
-
-    fn get_dtype() -> DataType {
-        // Scale is not None to allow for get_any_value() to work.
-        DataType::Decimal(None, Some(0))
-    }
-}
-#[cfg(feature = "dtype-decimal")]
-impl PolarsNumericType for Int128Type {
-    type Native = i128;
-}
-#[cfg(feature = "dtype-decimal")]
-impl PolarsIntegerType for Int128Type {}
 #[cfg(feature = "object")]
 pub struct ObjectType<T>(T);
 #[cfg(feature = "object")]
@@ -317,11 +303,13 @@ pub type UInt8Chunked = ChunkedArray<UInt8Type>;
 pub type UInt16Chunked = ChunkedArray<UInt16Type>;
 pub type UInt32Chunked = ChunkedArray<UInt32Type>;
 pub type UInt64Chunked = ChunkedArray<UInt64Type>;
+#[cfg(feature = "dtype-u128")]
+pub type UInt128Chunked = ChunkedArray<UInt128Type>;
 pub type Int8Chunked = ChunkedArray<Int8Type>;
 pub type Int16Chunked = ChunkedArray<Int16Type>;
 pub type Int32Chunked = ChunkedArray<Int32Type>;
 pub type Int64Chunked = ChunkedArray<Int64Type>;
-#[cfg(feature = "dtype-decimal")]
+#[cfg(feature = "dtype-i128")]
 pub type Int128Chunked = ChunkedArray<Int128Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
 pub type Float64Chunked = ChunkedArray<Float64Type>;
@@ -395,11 +383,16 @@ impl NumericNative for u64 {
     type PolarsType = UInt64Type;
     type TrueDivPolarsType = Float64Type;
 }
-#[cfg(feature = "dtype-decimal")]
+#[cfg(feature = "dtype-i128")]
 impl NumericNative for i128 {
     type PolarsType = Int128Type;
     type TrueDivPolarsType = Float64Type;
 }
+#[cfg(feature = "dtype-u128")]
+impl NumericNative for u128 {
+    type PolarsType = UInt128Type;
+    type TrueDivPolarsType = Float64Type;
+}
 impl NumericNative for f32 {
     type PolarsType = Float32Type;
     type TrueDivPolarsType = Float32Type;
@@ -12,6 +12,8 @@ use std::{fmt, str};
     feature = "dtype-time"
 ))]
 use arrow::temporal_conversions::*;
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 #[cfg(feature = "dtype-datetime")]
 use chrono::NaiveDateTime;
 #[cfg(feature = "timezones")]
@@ -351,6 +353,10 @@ impl Debug for Series {
             DataType::UInt64 => {
                 format_array!(f, self.u64().unwrap(), "u64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                format_array!(f, self.u128().unwrap(), "u128", self.name(), "Series")
+            },
             DataType::Int8 => {
                 format_array!(f, self.i8().unwrap(), "i8", self.name(), "Series")
             },
@@ -363,6 +369,10 @@ impl Debug for Series {
             DataType::Int64 => {
                 format_array!(f, self.i64().unwrap(), "i64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => {
+                format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -383,6 +393,16 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -397,6 +417,11 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_, _) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -1042,6 +1067,33 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // write the calendar parts of the interval first, eg: "1mo 3d 4h 5m"
+    let mut buffer = itoa::Buffer::new();
+    let mut wrote_part = false;
+    if v.months() != 0 {
+        f.write_str(buffer.format(v.months()))?;
+        f.write_str("mo")?;
+        wrote_part = true;
+    }
+    if v.days() != 0 {
+        if wrote_part {
+            f.write_char(' ')?;
+        }
+        f.write_str(buffer.format(v.days()))?;
+        f.write_char('d')?;
+        wrote_part = true;
+    }
+    if v.ns() != 0 || !wrote_part {
+        if wrote_part {
+            f.write_char(' ')?;
+        }
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1148,6 +1200,10 @@ impl Display for AnyValue<'_> {
             AnyValue::Int16(v) => fmt_integer(f, width, *v),
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
+            #[cfg(feature = "dtype-i128")]
+            AnyValue::Int128(v) => fmt_integer(f, width, *v),
+            #[cfg(feature = "dtype-u128")]
+            AnyValue::UInt128(v) => fmt_integer(f, width, *v),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
@@ -1165,6 +1221,8 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
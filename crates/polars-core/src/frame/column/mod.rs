@@ -225,6 +225,10 @@ impl Column {
     pub fn try_i64(&self) -> Option<&Int64Chunked> {
         self.as_materialized_series().try_i64()
     }
+    #[cfg(feature = "dtype-i128")]
+    pub fn try_i128(&self) -> Option<&Int128Chunked> {
+        self.as_materialized_series().try_i128()
+    }
     pub fn try_u8(&self) -> Option<&UInt8Chunked> {
         self.as_materialized_series().try_u8()
     }
@@ -237,6 +241,10 @@ impl Column {
     pub fn try_u64(&self) -> Option<&UInt64Chunked> {
         self.as_materialized_series().try_u64()
     }
+    #[cfg(feature = "dtype-u128")]
+    pub fn try_u128(&self) -> Option<&UInt128Chunked> {
+        self.as_materialized_series().try_u128()
+    }
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         self.as_materialized_series().try_f32()
     }
@@ -303,6 +311,10 @@ impl Column {
     pub fn i64(&self) -> PolarsResult<&Int64Chunked> {
         self.as_materialized_series().i64()
     }
+    #[cfg(feature = "dtype-i128")]
+    pub fn i128(&self) -> PolarsResult<&Int128Chunked> {
+        self.as_materialized_series().i128()
+    }
     pub fn u8(&self) -> PolarsResult<&UInt8Chunked> {
         self.as_materialized_series().u8()
     }
@@ -315,6 +327,10 @@ impl Column {
     pub fn u64(&self) -> PolarsResult<&UInt64Chunked> {
         self.as_materialized_series().u64()
     }
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.as_materialized_series().u128()
+    }
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.as_materialized_series().f32()
     }
@@ -352,6 +368,10 @@ impl Column {
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
         self.as_materialized_series().categorical()
@@ -1272,7 +1292,7 @@ impl Column {
             },
         }
     }
-    pub fn mean_reduce(&self) -> Scalar {
+    pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.mean_reduce(),
             Column::Partitioned(s) => s.as_materialized_series().mean_reduce(),
@@ -190,13 +190,29 @@ where
                 num_groups_proxy(ca, multithreaded, sorted)
             },
             #[cfg(feature = "dtype-decimal")]
-            DataType::Decimal(_, _) => {
+            DataType::Int128 | DataType::Decimal(_, _) => {
                 // convince the compiler that we are this type.
                 let ca: &Int128Chunked = unsafe {
                     &*(self as *const ChunkedArray<T> as *const ChunkedArray<Int128Type>)
                 };
                 num_groups_proxy(ca, multithreaded, sorted)
             },
+            #[cfg(all(feature = "dtype-i128", not(feature = "dtype-decimal")))]
+            DataType::Int128 => {
+                // convince the compiler that we are this type.
+                let ca: &Int128Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<Int128Type>)
+                };
+                num_groups_proxy(ca, multithreaded, sorted)
+            },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                // convince the compiler that we are this type.
+                let ca: &UInt128Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt128Type>)
+                };
+                num_groups_proxy(ca, multithreaded, sorted)
+            },
             #[cfg(all(feature = "performant", feature = "dtype-i8", feature = "dtype-u8"))]
             DataType::Int8 => {
                 // convince the compiler that we are this type.
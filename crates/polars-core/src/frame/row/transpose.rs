@@ -37,12 +37,16 @@ impl DataFrame {
             DataType::Int16 => numeric_transpose::<Int16Type>(cols, names_out, &mut cols_t),
             DataType::Int32 => numeric_transpose::<Int32Type>(cols, names_out, &mut cols_t),
             DataType::Int64 => numeric_transpose::<Int64Type>(cols, names_out, &mut cols_t),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => numeric_transpose::<Int128Type>(cols, names_out, &mut cols_t),
             #[cfg(feature = "dtype-u8")]
             DataType::UInt8 => numeric_transpose::<UInt8Type>(cols, names_out, &mut cols_t),
             #[cfg(feature = "dtype-u16")]
             DataType::UInt16 => numeric_transpose::<UInt16Type>(cols, names_out, &mut cols_t),
             DataType::UInt32 => numeric_transpose::<UInt32Type>(cols, names_out, &mut cols_t),
             DataType::UInt64 => numeric_transpose::<UInt64Type>(cols, names_out, &mut cols_t),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => numeric_transpose::<UInt128Type>(cols, names_out, &mut cols_t),
             DataType::Float32 => numeric_transpose::<Float32Type>(cols, names_out, &mut cols_t),
             DataType::Float64 => numeric_transpose::<Float64Type>(cols, names_out, &mut cols_t),
             #[cfg(feature = "object")]
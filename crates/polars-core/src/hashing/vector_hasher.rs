@@ -167,8 +167,10 @@ vec_hash_numeric!(UInt16Chunked);
 vec_hash_numeric!(UInt8Chunked);
 vec_hash_numeric!(Float64Chunked);
 vec_hash_numeric!(Float32Chunked);
-#[cfg(feature = "dtype-decimal")]
+#[cfg(feature = "dtype-i128")]
 vec_hash_numeric!(Int128Chunked);
+#[cfg(feature = "dtype-u128")]
+vec_hash_numeric!(UInt128Chunked);
 
 impl VecHash for StringChunked {
     fn vec_hash(&self, random_state: PlRandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
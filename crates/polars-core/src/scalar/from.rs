@@ -29,3 +29,13 @@ impl_from! {
     (f64, Float64, Float64)
     (PlSmallStr, StringOwned, String)
 }
+
+#[cfg(feature = "dtype-i128")]
+impl_from! {
+    (i128, Int128, Int128)
+}
+
+#[cfg(feature = "dtype-u128")]
+impl_from! {
+    (u128, UInt128, UInt128)
+}
@@ -5,9 +5,9 @@ use crate::prelude::{DataType, Scalar};
 
 pub fn mean_reduce(value: Option<f64>, dtype: DataType) -> Scalar {
     match dtype {
-        DataType::Float32 => {
+        DataType::Float32 | DataType::Float16 => {
             let val = value.map(|m| m as f32);
-            Scalar::new(dtype, val.into())
+            Scalar::new(DataType::Float32, val.into())
         },
         dt if dt.is_numeric() || dt.is_decimal() || dt.is_bool() => {
             Scalar::new(DataType::Float64, value.into())
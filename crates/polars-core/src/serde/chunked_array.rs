@@ -133,6 +133,8 @@ macro_rules! impl_serialize {
 impl_serialize!(StringChunked);
 impl_serialize!(BooleanChunked);
 impl_serialize!(ListChunked);
+#[cfg(feature = "dtype-map")]
+impl_serialize!(MapChunked);
 impl_serialize!(BinaryChunked);
 #[cfg(feature = "dtype-array")]
 impl_serialize!(ArrayChunked);
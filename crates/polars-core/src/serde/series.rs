@@ -29,6 +29,11 @@ impl Serialize for Series {
                 let ca = self.list().unwrap();
                 ca.serialize(serializer)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let ca = self.map().unwrap();
+                ca.serialize(serializer)
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let ca = self.array().unwrap();
@@ -72,6 +77,16 @@ impl Serialize for Series {
                 let ca = self.time().unwrap();
                 ca.serialize(serializer)
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let ca = self.float16().unwrap();
+                ca.serialize(serializer)
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let ca = self.interval().unwrap();
+                ca.serialize(serializer)
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let ca = self.decimal().unwrap();
@@ -189,6 +204,16 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<u64>> = map.next_value()?;
                         Ok(Series::new(name, values))
                     },
+                    #[cfg(feature = "dtype-i128")]
+                    DataType::Int128 => {
+                        let values: Vec<Option<i128>> = map.next_value()?;
+                        Ok(Series::new(name, values))
+                    },
+                    #[cfg(feature = "dtype-u128")]
+                    DataType::UInt128 => {
+                        let values: Vec<Option<u128>> = map.next_value()?;
+                        Ok(Series::new(name, values))
+                    },
                     #[cfg(feature = "dtype-date")]
                     DataType::Date => {
                         let values: Vec<Option<i32>> = map.next_value()?;
@@ -213,6 +238,20 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<i64>> = map.next_value()?;
                         Ok(Series::new(name, values).cast(&DataType::Time).unwrap())
                     },
+                    #[cfg(feature = "dtype-f16")]
+                    DataType::Float16 => {
+                        let values: Vec<Option<u16>> = map.next_value()?;
+                        Ok(UInt16Chunked::from_slice_options(name, &values)
+                            .into_float16()
+                            .into_series())
+                    },
+                    #[cfg(feature = "dtype-interval")]
+                    DataType::Interval => {
+                        let values: Vec<Option<i128>> = map.next_value()?;
+                        Ok(Int128Chunked::from_slice_options(name, &values)
+                            .into_interval()
+                            .into_series())
+                    },
                     #[cfg(feature = "dtype-decimal")]
                     DataType::Decimal(precision, Some(scale)) => {
                         let values: Vec<Option<i128>> = map.next_value()?;
@@ -246,6 +285,26 @@ impl<'de> Deserialize<'de> for Series {
                         }
                         Ok(lb.finish().into_series())
                     },
+                    #[cfg(feature = "dtype-map")]
+                    DataType::Map(key, value) => {
+                        let values: Vec<Option<Series>> = map.next_value()?;
+                        let entries = DataType::map_entries(*key, *value);
+                        let mut lb = AnonymousListBuilder::new(
+                            name,
+                            values.len(),
+                            entries.inner_dtype().cloned(),
+                        );
+                        for value in &values {
+                            lb.append_opt_series(value.as_ref()).map_err(|e| {
+                                de::Error::custom(format!("could not append series to map: {e}"))
+                            })?;
+                        }
+                        let ca = lb
+                            .finish()
+                            .into_map()
+                            .map_err(|e| de::Error::custom(format!("could not create map: {e}")))?;
+                        Ok(ca.into_series())
+                    },
                     #[cfg(feature = "dtype-array")]
                     DataType::Array(inner, width) => {
                         let values: Vec<Option<Series>> = map.next_value()?;
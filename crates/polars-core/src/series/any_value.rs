@@ -111,6 +111,10 @@ impl Series {
             DataType::Int64 => any_values_to_integer::<Int64Type>(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => any_values_to_integer::<Int128Type>(values, strict)?
+                .with_cheap_metadata()
+                .into_series(),
             #[cfg(feature = "dtype-u8")]
             DataType::UInt8 => any_values_to_integer::<UInt8Type>(values, strict)?
                 .with_cheap_metadata()
@@ -125,12 +129,20 @@ impl Series {
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => any_values_to_integer::<UInt128Type>(values, strict)?
+                .with_cheap_metadata()
+                .into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
             DataType::Float64 => any_values_to_f64(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                Float16Chunked::from_f32(&any_values_to_f32(values, strict)?).into_series()
+            },
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
             DataType::String => any_values_to_string(values, strict)?.into_series(),
             DataType::Binary => any_values_to_binary(values, strict)?.into_series(),
@@ -144,6 +156,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ DataType::Categorical(_, _) => any_values_to_categorical(values, dt, strict)?,
             #[cfg(feature = "dtype-categorical")]
@@ -153,6 +167,13 @@ impl Series {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                any_values_to_list(values, entries.inner_dtype().unwrap(), strict)?
+                    .into_map()?
+                    .into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -385,6 +406,27 @@ fn any_values_to_time(values: &[AnyValue], strict: bool) -> PolarsResult<TimeChu
     Ok(builder.finish().into())
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                match av.cast(&DataType::Interval) {
+                    AnyValue::Interval(v) => builder.append_value(interval_to_i128(v)),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-datetime")]
 fn any_values_to_datetime(
     values: &[AnyValue],
@@ -304,6 +304,18 @@ pub mod checked {
                     .unwrap()
                     .apply(|opt_v| opt_v.and_then(|v| v.checked_div(rhs.to_i64().unwrap())))
                     .into_series(),
+                #[cfg(feature = "dtype-i128")]
+                Int128 => s
+                    .i128()
+                    .unwrap()
+                    .apply(|opt_v| opt_v.and_then(|v| v.checked_div(rhs.to_i128().unwrap())))
+                    .into_series(),
+                #[cfg(feature = "dtype-u128")]
+                UInt128 => s
+                    .u128()
+                    .unwrap()
+                    .apply(|opt_v| opt_v.and_then(|v| v.checked_div(rhs.to_u128().unwrap())))
+                    .into_series(),
                 Float32 => s
                     .f32()
                     .unwrap()
@@ -488,6 +500,9 @@ impl Add for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.add(b))
             },
+            // Decimals derive their output precision and scale from both operands.
+            #[cfg(feature = "dtype-decimal")]
+            (DataType::Decimal(_, _), DataType::Decimal(_, _)) => self.add_to(rhs),
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::add().execute(self, rhs)
             },
@@ -513,6 +528,8 @@ impl Sub for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
             },
+            #[cfg(feature = "dtype-decimal")]
+            (DataType::Decimal(_, _), DataType::Decimal(_, _)) => self.subtract(rhs),
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::sub().execute(self, rhs)
             },
@@ -554,6 +571,8 @@ impl Mul for &Series {
                 let out = rhs.multiply(self)?;
                 Ok(out.with_name(self.name().clone()))
             },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(_, _), Decimal(_, _)) => self.multiply(rhs),
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::mul().execute(self, rhs)
             },
@@ -591,6 +610,8 @@ impl Div for &Series {
             | (_, Time)
             | (_, Date)
             | (_, Datetime(_, _)) => polars_bail!(opq = div, self.dtype(), rhs.dtype()),
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(_, _), Decimal(_, _)) => self.divide(rhs),
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::div().execute(self, rhs)
             },
@@ -58,12 +58,16 @@ macro_rules! impl_operation {
                             Int16 => apply_operation_mut::<Int16Type, _>(lhs, rhs, $function),
                             Int32 => apply_operation_mut::<Int32Type, _>(lhs, rhs, $function),
                             Int64 => apply_operation_mut::<Int64Type, _>(lhs, rhs, $function),
+                            #[cfg(feature = "dtype-i128")]
+                            Int128 => apply_operation_mut::<Int128Type, _>(lhs, rhs, $function),
                             #[cfg(feature = "dtype-u8")]
                             UInt8 => apply_operation_mut::<UInt8Type, _>(lhs, rhs, $function),
                             #[cfg(feature = "dtype-u16")]
                             UInt16 => apply_operation_mut::<UInt16Type, _>(lhs, rhs, $function),
                             UInt32 => apply_operation_mut::<UInt32Type, _>(lhs, rhs, $function),
                             UInt64 => apply_operation_mut::<UInt64Type, _>(lhs, rhs, $function),
+                            #[cfg(feature = "dtype-u128")]
+                            UInt128 => apply_operation_mut::<UInt128Type, _>(lhs, rhs, $function),
                             Float32 => apply_operation_mut::<Float32Type, _>(lhs, rhs, $function),
                             Float64 => apply_operation_mut::<Float64Type, _>(lhs, rhs, $function),
                             _ => unreachable!(),
@@ -60,10 +60,14 @@ macro_rules! impl_eq_compare {
             UInt16 => lhs.u16().unwrap().$method(rhs.u16().unwrap()),
             UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
             UInt64 => lhs.u64().unwrap().$method(rhs.u64().unwrap()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => lhs.u128().unwrap().$method(rhs.u128().unwrap()),
             Int8 => lhs.i8().unwrap().$method(rhs.i8().unwrap()),
             Int16 => lhs.i16().unwrap().$method(rhs.i16().unwrap()),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => lhs.i128().unwrap().$method(rhs.i128().unwrap()),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
@@ -153,6 +157,10 @@ macro_rules! impl_ineq_compare {
                 rhs.name(), rhs.dtype()
             )
         )?;
+        // Intervals have no total order, e.g. "1mo" vs "30d".
+        if matches!(lhs.dtype(), Interval) {
+            bail_invalid_ineq!(lhs, rhs, $op)
+        }
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
@@ -164,10 +172,14 @@ macro_rules! impl_ineq_compare {
             UInt16 => lhs.u16().unwrap().$method(rhs.u16().unwrap()),
             UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
             UInt64 => lhs.u64().unwrap().$method(rhs.u64().unwrap()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => lhs.u128().unwrap().$method(rhs.u128().unwrap()),
             Int8 => lhs.i8().unwrap().$method(rhs.i8().unwrap()),
             Int16 => lhs.i16().unwrap().$method(rhs.i16().unwrap()),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => lhs.i128().unwrap().$method(rhs.i128().unwrap()),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => bail_invalid_ineq!(lhs, rhs, $op),
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::Metadata;
 #[cfg(feature = "dtype-categorical")]
 use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
@@ -44,12 +46,16 @@ impl Series {
             Int16 => Int16Chunked::from_chunks(name, chunks).into_series(),
             Int32 => Int32Chunked::from_chunks(name, chunks).into_series(),
             Int64 => Int64Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => Int128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-u8")]
             UInt8 => UInt8Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-u16")]
             UInt16 => UInt16Chunked::from_chunks(name, chunks).into_series(),
             UInt32 => UInt32Chunked::from_chunks(name, chunks).into_series(),
             UInt64 => UInt64Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => UInt128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-date")]
             Date => Int32Chunked::from_chunks(name, chunks)
                 .into_date()
@@ -62,6 +68,14 @@ impl Series {
             Duration(tu) => Int64Chunked::from_chunks(name, chunks)
                 .into_duration(*tu)
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-datetime")]
             Datetime(tu, tz) => Int64Chunked::from_chunks(name, chunks)
                 .into_datetime(*tu, tz.clone())
@@ -80,6 +94,14 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => ListChunked::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                DataType::map_entries(key.as_ref().clone(), value.as_ref().clone()),
+            )
+            .into_map_unchecked(key.as_ref().clone(), value.as_ref().clone())
+            .into_series(),
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -211,6 +233,15 @@ impl Series {
             ArrowDataType::Int16 => Ok(Int16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int32 => Ok(Int32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int64 => Ok(Int64Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-i128")]
+            ArrowDataType::Int128 => Ok(Int128Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-u128")]
+            ArrowDataType::UInt128 => Ok(UInt128Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                Ok(Float16Chunked::from_arrow_chunks(name, &chunks).into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -289,6 +320,10 @@ impl Series {
                     ArrowTimeUnit::Nanosecond => s,
                 })
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                Ok(IntervalChunked::from_arrow_chunks(name, &chunks).into_series())
+            },
             ArrowDataType::Null => Ok(new_null(name, &chunks)),
             #[cfg(not(feature = "dtype-categorical"))]
             ArrowDataType::Dictionary(_, _, _) => {
@@ -464,7 +499,7 @@ fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult
     let chunks = chunks
         .iter()
         .map(|arr| {
-            // we convert the map to the logical type: List<struct<key, value>>
+            // we convert the map to its physical layout: List<struct<key, value>>
             let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
             let inner = arr.field().clone();
 
@@ -478,7 +513,15 @@ fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult
             )) as ArrayRef
         })
         .collect::<Vec<_>>();
-    Series::try_from((name, chunks))
+    let s = Series::try_from((name, chunks))?;
+    #[cfg(feature = "dtype-map")]
+    {
+        Ok(s.list()?.clone().into_map()?.into_series())
+    }
+    #[cfg(not(feature = "dtype-map"))]
+    {
+        Ok(s)
+    }
 }
 
 fn convert<F: Fn(&dyn Array) -> ArrayRef>(arr: &[ArrayRef], f: F) -> Vec<ArrayRef> {
@@ -625,8 +668,17 @@ unsafe fn to_physical_and_dtype(
                 (arrays, DataType::Struct(pl_fields.unwrap()))
             })
         },
+        #[cfg(feature = "dtype-interval")]
+        dt @ ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         // Use Series architecture to convert nested logical types to physical.
-        dt @ (ArrowDataType::Duration(_)
+        dt @ (ArrowDataType::Map(_, _)
+        | ArrowDataType::Float16
+        | ArrowDataType::Duration(_)
         | ArrowDataType::Time32(_)
         | ArrowDataType::Time64(_)
         | ArrowDataType::Timestamp(_, _)
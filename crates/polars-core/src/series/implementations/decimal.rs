@@ -41,14 +41,13 @@ impl SeriesWrap<DecimalChunked> {
     fn agg_helper<F: Fn(&Int128Chunked) -> Series>(&self, f: F) -> Series {
         let agg_s = f(&self.0);
         match agg_s.dtype() {
-            DataType::Decimal(_, _) => {
-                let ca = agg_s.decimal().unwrap();
-                let ca = ca.as_ref().clone();
+            DataType::Int128 => {
+                let ca = agg_s.i128().unwrap().clone();
                 let precision = self.0.precision();
                 let scale = self.0.scale();
                 ca.into_decimal_unchecked(precision, scale).into_series()
             },
-            DataType::List(dtype) if dtype.is_decimal() => {
+            DataType::List(dtype) if matches!(dtype.as_ref(), DataType::Int128) => {
                 let dtype = self.0.dtype();
                 let ca = agg_s.list().unwrap();
                 let arr = ca.downcast_iter().next().unwrap();
@@ -83,19 +82,6 @@ impl SeriesWrap<DecimalChunked> {
     }
 }
 
-unsafe impl IntoSeries for Int128Chunked {
-    fn into_series(self) -> Series
-    where
-        Self: Sized,
-    {
-        // this is incorrect as it ignores the datatype
-        // the caller must correct this.
-        let mut ca = DecimalChunked::new_logical(self);
-        ca.2 = Some(DataType::Decimal(None, None));
-        ca.into_series()
-    }
-}
-
 impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
     fn compute_len(&mut self) {
         self.0.compute_len()
@@ -146,7 +132,8 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
-        self.agg_helper(|ca| ca.agg_sum(groups))
+        // The expression engine calls `try_agg_sum` directly to surface overflows as errors.
+        self.0.try_agg_sum(groups).unwrap().into_series()
     }
 
     #[cfg(feature = "algorithm_group_by")]
@@ -344,14 +331,9 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(_, Some(scale)) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        let sum = self.0.try_sum()?;
+        let dtype = decimal_sum_dtype(self.0.precision(), self.0.scale());
+        Ok(Scalar::new(dtype, AnyValue::Decimal(sum, self.0.scale())))
     }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
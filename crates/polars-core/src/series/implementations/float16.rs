@@ -0,0 +1,391 @@
+use super::*;
+use crate::chunked_array::logical::total_order_key;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn upcast(&self) -> Series {
+        self.0.to_f32().into_series()
+    }
+
+    /// Compute on the upcasted values and round the result back to half precision.
+    fn apply_upcasted<F>(&self, op: F) -> Series
+    where
+        F: FnOnce(Series) -> Series,
+    {
+        let out = op(self.upcast());
+        Float16Chunked::from_f32(out.f32().unwrap()).into_series()
+    }
+
+    fn arithmetic<F>(&self, rhs: &Series, op: F) -> PolarsResult<Series>
+    where
+        F: FnOnce(&Series, &Series) -> PolarsResult<Series>,
+    {
+        let out = op(&self.upcast(), &rhs.cast(&DataType::Float32)?)?;
+        match (rhs.dtype(), out.dtype()) {
+            (DataType::Float16, DataType::Float32) => out.cast(&DataType::Float16),
+            _ => Ok(out),
+        }
+    }
+
+    fn reduce_upcasted(&self, sc: PolarsResult<Scalar>) -> PolarsResult<Scalar> {
+        let sc = sc?;
+        Ok(Scalar::new(DataType::Float16, sc.value().clone()))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _get_flags(&self) -> MetadataFlags {
+        self.0.get_flags()
+    }
+
+    fn _set_flags(&mut self, flags: MetadataFlags) {
+        self.0.set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.to_physical_repr();
+        let other = other.u16().unwrap();
+        let key = |v: Option<u16>| v.map(total_order_key);
+        key(self.0.physical().get_unchecked(idx_self)) == key(other.get_unchecked(idx_other))
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.to_physical_repr().into_owned();
+        self.0
+            .zip_with(mask, other.as_ref().as_ref())
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(&self, random_state: PlRandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.0.to_total_order_keys().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlRandomState,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0
+            .to_total_order_keys()
+            .vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        self.apply_upcasted(|s| s.agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.apply_upcasted(|s| s.agg_max(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.apply_upcasted(|s| s.agg_sum(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.upcast().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.upcast().agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l - r)
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l + r)
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l * r)
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l / r)
+    }
+
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l % r)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.0
+            .to_total_order_keys()
+            .group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.to_f32().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_float16().into_series()
+    }
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (
+            a.into_float16().into_series(),
+            b.into_float16().into_series(),
+        )
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.upcast()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.upcast().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.upcast().median()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.upcast().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.upcast().var(ddof)
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.to_physical_repr();
+        // 3 refs
+        // ref Cow
+        // ref SeriesTrait
+        // ref ChunkedArray
+        self.0.append(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0.extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0
+            .filter(filter)
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_float16().into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0.take_unchecked(indices).into_float16().into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_float16().into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0.take_unchecked(indices).into_float16().into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_float16().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .new_from_index(index, length)
+            .into_float16()
+            .into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        // The bit patterns of negative values don't sort like the floats they encode.
+        let out = self.upcast().sort_with(options)?;
+        Ok(Float16Chunked::from_f32(out.f32()?).into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.to_f32().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        let keys = self.0.to_total_order_keys().unique()?;
+        Ok(Float16Chunked::from_total_order_keys(&keys).into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.to_total_order_keys().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.to_total_order_keys().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.reverse().into_float16().into_series()
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.shift(periods).into_float16().into_series()
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        self.reduce_upcasted(self.upcast().sum_reduce())
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        self.reduce_upcasted(self.upcast().max_reduce())
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        self.reduce_upcasted(self.upcast().min_reduce())
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.upcast().median_reduce()
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.upcast().var_reduce(ddof)
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.upcast().std_reduce(ddof)
+    }
+
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.upcast().quantile_reduce(quantile, method)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
@@ -13,8 +13,14 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -469,10 +475,15 @@ impl_dyn_series!(Int8Chunked, Int8Type);
 impl_dyn_series!(Int16Chunked, Int16Type);
 impl_dyn_series!(Int32Chunked, Int32Type);
 impl_dyn_series!(Int64Chunked, Int64Type);
+#[cfg(feature = "dtype-i128")]
+impl_dyn_series!(Int128Chunked, Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_dyn_series!(UInt128Chunked, UInt128Type);
 
 impl<T: PolarsNumericType> private::PrivateSeriesNumeric for SeriesWrap<ChunkedArray<T>> {
     fn bit_repr(&self) -> Option<BitRepr> {
-        Some(self.0.to_bit_repr())
+        // 128-bit integers don't fit in any of the bit representations.
+        (size_of::<T::Native>() <= 8).then(|| self.0.to_bit_repr())
     }
 }
 
@@ -156,6 +156,12 @@ impl Series {
                 &DataType::Time.to_arrow(compat_level),
             )
             .unwrap(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.float16().unwrap().to_arrow_chunk(chunk_idx),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => self.interval().unwrap().to_arrow_chunk(chunk_idx),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => self.map().unwrap().to_arrow_chunk(chunk_idx, compat_level),
             #[cfg(feature = "object")]
             DataType::Object(_, None) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -39,6 +39,10 @@ from_iterator!(i8, Int8Type);
 from_iterator!(i16, Int16Type);
 from_iterator!(i32, Int32Type);
 from_iterator!(i64, Int64Type);
+#[cfg(feature = "dtype-i128")]
+from_iterator!(i128, Int128Type);
+#[cfg(feature = "dtype-u128")]
+from_iterator!(u128, UInt128Type);
 from_iterator!(f32, Float32Type);
 from_iterator!(f64, Float64Type);
 from_iterator!(bool, BooleanType);
@@ -622,6 +622,20 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().0.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.float16().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => Cow::Owned(
+                self.map()
+                    .unwrap()
+                    .0
+                    .clone()
+                    .into_series()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 let ca = self.categorical().unwrap();
@@ -683,6 +697,23 @@ impl Series {
             Duration(u) => Ok(self.i64()?.clone().into_duration(*u).into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Ok(self.i64()?.clone().into_time().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(self.i128()?.clone().into_interval().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(self.u16()?.clone().into_float16().into_series()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let ca = self
+                    .to_logical_repr_unchecked(&DataType::map_entries(
+                        key.as_ref().clone(),
+                        value.as_ref().clone(),
+                    ))?
+                    .list()?
+                    .clone();
+                Ok(ca
+                    .into_map_unchecked(key.as_ref().clone(), value.as_ref().clone())
+                    .into_series())
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical { .. } | Enum { .. } => {
                 Ok(CategoricalChunked::from_cats_and_dtype_unchecked(
@@ -799,6 +830,21 @@ impl Series {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn into_interval(self) -> Series {
+        match self.dtype() {
+            DataType::Int128 => self.i128().unwrap().clone().into_interval().into_series(),
+            DataType::Interval => self
+                .interval()
+                .unwrap()
+                .as_ref()
+                .clone()
+                .into_interval()
+                .into_series(),
+            dt => panic!("into_interval not implemented for {dt:?}"),
+        }
+    }
+
     pub(crate) fn into_date(self) -> Series {
         #[cfg(not(feature = "dtype-date"))]
         {
@@ -886,8 +932,22 @@ impl Series {
         self.slice(-(len as i64), len)
     }
 
-    pub fn mean_reduce(&self) -> Scalar {
-        crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
+    pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.decimal() {
+            let dtype = decimal_mean_dtype(ca.precision(), ca.scale());
+            let DataType::Decimal(_, Some(scale)) = dtype else {
+                unreachable!()
+            };
+            let av = ca
+                .try_mean()?
+                .map_or(AnyValue::Null, |v| AnyValue::Decimal(v, scale));
+            return Ok(Scalar::new(dtype, av));
+        }
+        Ok(crate::scalar::reduce::mean_reduce(
+            self.mean(),
+            self.dtype().clone(),
+        ))
     }
 
     /// Compute the unique elements, but maintain order. This requires more work
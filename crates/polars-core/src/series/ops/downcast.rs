@@ -61,6 +61,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int64 => Int64Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Int128`]
+    #[cfg(feature = "dtype-i128")]
+    pub fn try_i128(&self) -> Option<&Int128Chunked> {
+        try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         try_unpack_chunked!(self, DataType::Float32 => Float32Chunked)
@@ -91,6 +97,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::UInt64 => UInt64Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn try_u128(&self) -> Option<&UInt128Chunked> {
+        try_unpack_chunked!(self, DataType::UInt128 => UInt128Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Boolean`]
     pub fn try_bool(&self) -> Option<&BooleanChunked> {
         try_unpack_chunked!(self, DataType::Boolean => BooleanChunked)
@@ -117,6 +129,18 @@ impl Series {
         try_unpack_chunked!(self, DataType::Time => TimeChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_float16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Date`]
     #[cfg(feature = "dtype-date")]
     pub fn try_date(&self) -> Option<&DateChunked> {
@@ -146,6 +170,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Array`]
     #[cfg(feature = "dtype-array")]
     pub fn try_array(&self) -> Option<&ArrayChunked> {
@@ -213,6 +243,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Int64"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Int128`]
+    #[cfg(feature = "dtype-i128")]
+    pub fn i128(&self) -> PolarsResult<&Int128Chunked> {
+        self.try_i128()
+            .ok_or_else(|| unpack_chunked_err!(self => "Int128"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.try_f32()
@@ -249,6 +286,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "UInt64"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.try_u128()
+            .ok_or_else(|| unpack_chunked_err!(self => "UInt128"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Boolean`]
     pub fn bool(&self) -> PolarsResult<&BooleanChunked> {
         self.try_bool()
@@ -280,6 +324,20 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Time"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_float16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Date`]
     #[cfg(feature = "dtype-date")]
     pub fn date(&self) -> PolarsResult<&DateChunked> {
@@ -314,6 +372,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "List"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Array`]
     #[cfg(feature = "dtype-array")]
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
@@ -13,6 +13,15 @@ impl Series {
             DataType::List(inner_dtype) => {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entries(key.as_ref().clone(), value.as_ref().clone());
+                let ca =
+                    ListChunked::full_null_with_dtype(name, size, entries.inner_dtype().unwrap());
+                // SAFETY: the inner dtype is the map entries layout.
+                unsafe { ca.into_map_unchecked(key.as_ref().clone(), value.as_ref().clone()) }
+                    .into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
@@ -47,6 +56,14 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => UInt16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
@@ -385,6 +385,10 @@ macro_rules! match_dtype_to_logical_apply_macro {
             DataType::Int16 => $macro!(Int16Type $(, $opt_args)*),
             DataType::Int32 => $macro!(Int32Type $(, $opt_args)*),
             DataType::Int64 => $macro!(Int64Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => $macro!(Int128Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!(UInt128Type $(, $opt_args)*),
             DataType::Float32 => $macro!(Float32Type $(, $opt_args)*),
             DataType::Float64 => $macro!(Float64Type $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -501,12 +505,16 @@ macro_rules! with_match_physical_numeric_polars_type {(
         Int16 => __with_ty__! { Int16Type },
         Int32 => __with_ty__! { Int32Type },
         Int64 => __with_ty__! { Int64Type },
+            #[cfg(feature = "dtype-i128")]
+        Int128 => __with_ty__! { Int128Type },
             #[cfg(feature = "dtype-u8")]
         UInt8 => __with_ty__! { UInt8Type },
             #[cfg(feature = "dtype-u16")]
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+            #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
         Float32 => __with_ty__! { Float32Type },
         Float64 => __with_ty__! { Float64Type },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -527,12 +535,16 @@ macro_rules! with_match_physical_integer_polars_type {(
         Int16 => __with_ty__! { Int16Type },
         Int32 => __with_ty__! { Int32Type },
         Int64 => __with_ty__! { Int64Type },
+        #[cfg(feature = "dtype-i128")]
+        Int128 => __with_ty__! { Int128Type },
         #[cfg(feature = "dtype-u8")]
         UInt8 => __with_ty__! { UInt8Type },
         #[cfg(feature = "dtype-u16")]
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
         dt => panic!("not implemented for dtype {:?}", dt),
     }
 })}
@@ -555,6 +567,10 @@ macro_rules! downcast_as_macro_arg_physical {
             DataType::Int16 => $macro!($self.i16().unwrap() $(, $opt_args)*),
             DataType::Int32 => $macro!($self.i32().unwrap() $(, $opt_args)*),
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for {:?}", dt),
@@ -664,6 +680,10 @@ macro_rules! apply_method_physical_integer {
             DataType::Int16 => $self.i16().unwrap().$method($($args),*),
             DataType::Int32 => $self.i32().unwrap().$method($($args),*),
             DataType::Int64 => $self.i64().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => $self.i128().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $self.u128().unwrap().$method($($args),*),
             dt => panic!("not implemented for dtype {:?}", dt),
         }
     }
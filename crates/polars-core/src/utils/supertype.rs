@@ -151,6 +151,24 @@ pub fn get_supertype_with_options(
             (Int64, Float32) => Some(Float64), // Follow numpy
             (Int64, Float64) => Some(Float64),
 
+            #[cfg(feature = "dtype-i128")]
+            (Int128, Boolean) => Some(Int128),
+            #[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+            (Int128, UInt128) => Some(Float64), // Follow numpy
+            #[cfg(feature = "dtype-i128")]
+            (Int128, dt) if dt.is_integer() => Some(Int128),
+            #[cfg(feature = "dtype-i128")]
+            (Int128, Float32 | Float64) => Some(Float64),
+
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, Boolean) => Some(UInt128),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, dt) if dt.is_unsigned_integer() => Some(UInt128),
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, dt) if dt.is_signed_integer() => Some(Float64), // Follow numpy
+            #[cfg(feature = "dtype-u128")]
+            (UInt128, Float32 | Float64) => Some(Float64),
+
             #[cfg(all(feature = "dtype-u16", feature = "dtype-u8"))]
             (UInt16, UInt8) => Some(UInt16),
             #[cfg(feature = "dtype-u16")]
@@ -265,6 +283,8 @@ pub fn get_supertype_with_options(
             (Duration(_), Date) | (Date, Duration(_)) => Some(Date),
             #[cfg(feature = "dtype-duration")]
             (Duration(lu), Duration(ru)) => Some(Duration(get_time_units(lu, ru))),
+            #[cfg(feature = "dtype-interval")]
+            (Duration(_), Interval) | (Interval, Duration(_)) => Some(Interval),
 
             // both None or both Some("<tz>") timezones
             // we cast from more precision to higher precision as that always fits with occasional loss of precision
@@ -281,6 +301,12 @@ pub fn get_supertype_with_options(
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(List(Box::new(st)))
             }
+            #[cfg(feature = "dtype-map")]
+            (Map(key_left, value_left), Map(key_right, value_right)) => {
+                let key = get_supertype(key_left, key_right)?;
+                let value = get_supertype(value_left, value_right)?;
+                Some(Map(Box::new(key), Box::new(value)))
+            }
             #[cfg(feature = "dtype-array")]
             (List(inner_left), Array(inner_right, _)) | (Array(inner_left, _), List(inner_right)) => {
                 let st = get_supertype(inner_left, inner_right)?;
@@ -318,6 +344,8 @@ pub fn get_supertype_with_options(
                     },
                     // numeric vs float|str -> always float|str|decimal
                     UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    #[cfg(feature = "dtype-f16")]
+                    UnknownKind::Float | UnknownKind::Int(_) if matches!(dt, Float16) => Some(Float16),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -350,6 +378,10 @@ pub fn get_supertype_with_options(
                                     // Ensure we don't cast to float when dealing with dynamic literals
                                     Some(Int64)
                                 },
+                                #[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+                                UInt128 if smallest_fitting_dtype.is_signed_integer() => {
+                                    Some(Int128)
+                                },
                                 _ => {
                                     get_supertype(dt, &smallest_fitting_dtype)
                                 }
@@ -375,12 +407,28 @@ pub fn get_supertype_with_options(
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(p1, s1), Decimal(p2, s2)) => {
-                Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+                // Keep room for the integral digits of both sides.
+                let precision = match (p1, s1, p2, s2) {
+                    (&Some(p1), &Some(s1), &Some(p2), &Some(s2)) => {
+                        Some((p1.saturating_sub(s1).max(p2.saturating_sub(s2)) + s1.max(s2)).min(DEC128_MAX_PRECISION))
+                    },
+                    _ => (*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)),
+                };
+                Some(Decimal(precision, (*s1).max(*s2)))
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
-            (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            (Decimal(p, s), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => {
+                let precision = (*p).map(|p| {
+                    p.max(s.unwrap_or(0) + integer_decimal_precision(dt))
+                        .min(DEC128_MAX_PRECISION)
+                });
+                Some(Decimal(precision, *s))
+            },
+            // Half-precision floats widen like a 32-bit float.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, dt) if dt.is_numeric() || dt.is_bool() || dt.is_decimal() => inner(&Float32, dt, options),
             _ => None,
         }
     }
@@ -388,6 +436,20 @@ pub fn get_supertype_with_options(
     inner(l, r, options).or_else(|| inner(r, l, options))
 }
 
+/// The number of decimal digits needed to hold any value of an integer type.
+#[cfg(feature = "dtype-decimal")]
+fn integer_decimal_precision(dtype: &DataType) -> usize {
+    use DataType::*;
+    match dtype {
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        UInt64 => 20,
+        _ => DEC128_MAX_PRECISION,
+    }
+}
+
 /// Given multiple data types, determine the data type that all types can safely be cast to.
 ///
 /// Returns [`DataType::Null`] if no data types were passed.
@@ -457,11 +519,23 @@ pub fn materialize_dyn_int(v: i128) -> AnyValue<'static> {
             Some(v) => AnyValue::Int64(v),
             None => match u64::try_from(v).ok() {
                 Some(v) => AnyValue::UInt64(v),
-                None => AnyValue::Null,
+                None => materialize_dyn_int_128(v),
             },
         },
     }
 }
+#[allow(unused_variables)]
+fn materialize_dyn_int_128(v: i128) -> AnyValue<'static> {
+    #[cfg(feature = "dtype-i128")]
+    {
+        AnyValue::Int128(v)
+    }
+    #[cfg(not(feature = "dtype-i128"))]
+    {
+        AnyValue::Null
+    }
+}
+
 fn materialize_dyn_int_pos(v: i128) -> AnyValue<'static> {
     // Try to get the "smallest" fitting value.
     // TODO! next breaking go to true smallest.
@@ -473,7 +547,7 @@ fn materialize_dyn_int_pos(v: i128) -> AnyValue<'static> {
                 Some(v) => AnyValue::UInt32(v),
                 None => match u64::try_from(v).ok() {
                     Some(v) => AnyValue::UInt64(v),
-                    None => AnyValue::Null,
+                    None => materialize_dyn_int_128(v),
                 },
             },
         },
@@ -491,7 +565,7 @@ fn materialize_smallest_dyn_int(v: i128) -> AnyValue<'static> {
                     Some(v) => AnyValue::Int64(v),
                     None => match u64::try_from(v).ok() {
                         Some(v) => AnyValue::UInt64(v),
-                        None => AnyValue::Null,
+                        None => materialize_dyn_int_128(v),
                     },
                 },
             },
@@ -135,7 +135,7 @@ impl PhysicalExpr for AggregationExpr {
                 panic!("activate 'propagate_nans' feature")
             },
             GroupByMethod::Median => s.median_reduce().map(|sc| sc.into_column(s.name().clone())),
-            GroupByMethod::Mean => Ok(s.mean_reduce().into_column(s.name().clone())),
+            GroupByMethod::Mean => s.mean_reduce().map(|sc| sc.into_column(s.name().clone())),
             GroupByMethod::First => Ok(if s.is_empty() {
                 Column::full_null(s.name().clone(), 1, s.dtype())
             } else {
@@ -239,12 +239,29 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Mean => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_mean(&groups);
+                    let agg_c = match c.dtype() {
+                        // Decimal means can overflow, which the grouped kernels can't report.
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => c
+                            .as_materialized_series()
+                            .decimal()?
+                            .try_agg_mean(&groups)?
+                            .into_column(),
+                        _ => c.agg_mean(&groups),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Sum => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_sum(&groups);
+                    let agg_c = match c.dtype() {
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => c
+                            .as_materialized_series()
+                            .decimal()?
+                            .try_agg_sum(&groups)?
+                            .into_column(),
+                        _ => c.agg_sum(&groups),
+                    };
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
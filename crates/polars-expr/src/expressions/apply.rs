@@ -582,6 +582,10 @@ impl ApplyExpr {
                     let input = input.as_materialized_series();
 
                     let st = stats.get_stats(&root).ok()?;
+                    // The bloom filter can rule out values within the min/max range.
+                    if !st.may_contain_any(input) {
+                        return Some(false);
+                    }
                     let min = st.to_min()?;
                     let max = st.to_max()?;
 
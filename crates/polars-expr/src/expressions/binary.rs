@@ -68,6 +68,8 @@ pub fn apply_operator(left: &Column, right: &Column, op: Operator) -> PolarsResu
         Operator::TrueDivide => match left.dtype() {
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => left / right,
+            #[cfg(feature = "dtype-decimal")]
+            _ if right.dtype().is_decimal() && left.dtype().is_integer() => left / right,
             Duration(_) | Date | Datetime(_, _) | Float32 | Float64 => left / right,
             #[cfg(feature = "dtype-array")]
             Array(..) => left / right,
@@ -285,7 +287,7 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
-    use polars_io::predicates::{BatchStats, StatsEvaluator};
+    use polars_io::predicates::{BatchStats, ColumnStats, StatsEvaluator};
 
     use super::*;
 
@@ -387,6 +389,12 @@ mod stats {
         }
     }
 
+    /// The bloom filter of a column can rule out a literal of an equality predicate that lies
+    /// within the min/max range of the column.
+    fn bloom_filter_may_contain(stats: &ColumnStats, literal: &Column, op: Operator) -> bool {
+        !matches!(op, Operator::Eq) || stats.may_contain_any(literal.as_materialized_series())
+    }
+
     impl BinaryExpr {
         fn impl_should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
             // See: #5864 for the rationale behind this.
@@ -435,33 +443,27 @@ mod stats {
             let out = match (self.left.evaluate_inline(), self.right.evaluate_inline()) {
                 (None, Some(lit_s)) => {
                     let l = stats.get_stats(fld_l.name())?;
-                    match l.to_min_max() {
-                        None => Ok(true),
+                    let read = match l.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            Ok(apply_operator_stats_rhs_lit(
-                                &min_max_s.into_column(),
-                                &lit_s,
-                                self.op,
-                            ))
+                            apply_operator_stats_rhs_lit(&min_max_s.into_column(), &lit_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && bloom_filter_may_contain(l, &lit_s, self.op))
                 },
                 (Some(lit_s), None) => {
                     let r = stats.get_stats(fld_r.name())?;
-                    match r.to_min_max() {
-                        None => Ok(true),
+                    let read = match r.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            Ok(apply_operator_stats_lhs_lit(
-                                &lit_s,
-                                &min_max_s.into_column(),
-                                self.op,
-                            ))
+                            apply_operator_stats_lhs_lit(&lit_s, &min_max_s.into_column(), self.op)
                         },
-                    }
+                    };
+                    Ok(read && bloom_filter_may_contain(r, &lit_s, self.op))
                 },
                 // Default: read the file
                 _ => Ok(true),
@@ -101,6 +101,12 @@ impl LiteralExpr {
                     .into_time()
                     .into_column()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval(months, days, ns) => IntervalChunked::from_intervals(
+                get_literal_name().clone(),
+                [Some(arrow::types::months_days_ns::new(*months, *days, *ns))],
+            )
+            .into_column(),
             Series(series) => series.deref().clone().into_column(),
             OtherScalar(s) => s.clone().into_column(get_literal_name().clone()),
             lv @ (Int(_) | Float(_) | StrCat(_)) => polars_core::prelude::Series::from_any_values(
@@ -225,6 +225,9 @@ impl PhysicalExpr for SortByExpr {
                         e.evaluate(df, state).map(|s| match s.dtype() {
                             #[cfg(feature = "dtype-categorical")]
                             DataType::Categorical(_, _) | DataType::Enum(_, _) => s,
+                            // The bits of half-precision floats don't order like the floats.
+                            #[cfg(feature = "dtype-f16")]
+                            DataType::Float16 => s,
                             _ => s.to_physical_repr(),
                         })
                     })
@@ -288,6 +291,8 @@ impl PhysicalExpr for SortByExpr {
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => {
                         c.as_materialized_series().clone()
                     },
+                    #[cfg(feature = "dtype-f16")]
+                    DataType::Float16 => c.as_materialized_series().clone(),
                     // @scalar-opt
                     // @partition-opt
                     _ => c.to_physical_repr().take_materialized_series(),
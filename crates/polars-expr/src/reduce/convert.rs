@@ -23,8 +23,8 @@ pub fn into_reduction(
     };
     let out = match expr_arena.get(node) {
         AExpr::Agg(agg) => match agg {
-            IRAggExpr::Sum(input) => (new_sum_reduction(get_dt(*input)?), *input),
-            IRAggExpr::Mean(input) => (new_mean_reduction(get_dt(*input)?), *input),
+            IRAggExpr::Sum(input) => (new_sum_reduction(get_dt(*input)?)?, *input),
+            IRAggExpr::Mean(input) => (new_mean_reduction(get_dt(*input)?)?, *input),
             IRAggExpr::Min {
                 propagate_nans,
                 input,
@@ -33,11 +33,12 @@ pub fn into_reduction(
                 propagate_nans,
                 input,
             } => (new_max_reduction(get_dt(*input)?, *propagate_nans), *input),
-            IRAggExpr::Var(input, ddof) => {
-                (new_var_std_reduction(get_dt(*input)?, false, *ddof), *input)
-            },
+            IRAggExpr::Var(input, ddof) => (
+                new_var_std_reduction(get_dt(*input)?, false, *ddof)?,
+                *input,
+            ),
             IRAggExpr::Std(input, ddof) => {
-                (new_var_std_reduction(get_dt(*input)?, true, *ddof), *input)
+                (new_var_std_reduction(get_dt(*input)?, true, *ddof)?, *input)
             },
             _ => todo!(),
         },
@@ -5,10 +5,10 @@ use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
 
-pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+pub fn new_mean_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
     use DataType::*;
     use VecGroupedReduction as VGR;
-    match dtype {
+    Ok(match dtype {
         Boolean => Box::new(VGR::new(dtype, BoolMeanReducer)),
         _ if dtype.is_numeric() || dtype.is_temporal() => {
             with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
@@ -16,13 +16,13 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
-        _ => unimplemented!(),
-    }
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
+        _ => polars_bail!(opq = mean, dtype),
+    })
 }
 
-fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
-    match dtype {
+fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> PolarsResult<Series> {
+    Ok(match dtype {
         DataType::Float32 => {
             let ca: Float32Chunked = values
                 .into_iter()
@@ -37,15 +37,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(scale.unwrap() as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const MS_IN_DAY: i64 = 86_400_000;
@@ -60,10 +51,10 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .into_iter()
                 .map(|(s, c)| (c != 0).then(|| (s / c as f64) as i64))
                 .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series().cast(dtype).unwrap()
+            ca.into_series().cast(dtype)?
         },
-        _ => unimplemented!(),
-    }
+        _ => polars_bail!(opq = mean, dtype),
+    })
 }
 
 struct NumMeanReducer<T>(PhantomData<T>);
@@ -114,7 +105,7 @@ where
         dtype: &DataType,
     ) -> PolarsResult<Series> {
         assert!(m.is_none());
-        Ok(finish_output(v, dtype))
+        finish_output(v, dtype)
     }
 }
 
@@ -162,3 +153,76 @@ impl Reducer for BoolMeanReducer {
         Ok(ca.into_series())
     }
 }
+
+/// Averages decimals exactly, remembering an overflow of the sum so that `finish` can raise it.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    type Value = (Option<i128>, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (Some(0), 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.zip(b.0).and_then(|(a, b)| a.checked_add(b));
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>) {
+        if let Some(b) = b {
+            a.0 = a.0.and_then(|a| a.checked_add(b));
+            a.1 += 1;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>) {
+        for arr in ca.downcast_iter() {
+            for b in arr.non_null_values_iter() {
+                self.reduce_one(v, Some(b));
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(in_scale)) = dtype else {
+            unreachable!()
+        };
+        let out_dtype = decimal_mean_dtype(*precision, *in_scale);
+        let DataType::Decimal(precision, Some(out_scale)) = out_dtype else {
+            unreachable!()
+        };
+        let ca = v
+            .into_iter()
+            .map(|(sum, count)| {
+                if count == 0 {
+                    return Ok(None);
+                }
+                sum.and_then(|sum| decimal_mean(sum, count, *in_scale, out_scale))
+                    .and_then(|mean| decimal_fit_precision(mean, Some(DEC128_MAX_PRECISION)))
+                    .map(Some)
+                    .ok_or_else(|| decimal_overflow_err(&out_dtype))
+            })
+            .collect::<PolarsResult<Int128Chunked>>()?;
+        Ok(ca
+            .into_decimal_unchecked(precision, out_scale)
+            .into_series())
+    }
+}
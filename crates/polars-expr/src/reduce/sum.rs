@@ -19,22 +19,26 @@ impl<T: PolarsNumericType> SumReduce<T> {
     }
 }
 
-pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+pub fn new_sum_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
     use DataType::*;
-    match dtype {
+    Ok(match dtype {
         Boolean => Box::new(SumReduce::<IdxType>::new(dtype)),
         Int8 | UInt8 | Int16 | UInt16 => Box::new(SumReduce::<Int64Type>::new(dtype)),
         UInt32 => Box::new(SumReduce::<UInt32Type>::new(dtype)),
         UInt64 => Box::new(SumReduce::<UInt64Type>::new(dtype)),
         Int32 => Box::new(SumReduce::<Int32Type>::new(dtype)),
         Int64 => Box::new(SumReduce::<Int64Type>::new(dtype)),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => Box::new(SumReduce::<Int128Type>::new(dtype)),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => Box::new(SumReduce::<UInt128Type>::new(dtype)),
         Float32 => Box::new(SumReduce::<Float32Type>::new(dtype)),
         Float64 => Box::new(SumReduce::<Float64Type>::new(dtype)),
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(SumReduce::<Int128Type>::new(dtype)),
+        Decimal(_, _) => Box::new(VecGroupedReduction::new(dtype, DecimalSumReducer)),
         Duration(_) => Box::new(SumReduce::<Int64Type>::new(dtype)),
-        _ => unimplemented!(),
-    }
+        _ => polars_bail!(opq = sum, dtype),
+    })
 }
 
 fn cast_sum_input<'a>(s: &'a Series, dt: &DataType) -> PolarsResult<Cow<'a, Series>> {
@@ -42,10 +46,6 @@ fn cast_sum_input<'a>(s: &'a Series, dt: &DataType) -> PolarsResult<Cow<'a, Seri
     match dt {
         Boolean => Ok(Cow::Owned(s.cast(&IDX_DTYPE)?)),
         Int8 | UInt8 | Int16 | UInt16 => Ok(Cow::Owned(s.cast(&Int64)?)),
-        #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Ok(Cow::Owned(
-            s.decimal().unwrap().physical().clone().into_series(),
-        )),
         #[cfg(feature = "dtype-duration")]
         Duration(_) => Ok(Cow::Owned(
             s.duration().unwrap().physical().clone().into_series(),
@@ -180,3 +180,69 @@ where
         self
     }
 }
+
+/// Sums decimals exactly, remembering an overflow so that `finish` can raise it.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalSumReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalSumReducer {
+    type Dtype = Int128Type;
+    type Value = Option<i128>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        Some(0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = a.zip(*b).and_then(|(a, b)| a.checked_add(b));
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>) {
+        if let Some(b) = b {
+            *a = a.and_then(|a| a.checked_add(b));
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>) {
+        for arr in ca.downcast_iter() {
+            for b in arr.non_null_values_iter() {
+                self.reduce_one(v, Some(b));
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let out_dtype = decimal_sum_dtype(*precision, *scale);
+        let sums = v
+            .into_iter()
+            .map(|sum| {
+                sum.and_then(|sum| decimal_fit_precision(sum, Some(DEC128_MAX_PRECISION)))
+                    .ok_or_else(|| decimal_overflow_err(&out_dtype))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let DataType::Decimal(precision, _) = out_dtype else {
+            unreachable!()
+        };
+        Ok(Int128Chunked::from_vec(PlSmallStr::EMPTY, sums)
+            .into_decimal_unchecked(precision, *scale)
+            .into_series())
+    }
+}
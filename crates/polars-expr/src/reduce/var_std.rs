@@ -6,10 +6,14 @@ use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
 
-pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn GroupedReduction> {
+pub fn new_var_std_reduction(
+    dtype: DataType,
+    is_std: bool,
+    ddof: u8,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
     use DataType::*;
     use VecGroupedReduction as VGR;
-    match dtype {
+    Ok(match dtype {
         Boolean => Box::new(VGR::new(dtype, BoolVarStdReducer { is_std, ddof })),
         _ if dtype.is_numeric() => {
             with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
@@ -31,9 +35,11 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
                 _phantom: PhantomData,
             },
         )),
-        Duration(..) => todo!(),
-        _ => unimplemented!(),
-    }
+        _ => {
+            let op = if is_std { "`std`" } else { "`var`" };
+            polars_bail!(op = op, dtype)
+        },
+    })
 }
 
 struct VarStdReducer<T> {
@@ -126,19 +126,13 @@ impl ObjectStoreCredential {
 
 pub trait IntoCredentialProvider: Sized {
     #[cfg(feature = "aws")]
-    fn into_aws_provider(self) -> object_store::aws::AwsCredentialProvider {
-        unimplemented!()
-    }
+    fn into_aws_provider(self) -> object_store::aws::AwsCredentialProvider;
 
     #[cfg(feature = "azure")]
-    fn into_azure_provider(self) -> object_store::azure::AzureCredentialProvider {
-        unimplemented!()
-    }
+    fn into_azure_provider(self) -> object_store::azure::AzureCredentialProvider;
 
     #[cfg(feature = "gcp")]
-    fn into_gcp_provider(self) -> object_store::gcp::GcpCredentialProvider {
-        unimplemented!()
-    }
+    fn into_gcp_provider(self) -> object_store::gcp::GcpCredentialProvider;
 }
 
 impl IntoCredentialProvider for PlCredentialProvider {
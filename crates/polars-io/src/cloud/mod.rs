@@ -22,3 +22,5 @@ pub use polars_object_store::*;
 
 #[cfg(feature = "cloud")]
 pub mod credential_provider;
+#[cfg(any(feature = "azure", feature = "gcp"))]
+pub mod native_credential_provider;
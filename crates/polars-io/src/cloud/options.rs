@@ -38,6 +38,10 @@ use url::Url;
 
 #[cfg(feature = "cloud")]
 use super::credential_provider::PlCredentialProvider;
+#[cfg(feature = "azure")]
+use super::native_credential_provider::AzureCredentialSource;
+#[cfg(feature = "gcp")]
+use super::native_credential_provider::GcpCredentialSource;
 #[cfg(feature = "file_cache")]
 use crate::file_cache::get_env_file_cache_ttl;
 #[cfg(feature = "aws")]
@@ -94,6 +98,17 @@ impl Default for CloudOptions {
     }
 }
 
+/// The typed configuration in the environment variables starting with `prefix`, as read by the
+/// `from_env` constructors of the object_store builders.
+#[cfg(any(feature = "azure", feature = "gcp"))]
+fn env_config<T: FromStr + Eq + std::hash::Hash>(prefix: &str) -> Configs<T> {
+    parsed_untyped_config::<T, _>(std::env::vars_os().filter_map(|(key, value)| {
+        let (key, value) = (key.into_string().ok()?, value.into_string().ok()?);
+        key.starts_with(prefix).then_some((key, value))
+    }))
+    .unwrap_or_default()
+}
+
 #[cfg(feature = "http")]
 pub(crate) fn try_build_http_header_map_from_items_slice<S: AsRef<str>>(
     headers: &[(S, S)],
@@ -418,7 +433,20 @@ impl CloudOptions {
             .with_url(url)
             .with_retry(get_retry_config(self.max_retries));
 
-        let builder = if let Some(v) = self.credential_provider.clone() {
+        // Without a credential provider, use the native provider for the credentials that
+        // object_store would pick from the environment and the config.
+        let credential_provider = self.credential_provider.clone().or_else(|| {
+            let mut config = env_config::<AzureConfigKey>("AZURE_");
+            if let Ok(endpoint) = std::env::var("IDENTITY_ENDPOINT") {
+                config.push((AzureConfigKey::MsiEndpoint, endpoint));
+            }
+            if let Some(CloudConfig::Azure(options)) = &self.config {
+                config.extend(options.iter().cloned());
+            }
+            AzureCredentialSource::from_config(&config).map(PlCredentialProvider::from_azure)
+        });
+
+        let builder = if let Some(v) = credential_provider {
             builder.with_credentials(v.into_azure_provider())
         } else {
             builder
@@ -463,7 +491,21 @@ impl CloudOptions {
             .with_url(url)
             .with_retry(get_retry_config(self.max_retries));
 
-        let builder = if let Some(v) = self.credential_provider.clone() {
+        // Without a credential provider, use the native provider for the credentials that
+        // object_store would pick from the environment and the config.
+        let credential_provider = self.credential_provider.clone().or_else(|| {
+            let mut config = vec![];
+            if let Ok(path) = std::env::var("SERVICE_ACCOUNT") {
+                config.push((GoogleConfigKey::ServiceAccount, path));
+            }
+            config.extend(env_config::<GoogleConfigKey>("GOOGLE_"));
+            if let Some(CloudConfig::Gcp(options)) = &self.config {
+                config.extend(options.iter().cloned());
+            }
+            GcpCredentialSource::from_config(&config).map(PlCredentialProvider::from_gcp)
+        });
+
+        let builder = if let Some(v) = credential_provider {
             builder.with_credentials(v.into_gcp_provider())
         } else {
             builder
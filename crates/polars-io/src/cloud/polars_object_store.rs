@@ -6,7 +6,7 @@ use futures::{StreamExt, TryStreamExt};
 use object_store::path::Path;
 use object_store::{ObjectMeta, ObjectStore};
 use polars_core::prelude::{InitHashMaps, PlHashMap};
-use polars_error::{to_compute_err, PolarsError, PolarsResult};
+use polars_error::{polars_err, to_compute_err, PolarsError, PolarsResult};
 use tokio::io::AsyncWriteExt;
 
 use crate::pl_async::{
@@ -34,8 +34,7 @@ impl PolarsObjectStore {
            + TryStreamExt<Ok = Bytes, Error = PolarsError, Item = PolarsResult<Bytes>>
            + use<'a, T> {
         futures::stream::iter(
-            ranges
-                .map(|range| async { self.0.get_range(path, range).await.map_err(to_compute_err) }),
+            ranges.map(|range| async { self.0.get_range(path, range).await.map_err(range_err) }),
         )
         // Add a limit locally as this gets run inside a single `tune_with_concurrency_budget`.
         .buffered(get_concurrency_limit() as usize)
@@ -47,7 +46,7 @@ impl PolarsObjectStore {
         if parts.len() == 1 {
             tune_with_concurrency_budget(1, || self.0.get_range(path, range))
                 .await
-                .map_err(to_compute_err)
+                .map_err(range_err)
         } else {
             let parts = tune_with_concurrency_budget(
                 parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
@@ -237,6 +236,19 @@ impl PolarsObjectStore {
     }
 }
 
+/// Converts the error of a range request. HTTP servers without support for range requests respond
+/// with the entire file, which `object_store` reports as an unsupported operation.
+fn range_err(err: object_store::Error) -> PolarsError {
+    match err {
+        object_store::Error::NotSupported { .. } => polars_err!(
+            ComputeError:
+            "the server does not support range requests, which are required to read parts of \
+            a file: {}", err
+        ),
+        err => to_compute_err(err),
+    }
+}
+
 /// Splits a single range into multiple smaller ranges, which can be downloaded concurrently for
 /// much higher throughput.
 fn split_range(range: Range<usize>) -> impl ExactSizeIterator<Item = Range<usize>> {
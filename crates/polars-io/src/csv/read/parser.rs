@@ -13,7 +13,11 @@ use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
 use crate::path_utils::is_cloud_url;
-use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::compression::ChunkedDecompressor;
+
+/// Number of decompressed bytes that are counted at once when counting the rows of compressed
+/// files.
+const DECOMPRESSION_BLOCK_SIZE: usize = 1 << 24;
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
@@ -38,11 +42,9 @@ pub fn count_rows(
     };
 
     let mmap = unsafe { memmap::Mmap::map(&file).unwrap() };
-    let owned = &mut vec![];
-    let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
 
     count_rows_from_slice(
-        reader_bytes,
+        mmap.as_ref(),
         separator,
         quote_char,
         comment_prefix,
@@ -53,14 +55,40 @@ pub fn count_rows(
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
+///
+/// Compressed data is decompressed and counted one block at a time.
 pub fn count_rows_from_slice(
-    mut bytes: &[u8],
+    bytes: &[u8],
     separator: u8,
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
 ) -> PolarsResult<usize> {
+    let Some(mut decompressor) = ChunkedDecompressor::try_new(bytes, DECOMPRESSION_BLOCK_SIZE)?
+    else {
+        let bytes = skip_leading_eols(bytes, eol_char);
+        let count = count_rows_in_block(bytes, separator, quote_char, comment_prefix, eol_char)?;
+        return Ok(count - (has_header as usize));
+    };
+
+    let counter = CountLines::new(quote_char, eol_char);
+    let mut count = 0;
+    let mut is_first_block = true;
+    while let Some(block) = decompressor.next_block(|bytes| {
+        let (count, position) = counter.count(bytes);
+        (count > 0).then_some(position + 1)
+    })? {
+        let mut bytes = block.as_slice();
+        if std::mem::take(&mut is_first_block) {
+            bytes = skip_leading_eols(bytes, eol_char);
+        }
+        count += count_rows_in_block(bytes, separator, quote_char, comment_prefix, eol_char)?;
+    }
+    Ok(count - (has_header as usize))
+}
+
+fn skip_leading_eols(mut bytes: &[u8], eol_char: u8) -> &[u8] {
     for _ in 0..bytes.len() {
         if bytes[0] != eol_char {
             break;
@@ -68,7 +96,17 @@ pub fn count_rows_from_slice(
 
         bytes = &bytes[1..];
     }
+    bytes
+}
 
+/// Count the rows in decompressed bytes that start at the beginning of a row.
+fn count_rows_in_block(
+    bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    comment_prefix: Option<&CommentPrefix>,
+    eol_char: u8,
+) -> PolarsResult<usize> {
     const MIN_ROWS_PER_THREAD: usize = 1024;
     let max_threads = POOL.current_num_threads();
 
@@ -102,12 +140,7 @@ pub fn count_rows_from_slice(
         }
     });
 
-    let count_result: PolarsResult<usize> = POOL.install(|| iter.sum());
-
-    match count_result {
-        Ok(val) => Ok(val - (has_header as usize)),
-        Err(err) => Err(err),
-    }
+    POOL.install(|| iter.sum())
 }
 
 /// Skip the utf-8 Byte Order Mark.
@@ -23,7 +23,6 @@ use super::schema_inference::{check_decimal_comma, infer_file_schema};
 use super::utils::decompress;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
-#[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
 use crate::utils::compression::SupportedCompression;
 use crate::utils::update_row_counts2;
 use crate::RowIndex;
@@ -98,6 +97,8 @@ pub fn cast_columns(
 /// CSV file reader
 pub(crate) struct CoreReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
+    /// Whether `reader_bytes` still has to be decompressed
+    is_compressed: bool,
     /// Explicit schema for the CSV file
     schema: SchemaRef,
     /// Optional projection for which columns to load (zero-based column indices)
@@ -183,25 +184,48 @@ impl<'a> CoreReader<'a> {
                 compile with feature 'decompress' or 'decompress-fast'"
             );
         }
-        // We keep track of the inferred schema bool
-        // In case the file is compressed this schema inference is wrong and has to be done
-        // again after decompression.
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        let is_compressed = false;
+
+        // Compressed data is only decompressed up front if we need a bounded number of rows, or
+        // all rows for schema inference. Otherwise it stays compressed, so that the batched reader
+        // can decompress it incrementally.
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-        {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
-            if let Some(b) =
-                decompress(&reader_bytes, total_n_rows, separator, quote_char, eol_char)
-            {
-                reader_bytes = ReaderBytes::Owned(b.into());
+        let mut is_compressed = false;
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        if SupportedCompression::check(&reader_bytes).is_some() {
+            if n_rows.is_some() || (schema.is_none() && max_records.is_none()) {
+                let total_n_rows =
+                    n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+                if let Some(b) =
+                    decompress(&reader_bytes, total_n_rows, separator, quote_char, eol_char)
+                {
+                    reader_bytes = ReaderBytes::Owned(b.into());
+                }
+            } else {
+                is_compressed = true;
             }
         }
 
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
+                // Only the rows used for inference are decompressed.
+                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+                let head = is_compressed
+                    .then(|| {
+                        let n_rows = max_records.map(|n| {
+                            skip_rows + (has_header as usize) + skip_rows_after_header + n
+                        });
+                        decompress(&reader_bytes, n_rows, separator, quote_char, eol_char)
+                    })
+                    .flatten()
+                    .map(|b| ReaderBytes::Owned(b.into()));
+                #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+                let head: Option<ReaderBytes> = None;
+
                 let (inferred_schema, _, _) = infer_file_schema(
-                    &reader_bytes,
+                    head.as_ref().unwrap_or(&reader_bytes),
                     separator,
                     max_records,
                     has_header,
@@ -243,6 +267,7 @@ impl<'a> CoreReader<'a> {
 
         Ok(CoreReader {
             reader_bytes: Some(reader_bytes),
+            is_compressed,
             schema,
             projection,
             current_line: usize::from(has_header),
@@ -345,24 +370,7 @@ impl<'a> CoreReader<'a> {
 
         // An empty file with a schema should return an empty DataFrame with that schema
         if bytes.is_empty() {
-            let mut df = if projection.len() == self.schema.len() {
-                DataFrame::empty_with_schema(self.schema.as_ref())
-            } else {
-                DataFrame::empty_with_schema(
-                    &projection
-                        .iter()
-                        .map(|&i| self.schema.get_at_index(i).unwrap())
-                        .map(|(name, dtype)| Field {
-                            name: name.clone(),
-                            dtype: dtype.clone(),
-                        })
-                        .collect::<Schema>(),
-                )
-            };
-            if let Some(ref row_index) = self.row_index {
-                df.insert_column(0, Series::new_empty(row_index.name.clone(), &IDX_DTYPE))?;
-            }
-            return Ok(df);
+            return self.empty_df(&projection);
         }
 
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
@@ -505,6 +513,58 @@ impl<'a> CoreReader<'a> {
         accumulate_dataframes_vertical(dfs)
     }
 
+    fn empty_df(&self, projection: &[usize]) -> PolarsResult<DataFrame> {
+        let mut df = if projection.len() == self.schema.len() {
+            DataFrame::empty_with_schema(self.schema.as_ref())
+        } else {
+            DataFrame::empty_with_schema(
+                &projection
+                    .iter()
+                    .map(|&i| self.schema.get_at_index(i).unwrap())
+                    .map(|(name, dtype)| Field {
+                        name: name.clone(),
+                        dtype: dtype.clone(),
+                    })
+                    .collect::<Schema>(),
+            )
+        };
+        if let Some(ref row_index) = self.row_index {
+            df.insert_column(0, Series::new_empty(row_index.name.clone(), &IDX_DTYPE))?;
+        }
+        Ok(df)
+    }
+
+    /// Read compressed data with the batched reader, so that it is decompressed and parsed one
+    /// block at a time instead of decompressing the whole file up front.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn finish_compressed(mut self, reader_bytes: ReaderBytes<'a>) -> PolarsResult<DataFrame> {
+        let projection = self.get_projection()?;
+        let empty_df = self.empty_df(&projection)?;
+        self.projection = Some(projection);
+
+        let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
+        let predicate = self.predicate.take();
+        self.reader_bytes = Some(reader_bytes);
+        let mut reader = self.batched()?;
+
+        let mut dfs = vec![];
+        while let Some(batches) = reader.next_batches(n_threads)? {
+            for mut df in batches {
+                if let Some(predicate) = predicate.as_ref() {
+                    let s = predicate.evaluate_io(&df)?;
+                    let mask = s.bool()?;
+                    df = df.filter(mask)?;
+                }
+                dfs.push(df);
+            }
+        }
+
+        if dfs.is_empty() {
+            return Ok(empty_df);
+        }
+        accumulate_dataframes_vertical(dfs)
+    }
+
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn finish(mut self) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
@@ -516,6 +576,11 @@ impl<'a> CoreReader<'a> {
 
         let reader_bytes = self.reader_bytes.take().unwrap();
 
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        if self.is_compressed {
+            return self.finish_compressed(reader_bytes);
+        }
+
         let mut df = self.parse_csv(&reader_bytes)?;
 
         // if multi-threaded the n_rows was probabilistically determined.
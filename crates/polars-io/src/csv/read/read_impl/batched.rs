@@ -6,14 +6,16 @@ use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_core::POOL;
 use polars_error::PolarsResult;
+use polars_utils::mmap::MemSlice;
 use polars_utils::IdxSize;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use super::{cast_columns, read_chunk, CoreReader, CountLines};
+use super::{cast_columns, find_starting_point, read_chunk, CoreReader, CountLines};
 use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::update_row_counts2;
+use crate::utils::compression::ChunkedDecompressor;
 use crate::RowIndex;
 
 #[allow(clippy::too_many_arguments)]
@@ -115,45 +117,81 @@ impl Iterator for ChunkOffsetIter<'_> {
     }
 }
 
+/// Number of decompressed bytes per thread that the batched reader decompresses at once when
+/// reading compressed data.
+const DECOMPRESSION_BLOCK_SIZE_PER_THREAD: usize = 1 << 22;
+
+fn chunk_offsets_iter(
+    bytes: &[u8],
+    n_threads: usize,
+    rows_per_batch: usize,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> ChunkOffsetIter<'_> {
+    // Copied from [`CoreReader::parse_csv`]
+    let n_parts_hint = n_threads * 16;
+    let chunk_size = std::cmp::min(bytes.len() / n_parts_hint, 16 * 1024 * 1024);
+
+    // Use a small min chunk size to catch failures in tests.
+    #[cfg(debug_assertions)]
+    let min_chunk_size = 64;
+    #[cfg(not(debug_assertions))]
+    let min_chunk_size = 1024 * 4;
+
+    let chunk_size = std::cmp::max(chunk_size, min_chunk_size);
+
+    // this is arbitrarily chosen.
+    // we don't want this to depend on the thread pool size
+    // otherwise the chunks are not deterministic
+    let offset_batch_size = 16;
+    ChunkOffsetIter {
+        bytes,
+        offsets: VecDeque::with_capacity(offset_batch_size),
+        last_offset: 0,
+        n_chunks: offset_batch_size,
+        chunk_size,
+        rows_per_batch,
+        quote_char,
+        eol_char,
+    }
+}
+
 impl<'a> CoreReader<'a> {
     /// Create a batched csv reader that uses mmap to load data.
+    ///
+    /// Compressed data is decompressed incrementally, one block at a time.
     pub fn batched(mut self) -> PolarsResult<BatchedCsvReader<'a>> {
-        let reader_bytes = self.reader_bytes.take().unwrap();
-        let bytes = reader_bytes.as_ref();
-        let (bytes, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
-
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
-        // Copied from [`Self::parse_csv`]
-        let n_parts_hint = n_threads * 16;
-        let chunk_size = std::cmp::min(bytes.len() / n_parts_hint, 16 * 1024 * 1024);
-
-        // Use a small min chunk size to catch failures in tests.
-        #[cfg(debug_assertions)]
-        let min_chunk_size = 64;
-        #[cfg(not(debug_assertions))]
-        let min_chunk_size = 1024 * 4;
+        let reader_bytes = self.reader_bytes.take().unwrap();
+        let (reader_bytes, decompressor) = if self.is_compressed {
+            let decompressor = ChunkedDecompressor::try_new(
+                reader_bytes,
+                n_threads * DECOMPRESSION_BLOCK_SIZE_PER_THREAD,
+            )?;
+            // The bytes are filled in block by block by `next_batches`.
+            (ReaderBytes::Borrowed(&[]), decompressor)
+        } else {
+            (reader_bytes, None)
+        };
 
-        let chunk_size = std::cmp::max(chunk_size, min_chunk_size);
+        let bytes = reader_bytes.as_ref();
+        let (bytes, starting_point_offset) = if decompressor.is_some() {
+            (bytes, Some(0))
+        } else {
+            self.find_starting_point(bytes, self.quote_char, self.eol_char)?
+        };
 
-        // this is arbitrarily chosen.
-        // we don't want this to depend on the thread pool size
-        // otherwise the chunks are not deterministic
-        let offset_batch_size = 16;
         // extend lifetime. It is bound to `readerbytes` and we keep track of that
         // lifetime so this is sound.
         let bytes = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(bytes) };
-        let file_chunks = ChunkOffsetIter {
+        let file_chunks = chunk_offsets_iter(
             bytes,
-            offsets: VecDeque::with_capacity(offset_batch_size),
-            last_offset: 0,
-            n_chunks: offset_batch_size,
-            chunk_size,
-            rows_per_batch: self.chunk_size,
-            quote_char: self.quote_char,
-            eol_char: self.eol_char,
-        };
+            n_threads,
+            self.chunk_size,
+            self.quote_char,
+            self.eol_char,
+        );
 
         let projection = self.get_projection()?;
 
@@ -170,12 +208,18 @@ impl<'a> CoreReader<'a> {
 
         Ok(BatchedCsvReader {
             reader_bytes,
+            decompressor,
+            is_first_block: true,
+            n_threads,
             chunk_size: self.chunk_size,
             file_chunks_iter: file_chunks,
             file_chunks: vec![],
             projection,
             starting_point_offset,
             row_index: self.row_index,
+            skip_rows_before_header: self.skip_rows_before_header,
+            skip_rows_after_header: self.skip_rows_after_header,
+            has_header: self.has_header,
             comment_prefix: self.comment_prefix,
             quote_char: self.quote_char,
             eol_char: self.eol_char,
@@ -197,12 +241,19 @@ impl<'a> CoreReader<'a> {
 
 pub struct BatchedCsvReader<'a> {
     reader_bytes: ReaderBytes<'a>,
+    /// Set if the data is compressed, `reader_bytes` then holds the current decompressed block.
+    decompressor: Option<ChunkedDecompressor<'a>>,
+    is_first_block: bool,
+    n_threads: usize,
     chunk_size: usize,
     file_chunks_iter: ChunkOffsetIter<'a>,
     file_chunks: Vec<(usize, usize)>,
     projection: Vec<usize>,
     starting_point_offset: Option<usize>,
     row_index: Option<RowIndex>,
+    skip_rows_before_header: usize,
+    skip_rows_after_header: usize,
+    has_header: bool,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
     eol_char: u8,
@@ -232,9 +283,14 @@ impl BatchedCsvReader<'_> {
         // get next `n` offset positions.
         let file_chunks_iter = (&mut self.file_chunks_iter).take(n);
         self.file_chunks.extend(file_chunks_iter);
-        // depleted the offsets iterator, we are done as well.
-        if self.file_chunks.is_empty() {
-            return Ok(None);
+        // depleted the offsets iterator, continue with the next decompressed block if any.
+        while self.file_chunks.is_empty() {
+            if !self.next_decompressed_block()? {
+                // we are done as well.
+                return Ok(None);
+            }
+            let file_chunks_iter = (&mut self.file_chunks_iter).take(n);
+            self.file_chunks.extend(file_chunks_iter);
         }
         let chunks = &self.file_chunks;
 
@@ -297,6 +353,57 @@ impl BatchedCsvReader<'_> {
     }
 }
 
+impl BatchedCsvReader<'_> {
+    /// Replace the current bytes with the next block of decompressed data. Returns `false` if
+    /// there is no more data.
+    fn next_decompressed_block(&mut self) -> PolarsResult<bool> {
+        let Some(decompressor) = self.decompressor.as_mut() else {
+            return Ok(false);
+        };
+
+        let counter = CountLines::new(self.quote_char, self.eol_char);
+        let Some(block) = decompressor.next_block(|bytes| {
+            let (count, position) = counter.count(bytes);
+            (count > 0).then_some(position + 1)
+        })?
+        else {
+            return Ok(false);
+        };
+
+        let starting_point_offset = if std::mem::take(&mut self.is_first_block) {
+            find_starting_point(
+                &block,
+                self.quote_char,
+                self.eol_char,
+                self.schema.len(),
+                self.skip_rows_before_header,
+                self.skip_rows_after_header,
+                self.comment_prefix.as_ref(),
+                self.has_header,
+            )?
+        } else {
+            0
+        };
+
+        self.reader_bytes = ReaderBytes::Owned(MemSlice::from_vec(block));
+        self.starting_point_offset = Some(starting_point_offset);
+
+        let bytes = &self.reader_bytes[starting_point_offset.min(self.reader_bytes.len())..];
+        // extend lifetime. It is bound to `reader_bytes`, which is only replaced together with
+        // the iterator, so this is sound.
+        let bytes = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(bytes) };
+        self.file_chunks_iter = chunk_offsets_iter(
+            bytes,
+            self.n_threads,
+            self.chunk_size,
+            self.quote_char,
+            self.eol_char,
+        );
+
+        Ok(true)
+    }
+}
+
 pub struct OwnedBatchedCsvReader {
     #[allow(dead_code)]
     // this exist because we need to keep ownership
@@ -7,9 +7,29 @@ use polars_core::POOL;
 use polars_error::PolarsResult;
 
 use super::write_impl::{write, write_bom, write_header};
-use super::{QuoteStyle, SerializeOptions};
+use super::{CsvWriterOptions, QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
 
+impl CsvWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> CsvWriter<W> {
+        let options = &self.serialize_options;
+        CsvWriter::new(writer)
+            .include_bom(self.include_bom)
+            .include_header(self.include_header)
+            .with_separator(options.separator)
+            .with_line_terminator(options.line_terminator.clone())
+            .with_quote_char(options.quote_char)
+            .with_batch_size(self.batch_size)
+            .with_datetime_format(options.datetime_format.clone())
+            .with_date_format(options.date_format.clone())
+            .with_time_format(options.time_format.clone())
+            .with_float_scientific(options.float_scientific)
+            .with_float_precision(options.float_precision)
+            .with_null_value(options.null.clone())
+            .with_quote_style(options.quote_style)
+    }
+}
+
 /// Write a DataFrame to csv.
 ///
 /// Don't use a `Buffered` writer, the `CsvWriter` internally already buffers writes.
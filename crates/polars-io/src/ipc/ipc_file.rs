@@ -47,7 +47,7 @@ use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader};
-use crate::RowIndex;
+use crate::{KeyValueMetadata, RowIndex};
 
 #[derive(Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -115,6 +115,18 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self.get_metadata()?;
         Ok(self.schema.as_ref().unwrap().clone())
     }
+
+    /// Custom key/value metadata of the schema of the file and of its fields.
+    pub fn key_value_metadata(&mut self) -> PolarsResult<KeyValueMetadata> {
+        let metadata = self.get_metadata()?;
+        let file = metadata
+            .custom_schema_metadata
+            .as_deref()
+            .cloned()
+            .unwrap_or_default();
+        Ok(KeyValueMetadata::from_schema(file, &metadata.schema))
+    }
+
     /// Stop reading when `n` rows are read.
     pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
         self.n_rows = num_rows;
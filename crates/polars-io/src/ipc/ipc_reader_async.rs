@@ -1,18 +1,23 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow::io::ipc::read::{get_row_count, FileMetadata, OutOfSpecKind};
+use bytes::Bytes;
+use hashbrown::hash_map::Entry;
 use object_store::path::Path;
 use object_store::ObjectMeta;
 use polars_core::datatypes::IDX_DTYPE;
 use polars_core::frame::DataFrame;
 use polars_core::schema::{Schema, SchemaExt};
-use polars_error::{polars_bail, polars_err, to_compute_err, PolarsResult};
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
+use polars_utils::aliases::PlHashMap;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::cloud::{
     build_object_store, object_path_from_str, CloudLocation, CloudOptions, PolarsObjectStore,
 };
-use crate::file_cache::{init_entries_from_uri_list, FileCacheEntry};
+use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::{materialize_projection, IpcReader};
 use crate::shared::SerReader;
@@ -21,7 +26,6 @@ use crate::RowIndex;
 /// An Arrow IPC reader implemented on top of PolarsObjectStore.
 pub struct IpcReaderAsync {
     store: PolarsObjectStore,
-    cache_entry: Arc<FileCacheEntry>,
     path: Path,
 }
 
@@ -67,7 +71,6 @@ impl IpcReaderAsync {
         uri: &str,
         cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<IpcReaderAsync> {
-        let cache_entry = init_entries_from_uri_list(&[Arc::from(uri)], cloud_options)?[0].clone();
         let (CloudLocation { prefix, .. }, store) =
             build_object_store(uri, cloud_options, false).await?;
 
@@ -75,7 +78,6 @@ impl IpcReaderAsync {
 
         Ok(Self {
             store: PolarsObjectStore::new(store),
-            cache_entry,
             path,
         })
     }
@@ -88,7 +90,10 @@ impl IpcReaderAsync {
         Ok(self.object_metadata().await?.size)
     }
 
-    pub async fn metadata(&self) -> PolarsResult<FileMetadata> {
+    /// Fetch the footer of the file, including the footer metadata at the end of the file.
+    ///
+    /// Returns the size of the file and the footer.
+    async fn fetch_footer(&self) -> PolarsResult<(usize, Bytes)> {
         let file_size = self.file_size().await?;
 
         // TODO: Do a larger request and hope that the entire footer is contained within it to save one round-trip.
@@ -121,10 +126,65 @@ impl IpcReaderAsync {
             )
             .await?;
 
-        arrow::io::ipc::read::deserialize_footer(
-            footer.as_ref(),
-            footer_size.try_into().map_err(to_compute_err)?,
-        )
+        Ok((file_size, footer))
+    }
+
+    pub async fn metadata(&self) -> PolarsResult<FileMetadata> {
+        let (_, footer) = self.fetch_footer().await?;
+        deserialize_footer(&footer)
+    }
+
+    /// Create a reader over the file that fetches its parts with range requests.
+    ///
+    /// The dictionaries and record batches are fetched concurrently up front if `prefetch` is
+    /// set. Otherwise every one of them is fetched once it is read, so that reads with a row limit
+    /// only fetch the record batches they need.
+    pub async fn range_reader(&self, prefetch: bool) -> PolarsResult<IpcRangeReader> {
+        let mut reader = self.range_reader_impl(false).await?;
+        if prefetch {
+            reader.prefetch().await?;
+        }
+        Ok(reader)
+    }
+
+    async fn range_reader_impl(&self, headers_only: bool) -> PolarsResult<IpcRangeReader> {
+        let (file_size, footer) = self.fetch_footer().await?;
+        let metadata = deserialize_footer(&footer)?;
+
+        let footer_start = file_size - footer.len();
+        let mut ranges = metadata
+            .dictionaries
+            .iter()
+            .flatten()
+            .chain(metadata.blocks.iter())
+            .map(|block| {
+                let negative = |_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger);
+                let start = usize::try_from(block.offset).map_err(negative)?;
+                let mut len = usize::try_from(block.meta_data_length).map_err(negative)?;
+                if !headers_only {
+                    len += usize::try_from(block.body_length).map_err(negative)?;
+                }
+                polars_ensure!(
+                    start + len <= footer_start,
+                    ComputeError: "invalid ipc block at offset {}: block overlaps the footer", start
+                );
+                Ok(start..start + len)
+            })
+            .chain(std::iter::once(Ok(footer_start..file_size)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut fetched = PlHashMap::default();
+        fetched.insert(footer_start, footer);
+
+        Ok(IpcRangeReader {
+            store: self.store.clone(),
+            path: self.path.clone(),
+            size: file_size,
+            ranges,
+            fetched,
+            position: 0,
+        })
     }
 
     pub async fn data(
@@ -133,10 +193,7 @@ impl IpcReaderAsync {
         options: IpcReadOptions,
         verbose: bool,
     ) -> PolarsResult<DataFrame> {
-        // TODO: Only download what is needed rather than the entire file by
-        // making use of the projection, row limit, predicate and such.
-        let file = tokio::task::block_in_place(|| self.cache_entry.try_open_check_latest())?;
-        let bytes = unsafe { memmap::Mmap::map(&file) }.unwrap();
+        let reader = self.range_reader(options.row_limit.is_none()).await?;
 
         let projection = match options.projection.as_deref() {
             Some(projection) => {
@@ -174,22 +231,121 @@ impl IpcReaderAsync {
             None => None,
         };
 
-        let reader = <IpcReader<_> as SerReader<_>>::new(std::io::Cursor::new(bytes.as_ref()))
+        let reader = <IpcReader<_> as SerReader<_>>::new(reader)
             .with_row_index(options.row_index)
             .with_n_rows(options.row_limit)
             .with_projection(projection);
-        reader.finish_with_scan_ops(options.predicate, verbose)
+        tokio::task::block_in_place(|| reader.finish_with_scan_ops(options.predicate, verbose))
     }
 
     pub async fn count_rows(&self, _metadata: Option<&FileMetadata>) -> PolarsResult<i64> {
-        // TODO: Only download what is needed rather than the entire file by
-        // making use of the projection, row limit, predicate and such.
-        let file = tokio::task::block_in_place(|| self.cache_entry.try_open_check_latest())?;
-        let bytes = unsafe { memmap::Mmap::map(&file) }.unwrap();
-        get_row_count(&mut std::io::Cursor::new(bytes.as_ref()))
+        // Only the message headers of the record batches are needed to count the rows.
+        let mut reader = self.range_reader_impl(true).await?;
+        reader.prefetch().await?;
+        tokio::task::block_in_place(|| get_row_count(&mut reader))
+    }
+}
+
+/// Reads an Arrow IPC file from an object store with range requests.
+///
+/// Only the footer, dictionaries and record batches of the file can be read. Each of these is
+/// fetched when it is first read, unless it was prefetched.
+pub struct IpcRangeReader {
+    store: PolarsObjectStore,
+    path: Path,
+    size: usize,
+    /// The readable ranges of the file, sorted by their start.
+    ranges: Vec<Range<usize>>,
+    /// The fetched ranges, keyed by their start.
+    fetched: PlHashMap<usize, Bytes>,
+    position: usize,
+}
+
+impl IpcRangeReader {
+    async fn prefetch(&mut self) -> PolarsResult<()> {
+        let mut ranges = self
+            .ranges
+            .iter()
+            .filter(|range| !self.fetched.contains_key(&range.start))
+            .cloned()
+            .collect::<Vec<_>>();
+        let fetched: PlHashMap<usize, Bytes> =
+            self.store.get_ranges_sort(&self.path, &mut ranges).await?;
+        self.fetched.extend(fetched);
+        Ok(())
+    }
+}
+
+impl Read for IpcRangeReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+
+        let idx = self
+            .ranges
+            .partition_point(|range| range.start <= self.position);
+        let Some(range) = idx
+            .checked_sub(1)
+            .map(|idx| self.ranges[idx].clone())
+            .filter(|range| self.position < range.end)
+        else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "ipc reader attempted to read outside of the footer and blocks at offset {}",
+                    self.position
+                ),
+            ));
+        };
+
+        let bytes = match self.fetched.entry(range.start) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let bytes = crate::pl_async::get_runtime()
+                    .block_on_potential_spawn(self.store.get_range(&self.path, range.clone()))
+                    .map_err(std::io::Error::other)?;
+                entry.insert(bytes)
+            },
+        };
+
+        let bytes = &bytes[self.position - range.start..];
+        let n = buf.len().min(bytes.len());
+        buf[..n].copy_from_slice(&bytes[..n]);
+        self.position += n;
+        Ok(n)
     }
 }
 
+impl Seek for IpcRangeReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (self.size as u64).checked_add_signed(offset),
+            SeekFrom::Current(offset) => (self.position as u64).checked_add_signed(offset),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        self.position = position as usize;
+        Ok(position)
+    }
+}
+
+impl MmapBytesReader for IpcRangeReader {}
+
+fn deserialize_footer(footer: &[u8]) -> PolarsResult<FileMetadata> {
+    let footer_size = footer.len() - FOOTER_METADATA_SIZE;
+    arrow::io::ipc::read::deserialize_footer(
+        footer,
+        footer_size.try_into().map_err(to_compute_err)?,
+    )
+}
+
 const FOOTER_METADATA_SIZE: usize = 10;
 
 // TODO: Move to polars-arrow and deduplicate parsing of footer metadata in
@@ -244,6 +244,7 @@ where
             &mut self.writer,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                dictionary_deltas: false,
             },
         );
 
@@ -7,19 +7,34 @@ use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::schema_to_arrow_checked;
+use crate::KeyValueMetadata;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcWriterOptions {
     /// Data page compression
     pub compression: Option<IpcCompression>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Custom metadata to write to the schema of the file and its fields.
+    pub key_value_metadata: KeyValueMetadata,
+    /// Polars' flavor of arrow to write, e.g. whether view types are used.
+    pub compat_level: CompatLevel,
+    /// Maximum number of rows in a record batch. If not set, the chunks of the data are written
+    /// as record batches.
+    pub record_batch_size: Option<usize>,
+    /// Write dictionaries that extend a previously written dictionary as deltas.
+    pub dictionary_deltas: bool,
 }
 
 impl IpcWriterOptions {
     pub fn to_writer<W: Write>(&self, writer: W) -> IpcWriter<W> {
-        IpcWriter::new(writer).with_compression(self.compression)
+        IpcWriter::new(writer)
+            .with_compression(self.compression)
+            .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_compat_level(self.compat_level)
+            .with_record_batch_size(self.record_batch_size)
+            .with_dictionary_deltas(self.dictionary_deltas)
     }
 }
 
@@ -48,6 +63,9 @@ pub struct IpcWriter<W> {
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) compat_level: CompatLevel,
     pub(super) parallel: bool,
+    pub(super) key_value_metadata: KeyValueMetadata,
+    pub(super) record_batch_size: Option<usize>,
+    pub(super) dictionary_deltas: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -67,23 +85,55 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Set custom metadata to write to the schema of the file and its fields.
+    pub fn with_key_value_metadata(mut self, key_value_metadata: KeyValueMetadata) -> Self {
+        self.key_value_metadata = key_value_metadata;
+        self
+    }
+
+    /// Set the maximum number of rows in a record batch. Defaults to writing every chunk as a
+    /// record batch.
+    pub fn with_record_batch_size(mut self, record_batch_size: Option<usize>) -> Self {
+        self.record_batch_size = record_batch_size;
+        self
+    }
+
+    /// Write dictionaries that only append values to a previously written dictionary as
+    /// deltas. Defaults to `false`.
+    pub fn with_dictionary_deltas(mut self, dictionary_deltas: bool) -> Self {
+        self.dictionary_deltas = dictionary_deltas;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
-        let mut writer = write::FileWriter::new(
-            self.writer,
-            Arc::new(schema),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        );
+        check_record_batch_size(self.record_batch_size)?;
+        let schema = self.arrow_schema(schema)?;
+        let options = self.write_options();
+        let mut writer = write::FileWriter::new(self.writer, Arc::new(schema), None, options);
+        if !self.key_value_metadata.file.is_empty() {
+            writer.set_custom_schema_metadata(Arc::new(self.key_value_metadata.file));
+        }
         writer.start()?;
 
         Ok(BatchedWriter {
             writer,
             compat_level: self.compat_level,
+            record_batch_size: self.record_batch_size,
         })
     }
+
+    fn write_options(&self) -> WriteOptions {
+        WriteOptions {
+            compression: self.compression.map(|c| c.into()),
+            dictionary_deltas: self.dictionary_deltas,
+        }
+    }
+
+    fn arrow_schema(&self, schema: &Schema) -> PolarsResult<ArrowSchema> {
+        let mut schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        self.key_value_metadata.apply_to_schema(&mut schema)?;
+        Ok(schema)
+    }
 }
 
 impl<W> SerWriter<W> for IpcWriter<W>
@@ -96,37 +146,69 @@ where
             compression: None,
             compat_level: CompatLevel::newest(),
             parallel: true,
+            key_value_metadata: KeyValueMetadata::default(),
+            record_batch_size: None,
+            dictionary_deltas: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(&df.schema(), self.compat_level, "ipc")?;
-        let mut ipc_writer = write::FileWriter::try_new(
-            &mut self.writer,
-            Arc::new(schema),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        )?;
-        if self.parallel {
-            df.align_chunks_par();
-        } else {
-            df.align_chunks();
+        check_record_batch_size(self.record_batch_size)?;
+        let schema = self.arrow_schema(&df.schema())?;
+        let options = self.write_options();
+        let mut ipc_writer =
+            write::FileWriter::new(&mut self.writer, Arc::new(schema), None, options);
+        if !self.key_value_metadata.file.is_empty() {
+            ipc_writer.set_custom_schema_metadata(Arc::new(self.key_value_metadata.file.clone()));
         }
-        let iter = df.iter_chunks(self.compat_level, true);
+        ipc_writer.start()?;
+        if let Some(record_batch_size) = self.record_batch_size {
+            write_sized_record_batches(&mut ipc_writer, df, self.compat_level, record_batch_size)?;
+        } else {
+            if self.parallel {
+                df.align_chunks_par();
+            } else {
+                df.align_chunks();
+            }
+            let iter = df.iter_chunks(self.compat_level, true);
 
-        for batch in iter {
-            ipc_writer.write(&batch, None)?
+            for batch in iter {
+                ipc_writer.write(&batch, None)?
+            }
         }
         ipc_writer.finish()?;
         Ok(())
     }
 }
 
+fn check_record_batch_size(record_batch_size: Option<usize>) -> PolarsResult<()> {
+    polars_ensure!(
+        record_batch_size != Some(0),
+        InvalidOperation: "record batch size must be greater than 0"
+    );
+    Ok(())
+}
+
+/// Write `df` as record batches of exactly `record_batch_size` rows, except for the last one.
+fn write_sized_record_batches<W: Write>(
+    writer: &mut write::FileWriter<W>,
+    df: &DataFrame,
+    compat_level: CompatLevel,
+    record_batch_size: usize,
+) -> PolarsResult<()> {
+    for offset in (0..df.height()).step_by(record_batch_size) {
+        let batch = df
+            .slice(offset as i64, record_batch_size)
+            .rechunk_to_record_batch(compat_level);
+        writer.write(&batch, None)?;
+    }
+    Ok(())
+}
+
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
     compat_level: CompatLevel,
+    record_batch_size: Option<usize>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -135,6 +217,14 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if let Some(record_batch_size) = self.record_batch_size {
+            return write_sized_record_batches(
+                &mut self.writer,
+                df,
+                self.compat_level,
+                record_batch_size,
+            );
+        }
         let iter = df.iter_chunks(self.compat_level, true);
         for batch in iter {
             self.writer.write(&batch, None)?
@@ -1,25 +1,29 @@
+use std::fmt::{Display, Formatter};
 use std::num::NonZeroUsize;
 
-use polars_core::prelude::DataType;
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use polars_core::config;
+use polars_core::prelude::{DataType, PlIndexMap, PlIndexSet};
 use polars_core::utils::try_get_supertype;
-use polars_error::{polars_bail, PolarsResult};
-use simd_json::BorrowedValue;
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use simd_json::prelude::Writable;
+use simd_json::{BorrowedValue, StaticNode};
 
 pub(crate) fn json_values_to_supertype(
     values: &[BorrowedValue],
     infer_schema_len: NonZeroUsize,
-) -> PolarsResult<DataType> {
+    resolution: JsonConflictResolution,
+) -> PolarsResult<(DataType, Vec<JsonFieldConflict>)> {
     // struct types may have missing fields so find supertype
-    values
+    let dtypes = values
         .iter()
         .take(infer_schema_len.into())
-        .map(|value| polars_json::json::infer(value).map(|dt| DataType::from(&dt)))
-        .reduce(|l, r| {
-            let l = l?;
-            let r = r?;
-            try_get_supertype(&l, &r)
-        })
-        .unwrap_or_else(|| polars_bail!(ComputeError: "could not infer data-type"))
+        .map(polars_json::json::infer)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    infer_struct_with_conflicts(dtypes.into_iter(), resolution)
 }
 
 pub(crate) fn dtypes_to_supertype<I: Iterator<Item = DataType>>(
@@ -34,3 +38,206 @@ pub(crate) fn dtypes_to_supertype<I: Iterator<Item = DataType>>(
         })
         .unwrap_or_else(|| polars_bail!(ComputeError: "could not infer data-type"))
 }
+
+/// How to resolve a field whose values have conflicting types during JSON schema inference,
+/// e.g. a number in one row and a string or an object in another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonConflictResolution {
+    /// Use the supertype of the conflicting types.
+    #[default]
+    Supertype,
+    /// Widen the field to `String`. Scalars are written as text, objects and arrays become null.
+    String,
+    /// Widen the field to `String` and keep non-string values as their JSON encoding.
+    JsonEncoded,
+    /// Raise an error that lists all conflicting fields.
+    Raise,
+}
+
+impl JsonConflictResolution {
+    /// Whether non-string values are converted when read into a `String` column.
+    pub(crate) fn coerces_to_string(self) -> bool {
+        matches!(self, Self::String | Self::JsonEncoded)
+    }
+}
+
+/// A field for which values of conflicting types were found during JSON schema inference.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonFieldConflict {
+    /// Path to the field, nested struct fields are separated by dots.
+    pub path: PlSmallStr,
+    /// The conflicting types that were found.
+    pub dtypes: Vec<DataType>,
+}
+
+impl Display for JsonFieldConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}': [", self.path)?;
+        for (i, dtype) in self.dtypes.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{dtype}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Merge the inferred types of the rows of a JSON document, which are expected to be objects,
+/// into a single struct type while collecting the fields that have conflicting types.
+pub(crate) fn infer_struct_with_conflicts<I: Iterator<Item = ArrowDataType>>(
+    dtypes: I,
+    resolution: JsonConflictResolution,
+) -> PolarsResult<(DataType, Vec<JsonFieldConflict>)> {
+    let dtypes = dtypes.collect::<Vec<_>>();
+    let mut conflicts = vec![];
+    let merged = merge_dtypes(&dtypes, "", &mut conflicts);
+    if config::verbose() {
+        for conflict in &conflicts {
+            eprintln!("conflicting types found during JSON schema inference for field {conflict}");
+        }
+    }
+
+    let dtype = match resolution {
+        JsonConflictResolution::Supertype => {
+            dtypes_to_supertype(dtypes.iter().map(DataType::from))?
+        },
+        JsonConflictResolution::Raise if !conflicts.is_empty() => {
+            let conflicts = conflicts.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+            polars_bail!(
+                SchemaMismatch: "conflicting types found during JSON schema inference for fields {}",
+                conflicts.join(", ")
+            )
+        },
+        _ => {
+            polars_ensure!(
+                matches!(merged, ArrowDataType::Struct(_)),
+                ComputeError: "can only infer the schema of JSON objects"
+            );
+            DataType::from(&merged)
+        },
+    };
+    Ok((dtype, conflicts))
+}
+
+fn merge_dtypes(
+    dtypes: &[ArrowDataType],
+    path: &str,
+    conflicts: &mut Vec<JsonFieldConflict>,
+) -> ArrowDataType {
+    let mut unique = PlIndexSet::default();
+    for dtype in dtypes {
+        if *dtype != ArrowDataType::Null {
+            unique.insert(dtype);
+        }
+    }
+
+    if unique.is_empty() {
+        return ArrowDataType::Null;
+    }
+    if unique.len() == 1 {
+        return unique[0].clone();
+    }
+
+    if unique
+        .iter()
+        .all(|dt| matches!(dt, ArrowDataType::Struct(_)))
+    {
+        let mut fields = PlIndexMap::<&PlSmallStr, Vec<ArrowDataType>>::default();
+        for dt in &unique {
+            let ArrowDataType::Struct(struct_fields) = dt else {
+                unreachable!()
+            };
+            for field in struct_fields {
+                fields
+                    .entry(&field.name)
+                    .or_default()
+                    .push(field.dtype.clone());
+            }
+        }
+        let fields = fields
+            .into_iter()
+            .map(|(name, dtypes)| {
+                let path = if path.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{path}.{name}")
+                };
+                let dtype = merge_dtypes(&dtypes, &path, conflicts);
+                ArrowField::new(name.clone(), dtype, true)
+            })
+            .collect();
+        return ArrowDataType::Struct(fields);
+    }
+
+    if unique
+        .iter()
+        .all(|dt| matches!(dt, ArrowDataType::LargeList(_)))
+    {
+        let inner = unique
+            .iter()
+            .map(|dt| {
+                let ArrowDataType::LargeList(inner) = dt else {
+                    unreachable!()
+                };
+                inner.dtype.clone()
+            })
+            .collect::<Vec<_>>();
+        let dtype = merge_dtypes(&inner, &format!("{path}[]"), conflicts);
+        return ArrowDataType::LargeList(Box::new(ArrowField::new(
+            PlSmallStr::from_static("item"),
+            dtype,
+            true,
+        )));
+    }
+
+    // Integers and floats only differ in precision.
+    if unique
+        .iter()
+        .all(|dt| matches!(dt, ArrowDataType::Int64 | ArrowDataType::Float64))
+    {
+        return ArrowDataType::Float64;
+    }
+
+    conflicts.push(JsonFieldConflict {
+        path: path.into(),
+        dtypes: unique.iter().map(|dt| DataType::from(*dt)).collect(),
+    });
+    ArrowDataType::LargeUtf8
+}
+
+/// Replace the values in `value` that are read into a `String` column of `dtype` but are not
+/// strings, according to the conflict `resolution`.
+pub(crate) fn coerce_string_values(
+    value: &mut BorrowedValue,
+    dtype: &DataType,
+    resolution: JsonConflictResolution,
+) {
+    match (dtype, &mut *value) {
+        (_, BorrowedValue::Static(StaticNode::Null) | BorrowedValue::String(_)) => {},
+        (DataType::String, BorrowedValue::Static(node)) => {
+            *value = BorrowedValue::String(node.to_string().into());
+        },
+        (DataType::String, _) => {
+            *value = match resolution {
+                JsonConflictResolution::JsonEncoded => BorrowedValue::String(value.encode().into()),
+                _ => BorrowedValue::Static(StaticNode::Null),
+            }
+        },
+        (DataType::List(inner), BorrowedValue::Array(values)) => {
+            for value in values.iter_mut() {
+                coerce_string_values(value, inner, resolution);
+            }
+        },
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(fields), BorrowedValue::Object(object)) => {
+            for field in fields {
+                if let Some(value) = object.get_mut(field.name.as_str()) {
+                    coerce_string_values(value, &field.dtype, resolution);
+                }
+            }
+        },
+        _ => {},
+    }
+}
@@ -63,16 +63,16 @@
 //! ```
 //!
 pub(crate) mod infer;
-
 use std::io::Write;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 
 use arrow::legacy::conversion::chunk_to_struct;
+pub use infer::{JsonConflictResolution, JsonFieldConflict};
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_error::{polars_bail, PolarsResult};
-use polars_json::json::write::FallibleStreamingIterator;
+use polars_json::json::write::{FallibleStreamingIterator, PrettyWriter, SerializeOptions};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
@@ -80,11 +80,40 @@ use simd_json::BorrowedValue;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JsonWriterOptions {
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Used for [`DataType::Date`].
+    pub date_format: Option<String>,
+    /// Used for [`DataType::Time`].
+    pub time_format: Option<String>,
+    /// Used for [`DataType::Datetime`].
+    pub datetime_format: Option<String>,
+    /// Number of decimals written for floats.
+    pub float_precision: Option<usize>,
+    /// Leave out the keys of null values instead of writing `null`.
+    pub omit_null_fields: bool,
+}
+
+impl JsonWriterOptions {
+    /// Create a [`JsonWriter`] that writes newline-delimited JSON.
+    pub fn to_writer<W: Write>(&self, writer: W) -> JsonWriter<W> {
+        JsonWriter::new(writer)
+            .with_json_format(JsonFormat::JsonLines)
+            .with_serialize_options(self.serialize_options())
+    }
+
+    pub(crate) fn serialize_options(&self) -> SerializeOptions {
+        SerializeOptions {
+            date_format: self.date_format.clone(),
+            time_format: self.time_format.clone(),
+            datetime_format: self.datetime_format.clone(),
+            float_precision: self.float_precision,
+            omit_null_fields: self.omit_null_fields,
+        }
+    }
 }
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
@@ -106,6 +135,10 @@ pub enum JsonFormat {
     ///
     /// It is recommended to use the file extension `.jsonl` when saving as JSON Lines.
     JsonLines,
+    /// A single JSON object mapping each column name to an array of the column's values.
+    ///
+    /// This format is only supported for writing.
+    JsonColumnar,
 }
 
 /// Writes a DataFrame to JSON.
@@ -120,6 +153,8 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    options: SerializeOptions,
+    pretty: bool,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -127,6 +162,48 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set the format string used for [`DataType::Date`].
+    pub fn with_date_format(mut self, format: Option<String>) -> Self {
+        self.options.date_format = format;
+        self
+    }
+
+    /// Set the format string used for [`DataType::Time`].
+    pub fn with_time_format(mut self, format: Option<String>) -> Self {
+        self.options.time_format = format;
+        self
+    }
+
+    /// Set the format string used for [`DataType::Datetime`]. Defaults to
+    /// `yyyy-mm-dd HH:MM:SS` for naive datetimes and RFC 3339 for datetimes with a time zone.
+    pub fn with_datetime_format(mut self, format: Option<String>) -> Self {
+        self.options.datetime_format = format;
+        self
+    }
+
+    /// Set the number of decimals written for floats.
+    pub fn with_float_precision(mut self, precision: Option<usize>) -> Self {
+        self.options.float_precision = precision;
+        self
+    }
+
+    /// Leave out the keys of null values, also in nested structs, instead of writing `null`.
+    pub fn with_omit_null_fields(mut self, omit: bool) -> Self {
+        self.options.omit_null_fields = omit;
+        self
+    }
+
+    /// Indent the output. Not supported for [`JsonFormat::JsonLines`].
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    pub(crate) fn with_serialize_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -139,42 +216,91 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            options: Default::default(),
+            pretty: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        df.align_chunks_par();
-        let fields = df
-            .iter()
-            .map(|s| {
-                #[cfg(feature = "object")]
-                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
-                Ok(s.field().to_arrow(CompatLevel::newest()))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
-        let batches = df
-            .iter_chunks(CompatLevel::newest(), false)
-            .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
-
-        match self.json_format {
-            JsonFormat::JsonLines => {
-                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
-                writer.collect::<PolarsResult<()>>()?;
-            },
-            JsonFormat::Json => {
-                let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
-            },
+        polars_ensure!(
+            !(self.pretty && matches!(self.json_format, JsonFormat::JsonLines)),
+            InvalidOperation: "pretty printing is not supported for JSON lines"
+        );
+        let fields = arrow_fields(df, &self.options)?;
+
+        if self.pretty {
+            let mut writer = PrettyWriter::new(&mut self.buffer);
+            write_df(&mut writer, df, &fields, &self.json_format, &self.options)?;
+            writer.write_all(b"\n")?;
+        } else {
+            write_df(
+                &mut self.buffer,
+                df,
+                &fields,
+                &self.json_format,
+                &self.options,
+            )?;
         }
 
         Ok(())
     }
 }
 
+/// Get the arrow fields of `df`, checking that all columns can be serialized with `options`.
+fn arrow_fields(df: &DataFrame, options: &SerializeOptions) -> PolarsResult<Vec<ArrowField>> {
+    df.iter()
+        .map(|s| {
+            #[cfg(feature = "object")]
+            polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
+            let field = s.field().to_arrow(CompatLevel::newest());
+            options.validate(&field.dtype)?;
+            Ok(field)
+        })
+        .collect()
+}
+
+fn write_df<W: Write>(
+    writer: &mut W,
+    df: &mut DataFrame,
+    fields: &[ArrowField],
+    json_format: &JsonFormat,
+    options: &SerializeOptions,
+) -> PolarsResult<()> {
+    if let JsonFormat::JsonColumnar = json_format {
+        let columns = df.iter().map(|s| {
+            let chunks = (0..s.n_chunks())
+                .map(|i| s.to_arrow(i, CompatLevel::newest()))
+                .collect::<Vec<_>>();
+            (s.name().as_str(), chunks)
+        });
+        return polars_json::json::write::write_columns(writer, columns, options);
+    }
+
+    df.align_chunks_par();
+    let batches = df
+        .iter_chunks(CompatLevel::newest(), false)
+        .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.to_vec())) as ArrayRef));
+
+    match json_format {
+        JsonFormat::JsonLines => {
+            let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![])
+                .with_options(options.clone());
+            let writer = polars_json::ndjson::write::FileWriter::new(writer, serializer);
+            writer.collect::<PolarsResult<()>>()?;
+        },
+        JsonFormat::Json => {
+            let serializer = polars_json::json::write::Serializer::new(batches, vec![])
+                .with_options(options.clone());
+            polars_json::json::write::write(writer, serializer)?;
+        },
+        JsonFormat::JsonColumnar => unreachable!(),
+    }
+    Ok(())
+}
+
 pub struct BatchedWriter<W: Write> {
     writer: W,
+    options: SerializeOptions,
 }
 
 impl<W> BatchedWriter<W>
@@ -182,25 +308,29 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer,
+            options: Default::default(),
+        }
     }
+
+    /// Set the options used to serialize the values, see [`JsonWriterOptions`].
+    pub fn with_options(mut self, options: &JsonWriterOptions) -> Self {
+        self.options = options.serialize_options();
+        self
+    }
+
     /// Write a batch to the json writer.
     ///
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let fields = df
-            .iter()
-            .map(|s| {
-                #[cfg(feature = "object")]
-                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
-                Ok(s.field().to_arrow(CompatLevel::newest()))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+        let fields = arrow_fields(df, &self.options)?;
         let chunks = df.iter_chunks(CompatLevel::newest(), false);
         let batches =
             chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
-        let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+        let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![])
+            .with_options(self.options.clone());
         while let Some(block) = serializer.next()? {
             self.writer.write_all(block)?;
         }
@@ -223,6 +353,7 @@ where
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
     json_format: JsonFormat,
+    conflict_resolution: JsonConflictResolution,
 }
 
 pub fn remove_bom(bytes: &[u8]) -> PolarsResult<&[u8]> {
@@ -251,6 +382,7 @@ where
             schema: None,
             schema_overwrite: None,
             json_format: JsonFormat::Json,
+            conflict_resolution: Default::default(),
         }
     }
 
@@ -264,10 +396,23 @@ where
     /// Because JSON values specify their types (number, string, etc), no upcasting or conversion is performed between
     /// incompatible types in the input. In the event that a column contains mixed dtypes, is it unspecified whether an
     /// error is returned or whether elements of incompatible dtypes are replaced with `null`.
-    fn finish(mut self) -> PolarsResult<DataFrame> {
+    fn finish(self) -> PolarsResult<DataFrame> {
+        self.finish_with_conflicts().map(|(df, _)| df)
+    }
+}
+
+impl<'a, R> JsonReader<'a, R>
+where
+    R: MmapBytesReader,
+{
+    /// Read the DataFrame like [`SerReader::finish`], and also return the fields that had
+    /// conflicting types during schema inference. No conflicts are returned if the schema is
+    /// given.
+    pub fn finish_with_conflicts(mut self) -> PolarsResult<(DataFrame, Vec<JsonFieldConflict>)> {
         let pre_rb: ReaderBytes = (&mut self.reader).into();
         let bytes = remove_bom(pre_rb.deref())?;
         let rb = ReaderBytes::Borrowed(bytes);
+        let mut conflicts = vec![];
         let out = match self.json_format {
             JsonFormat::Json => {
                 polars_ensure!(!self.ignore_errors, InvalidOperation: "'ignore_errors' only supported in ndjson");
@@ -276,14 +421,14 @@ where
                 compression::maybe_decompress_bytes(&bytes, owned)?;
                 // the easiest way to avoid ownership issues is by implicitly figuring out if
                 // decompression happened (owned is only populated on decompress), then pick which bytes to parse
-                let json_value = if owned.is_empty() {
+                let mut json_value = if owned.is_empty() {
                     simd_json::to_borrowed_value(&mut bytes).map_err(to_compute_err)?
                 } else {
                     simd_json::to_borrowed_value(owned).map_err(to_compute_err)?
                 };
                 if let BorrowedValue::Array(array) = &json_value {
                     if array.is_empty() & self.schema.is_none() & self.schema_overwrite.is_none() {
-                        return Ok(DataFrame::empty());
+                        return Ok((DataFrame::empty(), conflicts));
                     }
                 }
 
@@ -300,12 +445,14 @@ where
                 } else {
                     // infer
                     let inner_dtype = if let BorrowedValue::Array(values) = &json_value {
-                        infer::json_values_to_supertype(
+                        let (dtype, value_conflicts) = infer::json_values_to_supertype(
                             values,
                             self.infer_schema_len
                                 .unwrap_or(NonZeroUsize::new(usize::MAX).unwrap()),
-                        )?
-                        .to_arrow(CompatLevel::newest())
+                            self.conflict_resolution,
+                        )?;
+                        conflicts = value_conflicts;
+                        dtype.to_arrow(CompatLevel::newest())
                     } else {
                         polars_json::json::infer(&json_value)?
                     };
@@ -330,6 +477,18 @@ where
                     }
                 };
 
+                if self.conflict_resolution.coerces_to_string() {
+                    let row_dtype = DataType::from(&dtype);
+                    match &mut json_value {
+                        BorrowedValue::Array(rows) => rows.iter_mut().for_each(|row| {
+                            infer::coerce_string_values(row, &row_dtype, self.conflict_resolution)
+                        }),
+                        row => {
+                            infer::coerce_string_values(row, &row_dtype, self.conflict_resolution)
+                        },
+                    }
+                }
+
                 let dtype = if let BorrowedValue::Array(_) = &json_value {
                     ArrowDataType::LargeList(Box::new(arrow::datatypes::Field::new(
                         PlSmallStr::from_static("item"),
@@ -365,28 +524,29 @@ where
                     None,
                     None,
                     None,
+                    self.conflict_resolution,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
                     df.as_single_chunk_par();
                 }
+                conflicts = json_reader.take_conflicts();
                 Ok(df)
             },
+            JsonFormat::JsonColumnar => {
+                polars_bail!(nyi = "reading JSON in the columnar orientation")
+            },
         }?;
 
         // TODO! Ensure we don't materialize the columns we don't need
-        if let Some(proj) = self.projection.as_deref() {
-            out.select(proj.iter().cloned())
+        let out = if let Some(proj) = self.projection.as_deref() {
+            out.select(proj.iter().cloned())?
         } else {
-            Ok(out)
-        }
+            out
+        };
+        Ok((out, conflicts))
     }
-}
 
-impl<'a, R> JsonReader<'a, R>
-where
-    R: MmapBytesReader,
-{
     /// Set the JSON file's schema
     pub fn with_schema(mut self, schema: SchemaRef) -> Self {
         self.schema = Some(schema);
@@ -435,6 +595,16 @@ where
         self
     }
 
+    /// Set how fields whose values have conflicting types are resolved during schema inference.
+    ///
+    /// With [`JsonConflictResolution::String`] and [`JsonConflictResolution::JsonEncoded`],
+    /// non-string values that are read into a `String` column are converted as well, also when
+    /// the schema is given.
+    pub fn with_conflict_resolution(mut self, resolution: JsonConflictResolution) -> Self {
+        self.conflict_resolution = resolution;
+        self
+    }
+
     /// Return a `null` if an error occurs during parsing.
     pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
         self.ignore_errors = ignore;
@@ -79,6 +79,12 @@ impl std::ops::Deref for ReaderBytes<'_> {
     }
 }
 
+impl AsRef<[u8]> for ReaderBytes<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
 /// There are some places that perform manual lifetime management after transmuting `ReaderBytes`
 /// to have a `'static` inner lifetime. The advantage to doing this is that it lets you construct a
 /// `MemSlice` from the `ReaderBytes` in a zero-copy manner regardless of the underlying enum
@@ -10,13 +10,48 @@ use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
 use rayon::prelude::*;
 
+use crate::json::infer::coerce_string_values;
+use crate::json::{JsonConflictResolution, JsonFieldConflict};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::utils::compression::{count_eol, ChunkedDecompressor};
 use crate::{RowIndex, SerReader};
 const NEWLINE: u8 = b'\n';
 const CLOSING_BRACKET: u8 = b'}';
+/// Number of decompressed bytes that are counted at once when counting the rows of compressed
+/// files.
+const DECOMPRESSION_BLOCK_SIZE: usize = 1 << 24;
+
+/// Count the rows of NDJSON data without parsing them, useful for count(*) queries.
+///
+/// Compressed data is decompressed and counted one block at a time.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let n_threads = POOL.current_num_threads();
+    let Some(mut decompressor) = ChunkedDecompressor::try_new(bytes, DECOMPRESSION_BLOCK_SIZE)?
+    else {
+        return Ok(count_rows_par(bytes, n_threads));
+    };
+
+    let mut count = 0;
+    while let Some(block) = decompressor.next_block(|bytes| {
+        let (count, end) = count_eol(bytes, NEWLINE);
+        (count > 0).then_some(end)
+    })? {
+        count += count_rows_par(&block, n_threads);
+    }
+    Ok(count)
+}
+
+fn count_rows_par(bytes: &[u8], n_threads: usize) -> usize {
+    let file_chunks = get_file_chunks_json(bytes, n_threads);
+    let iter = file_chunks.par_iter().map(|(start_pos, stop_at_nbytes)| {
+        let bytes = &bytes[*start_pos..*stop_at_nbytes];
+        json_lines(bytes).count()
+    });
+    POOL.install(|| iter.sum())
+}
 
 #[must_use]
 pub struct JsonLineReader<'a, R>
@@ -37,6 +72,7 @@ where
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    conflict_resolution: JsonConflictResolution,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -105,6 +141,16 @@ where
         self
     }
 
+    /// Set how fields whose values have conflicting types are resolved during schema inference.
+    ///
+    /// With [`JsonConflictResolution::String`] and [`JsonConflictResolution::JsonEncoded`],
+    /// non-string values that are read into a `String` column are converted as well, also when
+    /// the schema is given.
+    pub fn with_conflict_resolution(mut self, resolution: JsonConflictResolution) -> Self {
+        self.conflict_resolution = resolution;
+        self
+    }
+
     /// Set values as `Null` if parsing fails because of schema mismatches.
     pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
         self.ignore_errors = ignore_errors;
@@ -127,10 +173,41 @@ where
             self.row_index,
             self.predicate,
             self.projection,
+            self.conflict_resolution,
         )?;
 
         json_reader.count()
     }
+
+    /// Read the DataFrame like [`SerReader::finish`], and also return the fields that had
+    /// conflicting types during schema inference. No conflicts are returned if the schema is
+    /// given.
+    pub fn finish_with_conflicts(mut self) -> PolarsResult<(DataFrame, Vec<JsonFieldConflict>)> {
+        let rechunk = self.rechunk;
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut json_reader = CoreJsonReader::new(
+            reader_bytes,
+            self.n_rows,
+            self.schema,
+            self.schema_overwrite,
+            self.n_threads,
+            1024, // sample size
+            self.chunk_size,
+            self.low_memory,
+            self.infer_schema_len,
+            self.ignore_errors,
+            self.row_index,
+            self.predicate,
+            self.projection,
+            self.conflict_resolution,
+        )?;
+
+        let mut df: DataFrame = json_reader.as_df()?;
+        if rechunk && df.first_col_n_chunks() > 1 {
+            df.as_single_chunk_par();
+        }
+        Ok((df, json_reader.take_conflicts()))
+    }
 }
 
 impl JsonLineReader<'_, File> {
@@ -162,32 +239,11 @@ where
             row_index: None,
             predicate: None,
             projection: None,
+            conflict_resolution: Default::default(),
         }
     }
-    fn finish(mut self) -> PolarsResult<DataFrame> {
-        let rechunk = self.rechunk;
-        let reader_bytes = get_reader_bytes(&mut self.reader)?;
-        let mut json_reader = CoreJsonReader::new(
-            reader_bytes,
-            self.n_rows,
-            self.schema,
-            self.schema_overwrite,
-            self.n_threads,
-            1024, // sample size
-            self.chunk_size,
-            self.low_memory,
-            self.infer_schema_len,
-            self.ignore_errors,
-            self.row_index,
-            self.predicate,
-            self.projection,
-        )?;
-
-        let mut df: DataFrame = json_reader.as_df()?;
-        if rechunk && df.first_col_n_chunks() > 1 {
-            df.as_single_chunk_par();
-        }
-        Ok(df)
+    fn finish(self) -> PolarsResult<DataFrame> {
+        self.finish_with_conflicts().map(|(df, _)| df)
     }
 }
 
@@ -203,6 +259,8 @@ pub(crate) struct CoreJsonReader<'a> {
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    conflict_resolution: JsonConflictResolution,
+    conflicts: Vec<JsonFieldConflict>,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -220,15 +278,23 @@ impl<'a> CoreJsonReader<'a> {
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         projection: Option<Arc<[PlSmallStr]>>,
+        conflict_resolution: JsonConflictResolution,
     ) -> PolarsResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
+        let mut conflicts = vec![];
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
                 let bytes: &[u8] = &reader_bytes;
                 let mut cursor = Cursor::new(bytes);
-                Arc::new(crate::ndjson::infer_schema(&mut cursor, infer_schema_len)?)
+                let (schema, schema_conflicts) = crate::ndjson::infer_schema_with_conflicts(
+                    &mut cursor,
+                    infer_schema_len,
+                    conflict_resolution,
+                )?;
+                conflicts = schema_conflicts;
+                Arc::new(schema)
             },
         };
         if let Some(overwriting_schema) = schema_overwrite {
@@ -248,20 +314,20 @@ impl<'a> CoreJsonReader<'a> {
             row_index,
             predicate,
             projection,
+            conflict_resolution,
+            conflicts,
         })
     }
 
+    /// The fields that had conflicting types during schema inference.
+    pub(crate) fn take_conflicts(&mut self) -> Vec<JsonFieldConflict> {
+        std::mem::take(&mut self.conflicts)
+    }
+
     fn count(mut self) -> PolarsResult<usize> {
         let bytes = self.reader_bytes.take().unwrap();
         let n_threads = self.n_threads.unwrap_or(POOL.current_num_threads());
-        let file_chunks = get_file_chunks_json(bytes.as_ref(), n_threads);
-
-        let iter = file_chunks.par_iter().map(|(start_pos, stop_at_nbytes)| {
-            let bytes = &bytes[*start_pos..*stop_at_nbytes];
-            let iter = json_lines(bytes);
-            iter.count()
-        });
-        Ok(POOL.install(|| iter.sum()))
+        Ok(count_rows_par(bytes.as_ref(), n_threads))
     }
 
     fn parse_json(&mut self, mut n_threads: usize, bytes: &[u8]) -> PolarsResult<DataFrame> {
@@ -305,7 +371,12 @@ impl<'a> CoreJsonReader<'a> {
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
                     let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
+                    parse_lines(
+                        &bytes[start_pos..stop_at_nbytes],
+                        &mut buffers,
+                        &self.schema,
+                        self.conflict_resolution,
+                    )?;
                     let mut local_df = DataFrame::new(
                         buffers
                             .into_values()
@@ -365,6 +436,8 @@ fn parse_impl(
     bytes: &[u8],
     buffers: &mut PlIndexMap<BufferKey, Buffer>,
     scratch: &mut Scratch,
+    schema: &Schema,
+    conflict_resolution: JsonConflictResolution,
 ) -> PolarsResult<usize> {
     scratch.json.clear();
     scratch.json.extend_from_slice(bytes);
@@ -372,7 +445,14 @@ fn parse_impl(
     let value = simd_json::to_borrowed_value_with_buffers(&mut scratch.json, &mut scratch.buffers)
         .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
     match value {
-        simd_json::BorrowedValue::Object(value) => {
+        simd_json::BorrowedValue::Object(mut value) => {
+            if conflict_resolution.coerces_to_string() {
+                for (name, dtype) in schema.iter() {
+                    if let Some(value) = value.get_mut(name.as_str()) {
+                        coerce_string_values(value, dtype, conflict_resolution);
+                    }
+                }
+            }
             buffers.iter_mut().try_for_each(|(s, inner)| {
                 match s.0.map_lookup(&value) {
                     Some(v) => inner.add(v)?,
@@ -407,12 +487,17 @@ fn json_lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
     })
 }
 
-fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> PolarsResult<()> {
+fn parse_lines(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    schema: &Schema,
+    conflict_resolution: JsonConflictResolution,
+) -> PolarsResult<()> {
     let mut scratch = Scratch::default();
 
     let iter = json_lines(bytes);
     for bytes in iter {
-        parse_impl(bytes, buffers, &mut scratch)?;
+        parse_impl(bytes, buffers, &mut scratch, schema, conflict_resolution)?;
     }
     Ok(())
 }
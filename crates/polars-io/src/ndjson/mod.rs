@@ -3,6 +3,8 @@ use std::num::NonZeroUsize;
 use arrow::array::StructArray;
 use polars_core::prelude::*;
 
+use crate::json::{JsonConflictResolution, JsonFieldConflict};
+
 pub(crate) mod buffer;
 pub mod core;
 
@@ -10,11 +12,23 @@ pub fn infer_schema<R: std::io::BufRead>(
     reader: &mut R,
     infer_schema_len: Option<NonZeroUsize>,
 ) -> PolarsResult<Schema> {
+    infer_schema_with_conflicts(reader, infer_schema_len, Default::default())
+        .map(|(schema, _)| schema)
+}
+
+/// Infer the schema of newline-delimited JSON from the first `infer_schema_len` lines, or all
+/// lines if `None`. Fields with values of conflicting types are resolved according to
+/// `resolution` and returned as well.
+pub fn infer_schema_with_conflicts<R: std::io::BufRead>(
+    reader: &mut R,
+    infer_schema_len: Option<NonZeroUsize>,
+    resolution: JsonConflictResolution,
+) -> PolarsResult<(Schema, Vec<JsonFieldConflict>)> {
     let dtypes = polars_json::ndjson::iter_unique_dtypes(reader, infer_schema_len)?;
-    let dtype = crate::json::infer::dtypes_to_supertype(dtypes.map(|dt| DataType::from(&dt)))?;
+    let (dtype, conflicts) = crate::json::infer::infer_struct_with_conflicts(dtypes, resolution)?;
     let schema = StructArray::get_fields(&dtype.to_arrow(CompatLevel::newest()))
         .iter()
         .map(Into::<Field>::into)
         .collect();
-    Ok(schema)
+    Ok((schema, conflicts))
 }
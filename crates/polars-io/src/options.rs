@@ -1,4 +1,8 @@
+use std::collections::BTreeMap;
+
+use arrow::datatypes::{ArrowSchema, Metadata};
 use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::IdxSize;
 #[cfg(feature = "serde")]
@@ -22,6 +26,9 @@ pub struct HiveOptions {
     pub hive_start_idx: usize,
     pub schema: Option<SchemaRef>,
     pub try_parse_dates: bool,
+    /// Layout of the partition directories, e.g. `{year}/{month}/{day}`. Partitions are parsed
+    /// from `key=value` directories if this is `None`.
+    pub partition_scheme: Option<PlSmallStr>,
 }
 
 impl Default for HiveOptions {
@@ -31,6 +38,44 @@ impl Default for HiveOptions {
             hive_start_idx: 0,
             schema: None,
             try_parse_dates: true,
+            partition_scheme: None,
+        }
+    }
+}
+
+/// Custom key/value metadata of a file and of its columns.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyValueMetadata {
+    /// Metadata of the file.
+    pub file: Metadata,
+    /// Metadata of individual columns, by column name.
+    pub columns: BTreeMap<PlSmallStr, Metadata>,
+}
+
+impl KeyValueMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.file.is_empty() && self.columns.values().all(|m| m.is_empty())
+    }
+
+    /// Adds the column metadata to the fields of `schema`.
+    pub(crate) fn apply_to_schema(&self, schema: &mut ArrowSchema) -> PolarsResult<()> {
+        for (name, metadata) in &self.columns {
+            let field = schema.try_get_mut(name)?;
+            field
+                .metadata
+                .extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
         }
+        Ok(())
+    }
+
+    /// Collects the metadata of the fields of `schema` that have any.
+    pub(crate) fn from_schema(file: Metadata, schema: &ArrowSchema) -> Self {
+        let columns = schema
+            .iter_values()
+            .filter(|field| !field.metadata.is_empty())
+            .map(|field| (field.name.clone(), field.metadata.clone()))
+            .collect();
+        Self { file, columns }
     }
 }
@@ -0,0 +1,236 @@
+//! Split block bloom filters of Parquet column chunks.
+use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{
+    hash_byte, hash_native, insert, is_in_set, optimal_num_bytes,
+};
+use polars_parquet::parquet::schema::types::{PhysicalType, PrimitiveType};
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-time"))]
+use polars_parquet::parquet::schema::types::{PrimitiveLogicalType, TimeUnit as ParquetTimeUnit};
+
+/// The bloom filter of a column chunk.
+///
+/// A bloom filter can tell with certainty that a value is not in a column chunk, which allows
+/// skipping row groups for equality and `is_in` predicates.
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    bitset: Vec<u8>,
+}
+
+impl BloomFilter {
+    pub(crate) fn new(bitset: Vec<u8>) -> Self {
+        Self { bitset }
+    }
+
+    /// Builds a bloom filter of the non-null values of `s` that has a false positive
+    /// probability of at most `fpp`.
+    pub(crate) fn try_from_series(s: &Series, fpp: f64) -> PolarsResult<Self> {
+        let mut hashes = vec![];
+        for_each_hash(s, |hash| hashes.push(hash))?;
+        hashes.sort_unstable();
+        hashes.dedup();
+
+        let mut bitset = vec![0; optimal_num_bytes(hashes.len(), fpp)];
+        for hash in hashes {
+            insert(&mut bitset, hash);
+        }
+        Ok(Self { bitset })
+    }
+
+    pub(crate) fn into_bitset(self) -> Vec<u8> {
+        self.bitset
+    }
+
+    /// Returns `false` if none of the non-null `values` is in the column chunk, and `true` if any
+    /// of them might be. `values` must have the [`DataType`] of the column.
+    pub fn may_contain_any(&self, values: &Series) -> PolarsResult<bool> {
+        let mut may_contain = false;
+        match values.dtype() {
+            // Floats that compare equal can have different bit patterns.
+            DataType::Float32 => {
+                for v in values.f32()?.iter().flatten() {
+                    may_contain |= v.is_nan()
+                        || (v == 0.0 && self.contains(hash_native(-v)))
+                        || self.contains(hash_native(v));
+                }
+            },
+            DataType::Float64 => {
+                for v in values.f64()?.iter().flatten() {
+                    may_contain |= v.is_nan()
+                        || (v == 0.0 && self.contains(hash_native(-v)))
+                        || self.contains(hash_native(v));
+                }
+            },
+            _ => for_each_hash(values, |hash| may_contain |= self.contains(hash))?,
+        }
+        Ok(may_contain)
+    }
+
+    fn contains(&self, hash: u64) -> bool {
+        is_in_set(&self.bitset, hash)
+    }
+}
+
+/// Returns whether bloom filters can be built for columns of type `dtype`, i.e. whether
+/// [`for_each_hash`] can hash its values.
+pub(crate) fn is_supported(dtype: &DataType) -> bool {
+    use DataType::*;
+
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => true,
+        #[cfg(feature = "dtype-date")]
+        Date => true,
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(_, _) => true,
+        #[cfg(feature = "dtype-duration")]
+        Duration(_) => true,
+        #[cfg(feature = "dtype-time")]
+        Time => true,
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+        | String | Binary => true,
+        _ => false,
+    }
+}
+
+/// Returns whether values of type `dtype` are hashed by [`for_each_hash`] as the values of a column
+/// chunk of type `primitive_type`.
+///
+/// Other writers can store columns that are read as `dtype` with a different physical type or
+/// unit, e.g. `INT96` timestamps or `TIME_MILLIS` times. The bloom filters of those column chunks
+/// can't be used, as they would rule out values that are present.
+pub(crate) fn matches_parquet_type(dtype: &DataType, primitive_type: &PrimitiveType) -> bool {
+    use DataType::*;
+
+    let physical_type = primitive_type.physical_type;
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => physical_type == PhysicalType::ByteArray,
+        String | Binary => physical_type == PhysicalType::ByteArray,
+        Int8 | Int16 | Int32 | UInt8 | UInt16 | UInt32 => physical_type == PhysicalType::Int32,
+        #[cfg(feature = "dtype-date")]
+        Date => physical_type == PhysicalType::Int32,
+        Int64 | UInt64 => physical_type == PhysicalType::Int64,
+        Float32 => physical_type == PhysicalType::Float,
+        Float64 => physical_type == PhysicalType::Double,
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(tu, _) => {
+            let expected = match tu {
+                TimeUnit::Nanoseconds => ParquetTimeUnit::Nanoseconds,
+                TimeUnit::Microseconds => ParquetTimeUnit::Microseconds,
+                TimeUnit::Milliseconds => ParquetTimeUnit::Milliseconds,
+            };
+            physical_type == PhysicalType::Int64
+                && matches!(
+                    primitive_type.logical_type,
+                    Some(PrimitiveLogicalType::Timestamp { unit, .. }) if unit == expected
+                )
+        },
+        // Durations have no Parquet logical type, their unit is only stored in the Arrow schema.
+        #[cfg(feature = "dtype-duration")]
+        Duration(_) => {
+            physical_type == PhysicalType::Int64 && primitive_type.logical_type.is_none()
+        },
+        #[cfg(feature = "dtype-time")]
+        Time => {
+            physical_type == PhysicalType::Int64
+                && matches!(
+                    primitive_type.logical_type,
+                    Some(PrimitiveLogicalType::Time {
+                        unit: ParquetTimeUnit::Nanoseconds,
+                        ..
+                    })
+                )
+        },
+        _ => false,
+    }
+}
+
+/// Calls `f` with the hash of every non-null value in `s`. Values are hashed as their Parquet
+/// physical type, as required by the specification.
+fn for_each_hash(s: &Series, mut f: impl FnMut(u64)) -> PolarsResult<()> {
+    use DataType::*;
+
+    let s = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => s.cast(&String)?,
+        // Float16 is written as a `FIXED_LEN_BYTE_ARRAY(2)`, its bits must not be hashed as an
+        // integer.
+        Float16 => polars_bail!(
+            InvalidOperation: "bloom filters are not supported for columns of type {}", s.dtype()
+        ),
+        _ => s.to_physical_repr().into_owned(),
+    };
+    match s.dtype() {
+        Int8 | Int16 | UInt8 | UInt16 => s
+            .cast(&Int32)?
+            .i32()?
+            .iter()
+            .flatten()
+            .for_each(|v| f(hash_native(v))),
+        Int32 => s.i32()?.iter().flatten().for_each(|v| f(hash_native(v))),
+        UInt32 => s
+            .u32()?
+            .iter()
+            .flatten()
+            .for_each(|v| f(hash_native(v as i32))),
+        Int64 => s.i64()?.iter().flatten().for_each(|v| f(hash_native(v))),
+        UInt64 => s
+            .u64()?
+            .iter()
+            .flatten()
+            .for_each(|v| f(hash_native(v as i64))),
+        Float32 => s.f32()?.iter().flatten().for_each(|v| f(hash_native(v))),
+        Float64 => s.f64()?.iter().flatten().for_each(|v| f(hash_native(v))),
+        String => s.str()?.iter().flatten().for_each(|v| f(hash_byte(v))),
+        Binary => s.binary()?.iter().flatten().for_each(|v| f(hash_byte(v))),
+        dt => {
+            polars_bail!(InvalidOperation: "bloom filters are not supported for columns of type {dt}")
+        },
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter_may_contain_any() -> PolarsResult<()> {
+        let s = Series::new("a".into(), (0..1000i64).map(|i| i * 2).collect::<Vec<_>>());
+        let bf = BloomFilter::try_from_series(&s, 0.01)?;
+
+        assert!(bf.may_contain_any(&Series::new("".into(), [10i64]))?);
+        assert!(bf.may_contain_any(&Series::new("".into(), [Some(1i64), Some(998)]))?);
+
+        let absent = Series::new(
+            "".into(),
+            (0..1000i64).map(|i| i * 2 + 1).collect::<Vec<_>>(),
+        );
+        let false_positives = absent
+            .i64()?
+            .into_no_null_iter()
+            .filter(|&v| bf.may_contain_any(&Series::new("".into(), [v])).unwrap())
+            .count();
+        assert!(false_positives < 30);
+        assert!(!bf.may_contain_any(&Series::new("".into(), [None::<i64>]))?);
+
+        let s = Series::new("a".into(), [0.0f64, 1.5]);
+        let bf = BloomFilter::try_from_series(&s, 0.01)?;
+        assert!(bf.may_contain_any(&Series::new("".into(), [-0.0f64]))?);
+        assert!(bf.may_contain_any(&Series::new("".into(), [f64::NAN]))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bloom_filter_is_supported() {
+        assert!(is_supported(&DataType::UInt16));
+        assert!(is_supported(&DataType::String));
+        assert!(!is_supported(&DataType::Boolean));
+        assert!(!is_supported(&DataType::Float16));
+        #[cfg(feature = "dtype-i128")]
+        assert!(!is_supported(&DataType::Int128));
+        #[cfg(feature = "dtype-decimal")]
+        assert!(!is_supported(&DataType::Decimal(Some(10), Some(2))));
+        assert!(!is_supported(&DataType::List(Box::new(DataType::Int64))));
+    }
+}
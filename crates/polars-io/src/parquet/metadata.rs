@@ -2,7 +2,26 @@
 
 use std::sync::Arc;
 
+use polars_error::PolarsResult;
 pub use polars_parquet::parquet::metadata::FileMetadata;
 pub use polars_parquet::read::statistics::{deserialize, Statistics as ParquetStatistics};
+use polars_parquet::read::{infer_schema, ARROW_SCHEMA_META_KEY};
+
+use crate::KeyValueMetadata;
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+/// Reads the custom key/value metadata of a file and of its columns.
+///
+/// The arrow schema that is stored in the file metadata is not included.
+pub fn read_key_value_metadata(metadata: &FileMetadata) -> PolarsResult<KeyValueMetadata> {
+    let file = metadata
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter(|kv| kv.key != ARROW_SCHEMA_META_KEY)
+        .filter_map(|kv| Some((kv.key.as_str().into(), kv.value.as_deref()?.into())))
+        .collect();
+    let schema = infer_schema(metadata)?;
+    Ok(KeyValueMetadata::from_schema(file, &schema))
+}
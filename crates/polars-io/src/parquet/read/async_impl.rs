@@ -13,7 +13,7 @@ use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::sync::Mutex;
 
 use super::mmap::ColumnStore;
-use super::predicates::read_this_row_group;
+use super::predicates::{page_index_ranges, read_this_row_group};
 use crate::cloud::{
     build_object_store, object_path_from_str, CloudLocation, CloudOptions, PolarsObjectStore,
 };
@@ -160,6 +160,7 @@ pub async fn fetch_metadata(
 async fn download_projection(
     fields: Arc<[PlSmallStr]>,
     row_group: RowGroupMetadata,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
     async_reader: Arc<ParquetObjectStore>,
     sender: QueueSend,
     rg_index: usize,
@@ -187,6 +188,8 @@ async fn download_projection(
             ranges.push(range);
         }
     });
+    // The page indexes are used to skip decoding pages that don't match the predicate.
+    ranges.extend(page_index_ranges(predicate.as_deref(), row_group));
 
     let result = async_reader
         .get_ranges(&mut ranges)
@@ -197,6 +200,7 @@ async fn download_projection(
 
 async fn download_row_group(
     rg: RowGroupMetadata,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
     async_reader: Arc<ParquetObjectStore>,
     sender: QueueSend,
     rg_index: usize,
@@ -208,6 +212,7 @@ async fn download_row_group(
     let mut ranges = rg
         .byte_ranges_iter()
         .map(|x| x.start as usize..x.end as usize)
+        .chain(page_index_ranges(predicate.as_deref(), &rg))
         .collect::<Vec<_>>();
 
     sender
@@ -286,6 +291,7 @@ impl FetchRowGroupsFromObjectStore {
                             let handle = tokio::spawn(download_projection(
                                 projected_fields.clone(),
                                 rg,
+                                predicate.clone(),
                                 reader.clone(),
                                 snd.clone(),
                                 *i,
@@ -295,6 +301,7 @@ impl FetchRowGroupsFromObjectStore {
                         None => {
                             let handle = tokio::spawn(download_row_group(
                                 rg,
+                                predicate.clone(),
                                 reader.clone(),
                                 snd.clone(),
                                 *i,
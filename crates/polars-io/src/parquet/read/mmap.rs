@@ -1,10 +1,15 @@
+use std::ops::Range;
+
 use arrow::array::Array;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::datatypes::Field;
 #[cfg(feature = "async")]
 use bytes::Bytes;
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
+use polars_parquet::parquet::read::page_index::deserialize_offset_index;
+use polars_parquet::parquet::read::PageMetaData;
 use polars_parquet::read::{
     column_iter_to_arrays, BasicDecompressor, ColumnChunkMetadata, Filter, PageReader,
 };
@@ -85,3 +90,128 @@ pub fn to_deserializer(
 
     column_iter_to_arrays(columns, types, field, filter)
 }
+
+/// The data pages of a column chunk that contain selected rows.
+#[derive(Debug, Clone, Default)]
+pub struct SparsePages {
+    /// The byte ranges in the file of the dictionary page, if any, followed by those of the
+    /// selected data pages.
+    pub byte_ranges: Vec<Range<usize>>,
+    /// The rows of the row group in the selected data pages.
+    pub rows: Vec<Range<usize>>,
+}
+
+/// Uses the offset index of `column` to find the data pages that contain rows selected by
+/// `row_mask`, which has a value for every row of the row group.
+///
+/// Returns `None` if all data pages are selected, or if the pages of the column chunk cannot be
+/// read independently, e.g. because they are encrypted.
+pub fn select_sparse_pages(
+    column: &ColumnChunkMetadata,
+    offset_index: &[u8],
+    row_mask: &Bitmap,
+) -> PolarsResult<Option<SparsePages>> {
+    // The values of repeated columns do not correspond to rows, and the AAD of encrypted pages
+    // depends on the pages that precede them.
+    if column.descriptor().descriptor.max_rep_level > 0 || column.is_encrypted() {
+        return Ok(None);
+    }
+
+    let locations = deserialize_offset_index(offset_index)?.page_locations;
+    let byte_range = column.byte_range();
+    let num_rows = row_mask.len();
+
+    let mut sparse = SparsePages::default();
+    let Some(first) = locations.first() else {
+        return Ok(None);
+    };
+    let data_start = first.offset as usize;
+    if first.first_row_index != 0 || data_start < byte_range.start as usize {
+        return Ok(None);
+    }
+    if data_start > byte_range.start as usize {
+        sparse
+            .byte_ranges
+            .push(byte_range.start as usize..data_start);
+    }
+
+    for (i, location) in locations.iter().enumerate() {
+        let start = location.first_row_index as usize;
+        let end = locations
+            .get(i + 1)
+            .map_or(num_rows, |next| next.first_row_index as usize);
+        if start >= end || end > num_rows {
+            return Ok(None);
+        }
+        if row_mask.clone().sliced(start, end - start).set_bits() == 0 {
+            continue;
+        }
+
+        let offset = location.offset as usize;
+        let len = location.compressed_page_size as usize;
+        if offset + len > byte_range.end as usize {
+            return Ok(None);
+        }
+        sparse.byte_ranges.push(offset..offset + len);
+        sparse.rows.push(start..end);
+    }
+
+    if sparse.rows.len() == locations.len() {
+        return Ok(None);
+    }
+    Ok(Some(sparse))
+}
+
+/// Like [`to_deserializer`], but for a column chunk of which only the [`SparsePages`] were read.
+///
+/// `chunk` holds the concatenated bytes of [`SparsePages::byte_ranges`] and `rows` are the
+/// [`SparsePages::rows`]. `filter` applies to the rows of the row group and must not select
+/// rows outside of `rows`.
+pub fn sparse_to_deserializer(
+    column: &ColumnChunkMetadata,
+    chunk: MemSlice,
+    rows: &[Range<usize>],
+    field: Field,
+    filter: Filter,
+) -> PolarsResult<Box<dyn Array>> {
+    let num_values = rows.iter().map(|range| range.len()).sum::<usize>();
+    let mut mask = MutableBitmap::with_capacity(num_values);
+    for range in rows {
+        match &filter {
+            Filter::Range(selected) => {
+                let start = selected.start.clamp(range.start, range.end);
+                let end = selected.end.clamp(start, range.end);
+                mask.extend_constant(start - range.start, false);
+                mask.extend_constant(end - start, true);
+                mask.extend_constant(range.end - end, false);
+            },
+            Filter::Mask(selected) => {
+                let end = range.end.min(selected.len());
+                if range.start < end {
+                    mask.extend_from_bitmap(
+                        &selected.clone().sliced(range.start, end - range.start),
+                    );
+                }
+                mask.extend_constant(range.end - end.max(range.start), false);
+            },
+        }
+    }
+    let mask = mask.freeze();
+    polars_ensure!(
+        mask.set_bits() == filter.num_rows(),
+        ComputeError: "filter selects rows of pages that were not read"
+    );
+
+    let page_meta = PageMetaData {
+        num_values: num_values as i64,
+        ..PageMetaData::from(column)
+    };
+    let pages =
+        PageReader::new_with_page_meta(MemReader::new(chunk), page_meta, vec![], usize::MAX);
+    column_iter_to_arrays(
+        vec![BasicDecompressor::new(pages, vec![])],
+        vec![&column.descriptor().descriptor.primitive_type],
+        field,
+        Some(Filter::new_masked(mask)),
+    )
+}
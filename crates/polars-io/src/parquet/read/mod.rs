@@ -33,6 +33,8 @@ or set 'streaming'",
 
 pub use options::{ParallelStrategy, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
+pub use polars_parquet::read::{DecryptionKeyRetriever, FileDecryptionProperties};
+pub(crate) use read_impl::should_copy_sortedness;
 pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
@@ -40,8 +42,13 @@ pub use reader::{BatchedParquetReader, ParquetReader};
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
-    pub use super::predicates::read_this_row_group;
+    pub use super::mmap::{
+        select_sparse_pages, sparse_to_deserializer, to_deserializer, SparsePages,
+    };
+    pub use super::predicates::{
+        bloom_filter_ranges, page_index_ranges, read_this_row_group,
+        read_this_row_group_with_bloom_filters, select_pages,
+    };
     pub use super::read_impl::{calc_prefilter_cost, PrefilterMaskSetting};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -1,4 +1,5 @@
 use polars_core::schema::SchemaRef;
+use polars_parquet::read::FileDecryptionProperties;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +10,8 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Keys to decrypt files written with parquet modular encryption.
+    pub decryption: Option<FileDecryptionProperties>,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
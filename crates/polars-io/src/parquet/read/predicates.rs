@@ -1,8 +1,17 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::config;
 use polars_core::prelude::*;
-use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::parquet::bloom_filter;
+use polars_parquet::parquet::read::page_index::{
+    deserialize_column_index, deserialize_offset_index,
+};
+use polars_parquet::read::statistics::{self, deserialize, Statistics};
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
+use polars_utils::mmap::MemSlice;
 
+use crate::parquet::bloom_filter::{matches_parquet_type, BloomFilter};
 use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
 
 impl ColumnStats {
@@ -16,10 +25,43 @@ impl ColumnStats {
     }
 }
 
+/// Returns the byte range of the bloom filter of a column chunk in the file. The bloom filters of
+/// encrypted columns are not used.
+fn bloom_filter_range(column: &ColumnChunkMetadata) -> Option<Range<usize>> {
+    if column.is_encrypted() {
+        return None;
+    }
+    let metadata = column.metadata();
+    let offset = usize::try_from(metadata.bloom_filter_offset?).ok()?;
+    let length = usize::try_from(metadata.bloom_filter_length?).ok()?;
+    Some(offset..offset + length)
+}
+
+/// Reads the bloom filter of a column chunk that is read as `dtype` from `bloom_filters`, which
+/// maps file offsets to the bytes of the bloom filters.
+fn get_bloom_filter(
+    column: &ColumnChunkMetadata,
+    dtype: &DataType,
+    bloom_filters: &PlHashMap<usize, MemSlice>,
+) -> PolarsResult<Option<Arc<BloomFilter>>> {
+    if !matches_parquet_type(dtype, &column.descriptor().descriptor.primitive_type) {
+        return Ok(None);
+    }
+    let Some(bytes) = bloom_filter_range(column).and_then(|range| bloom_filters.get(&range.start))
+    else {
+        return Ok(None);
+    };
+    let mut bitset = vec![];
+    bloom_filter::deserialize(bytes, &mut bitset)?;
+    // An empty bitset means that the bloom filter is not supported.
+    Ok((!bitset.is_empty()).then(|| Arc::new(BloomFilter::new(bitset))))
+}
+
 /// Collect the statistics in a row-group
 pub(crate) fn collect_statistics(
     md: &RowGroupMetadata,
     schema: &ArrowSchema,
+    bloom_filters: &PlHashMap<usize, MemSlice>,
 ) -> PolarsResult<Option<BatchStats>> {
     // TODO! fix this performance. This is a full sequential scan.
     let stats = schema
@@ -30,7 +72,17 @@ pub(crate) fn collect_statistics(
             Ok(if iter.len() == 0 {
                 ColumnStats::new(field.into(), None, None, None)
             } else {
+                // Bloom filters are only used for columns without nesting.
+                let bloom_filter = match iter.len() {
+                    1 => {
+                        let mut columns = md.columns_under_root_iter(&field.name).unwrap();
+                        let dtype = Field::from(field).dtype;
+                        get_bloom_filter(columns.next().unwrap(), &dtype, bloom_filters)?
+                    },
+                    _ => None,
+                };
                 ColumnStats::from_arrow_stats(deserialize(field, iter)?, field)
+                    .with_bloom_filter(bloom_filter)
             })
         })
         .collect::<PolarsResult<Vec<_>>>()?;
@@ -46,10 +98,46 @@ pub(crate) fn collect_statistics(
     )))
 }
 
+/// Returns the byte ranges of the bloom filters of the columns used in `predicate`.
+///
+/// Bloom filters of which the length is not stored in the metadata are not used.
+pub fn bloom_filter_ranges(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+) -> Vec<Range<usize>> {
+    let Some(mut live_variables) = predicate.and_then(|pred| pred.live_variables()) else {
+        return vec![];
+    };
+    live_variables.sort_unstable();
+    live_variables.dedup();
+
+    live_variables
+        .iter()
+        .filter_map(|name| {
+            let mut iter = md.columns_under_root_iter(name)?;
+            match iter.len() {
+                1 => bloom_filter_range(iter.next().unwrap()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 pub fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetadata,
     schema: &ArrowSchema,
+) -> PolarsResult<bool> {
+    read_this_row_group_with_bloom_filters(predicate, md, schema, &PlHashMap::default())
+}
+
+/// Like [`read_this_row_group`], but also uses the bloom filters in `bloom_filters`, which maps
+/// the start of the [`bloom_filter_ranges`] to their bytes.
+pub fn read_this_row_group_with_bloom_filters(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    bloom_filters: &PlHashMap<usize, MemSlice>,
 ) -> PolarsResult<bool> {
     if std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok() {
         return Ok(true);
@@ -59,7 +147,7 @@ pub fn read_this_row_group(
 
     if let Some(pred) = predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(md, schema)? {
+            if let Some(stats) = collect_statistics(md, schema, bloom_filters)? {
                 let pred_result = pred.should_read(&stats);
 
                 // a parquet file may not have statistics of all columns
@@ -86,3 +174,182 @@ pub fn read_this_row_group(
 
     Ok(should_read)
 }
+
+/// Returns the sorted and deduplicated live variables of `predicate` if it can be evaluated on
+/// statistics.
+fn stats_live_variables(predicate: Option<&dyn PhysicalIoExpr>) -> Option<Vec<PlSmallStr>> {
+    let predicate = predicate?;
+    predicate.as_stats_evaluator()?;
+    let mut live_variables = predicate.live_variables()?;
+    live_variables.sort_unstable();
+    live_variables.dedup();
+    Some(live_variables)
+}
+
+/// Returns the byte ranges of the column indexes and offset indexes of the columns used in
+/// `predicate`, which are needed by [`select_pages`]. The indexes of encrypted columns are not
+/// used.
+pub fn page_index_ranges(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+) -> Vec<Range<usize>> {
+    let Some(live_variables) = stats_live_variables(predicate) else {
+        return vec![];
+    };
+
+    live_variables
+        .iter()
+        .filter_map(|name| {
+            let mut iter = md.columns_under_root_iter(name)?;
+            match iter.len() {
+                1 => {
+                    let column = iter.next().unwrap();
+                    if column.is_encrypted() {
+                        return None;
+                    }
+                    Some([column.column_index_range()?, column.offset_index_range()?])
+                },
+                _ => None,
+            }
+        })
+        .flatten()
+        .map(|range| range.start as usize..range.end as usize)
+        .collect()
+}
+
+/// The page statistics and the first row of every page of a column chunk.
+struct PageStats {
+    stats: ColumnStats,
+    first_rows: Vec<usize>,
+}
+
+fn collect_page_stats(
+    column: &ColumnChunkMetadata,
+    field: &ArrowField,
+    num_rows: usize,
+    page_indexes: &PlHashMap<usize, MemSlice>,
+) -> PolarsResult<Option<PageStats>> {
+    let (Some(column_index_range), Some(offset_index_range)) =
+        (column.column_index_range(), column.offset_index_range())
+    else {
+        return Ok(None);
+    };
+    let (Some(column_index), Some(offset_index)) = (
+        page_indexes.get(&(column_index_range.start as usize)),
+        page_indexes.get(&(offset_index_range.start as usize)),
+    ) else {
+        return Ok(None);
+    };
+    let column_index = deserialize_column_index(column_index)?;
+    let offset_index = deserialize_offset_index(offset_index)?;
+
+    let first_rows = offset_index
+        .page_locations
+        .iter()
+        .map(|location| usize::try_from(location.first_row_index))
+        .collect::<Result<Vec<_>, _>>()
+        .ok();
+    let Some(first_rows) = first_rows.filter(|first_rows| {
+        first_rows.len() == column_index.null_pages.len()
+            && first_rows.first() == Some(&0)
+            && first_rows.is_sorted()
+            && first_rows.last() < Some(&num_rows)
+    }) else {
+        return Ok(None);
+    };
+
+    let stats = statistics::deserialize_column_index(field, column, &column_index)?;
+    Ok(Some(PageStats {
+        stats: ColumnStats::from_arrow_stats(stats, field),
+        first_rows,
+    }))
+}
+
+/// Evaluates `predicate` on the page statistics of the columns it uses and returns a mask of the
+/// rows of the row group that may match it.
+///
+/// `page_indexes` maps the start of the [`page_index_ranges`] to their bytes. Returns `None` if
+/// the page statistics cannot exclude any rows.
+pub fn select_pages(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    page_indexes: &PlHashMap<usize, MemSlice>,
+) -> PolarsResult<Option<Bitmap>> {
+    if page_indexes.is_empty() || std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok() {
+        return Ok(None);
+    }
+    let Some(live_variables) = stats_live_variables(predicate) else {
+        return Ok(None);
+    };
+    let evaluator = predicate.unwrap().as_stats_evaluator().unwrap();
+
+    let num_rows = md.num_rows();
+    let mut fields = Vec::with_capacity(live_variables.len());
+    let mut page_stats = Vec::with_capacity(live_variables.len());
+    for name in &live_variables {
+        // The predicate can refer to columns that are not in the file, e.g. hive columns.
+        let Some(field) = schema.get(name) else {
+            return Ok(None);
+        };
+        let stats = match md.columns_under_root_iter(name) {
+            Some(mut iter) if iter.len() == 1 => {
+                collect_page_stats(iter.next().unwrap(), field, num_rows, page_indexes)?
+            },
+            _ => None,
+        };
+        fields.push(field);
+        page_stats.push(stats);
+    }
+    if page_stats.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+
+    // Rows between two consecutive boundaries lie within a single page of every column.
+    let mut boundaries = page_stats
+        .iter()
+        .flatten()
+        .flat_map(|stats| stats.first_rows.iter().copied())
+        .chain([num_rows])
+        .collect::<Vec<_>>();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let batch_schema = Arc::new(Schema::from_iter(fields.iter().map(|f| Field::from(*f))));
+    let mut mask = MutableBitmap::with_capacity(num_rows);
+    for segment in boundaries.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let stats = fields
+            .iter()
+            .zip(&page_stats)
+            .map(|(field, page_stats)| match page_stats {
+                Some(PageStats { stats, first_rows }) => {
+                    let page = first_rows.partition_point(|&first_row| first_row <= start) - 1;
+                    let slice = |s: Option<&Series>| s.map(|s| s.slice(page as i64, 1));
+                    ColumnStats::new(
+                        Field::from(*field),
+                        slice(stats.get_null_count_state()),
+                        slice(stats.get_min_state()),
+                        slice(stats.get_max_state()),
+                    )
+                },
+                None => ColumnStats::from_field(Field::from(*field)),
+            })
+            .collect();
+        // The number of rows of a segment can differ from the number of rows of its pages.
+        let stats = BatchStats::new(batch_schema.clone(), stats, None);
+        let may_match = !matches!(evaluator.should_read(&stats), Ok(false));
+        mask.extend_constant(end - start, may_match);
+    }
+
+    let mask = mask.freeze();
+    if config::verbose() {
+        eprintln!(
+            "parquet page index: {} of {} rows in row group can be skipped",
+            mask.unset_bits(),
+            num_rows
+        );
+    }
+
+    Ok((mask.unset_bits() > 0).then_some(mask))
+}
@@ -3,7 +3,8 @@ use std::collections::VecDeque;
 use std::ops::{Deref, Range};
 
 use arrow::array::BooleanArray;
-use arrow::bitmap::MutableBitmap;
+use arrow::bitmap::iterator::TrueIdxIter;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::chunked_array::builder::NullChunkedBuilder;
 use polars_core::prelude::*;
@@ -15,12 +16,15 @@ use polars_parquet::parquet::statistics::Statistics;
 use polars_parquet::read::{
     self, ColumnChunkMetadata, FileMetadata, Filter, PhysicalType, RowGroupMetadata,
 };
+use polars_utils::mmap::MemSlice;
 use rayon::prelude::*;
 
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
-use super::predicates::read_this_row_group;
+use super::predicates::{
+    bloom_filter_ranges, page_index_ranges, read_this_row_group_with_bloom_filters, select_pages,
+};
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
 use super::{mmap, ParallelStrategy};
@@ -43,34 +47,38 @@ fn assert_dtypes(dtype: &ArrowDataType) {
         // These should all be casted to the BinaryView / Utf8View variants
         D::Utf8 | D::Binary | D::LargeUtf8 | D::LargeBinary => unreachable!(),
 
-        // These should be casted to Float32
-        D::Float16 => unreachable!(),
-
         // This should have been converted to a LargeList
         D::List(_) => unreachable!(),
 
-        // This should have been converted to a LargeList(Struct(_))
-        D::Map(_, _) => unreachable!(),
-
         // Recursive checks
         D::Dictionary(_, dtype, _) => assert_dtypes(dtype),
         D::Extension(_, dtype, _) => assert_dtypes(dtype),
         D::LargeList(inner) => assert_dtypes(&inner.dtype),
-        D::FixedSizeList(inner, _) => assert_dtypes(&inner.dtype),
+        D::FixedSizeList(inner, _) | D::Map(inner, _) => assert_dtypes(&inner.dtype),
         D::Struct(fields) => fields.iter().for_each(|f| assert_dtypes(f.dtype())),
 
         _ => {},
     }
 }
 
-fn should_copy_sortedness(dtype: &DataType) -> bool {
+/// Whether the sort order of parquet matches the sort order of polars for `dtype`.
+pub(crate) fn should_copy_sortedness(dtype: &DataType) -> bool {
     // @NOTE: For now, we are a bit conservative with this.
     use DataType as D;
 
     matches!(
         dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
-    )
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::String
+            | D::Binary
+    ) || dtype.is_temporal()
 }
 
 pub fn try_set_sorted_flag(
@@ -92,6 +100,17 @@ pub fn try_set_sorted_flag(
     }
 }
 
+/// Sets the sorted flag of a column with a single leaf column in the parquet schema.
+fn try_set_sorted_flag_of_root(
+    series: &mut Series,
+    md: &RowGroupMetadata,
+    sorting_map: &PlHashMap<usize, IsSorted>,
+) {
+    if let Some(&[col_idx]) = md.columns_idxs_under_root_iter(series.name()) {
+        try_set_sorted_flag(series, col_idx, sorting_map);
+    }
+}
+
 pub fn create_sorting_map(md: &RowGroupMetadata) -> PlHashMap<usize, IsSorted> {
     let capacity = md.sorting_columns().map_or(0, |s| s.len());
     let mut sorting_map = PlHashMap::with_capacity(capacity);
@@ -196,6 +215,99 @@ fn column_idx_to_series(
     Ok(series)
 }
 
+/// Evaluates the predicate on the statistics and, for local files, the bloom filters of a row
+/// group.
+///
+/// The bloom filters of files read from cloud storage by the in-memory engine are not used, as
+/// only the column chunks are prefetched. The streaming engine fetches them on demand.
+fn read_this_row_group_from_store(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    store: &ColumnStore,
+) -> PolarsResult<bool> {
+    let bloom_filters = match store {
+        ColumnStore::Local(mem_slice) => bloom_filter_ranges(predicate, md)
+            .into_iter()
+            .filter(|range| range.end <= mem_slice.len())
+            .map(|range| (range.start, mem_slice.slice(range)))
+            .collect(),
+        #[cfg(feature = "async")]
+        ColumnStore::Fetched(_) => PlHashMap::default(),
+    };
+    read_this_row_group_with_bloom_filters(predicate, md, schema, &bloom_filters)
+}
+
+/// Evaluates the predicate on the page index of a row group and returns a mask of the rows that
+/// may match it. Cloud downloads prefetch the page indexes together with the column chunks.
+fn select_pages_from_store(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    store: &ColumnStore,
+) -> PolarsResult<Option<Bitmap>> {
+    let page_indexes = match store {
+        ColumnStore::Local(mem_slice) => page_index_ranges(predicate, md)
+            .into_iter()
+            .filter(|range| range.end <= mem_slice.len())
+            .map(|range| (range.start, mem_slice.slice(range)))
+            .collect(),
+        #[cfg(feature = "async")]
+        ColumnStore::Fetched(fetched) => page_index_ranges(predicate, md)
+            .into_iter()
+            .filter_map(|range| {
+                let bytes = fetched.get(&(range.start as u64))?;
+                Some((range.start, MemSlice::from_bytes(bytes.clone())))
+            })
+            .collect(),
+    };
+    select_pages(predicate, md, schema, &page_indexes)
+}
+
+/// Returns the filter of the rows in `slice` of a row group that are selected by `page_mask`.
+fn row_group_filter(slice: (usize, usize), page_mask: Option<&Bitmap>) -> Filter {
+    match page_mask {
+        None => Filter::new_ranged(slice.0, slice.0 + slice.1),
+        Some(page_mask) => {
+            let mut mask = MutableBitmap::with_capacity(slice.0 + slice.1);
+            mask.extend_constant(slice.0, false);
+            mask.extend_from_bitmap(&page_mask.clone().sliced(slice.0, slice.1));
+            Filter::new_masked(mask.freeze())
+        },
+    }
+}
+
+/// Scatters `mask`, which has a value for every row selected by `page_mask`, onto all rows of the
+/// row group.
+fn expand_page_mask(page_mask: &Bitmap, mask: &Bitmap) -> Bitmap {
+    let mut values = mask.iter();
+    page_mask
+        .iter()
+        .map(|selected| selected && values.next().unwrap())
+        .collect()
+}
+
+/// Adds a row index column for the rows of a row group that are selected by `filter`. The first
+/// row of the row group gets index `offset`.
+fn with_row_index_filtered(df: &mut DataFrame, name: PlSmallStr, offset: IdxSize, filter: &Filter) {
+    match filter {
+        Filter::Range(range) => {
+            df.with_row_index_mut(name, Some(offset + range.start as IdxSize));
+        },
+        Filter::Mask(mask) => {
+            let mut ca = IdxCa::from_vec(
+                name,
+                TrueIdxIter::new(mask.len(), Some(mask))
+                    .map(|i| offset + i as IdxSize)
+                    .collect(),
+            );
+            ca.set_sorted_flag(IsSorted::Ascending);
+            // SAFETY: The row index has a value for every selected row.
+            unsafe { df.get_columns_mut() }.insert(0, ca.into_column());
+        },
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -381,13 +493,28 @@ fn rg_to_dfs_prefiltered(
                 let md = &file_metadata.row_groups[rg_idx];
 
                 if use_statistics {
-                    match read_this_row_group(Some(predicate), md, schema) {
+                    match read_this_row_group_from_store(Some(predicate), md, schema, store) {
                         Ok(false) => return Ok(None),
                         Ok(true) => {},
                         Err(e) => return Err(e),
                     }
                 }
 
+                // The live columns are only decoded for the rows in the pages that may match the
+                // predicate.
+                let page_mask = if use_statistics {
+                    select_pages_from_store(Some(predicate), md, schema, store)?
+                } else {
+                    None
+                };
+                let live_filter = page_mask.clone().map(Filter::new_masked);
+                let live_height = live_filter
+                    .as_ref()
+                    .map_or(md.num_rows(), |filter| filter.num_rows());
+                if live_height == 0 {
+                    return Ok(None);
+                }
+
                 let sorting_map = create_sorting_map(md);
 
                 // Collect the data for the live columns
@@ -401,17 +528,22 @@ fn rg_to_dfs_prefiltered(
                         let Some(iter) = md.columns_under_root_iter(name) else {
                             return Ok(Column::full_null(
                                 name.clone(),
-                                md.num_rows(),
+                                live_height,
                                 &DataType::from_arrow(&field.dtype, true),
                             ));
                         };
 
                         let part = iter.collect::<Vec<_>>();
 
-                        let mut series =
-                            column_idx_to_series(col_idx, part.as_slice(), None, schema, store)?;
+                        let mut series = column_idx_to_series(
+                            col_idx,
+                            part.as_slice(),
+                            live_filter.clone(),
+                            schema,
+                            store,
+                        )?;
 
-                        try_set_sorted_flag(&mut series, col_idx, &sorting_map);
+                        try_set_sorted_flag_of_root(&mut series, md, &sorting_map);
 
                         Ok(series.into_column())
                     })
@@ -419,19 +551,26 @@ fn rg_to_dfs_prefiltered(
 
                 // Apply the predicate to the live columns and save the dataframe and the bitmask
                 let md = &file_metadata.row_groups[rg_idx];
-                let mut df = unsafe { DataFrame::new_no_checks(md.num_rows(), live_columns) };
+                let mut df = unsafe { DataFrame::new_no_checks(live_height, live_columns) };
 
                 materialize_hive_partitions(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    md.num_rows(),
+                    live_height,
                 );
                 let s = predicate.evaluate_io(&df)?;
                 let mask = s.bool().expect("filter predicates was not of type boolean");
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(rc.name.clone(), Some(rg_offsets[rg_idx] + rc.offset));
+                    with_row_index_filtered(
+                        &mut df,
+                        rc.name.clone(),
+                        rg_offsets[rg_idx] + rc.offset,
+                        live_filter
+                            .as_ref()
+                            .unwrap_or(&Filter::new_ranged(0, md.num_rows())),
+                    );
                 }
                 df = df.filter(mask)?;
 
@@ -447,7 +586,10 @@ fn rg_to_dfs_prefiltered(
                     }
                 }
 
-                let filter_mask = filter_mask.freeze();
+                let filter_mask = match &page_mask {
+                    Some(page_mask) => expand_page_mask(page_mask, &filter_mask.freeze()),
+                    None => filter_mask.freeze(),
+                };
 
                 debug_assert_eq!(md.num_rows(), filter_mask.len());
                 debug_assert_eq!(df.height(), filter_mask.set_bits());
@@ -472,7 +614,7 @@ fn rg_to_dfs_prefiltered(
                 #[cfg(debug_assertions)]
                 {
                     let md = &file_metadata.row_groups[rg_idx];
-                    debug_assert_eq!(md.num_rows(), mask.len());
+                    debug_assert_eq!(md.num_rows(), filter_mask.len());
                 }
 
                 let n_rows_in_result = filter_mask.set_bits();
@@ -512,7 +654,7 @@ fn rg_to_dfs_prefiltered(
                                 store,
                             )?;
 
-                            debug_assert_eq!(array.len(), mask.len());
+                            debug_assert_eq!(array.len(), filter_mask.len());
 
                             let mask_arr = BooleanArray::new(
                                 ArrowDataType::Boolean,
@@ -523,10 +665,12 @@ fn rg_to_dfs_prefiltered(
                             array.filter(&mask_arr)
                         };
 
-                        let mut series = if mask_setting.should_prefilter(
-                            prefilter_cost,
-                            &schema.get_at_index(col_idx).unwrap().1.dtype,
-                        ) {
+                        // Pages that were excluded by the page index are never decoded.
+                        let mut series = if page_mask.is_some()
+                            || mask_setting.should_prefilter(
+                                prefilter_cost,
+                                &schema.get_at_index(col_idx).unwrap().1.dtype,
+                            ) {
                             pre()?
                         } else {
                             post()?
@@ -534,7 +678,7 @@ fn rg_to_dfs_prefiltered(
 
                         debug_assert_eq!(series.len(), filter_mask.set_bits());
 
-                        try_set_sorted_flag(&mut series, col_idx, &sorting_map);
+                        try_set_sorted_flag_of_root(&mut series, md, &sorting_map);
 
                         Ok(series.into_column())
                     })
@@ -620,12 +764,23 @@ fn rg_to_dfs_optionally_par_over_columns(
             split_slice_at_file(&mut n_rows_processed, md.num_rows(), slice.0, slice_end);
         let current_row_count = md.num_rows() as IdxSize;
 
-        if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
-        {
+        if use_statistics && !read_this_row_group_from_store(predicate, md, schema, store)? {
+            *previous_row_count += rg_slice.1 as IdxSize;
+            continue;
+        }
+
+        let page_mask = if use_statistics {
+            select_pages_from_store(predicate, md, schema, store)?
+        } else {
+            None
+        };
+        let filter = row_group_filter(rg_slice, page_mask.as_ref());
+        let height = filter.num_rows();
+        if height == 0 && page_mask.is_some() {
             *previous_row_count += rg_slice.1 as IdxSize;
             continue;
         }
+
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
         {
@@ -644,7 +799,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         let Some(iter) = md.columns_under_root_iter(name) else {
                             return Ok(Column::full_null(
                                 name.clone(),
-                                rg_slice.1,
+                                height,
                                 &DataType::from_arrow(&field.dtype, true),
                             ));
                         };
@@ -654,12 +809,12 @@ fn rg_to_dfs_optionally_par_over_columns(
                         let mut series = column_idx_to_series(
                             *column_i,
                             part.as_slice(),
-                            Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                            Some(filter.clone()),
                             schema,
                             store,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        try_set_sorted_flag_of_root(&mut series, md, &sorting_map);
 
                         Ok(series.into_column())
                     })
@@ -674,7 +829,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                     let Some(iter) = md.columns_under_root_iter(name) else {
                         return Ok(Column::full_null(
                             name.clone(),
-                            rg_slice.1,
+                            height,
                             &DataType::from_arrow(&field.dtype, true),
                         ));
                     };
@@ -684,27 +839,29 @@ fn rg_to_dfs_optionally_par_over_columns(
                     let mut series = column_idx_to_series(
                         *column_i,
                         part.as_slice(),
-                        Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                        Some(filter.clone()),
                         schema,
                         store,
                     )?;
 
-                    try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                    try_set_sorted_flag_of_root(&mut series, md, &sorting_map);
 
                     Ok(series.into_column())
                 })
                 .collect::<PolarsResult<Vec<_>>>()?
         };
 
-        let mut df = unsafe { DataFrame::new_no_checks(rg_slice.1, columns) };
+        let mut df = unsafe { DataFrame::new_no_checks(height, columns) };
         if let Some(rc) = &row_index {
-            df.with_row_index_mut(
+            with_row_index_filtered(
+                &mut df,
                 rc.name.clone(),
-                Some(*previous_row_count + rc.offset + rg_slice.0 as IdxSize),
+                *previous_row_count + rc.offset,
+                &filter,
             );
         }
 
-        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, rg_slice.1);
+        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, height);
         apply_predicate(&mut df, predicate, true)?;
 
         *previous_row_count = previous_row_count.checked_add(current_row_count).ok_or_else(||
@@ -770,9 +927,24 @@ fn rg_to_dfs_par_over_rg(
         row_groups
             .into_par_iter()
             .map(|(md, slice, row_count_start)| {
-                if slice.1 == 0 || use_statistics && !read_this_row_group(predicate, md, schema)? {
+                if slice.1 == 0
+                    || use_statistics
+                        && !read_this_row_group_from_store(predicate, md, schema, store)?
+                {
                     return Ok(None);
                 }
+
+                let page_mask = if use_statistics {
+                    select_pages_from_store(predicate, md, schema, store)?
+                } else {
+                    None
+                };
+                let filter = row_group_filter(slice, page_mask.as_ref());
+                let height = filter.num_rows();
+                if height == 0 {
+                    return Ok(None);
+                }
+
                 // test we don't read the parquet file if this env var is set
                 #[cfg(debug_assertions)]
                 {
@@ -789,7 +961,7 @@ fn rg_to_dfs_par_over_rg(
                         let Some(iter) = md.columns_under_root_iter(name) else {
                             return Ok(Column::full_null(
                                 name.clone(),
-                                md.num_rows(),
+                                height,
                                 &DataType::from_arrow(&field.dtype, true),
                             ));
                         };
@@ -799,23 +971,25 @@ fn rg_to_dfs_par_over_rg(
                         let mut series = column_idx_to_series(
                             *column_i,
                             part.as_slice(),
-                            Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
+                            Some(filter.clone()),
                             schema,
                             store,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        try_set_sorted_flag_of_root(&mut series, md, &sorting_map);
 
                         Ok(series.into_column())
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                let mut df = unsafe { DataFrame::new_no_checks(slice.1, columns) };
+                let mut df = unsafe { DataFrame::new_no_checks(height, columns) };
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(
+                    with_row_index_filtered(
+                        &mut df,
                         rc.name.clone(),
-                        Some(row_count_start as IdxSize + rc.offset + slice.0 as IdxSize),
+                        row_count_start as IdxSize + rc.offset,
+                        &filter,
                     );
                 }
 
@@ -823,7 +997,7 @@ fn rg_to_dfs_par_over_rg(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    slice.1,
+                    height,
                 );
                 apply_predicate(&mut df, predicate, false)?;
 
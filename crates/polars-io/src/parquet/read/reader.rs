@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "cloud")]
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_parquet::read;
+use polars_parquet::read::FileDecryptionProperties;
 
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
@@ -19,10 +20,10 @@ use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_pr
 #[cfg(feature = "cloud")]
 use crate::cloud::CloudOptions;
 use crate::mmap::MmapBytesReader;
-use crate::parquet::metadata::FileMetadataRef;
+use crate::parquet::metadata::{read_key_value_metadata, FileMetadataRef};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
-use crate::RowIndex;
+use crate::{KeyValueMetadata, RowIndex};
 
 /// Read Apache parquet format into a DataFrame.
 #[must_use]
@@ -41,6 +42,7 @@ pub struct ParquetReader<R: Read + Seek> {
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     use_statistics: bool,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -178,17 +180,32 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Decrypt a file written with parquet modular encryption. Requires the
+    /// `parquet_encryption` feature.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                self.decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
 
+    /// Custom key/value metadata of the file and of its columns.
+    pub fn key_value_metadata(&mut self) -> PolarsResult<KeyValueMetadata> {
+        read_key_value_metadata(self.get_metadata()?)
+    }
+
     pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
         self.predicate = predicate;
         self
@@ -238,6 +255,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             use_statistics: true,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
@@ -494,6 +512,11 @@ impl ParquetAsyncReader {
         self.reader.get_metadata().await
     }
 
+    /// Custom key/value metadata of the file and of its columns.
+    pub async fn key_value_metadata(&mut self) -> PolarsResult<KeyValueMetadata> {
+        read_key_value_metadata(self.get_metadata().await?)
+    }
+
     pub async fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
         let metadata = self.get_metadata().await?.clone();
@@ -3,22 +3,40 @@ use std::sync::Mutex;
 
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    FallibleStreamingIterator, FileWriter, KeyValue, Page, ParquetType, RowGroupIterColumns,
+    SchemaDescriptor, SortingColumn, WriteOptions,
 };
 use rayon::prelude::*;
 
+use crate::parquet::bloom_filter::BloomFilter;
+
+/// A row group that is encoded and compressed, but not yet written.
+pub struct EncodedRowGroup {
+    columns: RowGroupIterColumns<'static, PolarsError>,
+    /// Pairs of leaf column index and bloom filter bitset.
+    bloom_filters: Vec<(usize, Vec<u8>)>,
+    sorting_columns: Vec<SortingColumn>,
+}
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
-    pub(super) options: WriteOptions,
+    /// The write options of every column.
+    pub(super) column_options: Vec<WriteOptions>,
+    /// Column index, leaf column index and false positive probability of every bloom filter.
+    pub(super) bloom_filters: Vec<(usize, usize, f64)>,
+    /// Column index and leaf column index of every column whose sortedness can be written.
+    pub(super) sorting_columns: Vec<(usize, usize)>,
+    /// Custom key/value metadata written to the footer.
+    pub(super) key_value_metadata: Option<Vec<KeyValue>>,
     pub(super) parallel: bool,
 }
 
@@ -26,21 +44,66 @@ impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
-        rb_iter.filter_map(move |batch| match batch.len() {
-            0 => None,
-            _ => {
-                let row_group = create_eager_serializer(
-                    batch,
-                    self.parquet_schema.fields(),
-                    self.encodings.as_ref(),
-                    self.options,
-                );
+        rb_iter
+            .enumerate()
+            .filter_map(move |(i, batch)| match batch.len() {
+                0 => None,
+                _ => {
+                    let row_group = create_eager_serializer(
+                        batch,
+                        self.parquet_schema.fields(),
+                        self.encodings.as_ref(),
+                        &self.column_options,
+                    )
+                    .and_then(|columns| {
+                        Ok(EncodedRowGroup {
+                            columns,
+                            bloom_filters: self.row_group_bloom_filters(df, i)?,
+                            sorting_columns: self.row_group_sorting_columns(df, i),
+                        })
+                    });
 
-                Some(row_group)
-            },
-        })
+                    Some(row_group)
+                },
+            })
+    }
+
+    /// Builds the bloom filters of the row group formed by the `i`-th chunk of `df`.
+    fn row_group_bloom_filters(
+        &self,
+        df: &DataFrame,
+        i: usize,
+    ) -> PolarsResult<Vec<(usize, Vec<u8>)>> {
+        self.bloom_filters
+            .iter()
+            .map(|&(column, leaf, fpp)| {
+                let s = df.get_columns()[column]
+                    .as_materialized_series()
+                    .select_chunk(i);
+                Ok((leaf, BloomFilter::try_from_series(&s, fpp)?.into_bitset()))
+            })
+            .collect()
+    }
+
+    /// Returns the columns of `df` with a known sort order as sorting columns of the row group
+    /// formed by its `i`-th chunk.
+    fn row_group_sorting_columns(&self, df: &DataFrame, i: usize) -> Vec<SortingColumn> {
+        self.sorting_columns
+            .iter()
+            .filter_map(|&(column, leaf)| {
+                let column = &df.get_columns()[column];
+                let descending = match column.is_sorted_flag() {
+                    IsSorted::Ascending => false,
+                    IsSorted::Descending => true,
+                    IsSorted::Not => return None,
+                };
+                let s = column.as_materialized_series().select_chunk(i);
+                let nulls_first = s.null_count() > 0 && s.get(0).is_ok_and(|v| v.is_null());
+                Some(SortingColumn::new(leaf as i32, descending, nulls_first))
+            })
+            .collect()
     }
 
     /// Write a batch to the parquet writer.
@@ -52,13 +115,18 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
-            self.options,
+            &self.column_options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (i, group) = group?;
+            writer.write(group)?;
+            writer.set_sorting_columns(self.row_group_sorting_columns(df, i))?;
+            for (leaf, bitset) in self.row_group_bloom_filters(df, i)? {
+                writer.add_bloom_filter(leaf, bitset)?;
+            }
         }
         Ok(())
     }
@@ -67,14 +135,15 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            writer.write(group.columns)?;
+            writer.set_sorting_columns(group.sorting_columns)?;
+            for (leaf, bitset) in group.bloom_filters {
+                writer.add_bloom_filter(leaf, bitset)?;
+            }
         }
         Ok(())
     }
@@ -82,7 +151,7 @@ impl<W: Write> BatchedWriter<W> {
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&self) -> PolarsResult<u64> {
         let mut writer = self.writer.lock().unwrap();
-        let size = writer.end(None)?;
+        let size = writer.end(self.key_value_metadata.clone())?;
         Ok(size)
     }
 }
@@ -92,19 +161,26 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
-    options: WriteOptions,
+    column_options: &'a [WriteOptions],
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<(usize, RowGroupIterColumns<'static, PolarsError>)>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
-    rb_iter.filter_map(move |batch| match batch.len() {
-        0 => None,
-        _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
-
-            Some(row_group)
-        },
-    })
+    rb_iter
+        .enumerate()
+        .filter_map(move |(i, batch)| match batch.len() {
+            0 => None,
+            _ => {
+                let row_group = create_serializer(
+                    batch,
+                    parquet_schema.fields(),
+                    encodings,
+                    column_options,
+                    parallel,
+                );
+
+                Some(row_group.map(|row_group| (i, row_group)))
+            },
+        })
 }
 
 fn pages_iter_to_compressor(
@@ -147,12 +223,13 @@ fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    column_options: &[WriteOptions],
     parallel: bool,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = move |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *options) };
 
     let columns = if parallel {
         POOL.install(|| {
@@ -161,6 +238,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(column_options)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -170,6 +248,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(column_options)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -185,17 +264,19 @@ fn create_eager_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    column_options: &[WriteOptions],
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = move |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *options) };
 
     let columns = batch
         .columns()
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(column_options)
         .flat_map(func)
         .collect::<Vec<_>>();
 
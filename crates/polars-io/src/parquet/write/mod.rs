@@ -4,7 +4,12 @@ mod batched_writer;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
-pub use options::{BrotliLevel, GzipLevel, ParquetCompression, ParquetWriteOptions, ZstdLevel};
-pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
+pub use batched_writer::{BatchedWriter, EncodedRowGroup};
+pub use options::{
+    BrotliLevel, GzipLevel, ParquetBloomFilterOptions, ParquetColumnWriteOptions,
+    ParquetCompression, ParquetEncoding, ParquetWriteOptions, ZstdLevel,
+};
+pub use polars_parquet::write::{
+    ColumnEncryptionKey, FileEncryptionProperties, RowGroupIterColumns, StatisticsOptions,
+};
 pub use writer::ParquetWriter;
@@ -1,12 +1,15 @@
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BrotliLevel as BrotliLevelParquet, CompressionOptions, Encoding, FileEncryptionProperties,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+use crate::KeyValueMetadata;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -19,6 +22,90 @@ pub struct ParquetWriteOptions {
     pub data_page_size: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Columns to write bloom filters for.
+    pub bloom_filters: Vec<ParquetBloomFilterOptions>,
+    /// Per-column overrides of the options above.
+    pub column_options: Vec<ParquetColumnWriteOptions>,
+    /// Custom metadata to write to the footer of the file and to its columns.
+    pub key_value_metadata: KeyValueMetadata,
+    /// Encrypt the file with parquet modular encryption.
+    pub encryption: Option<FileEncryptionProperties>,
+}
+
+/// Overrides of the write options for a single column. Options that are `None` are taken from
+/// the file-level options.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnWriteOptions {
+    /// Name of the column.
+    pub column: PlSmallStr,
+    /// Data page compression of the column.
+    pub compression: Option<ParquetCompression>,
+    /// Encoding of the data pages of the column. An encoding can't be combined with dictionary
+    /// encoding.
+    pub encoding: Option<ParquetEncoding>,
+    /// Whether to dictionary encode the column. Dictionary encoding falls back to plain encoding
+    /// for columns that don't fit in a dictionary.
+    pub dictionary: Option<bool>,
+    /// Statistics to write for the column.
+    pub statistics: Option<StatisticsOptions>,
+}
+
+impl ParquetColumnWriteOptions {
+    pub fn new(column: impl Into<PlSmallStr>) -> Self {
+        Self {
+            column: column.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The non-dictionary encodings that can be selected for a column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    /// Supported for all columns.
+    Plain,
+    /// Run length encoding of booleans.
+    Rle,
+    /// Delta encoding of integers, dates and times.
+    DeltaBinaryPacked,
+    /// Delta encoding of the lengths of strings and binary values.
+    DeltaLengthByteArray,
+    /// Splits the bytes of floats and integers into separate streams, which compresses well for
+    /// floating point data.
+    ByteStreamSplit,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::Rle => Encoding::Rle,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+            ParquetEncoding::ByteStreamSplit => Encoding::ByteStreamSplit,
+        }
+    }
+}
+
+/// Write a bloom filter for a column.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetBloomFilterOptions {
+    /// Name of the column.
+    pub column: PlSmallStr,
+    /// Target false positive probability of the bloom filter, in the range (0, 1).
+    pub fpp: f64,
+}
+
+impl Eq for ParquetBloomFilterOptions {}
+
+impl std::hash::Hash for ParquetBloomFilterOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.column.hash(state);
+        self.fpp.to_bits().hash(state);
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -96,3 +183,33 @@ impl From<ParquetCompression> for CompressionOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_serde_encryption_keys_are_skipped() {
+        use polars_parquet::write::ColumnEncryptionKey;
+
+        use super::*;
+
+        let options = ParquetWriteOptions {
+            encryption: Some(
+                FileEncryptionProperties::new(b"footer-key-value".to_vec())
+                    .with_column_key(ColumnEncryptionKey::new("a", b"column-key-value".to_vec())),
+            ),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        assert!(!json.contains("footer_key\""));
+        assert!(!json.contains("\"key\""));
+
+        let encryption = serde_json::from_str::<ParquetWriteOptions>(&json)
+            .unwrap()
+            .encryption
+            .unwrap();
+        assert!(encryption.footer_key.is_empty());
+        assert_eq!(encryption.column_keys[0].column, "a");
+        assert!(encryption.column_keys[0].key.is_empty());
+    }
+}
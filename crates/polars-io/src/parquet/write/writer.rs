@@ -1,18 +1,23 @@
 use std::io::Write;
 use std::sync::Mutex;
 
-use arrow::datatypes::PhysicalType;
+use arrow::datatypes::{Metadata, PhysicalType};
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, StatisticsOptions,
-    Version, WriteOptions,
+    to_parquet_schema, transverse, CompressionOptions, Encoding, FileEncryptionProperties,
+    FileWriter, KeyValue, StatisticsOptions, Version, WriteOptions, ARROW_SCHEMA_META_KEY,
 };
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
-use super::ParquetWriteOptions;
+use super::{
+    ParquetBloomFilterOptions, ParquetColumnWriteOptions, ParquetEncoding, ParquetWriteOptions,
+};
+use crate::parquet::bloom_filter;
+use crate::parquet::read::should_copy_sortedness;
 use crate::prelude::chunk_df_for_writing;
 use crate::shared::schema_to_arrow_checked;
+use crate::KeyValueMetadata;
 
 impl ParquetWriteOptions {
     pub fn to_writer<F>(&self, f: F) -> ParquetWriter<F>
@@ -24,6 +29,10 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_bloom_filters(self.bloom_filters.clone())
+            .with_column_options(self.column_options.clone())
+            .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -39,6 +48,14 @@ pub struct ParquetWriter<W> {
     row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
+    /// Columns to write bloom filters for
+    bloom_filters: Vec<ParquetBloomFilterOptions>,
+    /// Per-column overrides of the write options
+    column_options: Vec<ParquetColumnWriteOptions>,
+    /// Custom metadata of the file and its columns
+    key_value_metadata: KeyValueMetadata,
+    /// Parquet modular encryption of the file
+    encryption: Option<FileEncryptionProperties>,
     /// Serialize columns in parallel
     parallel: bool,
 }
@@ -58,6 +75,10 @@ where
             statistics: StatisticsOptions::default(),
             row_group_size: None,
             data_page_size: None,
+            bloom_filters: vec![],
+            column_options: vec![],
+            key_value_metadata: KeyValueMetadata::default(),
+            encryption: None,
             parallel: true,
         }
     }
@@ -90,6 +111,36 @@ where
         self
     }
 
+    /// Write a bloom filter for each of the given columns in every row group.
+    ///
+    /// Bloom filters allow readers to skip row groups for equality and `is_in` predicates on
+    /// columns with many distinct values, where min/max statistics don't help. The in-memory engine
+    /// only uses them for local files, the streaming engine also for cloud storage.
+    pub fn with_bloom_filters(mut self, bloom_filters: Vec<ParquetBloomFilterOptions>) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
+    /// Override the compression, encoding, dictionary encoding or statistics of individual columns.
+    pub fn with_column_options(mut self, column_options: Vec<ParquetColumnWriteOptions>) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
+    /// Write custom metadata to the footer of the file and to the fields of its arrow schema.
+    pub fn with_key_value_metadata(mut self, key_value_metadata: KeyValueMetadata) -> Self {
+        self.key_value_metadata = key_value_metadata;
+        self
+    }
+
+    /// Encrypt the file with parquet modular encryption, using AES-GCM.
+    ///
+    /// Requires the `parquet_encryption` feature.
+    pub fn with_encryption(mut self, encryption: Option<FileEncryptionProperties>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -97,17 +148,67 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        let bloom_filters = resolve_bloom_filters(&self.bloom_filters, schema)?;
+        let column_indexes = self
+            .column_options
+            .iter()
+            .map(|options| schema.try_index_of(&options.column))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let sortable_columns = schema
+            .iter_values()
+            .enumerate()
+            .filter(|(_, dtype)| should_copy_sortedness(dtype))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+        let mut schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        self.key_value_metadata.apply_to_schema(&mut schema)?;
+        let key_value_metadata = file_key_value_metadata(&self.key_value_metadata.file)?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let mut encodings = get_encodings(&schema);
+        // Bloom filters and sorting columns are added per leaf column of the parquet schema.
+        let leaf_offsets = encodings
+            .iter()
+            .scan(0, |offset, encodings| {
+                let start = *offset;
+                *offset += encodings.len();
+                Some(start)
+            })
+            .collect::<Vec<_>>();
+        let bloom_filters = bloom_filters
+            .into_iter()
+            .map(|(idx, fpp)| (idx, leaf_offsets[idx], fpp))
+            .collect();
+        let sorting_columns = sortable_columns
+            .into_iter()
+            .map(|idx| (idx, leaf_offsets[idx]))
+            .collect();
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let mut column_options = vec![options; schema.len()];
+        for (column, &idx) in self.column_options.iter().zip(&column_indexes) {
+            let dtype = &schema.get_at_index(idx).unwrap().1.dtype;
+            apply_column_options(column, dtype, &mut column_options[idx], &mut encodings[idx])?;
+        }
+        // The file writer only uses the statistics to decide whether to write column indexes at
+        // all; columns whose pages carry no statistics are skipped. So the file has to enable them
+        // as soon as any column writes statistics.
+        let mut file_options = options;
+        if let Some(column) = column_options.iter().find(|o| o.has_statistics()) {
+            file_options.statistics = column.statistics;
+        }
+        let mut writer = FileWriter::try_new(self.writer, schema, file_options)?;
+        if let Some(encryption) = self.encryption {
+            writer = writer.with_encryption(encryption)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
-            options,
+            column_options,
+            bloom_filters,
+            sorting_columns,
+            key_value_metadata,
             parallel: self.parallel,
         })
     }
@@ -130,6 +231,162 @@ where
     }
 }
 
+/// Returns the index in `schema` and the false positive probability of every bloom filter column.
+fn resolve_bloom_filters(
+    bloom_filters: &[ParquetBloomFilterOptions],
+    schema: &Schema,
+) -> PolarsResult<Vec<(usize, f64)>> {
+    bloom_filters
+        .iter()
+        .map(|options| {
+            let (idx, _, dtype) = schema.try_get_full(&options.column)?;
+            polars_ensure!(
+                options.fpp > 0.0 && options.fpp < 1.0,
+                InvalidOperation: "bloom filter false positive probability must be in the range (0, 1), got {}",
+                options.fpp
+            );
+            polars_ensure!(
+                bloom_filter::is_supported(dtype),
+                InvalidOperation: "bloom filters are not supported for column '{}' of type {}",
+                options.column, dtype
+            );
+            Ok((idx, options.fpp))
+        })
+        .collect()
+}
+
+/// Converts the custom file metadata to parquet key/value pairs.
+fn file_key_value_metadata(metadata: &Metadata) -> PolarsResult<Option<Vec<KeyValue>>> {
+    if metadata.is_empty() {
+        return Ok(None);
+    }
+    polars_ensure!(
+        !metadata.contains_key(ARROW_SCHEMA_META_KEY),
+        InvalidOperation: "the key '{}' is reserved for the arrow schema", ARROW_SCHEMA_META_KEY
+    );
+    Ok(Some(
+        metadata
+            .iter()
+            .map(|(key, value)| KeyValue {
+                key: key.to_string(),
+                value: Some(value.to_string()),
+            })
+            .collect(),
+    ))
+}
+
+/// Applies the overrides of `column` to the write options and leaf encodings of that column.
+fn apply_column_options(
+    column: &ParquetColumnWriteOptions,
+    dtype: &ArrowDataType,
+    options: &mut WriteOptions,
+    encodings: &mut [Encoding],
+) -> PolarsResult<()> {
+    if let Some(compression) = column.compression {
+        options.compression = compression.into();
+    }
+    if let Some(statistics) = column.statistics {
+        options.statistics = statistics;
+    }
+
+    let is_dictionary = matches!(dtype.to_physical_type(), PhysicalType::Dictionary(_));
+    polars_ensure!(
+        !is_dictionary || (column.encoding.is_none() && column.dictionary != Some(false)),
+        InvalidOperation: "categorical column '{}' is always dictionary encoded",
+        column.column
+    );
+    match (column.dictionary, column.encoding) {
+        (Some(true), Some(encoding)) => polars_bail!(
+            InvalidOperation: "cannot combine dictionary encoding with encoding {:?} for column '{}'",
+            encoding, column.column
+        ),
+        (_, Some(encoding)) => {
+            polars_ensure!(
+                is_encoding_supported(encoding, dtype),
+                InvalidOperation: "parquet encoding {:?} is not supported for column '{}' of type {:?}",
+                encoding, column.column, dtype
+            );
+            encodings.fill(encoding.into());
+        },
+        (Some(true), None) => {
+            let supported = transverse(dtype, supports_dictionary);
+            polars_ensure!(
+                supported.iter().any(|&supported| supported),
+                InvalidOperation: "dictionary encoding is not supported for column '{}' of type {:?}",
+                column.column, dtype
+            );
+            // Leaves of nested columns that can't be dictionary encoded keep their default.
+            for (encoding, supported) in encodings.iter_mut().zip(supported) {
+                if supported {
+                    *encoding = Encoding::RleDictionary;
+                }
+            }
+        },
+        (Some(false), None) => {
+            for encoding in encodings.iter_mut() {
+                if *encoding == Encoding::RleDictionary {
+                    *encoding = Encoding::Plain;
+                }
+            }
+        },
+        (None, None) => {},
+    }
+    Ok(())
+}
+
+/// Returns whether columns of type `dtype` can be written with `encoding`.
+fn is_encoding_supported(encoding: ParquetEncoding, dtype: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+
+    let is_integer = matches!(
+        dtype,
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Date32
+            | D::Date64
+            | D::Time32(_)
+            | D::Time64(_)
+            | D::Timestamp(_, _)
+            | D::Duration(_)
+    );
+    match encoding {
+        ParquetEncoding::Plain => true,
+        ParquetEncoding::Rle => matches!(dtype, D::Boolean),
+        ParquetEncoding::DeltaBinaryPacked => is_integer,
+        ParquetEncoding::DeltaLengthByteArray => matches!(
+            dtype,
+            D::LargeBinary | D::LargeUtf8 | D::BinaryView | D::Utf8View
+        ),
+        ParquetEncoding::ByteStreamSplit => is_integer || matches!(dtype, D::Float32 | D::Float64),
+    }
+}
+
+/// Returns whether leaves of type `dtype` can be dictionary encoded.
+fn supports_dictionary(dtype: &ArrowDataType) -> bool {
+    use arrow::types::PrimitiveType::*;
+
+    match dtype.to_physical_type() {
+        PhysicalType::Dictionary(_)
+        | PhysicalType::Binary
+        | PhysicalType::LargeBinary
+        | PhysicalType::Utf8
+        | PhysicalType::LargeUtf8
+        | PhysicalType::Utf8View
+        | PhysicalType::BinaryView => true,
+        PhysicalType::Primitive(dt) => matches!(
+            dt,
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+        ),
+        _ => false,
+    }
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .iter_values()
@@ -1,23 +1,35 @@
 //! Functionality for writing a DataFrame partitioned into multiple files.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::POOL;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::parquet::write::ParquetWriteOptions;
+#[cfg(feature = "csv")]
+use crate::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
-use crate::prelude::IpcWriterOptions;
+use crate::ipc::IpcWriterOptions;
+#[cfg(feature = "json")]
+use crate::json::JsonWriterOptions;
+#[cfg(feature = "parquet")]
+use crate::parquet::write::ParquetWriteOptions;
 use crate::prelude::URL_ENCODE_CHAR_SET;
 use crate::{SerWriter, WriteDataFrameToFile};
 
+#[cfg(feature = "parquet")]
 impl WriteDataFrameToFile for ParquetWriteOptions {
     fn write_df_to_file<W: std::io::Write>(&self, mut df: DataFrame, file: W) -> PolarsResult<()> {
         self.to_writer(file).finish(&mut df)?;
         Ok(())
     }
+
+    fn file_extension(&self) -> &'static str {
+        "parquet"
+    }
 }
 
 #[cfg(feature = "ipc")]
@@ -26,92 +38,293 @@ impl WriteDataFrameToFile for IpcWriterOptions {
         self.to_writer(file).finish(&mut df)?;
         Ok(())
     }
+
+    fn file_extension(&self) -> &'static str {
+        "ipc"
+    }
+}
+
+#[cfg(feature = "csv")]
+impl WriteDataFrameToFile for CsvWriterOptions {
+    fn write_df_to_file<W: std::io::Write>(&self, mut df: DataFrame, file: W) -> PolarsResult<()> {
+        self.to_writer(file).finish(&mut df)?;
+        Ok(())
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "csv"
+    }
+}
+
+#[cfg(feature = "json")]
+impl WriteDataFrameToFile for JsonWriterOptions {
+    fn write_df_to_file<W: std::io::Write>(&self, mut df: DataFrame, file: W) -> PolarsResult<()> {
+        self.to_writer(file).finish(&mut df)?;
+        Ok(())
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "jsonl"
+    }
+}
+
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Directory layout used to encode the partition keys of a dataset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartitionLayout {
+    /// One `key=value` directory per partition column.
+    #[default]
+    Hive,
+    /// One directory per partition column, named only after the value.
+    ValueOnly,
+}
+
+/// Behavior when the target directory of a partitioned write already contains data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartitionWriteMode {
+    /// Replace existing files that have the same name as a file that is written. Other files in
+    /// the partition directories are kept.
+    #[default]
+    Overwrite,
+    /// Remove every partition directory that is written to before writing, so that it only
+    /// contains the new files. Partitions that receive no data are left untouched.
+    ReplacePartitions,
+    /// Add new files next to the files that already exist in a partition directory.
+    Append,
+    /// Raise an error if the target directory exists and is not empty.
+    ErrorIfExists,
+}
+
+/// Options for writing a partitioned dataset.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartitionedWriteOptions {
+    /// Columns to partition by.
+    pub partition_by: Vec<PlSmallStr>,
+    /// Also write the partition columns into the files.
+    pub include_partition_columns: bool,
+    /// Approximate size in bytes of the files written for a partition.
+    pub chunk_size: usize,
+    /// Upper bound on the number of rows written to a single file.
+    pub max_rows_per_file: Option<usize>,
+    /// Template for the file names within a partition directory. `{part}` is replaced by the
+    /// zero-padded hexadecimal file index, `{i}` by the decimal file index and `{ext}` by the
+    /// extension of the file format.
+    pub filename_template: PlSmallStr,
+    pub layout: PartitionLayout,
+    pub mode: PartitionWriteMode,
+}
+
+impl PartitionedWriteOptions {
+    pub fn new<I, S>(partition_by: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        Self {
+            partition_by: partition_by.into_iter().map(Into::into).collect(),
+            include_partition_columns: true,
+            chunk_size: u32::MAX as usize,
+            max_rows_per_file: None,
+            filename_template: PlSmallStr::from_static("{part}.{ext}"),
+            layout: PartitionLayout::default(),
+            mode: PartitionWriteMode::default(),
+        }
+    }
+
+    /// Write the partition columns into the files. Defaults to `true`.
+    pub fn with_include_partition_columns(mut self, include: bool) -> Self {
+        self.include_partition_columns = include;
+        self
+    }
+
+    /// Set the approximate size in bytes of the files written for a partition.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Set the maximum number of rows written to a single file.
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
+
+    /// Set the template used for file names, see
+    /// [`filename_template`](PartitionedWriteOptions::filename_template).
+    pub fn with_filename_template(mut self, template: impl Into<PlSmallStr>) -> Self {
+        self.filename_template = template.into();
+        self
+    }
+
+    pub fn with_layout(mut self, layout: PartitionLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: PartitionWriteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn validate(&self, schema: &Schema) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.partition_by.is_empty(),
+            InvalidOperation: "partitioned write requires at least one column to partition by"
+        );
+        for name in &self.partition_by {
+            schema.try_get(name)?;
+        }
+        polars_ensure!(
+            self.include_partition_columns || schema.len() > self.partition_by.len(),
+            InvalidOperation: "cannot exclude the partition columns: no columns would be left to write"
+        );
+        polars_ensure!(
+            self.max_rows_per_file != Some(0),
+            InvalidOperation: "'max_rows_per_file' must be greater than 0"
+        );
+        let template = self.filename_template.as_str();
+        polars_ensure!(
+            template.contains("{part}") || template.contains("{i}"),
+            InvalidOperation: "filename template '{}' must contain '{{part}}' or '{{i}}'", template
+        );
+        polars_ensure!(
+            !template.contains(['/', '\\']),
+            InvalidOperation: "filename template '{}' must not contain a path separator", template
+        );
+        Ok(())
+    }
+
+    /// The name of the file with index `idx` within a partition directory.
+    pub fn file_name(&self, idx: usize, extension: &str) -> String {
+        self.filename_template
+            // Use a fixed-width file name so that it sorts properly.
+            .replace("{part}", &format!("{:08x}", idx))
+            .replace("{i}", &idx.to_string())
+            .replace("{ext}", extension)
+    }
+
+    /// The directory (relative to the dataset root) for the partition of the first row of `df`.
+    pub fn partition_path(&self, df: &DataFrame) -> PolarsResult<PathBuf> {
+        let mut path = PathBuf::new();
+        for name in &self.partition_by {
+            let s = df.column(name)?.slice(0, 1).cast(&DataType::String)?;
+            let value = s.str()?.get(0).unwrap_or(HIVE_DEFAULT_PARTITION);
+            let value = percent_encoding::percent_encode(value.as_bytes(), URL_ENCODE_CHAR_SET);
+            match self.layout {
+                PartitionLayout::Hive => path.push(format!("{}={}", name, value)),
+                PartitionLayout::ValueOnly => path.push(value.to_string()),
+            }
+        }
+        Ok(path)
+    }
+
+    /// Prepare the dataset root according to the write mode.
+    pub fn init_base_dir(&self, base_path: &Path) -> PolarsResult<()> {
+        if self.mode == PartitionWriteMode::ErrorIfExists
+            && base_path.exists()
+            && std::fs::read_dir(base_path)?.next().is_some()
+        {
+            polars_bail!(
+                ComputeError: "partitioned write target '{}' already exists and is not empty",
+                base_path.display()
+            );
+        }
+        std::fs::create_dir_all(base_path)?;
+        Ok(())
+    }
+
+    /// Create the directory `dir` of a single partition according to the write mode and return
+    /// the first file index that may be written to.
+    pub fn init_partition_dir(&self, dir: &Path, extension: &str) -> PolarsResult<usize> {
+        if self.mode == PartitionWriteMode::ReplacePartitions && dir.exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+        std::fs::create_dir_all(dir)?;
+
+        let mut idx = 0;
+        if self.mode == PartitionWriteMode::Append {
+            while dir.join(self.file_name(idx, extension)).exists() {
+                idx += 1;
+            }
+        }
+        Ok(idx)
+    }
+
+    /// Remove the partition columns from `df` if they should not be written to the files.
+    pub fn file_columns(&self, df: DataFrame) -> DataFrame {
+        if self.include_partition_columns {
+            df
+        } else {
+            df.drop_many(self.partition_by.iter().cloned())
+        }
+    }
+
+    /// Split `df` into one [`DataFrame`] per partition.
+    pub fn split(&self, df: &DataFrame) -> PolarsResult<Vec<DataFrame>> {
+        let groups = df
+            .group_by(self.partition_by.iter().cloned())?
+            .take_groups();
+        let out = match groups {
+            GroupsProxy::Idx(idx) => idx
+                .all()
+                .iter()
+                .map(|group| unsafe {
+                    df._take_unchecked_slice_sorted(group, true, IsSorted::Ascending)
+                })
+                .collect(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .iter()
+                .map(|&[offset, len]| df.slice(offset as i64, len as usize))
+                .collect(),
+        };
+        Ok(out)
+    }
 }
 
 fn write_partitioned_dataset_impl<W>(
     df: &mut DataFrame,
     path: &Path,
-    partition_by: Vec<PlSmallStr>,
+    options: &PartitionedWriteOptions,
     file_write_options: &W,
-    chunk_size: usize,
 ) -> PolarsResult<()>
 where
     W: WriteDataFrameToFile + Send + Sync,
 {
-    let partition_by = partition_by
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<PlSmallStr>>();
+    options.validate(&df.schema())?;
     // Ensure we have a single chunk as the gather will otherwise rechunk per group.
     df.as_single_chunk_par();
 
-    // Note: When adding support for formats other than Parquet, avoid writing the partitioned
-    // columns into the file. We write them for parquet because they are encoded efficiently with
-    // RLE and also gives us a way to get the hive schema from the parquet file for free.
-    let get_hive_path_part = {
-        let schema = &df.schema();
-
-        let partition_by_col_idx = partition_by
-            .iter()
-            .map(|x| {
-                let Some(i) = schema.index_of(x.as_str()) else {
-                    polars_bail!(col_not_found = x)
-                };
-                Ok(i)
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
-
-        move |df: &DataFrame| {
-            let cols = df.get_columns();
-
-            partition_by_col_idx
-                .iter()
-                .map(|&i| {
-                    let s = &cols[i].slice(0, 1).cast(&DataType::String).unwrap();
-
-                    format!(
-                        "{}={}",
-                        s.name(),
-                        percent_encoding::percent_encode(
-                            s.str()
-                                .unwrap()
-                                .get(0)
-                                .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-                                .as_bytes(),
-                            URL_ENCODE_CHAR_SET
-                        )
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("/")
-        }
-    };
-
     let base_path = path;
-    let groups = df.group_by(partition_by)?.take_groups();
+    options.init_base_dir(base_path)?;
+    let extension = file_write_options.file_extension();
+    let groups = df
+        .group_by(options.partition_by.iter().cloned())?
+        .take_groups();
 
     let init_part_base_dir = |part_df: &DataFrame| {
-        let path_part = get_hive_path_part(part_df);
-        let dir = base_path.join(path_part);
-        std::fs::create_dir_all(&dir)?;
+        let dir = base_path.join(options.partition_path(part_df)?);
+        let first_idx = options.init_partition_dir(&dir, extension)?;
 
-        PolarsResult::Ok(dir)
+        PolarsResult::Ok((dir, first_idx))
     };
 
-    fn get_path_for_index(i: usize) -> String {
-        // Use a fixed-width file name so that it sorts properly.
-        format!("{:08x}.parquet", i)
-    }
-
     let get_n_files_and_rows_per_file = |part_df: &DataFrame| {
-        let n_files = (part_df.estimated_size() / chunk_size).clamp(1, 0xffff_ffff);
-        let rows_per_file = (df.height() / n_files).saturating_add(1);
+        let n_files = (part_df.estimated_size() / options.chunk_size.max(1)).clamp(1, 0xffff_ffff);
+        let mut rows_per_file = (part_df.height() / n_files).saturating_add(1);
+        if let Some(max_rows) = options.max_rows_per_file {
+            rows_per_file = rows_per_file.min(max_rows);
+        }
+        let n_files = part_df.height().div_ceil(rows_per_file).max(1);
         (n_files, rows_per_file)
     };
 
     let write_part = |df: DataFrame, path: &Path| {
         let f = std::fs::File::create(path)?;
-        file_write_options.write_df_to_file(df, f)?;
+        file_write_options.write_df_to_file(options.file_columns(df), f)?;
         PolarsResult::Ok(())
     };
 
@@ -120,11 +333,12 @@ where
     const MAX_OPEN_FILES: usize = 8;
 
     let finish_part_df = |df: DataFrame| {
-        let dir_path = init_part_base_dir(&df)?;
+        let (dir_path, first_idx) = init_part_base_dir(&df)?;
         let (n_files, rows_per_file) = get_n_files_and_rows_per_file(&df);
+        let file_path = |idx: usize| dir_path.join(options.file_name(first_idx + idx, extension));
 
         if n_files == 1 {
-            write_part(df.clone(), &dir_path.join(get_path_for_index(0)))
+            write_part(df.clone(), &file_path(0))
         } else {
             (0..df.height())
                 .step_by(rows_per_file)
@@ -136,7 +350,7 @@ where
                         .into_par_iter()
                         .map(|&(idx, slice_start)| {
                             let df = df.slice(slice_start as i64, rows_per_file);
-                            write_part(df.clone(), &dir_path.join(get_path_for_index(idx)))
+                            write_part(df.clone(), &file_path(idx))
                         })
                         .reduce(
                             || PolarsResult::Ok(()),
@@ -187,7 +401,7 @@ where
     Ok(())
 }
 
-/// Write a partitioned parquet dataset. This functionality is unstable.
+/// Write a partitioned dataset. This functionality is unstable.
 pub fn write_partitioned_dataset<I, S, W>(
     df: &mut DataFrame,
     path: &Path,
@@ -200,9 +414,20 @@ where
     S: Into<PlSmallStr>,
     W: WriteDataFrameToFile + Send + Sync,
 {
-    let partition_by = partition_by
-        .into_iter()
-        .map(Into::into)
-        .collect::<Vec<PlSmallStr>>();
-    write_partitioned_dataset_impl(df, path, partition_by, file_write_options, chunk_size)
+    let options = PartitionedWriteOptions::new(partition_by).with_chunk_size(chunk_size);
+    write_partitioned_dataset_impl(df, path, &options, file_write_options)
+}
+
+/// Write a partitioned dataset with the given [`PartitionedWriteOptions`]. This functionality is
+/// unstable.
+pub fn write_partitioned_dataset_with_options<W>(
+    df: &mut DataFrame,
+    path: &Path,
+    options: &PartitionedWriteOptions,
+    file_write_options: &W,
+) -> PolarsResult<()>
+where
+    W: WriteDataFrameToFile + Send + Sync,
+{
+    write_partitioned_dataset_impl(df, path, options, file_write_options)
 }
@@ -48,6 +48,7 @@ pub fn apply_predicate(
 /// - Null count
 /// - Minimum value
 /// - Maximum value
+/// - Bloom filter, if the Parquet file has one for the column
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
@@ -56,6 +57,9 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bloom_filter: Option<Arc<crate::parquet::bloom_filter::BloomFilter>>,
 }
 
 impl ColumnStats {
@@ -71,6 +75,8 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
@@ -81,6 +87,8 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
@@ -92,9 +100,21 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
+    /// Attaches the bloom filter of the column.
+    #[cfg(feature = "parquet")]
+    pub fn with_bloom_filter(
+        mut self,
+        bloom_filter: Option<Arc<crate::parquet::bloom_filter::BloomFilter>>,
+    ) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
     pub fn field_name(&self) -> &PlSmallStr {
         self.field.name()
     }
@@ -119,6 +139,20 @@ impl ColumnStats {
         self.max_value.as_ref()
     }
 
+    /// Returns whether the column may contain any of `values`, according to its bloom filter.
+    ///
+    /// Returns `true` if the column has no bloom filter or if `values` contains nulls.
+    #[cfg(feature = "parquet")]
+    pub fn may_contain_any(&self, values: &Series) -> bool {
+        match &self.bloom_filter {
+            Some(bloom_filter) if !values.has_nulls() => values
+                .strict_cast(self.dtype())
+                .and_then(|values| bloom_filter.may_contain_any(&values))
+                .unwrap_or(true),
+            _ => true,
+        }
+    }
+
     /// Returns the null count of the column.
     pub fn null_count(&self) -> Option<usize> {
         match self.dtype() {
@@ -9,8 +9,16 @@ pub use crate::json::*;
 pub use crate::ndjson::core::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
-#[cfg(feature = "parquet")]
-pub use crate::partition::write_partitioned_dataset;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+pub use crate::partition::{
+    write_partitioned_dataset, write_partitioned_dataset_with_options, PartitionLayout,
+    PartitionWriteMode, PartitionedWriteOptions,
+};
 pub use crate::path_utils::*;
 pub use crate::shared::{SerReader, SerWriter};
 pub use crate::utils::*;
@@ -42,6 +42,9 @@ where
 
 pub trait WriteDataFrameToFile {
     fn write_df_to_file<W: std::io::Write>(&self, df: DataFrame, file: W) -> PolarsResult<()>;
+
+    /// Extension of the files written, without the leading dot.
+    fn file_extension(&self) -> &'static str;
 }
 
 pub trait ArrowReader {
@@ -103,7 +106,11 @@ pub(crate) fn finish_reader<R: ArrowReader>(
                         .map(Column::from)
                 })
                 .collect::<PolarsResult<_>>()?;
-            DataFrame::new(empty_cols)?
+            let mut df = DataFrame::new(empty_cols)?;
+            if let Some(rc) = &row_index {
+                df.with_row_index_mut(rc.name.clone(), Some(rc.offset));
+            }
+            df
         } else {
             // If there are any rows, accumulate them into a df
             accumulate_dataframes_vertical_unchecked(parsed_dfs)
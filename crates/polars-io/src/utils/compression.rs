@@ -64,3 +64,278 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Size of the blocks used when only the head of a compressed file is needed.
+const HEAD_BLOCK_SIZE: usize = 1 << 20;
+
+/// Incrementally decompresses a compressed byte stream.
+///
+/// Decompressed data is handed out in blocks that always end on a line boundary, so that
+/// line-based formats (CSV, NDJSON) can be parsed block by block without materializing the
+/// whole decompressed file in memory.
+pub struct ChunkedDecompressor<'a> {
+    decoder: Box<dyn Read + Send + Sync + 'a>,
+    /// Decompressed bytes following the last line end of the previous block.
+    remainder: Vec<u8>,
+    block_size: usize,
+    is_exhausted: bool,
+}
+
+impl<'a> ChunkedDecompressor<'a> {
+    /// Create a decompressor over `bytes` that reads roughly `block_size` decompressed bytes per
+    /// block. Returns `None` if `bytes` is not compressed with a supported algorithm.
+    pub fn try_new<B>(bytes: B, block_size: usize) -> PolarsResult<Option<Self>>
+    where
+        B: AsRef<[u8]> + Send + Sync + 'a,
+    {
+        let Some(algo) = SupportedCompression::check(bytes.as_ref()) else {
+            return Ok(None);
+        };
+
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        {
+            let reader = std::io::Cursor::new(bytes);
+            let decoder: Box<dyn Read + Send + Sync + 'a> = match algo {
+                SupportedCompression::GZIP => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+                SupportedCompression::ZLIB => Box::new(flate2::read::ZlibDecoder::new(reader)),
+                SupportedCompression::ZSTD => Box::new(zstd::Decoder::new(reader)?),
+            };
+
+            Ok(Some(Self {
+                decoder,
+                remainder: vec![],
+                block_size: block_size.max(1),
+                is_exhausted: false,
+            }))
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        {
+            let _ = (algo, block_size);
+            polars_bail!(
+                ComputeError: "cannot decompress without 'decompress' or 'decompress-fast' feature"
+            )
+        }
+    }
+
+    /// Decompress the next block.
+    ///
+    /// `find_last_line_end` must return the offset just past the last complete line in the given
+    /// bytes, or `None` if they don't contain a complete line yet. The bytes after that offset are
+    /// carried over to the next block. Once the stream is exhausted, whatever is left is returned
+    /// as the final block.
+    pub fn next_block(
+        &mut self,
+        find_last_line_end: impl Fn(&[u8]) -> Option<usize>,
+    ) -> PolarsResult<Option<Vec<u8>>> {
+        let mut block = std::mem::take(&mut self.remainder);
+
+        while !self.is_exhausted {
+            let n_read = (&mut *self.decoder)
+                .take(self.block_size as u64)
+                .read_to_end(&mut block)?;
+
+            if n_read == 0 {
+                self.is_exhausted = true;
+                break;
+            }
+
+            if let Some(end) = find_last_line_end(&block) {
+                self.remainder = block[end..].to_vec();
+                block.truncate(end);
+                return Ok(Some(block));
+            }
+        }
+
+        Ok((!block.is_empty()).then_some(block))
+    }
+}
+
+/// Like [`maybe_decompress_bytes`], but stops decompressing once at least `n_lines` lines are
+/// available in `out`. This is useful when only the head of a file is needed, e.g. for schema
+/// inference.
+///
+/// `count_lines` must return the number of complete lines in the given bytes together with the
+/// offset just past the last one.
+pub fn maybe_decompress_bytes_head<'a>(
+    bytes: &'a [u8],
+    out: &'a mut Vec<u8>,
+    n_lines: usize,
+    count_lines: impl Fn(&[u8]) -> (usize, usize),
+) -> PolarsResult<&'a [u8]> {
+    assert!(out.is_empty());
+
+    let Some(mut decompressor) = ChunkedDecompressor::try_new(bytes, HEAD_BLOCK_SIZE)? else {
+        return Ok(bytes);
+    };
+
+    let mut n_lines_read = 0;
+    while n_lines_read < n_lines {
+        let Some(block) = decompressor.next_block(|bytes| {
+            let (count, end) = count_lines(bytes);
+            (count > 0).then_some(end)
+        })?
+        else {
+            break;
+        };
+
+        n_lines_read += count_lines(&block).0;
+        out.extend_from_slice(&block);
+    }
+
+    Ok(out)
+}
+
+/// Returns the number of lines in `bytes` and the offset just past the last `eol_char`.
+///
+/// Unlike CSV, the lines of line-delimited formats such as NDJSON can't contain an embedded
+/// `eol_char`, so a plain byte search suffices.
+pub fn count_eol(bytes: &[u8], eol_char: u8) -> (usize, usize) {
+    match memchr::memrchr(eol_char, bytes) {
+        Some(position) => (memchr::memchr_iter(eol_char, bytes).count(), position + 1),
+        None => (0, 0),
+    }
+}
+
+#[cfg(all(test, any(feature = "decompress", feature = "decompress-fast")))]
+mod tests {
+    use super::*;
+
+    fn lines(n: usize) -> Vec<u8> {
+        (0..n)
+            .flat_map(|i| format!("{i},value_{i}\n").into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_chunked_decompressor_blocks_end_on_lines() {
+        let data = lines(1000);
+        let compressed = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        let mut decompressor = ChunkedDecompressor::try_new(compressed.as_slice(), 100)
+            .unwrap()
+            .unwrap();
+
+        let mut out = vec![];
+        let mut n_blocks = 0;
+        while let Some(block) = decompressor
+            .next_block(|bytes| {
+                let (count, end) = count_eol(bytes, b'\n');
+                (count > 0).then_some(end)
+            })
+            .unwrap()
+        {
+            assert_eq!(block.last(), Some(&b'\n'));
+            out.extend_from_slice(&block);
+            n_blocks += 1;
+        }
+
+        assert!(n_blocks > 1);
+        assert_eq!(out, data);
+    }
+
+    #[test]
+    fn test_chunked_decompressor_uncompressed() {
+        let data = lines(10);
+        assert!(ChunkedDecompressor::try_new(data.as_slice(), 100)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_decompress_bytes_head() {
+        let data = lines(200_000);
+        let compressed = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        let mut out = vec![];
+        let head = maybe_decompress_bytes_head(&compressed, &mut out, 10, |b| count_eol(b, b'\n'))
+            .unwrap();
+
+        assert!(count_eol(head, b'\n').0 >= 10);
+        assert!(head.len() < data.len());
+        assert!(data.starts_with(head));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_batched_csv_reader_compressed() {
+        use std::io::Cursor;
+
+        use crate::csv::read::CsvReadOptions;
+        use crate::SerReader;
+
+        // Large enough to span multiple decompressed blocks when reading with a single thread.
+        let data = [b"a,b\n".as_slice(), &lines(500_000)].concat();
+        let compressed = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        let expected = CsvReadOptions::default()
+            .into_reader_with_file_handle(Cursor::new(data))
+            .finish()
+            .unwrap();
+
+        let mut reader = CsvReadOptions::default()
+            .with_n_threads(Some(1))
+            .into_reader_with_file_handle(Cursor::new(compressed));
+        let mut batched = reader.batched_borrowed().unwrap();
+        let mut out = DataFrame::empty_with_schema(&expected.schema());
+        while let Some(batches) = batched.next_batches(4).unwrap() {
+            for df in batches {
+                out.vstack_mut(&df).unwrap();
+            }
+        }
+
+        assert!(out.equals(&expected));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_reader_finish_compressed() {
+        use std::io::Cursor;
+
+        use crate::csv::read::CsvReadOptions;
+        use crate::SerReader;
+
+        let data = [b"a,b\n".as_slice(), &lines(500_000)].concat();
+        let compressed = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        let read = |bytes: Vec<u8>| {
+            CsvReadOptions::default()
+                .with_columns(Some(["b".into()].into()))
+                .into_reader_with_file_handle(Cursor::new(bytes))
+                .finish()
+                .unwrap()
+        };
+        assert!(read(compressed).equals(&read(data)));
+
+        let empty = zstd::encode_all(b"a,b\n".as_slice(), 0).unwrap();
+        assert_eq!(read(empty).shape(), (0, 1));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_count_rows_compressed() {
+        use crate::csv::read::count_rows_from_slice;
+
+        // Large enough to span multiple decompressed blocks.
+        let data = [b"a,b\n".as_slice(), &lines(1_500_000)].concat();
+        let compressed = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        let count =
+            |bytes: &[u8]| count_rows_from_slice(bytes, b',', Some(b'"'), None, b'\n', true);
+        assert_eq!(count(&data).unwrap(), 1_500_000);
+        assert_eq!(count(&compressed).unwrap(), 1_500_000);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_count_rows_ndjson_compressed() {
+        use crate::ndjson::core::count_rows;
+
+        let data = (0..1_500_000)
+            .flat_map(|i| format!("{{\"a\":{i}}}\n").into_bytes())
+            .collect::<Vec<_>>();
+        let compressed = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        assert_eq!(count_rows(&data).unwrap(), 1_500_000);
+        assert_eq!(count_rows(&compressed).unwrap(), 1_500_000);
+    }
+}
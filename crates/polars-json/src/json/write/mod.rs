@@ -1,19 +1,85 @@
 //! APIs to write to JSON
+mod pretty;
 mod serialize;
 mod utf8;
 
+use std::fmt::Write as _;
 use std::io::Write;
 
 use arrow::array::Array;
-use arrow::datatypes::ArrowSchema;
+use arrow::datatypes::{ArrowDataType, ArrowSchema};
 use arrow::io::iterator::StreamingIterator;
 use arrow::record_batch::RecordBatchT;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 pub use fallible_streaming_iterator::*;
-use polars_error::{PolarsError, PolarsResult};
+use polars_error::{polars_bail, PolarsError, PolarsResult};
+pub use pretty::PrettyWriter;
 pub(crate) use serialize::new_serializer;
 use serialize::serialize;
 pub use utf8::serialize_to_utf8;
 
+/// Options to serialize logical types and nulls to JSON.
+///
+/// The default is to format times and dates as `chrono` crate formats them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SerializeOptions {
+    /// Used for [`ArrowDataType::Date32`].
+    pub date_format: Option<String>,
+    /// Used for [`ArrowDataType::Time64`].
+    pub time_format: Option<String>,
+    /// Used for [`ArrowDataType::Timestamp`].
+    pub datetime_format: Option<String>,
+    /// Number of decimals written for floats.
+    pub float_precision: Option<usize>,
+    /// Leave out the keys of null values in JSON objects instead of writing `null`.
+    pub omit_null_fields: bool,
+}
+
+impl SerializeOptions {
+    /// Check that the format strings can format all the temporal values in `dtype`.
+    pub fn validate(&self, dtype: &ArrowDataType) -> PolarsResult<()> {
+        // Formatting a sample value fails if the format string is invalid or refers to a field
+        // that the type does not have, e.g. a time zone for naive datetimes.
+        fn check(
+            format: Option<&String>,
+            sample: impl Fn(&mut String, &str) -> std::fmt::Result,
+        ) -> PolarsResult<()> {
+            if let Some(format) = format {
+                if sample(&mut String::new(), format).is_err() {
+                    polars_bail!(
+                        ComputeError: "invalid format string for JSON serialization: '{}'", format
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        match dtype.to_logical_type() {
+            ArrowDataType::Date32 => check(self.date_format.as_ref(), |out, f| {
+                write!(out, "{}", NaiveDate::default().format(f))
+            }),
+            ArrowDataType::Time64(_) => check(self.time_format.as_ref(), |out, f| {
+                write!(out, "{}", NaiveTime::default().format(f))
+            }),
+            ArrowDataType::Timestamp(_, None) => check(self.datetime_format.as_ref(), |out, f| {
+                write!(out, "{}", NaiveDateTime::default().format(f))
+            }),
+            ArrowDataType::Timestamp(_, Some(_)) => {
+                check(self.datetime_format.as_ref(), |out, f| {
+                    write!(out, "{}", DateTime::<FixedOffset>::default().format(f))
+                })
+            },
+            ArrowDataType::Struct(fields) => fields
+                .iter()
+                .try_for_each(|field| self.validate(&field.dtype)),
+            ArrowDataType::LargeList(field) | ArrowDataType::FixedSizeList(field, _) => {
+                self.validate(&field.dtype)
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
 /// Advancing this iterator CPU-bounded
@@ -25,6 +91,7 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: SerializeOptions,
 }
 
 impl<A, I> Serializer<A, I>
@@ -34,7 +101,17 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: Default::default(),
+        }
+    }
+
+    /// Set the [`SerializeOptions`] used for the values.
+    pub fn with_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
@@ -51,7 +128,9 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| serialize(array.as_ref(), &self.options, &mut self.buffer))
+            })
             .transpose()?;
         Ok(())
     }
@@ -88,7 +167,7 @@ impl<'a> RecordSerializer<'a> {
         let iterators = chunk
             .arrays()
             .iter()
-            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX))
+            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX, &Default::default()))
             .collect();
 
         Self {
@@ -156,3 +235,40 @@ where
     writer.write_all(b"]")?;
     Ok(())
 }
+
+/// Writes valid JSON in a columnar orientation, `{"a":[1,2],"b":["x","y"]}`, to `writer`.
+///
+/// Every column is given by its name and its chunks.
+pub fn write_columns<'a, W, A, I>(
+    writer: &mut W,
+    columns: I,
+    options: &SerializeOptions,
+) -> PolarsResult<()>
+where
+    W: std::io::Write,
+    A: AsRef<dyn Array>,
+    I: Iterator<Item = (&'a str, Vec<A>)>,
+{
+    let mut buffer = vec![];
+    writer.write_all(b"{")?;
+    for (i, (name, arrays)) in columns.enumerate() {
+        buffer.clear();
+        if i > 0 {
+            buffer.push(b',');
+        }
+        utf8::write_str(&mut buffer, name)?;
+        buffer.extend_from_slice(b":[");
+        let mut is_first_chunk = true;
+        for array in arrays.iter().filter(|array| !array.as_ref().is_empty()) {
+            if !is_first_chunk {
+                buffer.push(b',');
+            }
+            is_first_chunk = false;
+            serialize(array.as_ref(), options, &mut buffer);
+        }
+        buffer.push(b']');
+        writer.write_all(&buffer)?;
+    }
+    writer.write_all(b"}")?;
+    Ok(())
+}
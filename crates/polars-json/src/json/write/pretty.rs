@@ -0,0 +1,99 @@
+use std::io::{Result, Write};
+
+const INDENT: &[u8] = b"  ";
+
+/// A [`Write`] adapter that re-indents the compact JSON written to it.
+///
+/// The JSON may be written in arbitrary pieces, the state of the tokenizer is kept between writes.
+/// Empty objects and arrays are kept on a single line.
+pub struct PrettyWriter<W: Write> {
+    writer: W,
+    buffer: Vec<u8>,
+    depth: usize,
+    in_string: bool,
+    is_escaped: bool,
+    /// An object or array was opened and we don't know yet whether it is empty.
+    is_open_pending: bool,
+}
+
+impl<W: Write> PrettyWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: vec![],
+            depth: 0,
+            in_string: false,
+            is_escaped: false,
+            is_open_pending: false,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn newline(&mut self) {
+        self.buffer.push(b'\n');
+        for _ in 0..self.depth {
+            self.buffer.extend_from_slice(INDENT);
+        }
+    }
+}
+
+impl<W: Write> Write for PrettyWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.buffer.clear();
+        for &byte in buf {
+            if self.in_string {
+                self.buffer.push(byte);
+                if self.is_escaped {
+                    self.is_escaped = false;
+                } else if byte == b'\\' {
+                    self.is_escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+
+            if self.is_open_pending {
+                self.is_open_pending = false;
+                if matches!(byte, b'}' | b']') {
+                    self.depth -= 1;
+                    self.buffer.push(byte);
+                    continue;
+                }
+                self.newline();
+            }
+
+            match byte {
+                b'"' => {
+                    self.in_string = true;
+                    self.buffer.push(byte);
+                },
+                b'{' | b'[' => {
+                    self.buffer.push(byte);
+                    self.depth += 1;
+                    self.is_open_pending = true;
+                },
+                b'}' | b']' => {
+                    self.depth -= 1;
+                    self.newline();
+                    self.buffer.push(byte);
+                },
+                b',' => {
+                    self.buffer.push(byte);
+                    self.newline();
+                },
+                b':' => self.buffer.extend_from_slice(b": "),
+                _ => self.buffer.push(byte),
+            }
+        }
+        self.writer.write_all(&self.buffer)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
@@ -11,14 +11,15 @@ use arrow::offset::Offset;
 use arrow::temporal_conversions::parse_offset_tz;
 use arrow::temporal_conversions::{
     date32_to_date, duration_ms_to_duration, duration_ns_to_duration, duration_s_to_duration,
-    duration_us_to_duration, parse_offset, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
-    timestamp_s_to_datetime, timestamp_to_datetime, timestamp_us_to_datetime,
+    duration_us_to_duration, parse_offset, time64ns_to_time, time64us_to_time,
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_s_to_datetime,
+    timestamp_to_datetime, timestamp_us_to_datetime,
 };
 use arrow::types::NativeType;
-use chrono::{Duration, NaiveDate, NaiveDateTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use streaming_iterator::StreamingIterator;
 
-use super::utf8;
+use super::{utf8, SerializeOptions};
 
 fn write_integer<I: itoa::Integer>(buf: &mut Vec<u8>, val: I) {
     let mut buffer = itoa::Buffer::new();
@@ -32,6 +33,11 @@ fn write_float<I: ryu::Float>(f: &mut Vec<u8>, val: I) {
     f.extend_from_slice(value.as_bytes())
 }
 
+/// Writes a value formatted with a user-given format string as a JSON string.
+fn write_formatted(buf: &mut Vec<u8>, value: impl std::fmt::Display) {
+    utf8::write_str(buf, &value.to_string()).unwrap()
+}
+
 fn materialize_serializer<'a, I, F, T>(
     f: F,
     iterator: I,
@@ -93,16 +99,19 @@ fn primitive_serializer<'a, T: NativeType + itoa::Integer>(
 
 fn float_serializer<'a, T>(
     array: &'a PrimitiveArray<T>,
+    precision: Option<usize>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
-    T: num_traits::Float + NativeType + ryu::Float,
+    T: num_traits::Float + NativeType + ryu::Float + std::fmt::Display,
 {
-    let f = |x: Option<&T>, buf: &mut Vec<u8>| {
+    let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             if T::is_nan(*x) || T::is_infinite(*x) {
                 buf.extend(b"null")
+            } else if let Some(precision) = precision {
+                write!(buf, "{x:.precision$}").unwrap()
             } else {
                 write_float(buf, *x)
             }
@@ -183,6 +192,7 @@ fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
     take: usize,
+    options: &SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -195,8 +205,9 @@ fn struct_serializer<'a>(
         .values()
         .iter()
         .map(|x| x.as_ref())
-        .map(|arr| new_serializer(arr, offset, take))
+        .map(|arr| new_serializer(arr, offset, take, options))
         .collect::<Vec<_>>();
+    let omit_null_fields = options.omit_null_fields;
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new_with_validity(0..array.len(), array.validity()),
@@ -205,11 +216,13 @@ fn struct_serializer<'a>(
                 let names = array.fields().iter().map(|f| f.name.as_str());
                 serialize_item(
                     buf,
-                    names.zip(
-                        serializers
-                            .iter_mut()
-                            .map(|serializer| serializer.next().unwrap()),
-                    ),
+                    names
+                        .zip(
+                            serializers
+                                .iter_mut()
+                                .map(|serializer| serializer.next().unwrap()),
+                        )
+                        .filter(|(_, value)| !(omit_null_fields && *value == b"null")),
                     true,
                 );
             } else {
@@ -227,6 +240,7 @@ fn list_serializer<'a, O: Offset>(
     array: &'a ListArray<O>,
     offset: usize,
     take: usize,
+    options: &SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // [[1, 2], [3]]
     // [
@@ -237,7 +251,7 @@ fn list_serializer<'a, O: Offset>(
     let offsets = array.offsets().as_slice();
     let start = offsets[0].to_usize();
     let end = offsets.last().unwrap().to_usize();
-    let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
+    let mut serializer = new_serializer(array.values().as_ref(), start, end - start, options);
 
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
         if let Some(offset) = offset {
@@ -266,8 +280,9 @@ fn fixed_size_list_serializer<'a>(
     array: &'a FixedSizeListArray,
     offset: usize,
     take: usize,
+    options: &SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let mut serializer = new_serializer(array.values().as_ref(), offset, take);
+    let mut serializer = new_serializer(array.values().as_ref(), offset, take, options);
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter())),
@@ -295,6 +310,7 @@ fn fixed_size_list_serializer<'a>(
 fn date_serializer<'a, T, F>(
     array: &'a PrimitiveArray<T>,
     convert: F,
+    format: Option<String>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
@@ -305,7 +321,35 @@ where
     let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let nd = convert(*x);
-            write!(buf, "\"{nd}\"").unwrap();
+            match &format {
+                Some(format) => write_formatted(buf, nd.format(format)),
+                None => write!(buf, "\"{nd}\"").unwrap(),
+            }
+        } else {
+            buf.extend_from_slice(b"null")
+        }
+    };
+
+    materialize_serializer(f, array.iter(), offset, take)
+}
+
+fn time_serializer<'a, F>(
+    array: &'a PrimitiveArray<i64>,
+    convert: F,
+    format: Option<String>,
+    offset: usize,
+    take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
+where
+    F: Fn(i64) -> NaiveTime + 'static + Send + Sync,
+{
+    let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
+        if let Some(x) = x {
+            let nt = convert(*x);
+            match &format {
+                Some(format) => write_formatted(buf, nt.format(format)),
+                None => write!(buf, "\"{nt}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -339,6 +383,7 @@ where
 fn timestamp_serializer<'a, F>(
     array: &'a PrimitiveArray<i64>,
     convert: F,
+    format: Option<String>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
@@ -348,7 +393,10 @@ where
     let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let ndt = convert(*x);
-            write!(buf, "\"{ndt}\"").unwrap();
+            match &format {
+                Some(format) => write_formatted(buf, ndt.format(format)),
+                None => write!(buf, "\"{ndt}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -360,6 +408,7 @@ fn timestamp_tz_serializer<'a>(
     array: &'a PrimitiveArray<i64>,
     time_unit: TimeUnit,
     tz: &str,
+    format: Option<String>,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
@@ -367,8 +416,11 @@ fn timestamp_tz_serializer<'a>(
         Ok(parsed_tz) => {
             let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
                 if let Some(x) = x {
-                    let dt_str = timestamp_to_datetime(*x, time_unit, &parsed_tz).to_rfc3339();
-                    write!(buf, "\"{dt_str}\"").unwrap();
+                    let dt = timestamp_to_datetime(*x, time_unit, &parsed_tz);
+                    match &format {
+                        Some(format) => write_formatted(buf, dt.format(format)),
+                        None => write!(buf, "\"{}\"", dt.to_rfc3339()).unwrap(),
+                    }
                 } else {
                     buf.extend_from_slice(b"null")
                 }
@@ -381,8 +433,11 @@ fn timestamp_tz_serializer<'a>(
             Ok(parsed_tz) => {
                 let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
                     if let Some(x) = x {
-                        let dt_str = timestamp_to_datetime(*x, time_unit, &parsed_tz).to_rfc3339();
-                        write!(buf, "\"{dt_str}\"").unwrap();
+                        let dt = timestamp_to_datetime(*x, time_unit, &parsed_tz);
+                        match &format {
+                            Some(format) => write_formatted(buf, dt.format(format)),
+                            None => write!(buf, "\"{}\"", dt.to_rfc3339()).unwrap(),
+                        }
                     } else {
                         buf.extend_from_slice(b"null")
                     }
@@ -405,6 +460,7 @@ pub(crate) fn new_serializer<'a>(
     array: &'a dyn Array,
     offset: usize,
     take: usize,
+    options: &SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     match array.dtype().to_logical_type() {
         ArrowDataType::Boolean => {
@@ -434,12 +490,18 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::UInt64 => {
             primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Float32 => {
-            float_serializer::<f32>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::Float64 => {
-            float_serializer::<f64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Float32 => float_serializer::<f32>(
+            array.as_any().downcast_ref().unwrap(),
+            options.float_precision,
+            offset,
+            take,
+        ),
+        ArrowDataType::Float64 => float_serializer::<f64>(
+            array.as_any().downcast_ref().unwrap(),
+            options.float_precision,
+            offset,
+            take,
+        ),
         #[cfg(feature = "dtype-decimal")]
         ArrowDataType::Decimal(_, scale) => {
             decimal_serializer(array.as_any().downcast_ref().unwrap(), *scale, offset, take)
@@ -450,15 +512,24 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Struct(_) => {
-            struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::FixedSizeList(_, _) => {
-            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::LargeList(_) => {
-            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Struct(_) => struct_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::FixedSizeList(_, _) => fixed_size_list_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::LargeList(_) => list_serializer::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         ArrowDataType::Dictionary(k, v, _) => match (k, &**v) {
             (IntegerType::UInt32, ArrowDataType::Utf8View) => {
                 let array = array
@@ -475,9 +546,24 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Date32 => date_serializer(
             array.as_any().downcast_ref().unwrap(),
             date32_to_date,
+            options.date_format.clone(),
             offset,
             take,
         ),
+        ArrowDataType::Time64(tu) => {
+            let convert = match tu {
+                TimeUnit::Nanosecond => time64ns_to_time,
+                TimeUnit::Microsecond => time64us_to_time,
+                _ => unreachable!(),
+            };
+            time_serializer(
+                array.as_any().downcast_ref().unwrap(),
+                convert,
+                options.time_format.clone(),
+                offset,
+                take,
+            )
+        },
         ArrowDataType::Timestamp(tu, None) => {
             let convert = match tu {
                 TimeUnit::Nanosecond => timestamp_ns_to_datetime,
@@ -488,6 +574,7 @@ pub(crate) fn new_serializer<'a>(
             timestamp_serializer(
                 array.as_any().downcast_ref().unwrap(),
                 convert,
+                options.datetime_format.clone(),
                 offset,
                 take,
             )
@@ -496,6 +583,7 @@ pub(crate) fn new_serializer<'a>(
             array.as_any().downcast_ref().unwrap(),
             *time_unit,
             tz,
+            options.datetime_format.clone(),
             offset,
             take,
         ),
@@ -543,8 +631,8 @@ fn serialize_item<'a>(
 /// Serializes `array` to a valid JSON to `buffer`
 /// # Implementation
 /// This operation is CPU-bounded
-pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+pub(crate) fn serialize(array: &dyn Array, options: &SerializeOptions, buffer: &mut Vec<u8>) {
+    let mut serializer = new_serializer(array, 0, usize::MAX, options);
 
     (0..array.len()).for_each(|i| {
         if i != 0 {
@@ -143,7 +143,7 @@ where
 
 pub fn serialize_to_utf8(array: &dyn Array) -> Utf8ViewArray {
     let mut values = MutableBinaryViewArray::with_capacity(array.len());
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+    let mut serializer = new_serializer(array, 0, usize::MAX, &Default::default());
 
     while let Some(v) = serializer.next() {
         unsafe { values.push_value(std::str::from_utf8_unchecked(v)) }
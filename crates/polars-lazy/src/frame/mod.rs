@@ -26,6 +26,8 @@ pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "ipc_streaming")]
+pub use ipc_stream::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
@@ -861,6 +863,34 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into a dataset of files partitioned on the values of
+    /// [`PartitionedWriteOptions::partition_by`], with one directory per partition. This methods
+    /// will return an error if the query cannot be completely done in a streaming fashion.
+    ///
+    /// Every partition keeps a file open until it is finished, so the number of open files
+    /// grows with the number of distinct partition keys.
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv",
+        feature = "json"
+    ))]
+    pub fn sink_partitioned(
+        self,
+        path: impl AsRef<Path>,
+        file_type: FileType,
+        options: PartitionedWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partitioned {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type,
+                options,
+            },
+            "collect()` and `write_partitioned_dataset_with_options()",
+        )
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -924,11 +954,8 @@ impl LazyFrame {
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
         #[cfg(feature = "new_streaming")]
         {
-            if self
-                .try_new_streaming_if_requested(payload.clone())
-                .is_some()
-            {
-                return Ok(());
+            if let Some(result) = self.try_new_streaming_if_requested(payload.clone()) {
+                return result.map(|_| ());
             }
         }
 
@@ -6,7 +6,18 @@ pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
-pub use polars_io::parquet::write::ParquetWriteOptions;
+pub use polars_io::parquet::write::{
+    ParquetBloomFilterOptions, ParquetColumnWriteOptions, ParquetEncoding, ParquetWriteOptions,
+};
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+pub use polars_io::partition::{PartitionLayout, PartitionWriteMode, PartitionedWriteOptions};
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub use polars_io::KeyValueMetadata;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
@@ -16,8 +27,8 @@ pub use polars_plan::plans::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+pub use polars_plan::prelude::{FileType, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -4,6 +4,8 @@ pub(super) mod csv;
 pub(super) mod file_list_reader;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
+#[cfg(feature = "ipc_streaming")]
+pub(super) mod ipc_stream;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
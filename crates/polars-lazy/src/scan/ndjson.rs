@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::json::JsonConflictResolution;
 use polars_io::{HiveOptions, RowIndex};
 use polars_plan::plans::{DslPlan, FileScan, ScanSources};
 use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
@@ -25,6 +26,7 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) conflict_resolution: JsonConflictResolution,
 }
 
 impl LazyJsonLineReader {
@@ -46,6 +48,7 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            conflict_resolution: Default::default(),
         }
     }
 
@@ -82,6 +85,12 @@ impl LazyJsonLineReader {
         self.infer_schema_length = num_rows;
         self
     }
+    /// Set how fields whose values have conflicting types are resolved during schema inference.
+    #[must_use]
+    pub fn with_conflict_resolution(mut self, resolution: JsonConflictResolution) -> Self {
+        self.conflict_resolution = resolution;
+        self
+    }
     /// Set the JSON file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -134,6 +143,7 @@ impl LazyFileListReader for LazyJsonLineReader {
                 hive_start_idx: 0,
                 schema: None,
                 try_parse_dates: true,
+                partition_scheme: None,
             },
             glob: true,
             include_file_paths: self.include_file_paths,
@@ -148,6 +158,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            conflict_resolution: self.conflict_resolution,
         };
 
         let scan_type = FileScan::NDJson {
@@ -576,3 +576,31 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "dtype-duration"))]
+fn test_streaming_group_by_unsupported_reduction() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2],
+        "d" => [1i64, 2, 3],
+    ]?
+    .lazy()
+    .with_column(col("d").cast(DataType::Duration(TimeUnit::Milliseconds)));
+
+    for agg in [col("d").var(1), col("d").std(1)] {
+        let err = df
+            .clone()
+            .group_by([col("g")])
+            .agg([agg])
+            .with_new_streaming(true)
+            .collect()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::InvalidOperation(_)), "{err}");
+        assert!(
+            err.to_string()
+                .contains("operation not supported for dtype `duration[ms]`"),
+            "{err}"
+        );
+    }
+    Ok(())
+}
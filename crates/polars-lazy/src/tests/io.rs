@@ -355,6 +355,297 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "parquet", feature = "is_in"))]
+fn test_parquet_bloom_filters() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    // Every row group spans almost the whole range of ids, so min/max statistics can't skip it.
+    let ids = (0..20_000i64).map(|i| (i * 7919) % 20_011 * 2);
+    let mut df = df![
+        "id" => ids.clone().collect::<Vec<_>>(),
+        "name" => ids.map(|i| format!("name-{i}")).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_parquet_bloom_filters.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(5_000))
+        .with_bloom_filters(vec![
+            ParquetBloomFilterOptions {
+                column: "id".into(),
+                fpp: 0.001,
+            },
+            ParquetBloomFilterOptions {
+                column: "name".into(),
+                fpp: 0.001,
+            },
+        ])
+        .finish(&mut df)?;
+
+    let scan = |parallel| {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&path, args)
+    };
+
+    for parallel in [ParallelStrategy::Columns, ParallelStrategy::RowGroups] {
+        let out = scan(parallel)?.filter(col("id").eq(lit(2i64))).collect()?;
+        assert_eq!(out.shape(), (1, 2));
+        let out = scan(parallel)?
+            .select([col("name")])
+            .filter(col("name").is_in(lit(Series::new("".into(), ["name-4", "x"]))))
+            .collect()?;
+        assert_eq!(out.shape(), (1, 1));
+
+        // The bloom filters rule out these values in every row group.
+        std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+        std::env::set_var("POLARS_PARQUET_PREFILTER", "0");
+        let out = scan(parallel)?.filter(col("id").eq(lit(3i64))).collect();
+        let out_is_in = scan(parallel)?
+            .select([col("name")])
+            .filter(col("name").is_in(lit(Series::new("".into(), ["name-3", "name-5"]))))
+            .collect();
+        std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+        std::env::remove_var("POLARS_PARQUET_PREFILTER");
+        assert_eq!(out?.shape(), (0, 2));
+        assert_eq!(out_is_in?.shape(), (0, 1));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_page_index() -> PolarsResult<()> {
+    use polars_core::utils::arrow::bitmap::Bitmap;
+    use polars_io::prelude::ParquetReader;
+    use polars_io::prelude::_internal::select_sparse_pages;
+    use polars_io::SerReader;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let n = 100_000;
+    let mut df = df![
+        "ts" => (0..n as i64).collect::<Vec<_>>(),
+        "value" => (0..n as i64).map(|i| (i * 7919 % 1000) as f64).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(n))
+        .with_data_page_size(Some(4096))
+        .finish(&mut df)?;
+
+    // Corrupt the first data page of `value`, so that reading it fails.
+    let metadata = ParquetReader::new(std::fs::File::open(&path)?)
+        .get_metadata()?
+        .clone();
+    let column = metadata.row_groups[0]
+        .columns_under_root_iter("value")
+        .unwrap()
+        .next()
+        .unwrap();
+    let mut bytes = std::fs::read(&path)?;
+    let offset_index = column.offset_index_range().unwrap();
+    let first_row = Bitmap::from_iter((0..n).map(|i| i == 0));
+    let pages = select_sparse_pages(
+        column,
+        &bytes[offset_index.start as usize..offset_index.end as usize],
+        &first_row,
+    )?
+    .unwrap();
+    let first_page = pages.byte_ranges.last().unwrap().clone();
+    bytes[first_page.end - 16..first_page.end].fill(0xFF);
+    std::fs::write(&path, bytes)?;
+
+    let scan = |parallel| {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&path, args)
+    };
+
+    for parallel in [ParallelStrategy::Columns, ParallelStrategy::RowGroups] {
+        for prefilter in ["0", "1"] {
+            std::env::set_var("POLARS_PARQUET_PREFILTER", prefilter);
+            let out = scan(parallel)?
+                .filter(col("ts").gt_eq(lit(90_000i64)))
+                .collect();
+            std::env::remove_var("POLARS_PARQUET_PREFILTER");
+            let out = out?;
+            assert_eq!(out.shape(), (10_000, 2));
+            assert_eq!(out.column("ts")?.get(0)?, AnyValue::Int64(90_000));
+        }
+    }
+
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = scan(ParallelStrategy::Auto)?
+            .filter(col("ts").gt_eq(lit(90_000i64)))
+            .with_new_streaming(true)
+            .collect()?;
+        assert_eq!(out.shape(), (10_000, 2));
+    }
+
+    // `file://` paths go through the object store, which fetches the page index with range
+    // requests.
+    #[cfg(feature = "cloud")]
+    {
+        let uri = format!("file://{}", path.display());
+        let out = LazyFrame::scan_parquet(&uri, Default::default())?
+            .filter(col("ts").gt_eq(lit(90_000i64)))
+            .collect()?;
+        assert_eq!(out.shape(), (10_000, 2));
+
+        #[cfg(feature = "new_streaming")]
+        {
+            let out = LazyFrame::scan_parquet(&uri, Default::default())?
+                .filter(col("ts").gt_eq(lit(90_000i64)))
+                .with_new_streaming(true)
+                .collect()?;
+            assert_eq!(out.shape(), (10_000, 2));
+        }
+    }
+
+    // Without the page index the corrupted page is read.
+    std::env::set_var("POLARS_NO_PARQUET_STATISTICS", "1");
+    let out = scan(ParallelStrategy::Columns)?
+        .filter(col("ts").gt_eq(lit(90_000i64)))
+        .collect();
+    std::env::remove_var("POLARS_NO_PARQUET_STATISTICS");
+    assert!(out.is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "ipc", feature = "streaming"))]
+fn test_sink_key_value_metadata() -> PolarsResult<()> {
+    use polars_io::prelude::{IpcReader, ParquetReader};
+    use polars_io::SerReader;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let key_value_metadata = KeyValueMetadata {
+        file: [("lineage".into(), "job-42".into())].into(),
+        columns: [("a".into(), [("unit".into(), "m".into())].into())].into(),
+    };
+    let lf = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?
+    .lazy();
+
+    let path = std::env::temp_dir().join("polars_test_sink_key_value_metadata.parquet");
+    lf.clone().sink_parquet(
+        &path,
+        ParquetWriteOptions {
+            key_value_metadata: key_value_metadata.clone(),
+            ..Default::default()
+        },
+    )?;
+    let mut reader = ParquetReader::new(std::fs::File::open(&path)?);
+    assert_eq!(reader.key_value_metadata()?, key_value_metadata);
+    std::fs::remove_file(&path)?;
+
+    let path = std::env::temp_dir().join("polars_test_sink_key_value_metadata.ipc");
+    let options = IpcWriterOptions {
+        key_value_metadata: key_value_metadata.clone(),
+        ..Default::default()
+    };
+    lf.clone().sink_ipc(&path, options.clone())?;
+    let mut reader = IpcReader::new(std::fs::File::open(&path)?);
+    assert_eq!(reader.key_value_metadata()?, key_value_metadata);
+
+    #[cfg(feature = "new_streaming")]
+    {
+        lf.with_new_streaming(true).sink_ipc(&path, options)?;
+        let mut reader = IpcReader::new(std::fs::File::open(&path)?);
+        assert_eq!(reader.key_value_metadata()?, key_value_metadata);
+    }
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "streaming"))]
+fn test_sink_ipc_options() -> PolarsResult<()> {
+    use arrow::datatypes::ArrowDataType;
+    use arrow::io::ipc::read::read_file_metadata;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let lf = df!["a" => (0..10).map(|i| i.to_string()).collect::<Vec<_>>()]?.lazy();
+    let path = std::env::temp_dir().join("polars_test_sink_ipc_options.ipc");
+    let options = IpcWriterOptions {
+        compat_level: CompatLevel::oldest(),
+        record_batch_size: Some(4),
+        ..Default::default()
+    };
+
+    let check = |path: &std::path::Path| -> PolarsResult<()> {
+        let metadata = read_file_metadata(&mut std::fs::File::open(path)?)?;
+        assert_eq!(metadata.blocks.len(), 3);
+        let (_, field) = metadata.schema.get_at_index(0).unwrap();
+        assert_eq!(field.dtype, ArrowDataType::LargeUtf8);
+        Ok(())
+    };
+
+    lf.clone().sink_ipc(&path, options.clone())?;
+    check(&path)?;
+
+    #[cfg(feature = "new_streaming")]
+    {
+        lf.with_new_streaming(true).sink_ipc(&path, options)?;
+        check(&path)?;
+    }
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "streaming"))]
+fn test_sink_partitioned_chunk_size() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let lf = df!["a" => [1, 1, 1, 2], "b" => [1i64, 2, 3, 4]]?.lazy();
+    let path = std::env::temp_dir().join("polars_test_sink_partitioned_chunk_size");
+    let _ = std::fs::remove_dir_all(&path);
+    let file_type = FileType::Csv(CsvWriterOptions::default());
+    // Every row exceeds the chunk size, so it is written to its own file.
+    let options = PartitionedWriteOptions::new(["a"]).with_chunk_size(1);
+
+    lf.clone()
+        .sink_partitioned(&path, file_type.clone(), options.clone())?;
+    assert_eq!(std::fs::read_dir(path.join("a=1"))?.count(), 3);
+    assert_eq!(std::fs::read_dir(path.join("a=2"))?.count(), 1);
+
+    #[cfg(feature = "new_streaming")]
+    assert!(lf
+        .with_new_streaming(true)
+        .sink_partitioned(&path, file_type, options)
+        .is_err());
+    std::fs::remove_dir_all(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "streaming"))]
+fn test_sink_json_options() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let lf = df!["a" => [Some(1.0 / 3.0), None], "b" => [1, 2]]?.lazy();
+    let path = std::env::temp_dir().join("polars_test_sink_json_options.jsonl");
+    let options = JsonWriterOptions {
+        float_precision: Some(3),
+        omit_null_fields: true,
+        ..Default::default()
+    };
+
+    lf.sink_json(&path, options)?;
+    assert_eq!(
+        std::fs::read_to_string(&path)?,
+        "{\"a\":0.333,\"b\":1}\n{\"b\":2}\n"
+    );
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -431,6 +722,81 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "ipc_streaming", not(target_os = "windows")))]
+fn test_scan_ipc_stream() -> PolarsResult<()> {
+    use polars_io::ipc::IpcStreamWriter;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_scan_ipc_stream");
+    std::fs::create_dir_all(&dir)?;
+    for i in 0..2 {
+        // Several record batches per file.
+        let mut df = df!["a" => [i * 6, i * 6 + 1, i * 6 + 2], "b" => ["x", "y", "z"]]?;
+        df.vstack_mut(&df![
+            "a" => [i * 6 + 3, i * 6 + 4, i * 6 + 5],
+            "b" => ["u", "v", "w"]
+        ]?)?;
+        let mut file = std::fs::File::create(dir.join(format!("{i}.arrows")))?;
+        IpcStreamWriter::new(&mut file).finish(&mut df)?;
+    }
+    let glob = dir.join("*.arrows");
+
+    let scan = |args: ScanArgsIpcStream| LazyFrame::scan_ipc_stream(&glob, args);
+    let engines = |lf: LazyFrame| -> PolarsResult<DataFrame> {
+        let df = lf.clone().collect()?;
+        #[cfg(feature = "new_streaming")]
+        assert_eq!(lf.with_new_streaming(true).collect()?, df);
+        Ok(df)
+    };
+
+    let df = engines(scan(Default::default())?)?;
+    assert_eq!(df.shape(), (12, 2));
+    assert_eq!(
+        df.column("a")?.as_materialized_series(),
+        &Series::new("a".into(), (0..12).collect::<Vec<i32>>())
+    );
+
+    // Projection, row index and n_rows over file boundaries.
+    let df = engines(
+        scan(ScanArgsIpcStream {
+            n_rows: Some(8),
+            row_index: Some(RowIndex {
+                name: "idx".into(),
+                offset: 10,
+            }),
+            include_file_paths: Some("path".into()),
+            ..Default::default()
+        })?
+        .select([col("b"), col("idx"), col("path")]),
+    )?;
+    assert_eq!(df.shape(), (8, 3));
+    assert_eq!(
+        df.column("idx")?.as_materialized_series(),
+        &Series::new("idx".into(), (10..18).collect::<Vec<IdxSize>>())
+    );
+    assert_eq!(df.column("b")?.str()?.get(7), Some("y"));
+    assert!(df
+        .column("path")?
+        .str()?
+        .get(7)
+        .unwrap()
+        .ends_with("1.arrows"));
+
+    let df = engines(
+        scan(Default::default())?
+            .filter(col("a").gt(lit(8)))
+            .select([col("a")]),
+    )?;
+    assert_eq!(df.shape(), (3, 1));
+
+    let df = engines(scan(Default::default())?.select([len()]))?;
+    assert_eq!(df.column("len")?.idx()?.get(0), Some(12));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -490,6 +856,30 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_ndjson_conflict_resolution() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_scan_ndjson_conflicts.jsonl");
+    std::fs::write(&path, "{\"a\":1}\n{\"a\":\"x\"}\n{\"a\":{\"b\":2}}\n")?;
+
+    let res = LazyJsonLineReader::new(&path)
+        .with_conflict_resolution(JsonConflictResolution::Raise)
+        .finish()?
+        .collect();
+    assert!(res.is_err());
+
+    let df = LazyJsonLineReader::new(&path)
+        .with_conflict_resolution(JsonConflictResolution::JsonEncoded)
+        .finish()?
+        .collect()?;
+    let a = df.column("a")?.str()?;
+    assert_eq!(Vec::from(a), [Some("1"), Some("x"), Some("{\"b\":2}")]);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -4,7 +4,6 @@ use polars_core::config;
 use polars_core::utils::{
     accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
 
 use super::*;
 
@@ -65,11 +64,11 @@ impl CsvExec {
         let finish_read =
             |i: usize, options: CsvReadOptions, predicate: Option<Arc<dyn PhysicalIoExpr>>| {
                 let source = self.sources.at(i);
-                let owned = &mut vec![];
 
                 let memslice = source.to_memslice_async_assume_latest(run_async)?;
 
-                let reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+                // Compressed sources are decompressed block by block by the reader.
+                let reader = std::io::Cursor::new(&memslice[..]);
                 let mut df = options
                     .into_reader_with_file_handle(reader)
                     ._with_predicate(predicate.clone())
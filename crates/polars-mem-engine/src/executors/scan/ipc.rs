@@ -3,6 +3,7 @@ use polars_core::config;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_error::feature_gated;
 use polars_io::cloud::CloudOptions;
+use polars_io::mmap::MmapBytesReader;
 use polars_io::path_utils::is_cloud_url;
 use polars_io::predicates::apply_predicate;
 use polars_utils::mmap::MemSlice;
@@ -50,7 +51,7 @@ impl IpcExec {
 
     fn read_impl(
         &mut self,
-        idx_to_cached_file: impl Fn(usize) -> Option<PolarsResult<std::fs::File>> + Send + Sync,
+        idx_to_reader: impl Fn(usize) -> Option<PolarsResult<Box<dyn MmapBytesReader>>> + Send + Sync,
     ) -> PolarsResult<DataFrame> {
         if config::verbose() {
             eprintln!("executing ipc read sync with row_index = {:?}, n_rows = {:?}, predicate = {:?} for paths {:?}",
@@ -74,20 +75,21 @@ impl IpcExec {
         let read_path = |index: usize, n_rows: Option<usize>| {
             let source = self.sources.at(index);
 
-            let memslice = match source {
-                ScanSourceRef::Path(path) => {
-                    let file = match idx_to_cached_file(index) {
-                        None => std::fs::File::open(path)?,
-                        Some(f) => f?,
+            let reader = match idx_to_reader(index) {
+                Some(reader) => reader?,
+                None => {
+                    let memslice = match source {
+                        ScanSourceRef::Path(path) => {
+                            MemSlice::from_file(&std::fs::File::open(path)?)?
+                        },
+                        ScanSourceRef::File(file) => MemSlice::from_file(file)?,
+                        ScanSourceRef::Buffer(buff) => MemSlice::from_bytes(buff.clone()),
                     };
-
-                    MemSlice::from_file(&file)?
+                    Box::new(std::io::Cursor::new(memslice))
                 },
-                ScanSourceRef::File(file) => MemSlice::from_file(file)?,
-                ScanSourceRef::Buffer(buff) => MemSlice::from_bytes(buff.clone()),
             };
 
-            IpcReader::new(std::io::Cursor::new(memslice))
+            IpcReader::new(reader)
                 .with_n_rows(n_rows)
                 .with_row_index(self.file_options.row_index.clone())
                 .with_projection(projection.clone())
@@ -170,12 +172,41 @@ impl IpcExec {
 
     #[cfg(feature = "cloud")]
     async fn read_async(&mut self) -> PolarsResult<DataFrame> {
-        // TODO: Better async impl that can download only the parts of the file it needs, and do it
-        // concurrently.
+        use std::str::FromStr;
+
+        use polars_io::cloud::CloudType;
         use polars_io::file_cache::init_entries_from_uri_list;
+        use polars_io::ipc::IpcReaderAsync;
 
         let paths = self.sources.into_paths().unwrap();
 
+        // HTTP sources are not cached, so only fetch the parts of the files that are read.
+        if paths.first().is_some_and(|p| {
+            is_cloud_url(p)
+                && matches!(
+                    CloudType::from_str(&p.to_string_lossy()),
+                    Ok(CloudType::Http)
+                )
+        }) {
+            let cloud_options = self.cloud_options.clone();
+            // With a row limit, record batches are only fetched once they are read.
+            let prefetch = self.file_options.slice.is_none();
+
+            return tokio::task::block_in_place(|| {
+                self.read_impl(|i| {
+                    let uri = paths[i].to_string_lossy();
+                    let reader =
+                        polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
+                            IpcReaderAsync::from_uri(&uri, cloud_options.as_ref())
+                                .await?
+                                .range_reader(prefetch)
+                                .await
+                        });
+                    Some(reader.map(|r| Box::new(r) as Box<dyn MmapBytesReader>))
+                })
+            });
+        }
+
         tokio::task::block_in_place(|| {
             let cache_entries = init_entries_from_uri_list(
                 paths
@@ -186,7 +217,13 @@ impl IpcExec {
                 self.cloud_options.as_ref(),
             )?;
 
-            self.read_impl(|i| Some(cache_entries[i].try_open_check_latest()))
+            self.read_impl(|i| {
+                let reader = cache_entries[i].try_open_check_latest().and_then(|file| {
+                    let memslice = MemSlice::from_file(&file)?;
+                    Ok(Box::new(std::io::Cursor::new(memslice)) as Box<dyn MmapBytesReader>)
+                });
+                Some(reader)
+            })
         })
     }
 }
@@ -0,0 +1,101 @@
+use polars_core::config;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::ipc::IpcStreamReader;
+use polars_io::predicates::apply_predicate;
+use polars_io::SerReader;
+
+use super::*;
+
+pub struct IpcStreamExec {
+    pub(crate) sources: ScanSources,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) file_options: FileScanOptions,
+}
+
+impl IpcStreamExec {
+    fn read(&mut self) -> PolarsResult<DataFrame> {
+        let verbose = config::verbose();
+        let run_async =
+            self.sources.is_cloud_url() || (self.sources.is_paths() && config::force_async());
+
+        let mut n_rows = self.file_options.slice.map(|x| {
+            assert_eq!(x.0, 0);
+            x.1
+        });
+
+        if verbose {
+            eprintln!(
+                "executing ipc stream read with row_index = {:?}, n_rows = {:?}, predicate = {:?} for {} sources",
+                self.file_options.row_index.as_ref(),
+                n_rows,
+                self.predicate.is_some(),
+                self.sources.len(),
+            );
+        }
+
+        let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let mut row_index = self.file_options.row_index.clone();
+
+        let mut dfs = vec![];
+        for (i, source) in self.sources.iter().enumerate() {
+            // The first source is always read, so that the output has the right schema.
+            if i > 0 && n_rows == Some(0) {
+                break;
+            }
+
+            let memslice = source.to_memslice_async_assume_latest(run_async)?;
+            let mut df = IpcStreamReader::new(std::io::Cursor::new(memslice))
+                .with_columns(
+                    self.file_options
+                        .with_columns
+                        .as_ref()
+                        .map(|cols| cols.iter().map(|c| c.to_string()).collect()),
+                )
+                .with_n_rows(n_rows)
+                .with_row_index(row_index.clone())
+                .set_rechunk(false)
+                .finish()?;
+
+            if let Some(row_index) = row_index.as_mut() {
+                row_index.offset += df.height() as IdxSize;
+            }
+            if let Some(n_rows) = n_rows.as_mut() {
+                *n_rows -= df.height();
+            }
+
+            if let Some(col) = &self.file_options.include_file_paths {
+                let name = source.to_include_path_name();
+                unsafe {
+                    df.with_column_unchecked(Column::new_scalar(
+                        col.clone(),
+                        Scalar::new(DataType::String, AnyValue::StringOwned(name.into())),
+                        df.height(),
+                    ))
+                };
+            }
+
+            apply_predicate(&mut df, predicate.as_deref(), true)?;
+            dfs.push(df);
+        }
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if self.file_options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+impl Executor for IpcStreamExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let profile_name = if state.has_node_timer() {
+            let ids = vec![self.sources.id()];
+            let name = comma_delimited("ipc_stream".to_string(), &ids);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        state.record(|| self.read(), profile_name)
+    }
+}
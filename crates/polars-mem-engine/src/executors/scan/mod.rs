@@ -2,6 +2,8 @@
 mod csv;
 #[cfg(feature = "ipc")]
 mod ipc;
+#[cfg(feature = "ipc_streaming")]
+mod ipc_stream;
 #[cfg(feature = "json")]
 mod ndjson;
 #[cfg(feature = "parquet")]
@@ -15,6 +17,8 @@ use std::mem;
 pub(crate) use csv::CsvExec;
 #[cfg(feature = "ipc")]
 pub(crate) use ipc::IpcExec;
+#[cfg(feature = "ipc_streaming")]
+pub(crate) use ipc_stream::IpcStreamExec;
 #[cfg(feature = "json")]
 pub(crate) use ndjson::JsonExec;
 #[cfg(feature = "parquet")]
@@ -1,10 +1,14 @@
 use polars_core::config;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::prelude::{JsonLineReader, SerReader};
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{count_eol, ChunkedDecompressor};
+use polars_io::RowIndex;
 
 use super::*;
 
+/// Number of decompressed bytes that are parsed at once when reading compressed files.
+const DECOMPRESSION_BLOCK_SIZE: usize = 1 << 24;
+
 pub struct JsonExec {
     sources: ScanSources,
     options: NDJsonReadOptions,
@@ -66,6 +70,7 @@ impl JsonExec {
             return Ok(df);
         }
 
+        let mut row_index = self.file_scan_options.row_index.clone();
         let dfs = self
             .sources
             .iter()
@@ -74,31 +79,12 @@ impl JsonExec {
                     return None;
                 }
 
-                let row_index = self.file_scan_options.row_index.as_mut();
-
                 let memslice = match source.to_memslice_async_assume_latest(run_async) {
                     Ok(memslice) => memslice,
                     Err(err) => return Some(Err(err)),
                 };
 
-                let owned = &mut vec![];
-                let curs = std::io::Cursor::new(match maybe_decompress_bytes(&memslice, owned) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                });
-                let reader = JsonLineReader::new(curs);
-
-                let df = reader
-                    .with_schema(schema.clone())
-                    .with_rechunk(self.file_scan_options.rechunk)
-                    .with_chunk_size(Some(self.options.chunk_size))
-                    .with_row_index(row_index)
-                    .with_predicate(self.predicate.clone().map(phys_expr_to_io_expr))
-                    .with_projection(self.file_scan_options.with_columns.clone())
-                    .low_memory(self.options.low_memory)
-                    .with_n_rows(n_rows)
-                    .with_ignore_errors(self.options.ignore_errors)
-                    .finish();
+                let df = self.read_source(&memslice, n_rows, row_index.as_mut());
 
                 let mut df = match df {
                     Ok(df) => df,
@@ -126,6 +112,70 @@ impl JsonExec {
 
         accumulate_dataframes_vertical(dfs)
     }
+
+    /// Read a single source. Compressed sources are decompressed and parsed one block at a time,
+    /// so that the decompressed file is never fully held in memory.
+    fn read_source(
+        &self,
+        bytes: &[u8],
+        mut n_rows: Option<usize>,
+        mut row_index: Option<&mut RowIndex>,
+    ) -> PolarsResult<DataFrame> {
+        let Some(mut decompressor) = ChunkedDecompressor::try_new(bytes, DECOMPRESSION_BLOCK_SIZE)?
+        else {
+            return self.read_bytes(bytes, n_rows, row_index);
+        };
+
+        let mut dfs = vec![];
+        while n_rows != Some(0) {
+            let Some(block) = decompressor.next_block(|bytes| {
+                let (count, end) = count_eol(bytes, b'\n');
+                (count > 0).then_some(end)
+            })?
+            else {
+                break;
+            };
+
+            let df = self.read_bytes(&block, n_rows, row_index.as_deref_mut())?;
+            if let Some(n_rows) = n_rows.as_mut() {
+                *n_rows -= df.height();
+            }
+            dfs.push(df);
+        }
+
+        if dfs.is_empty() {
+            return self.read_bytes(&[], n_rows, row_index);
+        }
+        accumulate_dataframes_vertical(dfs)
+    }
+
+    fn read_bytes(
+        &self,
+        bytes: &[u8],
+        n_rows: Option<usize>,
+        row_index: Option<&mut RowIndex>,
+    ) -> PolarsResult<DataFrame> {
+        let schema = self
+            .file_info
+            .reader_schema
+            .as_ref()
+            .unwrap()
+            .as_ref()
+            .unwrap_right();
+
+        JsonLineReader::new(std::io::Cursor::new(bytes))
+            .with_schema(schema.clone())
+            .with_rechunk(self.file_scan_options.rechunk)
+            .with_chunk_size(Some(self.options.chunk_size))
+            .with_row_index(row_index)
+            .with_predicate(self.predicate.clone().map(phys_expr_to_io_expr))
+            .with_projection(self.file_scan_options.with_columns.clone())
+            .low_memory(self.options.low_memory)
+            .with_n_rows(n_rows)
+            .with_ignore_errors(self.options.ignore_errors)
+            .with_conflict_resolution(self.options.conflict_resolution)
+            .finish()
+    }
 }
 
 impl Executor for JsonExec {
@@ -96,7 +96,8 @@ impl ParquetExec {
                             .map(|&i| {
                                 let memslice = self.sources.at(i).to_memslice()?;
 
-                                let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+                                let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                                    .with_decryption(self.options.decryption.clone());
 
                                 if i == 0 {
                                     if let Some(md) = self.metadata.clone() {
@@ -171,7 +172,8 @@ impl ParquetExec {
 
                 let memslice = source.to_memslice()?;
 
-                let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+                let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                    .with_decryption(self.options.decryption.clone());
 
                 if i == 0 {
                     if let Some(md) = self.metadata.clone() {
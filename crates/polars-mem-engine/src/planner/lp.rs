@@ -213,6 +213,15 @@ fn create_physical_plan_impl(
                     "sink_{file_type:?} not yet supported in standard engine. Use 'collect().write_{file_type:?}()'"
                 )
             },
+            #[cfg(any(
+                feature = "parquet",
+                feature = "ipc",
+                feature = "csv",
+                feature = "json"
+            ))]
+            SinkType::Partitioned { .. } => {
+                polars_bail!(InvalidOperation: "partitioned sink not supported in standard engine.")
+            },
             #[cfg(feature = "cloud")]
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
@@ -327,6 +336,12 @@ fn create_physical_plan_impl(
                     hive_parts,
                     cloud_options,
                 })),
+                #[cfg(feature = "ipc_streaming")]
+                FileScan::IpcStream { .. } => Ok(Box::new(executors::IpcStreamExec {
+                    sources,
+                    predicate,
+                    file_options,
+                })),
                 #[cfg(feature = "parquet")]
                 FileScan::Parquet {
                     options,
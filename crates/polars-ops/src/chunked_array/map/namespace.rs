@@ -0,0 +1,219 @@
+use arrow::array::{Array, ListArray};
+use arrow::offset::{Offsets, OffsetsBuffer};
+
+use super::*;
+
+/// The rechunked physical entries of a map with the key and value fields as [`Series`].
+struct Entries {
+    list: ListChunked,
+    keys: Series,
+    values: Series,
+    /// The first entry that is referenced by the list offsets.
+    start: usize,
+    /// The number of entries that are referenced by the list offsets.
+    len: usize,
+}
+
+fn entries(ca: &MapChunked) -> PolarsResult<Entries> {
+    let list = ca.physical().rechunk();
+    let inner = list.get_inner();
+    let fields = inner.struct_()?.fields_as_series();
+    let [keys, values] = <[Series; 2]>::try_from(fields).unwrap();
+    let offsets = list.downcast_iter().next().unwrap().offsets();
+    let start = *offsets.first() as usize;
+    let len = *offsets.last() as usize - start;
+    Ok(Entries {
+        list,
+        keys,
+        values,
+        start,
+        len,
+    })
+}
+
+/// Wrap one of the entry fields in the offsets and validity of the map.
+fn entries_field_to_list(list: &ListChunked, field: &Series) -> ListChunked {
+    let arr = list.downcast_iter().next().unwrap();
+    let field = field.rechunk();
+    let values = field.chunks()[0].clone();
+    let out = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values.dtype().clone()),
+        arr.offsets().clone(),
+        values,
+        arr.validity().cloned(),
+    );
+    // SAFETY: the physical values match the field dtype.
+    unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            list.name().clone(),
+            vec![out.boxed()],
+            &DataType::List(Box::new(field.dtype().clone())),
+        )
+    }
+    .list()
+    .unwrap()
+    .clone()
+}
+
+/// Find the index of the first entry in every row whose key equals `key`.
+///
+/// The indices point into the entries of the rechunked map. Rows that are null
+/// have a null index and rows without a matching key have `Some(None)`.
+fn find_keys(
+    ca: &MapChunked,
+    entries: &Entries,
+    key: &Series,
+) -> PolarsResult<Vec<Option<Option<IdxSize>>>> {
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        ShapeMismatch: "lengths of arguments do not align in map lookup, got length: {} for column: {}, got length: {} for argument 'key'",
+        ca.len(), ca.name(), key.len()
+    );
+    let key = key.strict_cast(ca.key_dtype())?;
+    let arr = entries.list.downcast_iter().next().unwrap();
+    let offsets = arr.offsets();
+    let start = entries.start;
+    let keys = entries.keys.slice(start as i64, entries.len);
+
+    let mask = if key.len() == 1 {
+        keys.equal(&key)?
+    } else {
+        // Repeat the key of every row for each of its entries.
+        let idx: IdxCa = (0..arr.len())
+            .flat_map(|i| {
+                let (s, e) = offsets.start_end(i);
+                std::iter::repeat_n(i as IdxSize, e - s)
+            })
+            .collect_ca(PlSmallStr::EMPTY);
+        keys.equal(&key.take(&idx)?)?
+    };
+    let mask = mask.rechunk();
+    let mask = mask.downcast_iter().next().unwrap();
+
+    Ok((0..arr.len())
+        .map(|i| {
+            if !arr.is_valid(i) {
+                return None;
+            }
+            let (s, e) = offsets.start_end(i);
+            let found = (s..e).find(|&j| {
+                let j = j - start;
+                mask.is_valid(j) && mask.value(j)
+            });
+            Some(found.map(|j| (j - start) as IdxSize))
+        })
+        .collect())
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        let entries = entries(self.as_map())?;
+        Ok(entries_field_to_list(&entries.list, &entries.keys))
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        let entries = entries(self.as_map())?;
+        Ok(entries_field_to_list(&entries.list, &entries.values))
+    }
+
+    /// Get the value of `key` in every map, or null if the key is missing.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let entries = entries(ca)?;
+        let found = find_keys(ca, &entries, key)?;
+        let values = entries.values.slice(entries.start as i64, entries.len);
+        let idx: IdxCa = found
+            .into_iter()
+            .map(|opt| opt.flatten())
+            .collect_ca(PlSmallStr::EMPTY);
+        let mut out = values.take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Check whether every map contains `key`.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let entries = entries(ca)?;
+        let found = find_keys(ca, &entries, key)?;
+        Ok(found
+            .into_iter()
+            .map(|opt| opt.map(|idx| idx.is_some()))
+            .collect_ca(ca.name().clone()))
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+/// Create a map from a list of keys and a list of values of the same length.
+///
+/// A row is null if either of the lists is null.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> PolarsResult<MapChunked> {
+    polars_ensure!(
+        keys.len() == values.len(),
+        ShapeMismatch: "lengths of arguments do not align in 'map.from_lists', got length: {} for keys, got length: {} for values",
+        keys.len(), values.len()
+    );
+    let keys = keys.rechunk();
+    let values = values.rechunk();
+    let keys_arr = keys.downcast_iter().next().unwrap();
+    let values_arr = values.downcast_iter().next().unwrap();
+
+    let mut keys_idx = Vec::with_capacity(keys_arr.values().len());
+    let mut values_idx = Vec::with_capacity(values_arr.values().len());
+    let mut offsets = Offsets::<i64>::with_capacity(keys.len());
+    for i in 0..keys.len() {
+        if !(keys_arr.is_valid(i) && values_arr.is_valid(i)) {
+            offsets.try_push(0).unwrap();
+            continue;
+        }
+        let (ks, ke) = keys_arr.offsets().start_end(i);
+        let (vs, ve) = values_arr.offsets().start_end(i);
+        polars_ensure!(
+            ke - ks == ve - vs,
+            ShapeMismatch: "map keys and values must have the same length, got {} keys and {} values in row {}",
+            ke - ks, ve - vs, i
+        );
+        keys_idx.extend((ks..ke).map(|j| j as IdxSize));
+        values_idx.extend((vs..ve).map(|j| j as IdxSize));
+        offsets.try_push(ke - ks).unwrap();
+    }
+
+    let key = keys
+        .get_inner()
+        .take(&IdxCa::from_vec(PlSmallStr::EMPTY, keys_idx))?
+        .with_name(PlSmallStr::from_static("key"));
+    polars_ensure!(key.null_count() == 0, ComputeError: "map keys cannot be null");
+    let value = values
+        .get_inner()
+        .take(&IdxCa::from_vec(PlSmallStr::EMPTY, values_idx))?
+        .with_name(PlSmallStr::from_static("value"));
+    let entries = StructChunked::from_series(PlSmallStr::EMPTY, key.len(), [key, value].iter())?;
+    let entries = entries.rechunk();
+
+    let validity = match (keys_arr.validity(), values_arr.validity()) {
+        (Some(l), Some(r)) => Some(l & r),
+        (Some(v), None) | (None, Some(v)) => Some(v.clone()),
+        (None, None) => None,
+    };
+    let entries_arr = entries.chunks()[0].clone();
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(entries_arr.dtype().clone()),
+        OffsetsBuffer::from(offsets),
+        entries_arr,
+        validity,
+    );
+    let key_dtype = keys.inner_dtype().clone();
+    let value_dtype = values.inner_dtype().clone();
+    // SAFETY: the entries have the `key` and `value` fields of the given dtypes.
+    unsafe {
+        let s = Series::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![arr.boxed()],
+            &DataType::Map(Box::new(key_dtype), Box::new(value_dtype)),
+        );
+        Ok(s.map()?.clone())
+    }
+}
@@ -5,7 +5,7 @@ use polars_core::chunked_array::ops::float_sorted_arg_max::{
     float_arg_max_sorted_ascending, float_arg_max_sorted_descending,
 };
 use polars_core::series::IsSorted;
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::with_match_physical_numeric_type;
 
 use super::*;
 
@@ -55,9 +55,14 @@ impl ArgAgg for Series {
                 let ca = s.i64().unwrap();
                 arg_min_numeric_dispatch(ca)
             },
+            #[cfg(feature = "dtype-i128")]
+            Int128 => arg_min_wide(s.i128().unwrap()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => arg_min_wide(s.u128().unwrap()),
             dt if dt.is_numeric() => {
-                with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
-                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                with_match_physical_numeric_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<<$T as NumericNative>::PolarsType> =
+                        s.as_ref().as_ref().as_ref();
                     arg_min_numeric_dispatch(ca)
                 })
             },
@@ -101,9 +106,14 @@ impl ArgAgg for Series {
                 let ca = s.i64().unwrap();
                 arg_max_numeric_dispatch(ca)
             },
+            #[cfg(feature = "dtype-i128")]
+            Int128 => arg_max_wide(s.i128().unwrap()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => arg_max_wide(s.u128().unwrap()),
             dt if dt.is_numeric() => {
-                with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
-                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                with_match_physical_numeric_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<<$T as NumericNative>::PolarsType> =
+                        s.as_ref().as_ref().as_ref();
                     arg_max_numeric_dispatch(ca)
                 })
             },
@@ -236,6 +246,35 @@ fn arg_max_str(ca: &StringChunked) -> Option<usize> {
     }
 }
 
+/// Fallback for 128-bit integers, which `argminmax` doesn't support.
+#[cfg(any(feature = "dtype-i128", feature = "dtype-u128"))]
+fn arg_min_wide<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Option<usize> {
+    match ca.is_sorted_flag() {
+        IsSorted::Ascending => ca.first_non_null(),
+        IsSorted::Descending => ca.last_non_null(),
+        IsSorted::Not => ca
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, val)| val.map(|val| (idx, val)))
+            .reduce(|acc, (idx, val)| if acc.1 > val { (idx, val) } else { acc })
+            .map(|tpl| tpl.0),
+    }
+}
+
+#[cfg(any(feature = "dtype-i128", feature = "dtype-u128"))]
+fn arg_max_wide<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Option<usize> {
+    match ca.is_sorted_flag() {
+        IsSorted::Ascending => ca.last_non_null(),
+        IsSorted::Descending => ca.first_non_null(),
+        IsSorted::Not => ca
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, val)| val.map(|val| (idx, val)))
+            .reduce(|acc, (idx, val)| if acc.1 < val { (idx, val) } else { acc })
+            .map(|tpl| tpl.0),
+    }
+}
+
 fn arg_min_numeric<'a, T>(ca: &'a ChunkedArray<T>) -> Option<usize>
 where
     T: PolarsNumericType,
@@ -5,6 +5,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "dtype-date")]
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_utils::binary_search::{find_first_ge_index, find_first_gt_index};
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -19,28 +20,188 @@ pub enum Roll {
     Raise,
 }
 
+/// A reusable business-day calendar.
+///
+/// A date is a business day if its weekday is set in the week mask and it isn't a holiday.
+/// Holidays can be grouped into named sets (e.g. one per exchange), and half-days mark
+/// business days with shortened hours. Dates are given as the number of days since the
+/// UNIX epoch.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BusinessCalendar {
+    week_mask: [bool; 7],
+    custom_holidays: Vec<i32>,
+    holiday_sets: Vec<(PlSmallStr, Vec<i32>)>,
+    half_days: Vec<i32>,
+    // Union of all holidays that fall on a business day of the week mask, sorted and
+    // deduplicated.
+    holidays: Vec<i32>,
+}
+
+impl Default for BusinessCalendar {
+    /// Monday to Friday, without holidays.
+    fn default() -> Self {
+        Self::new([true, true, true, true, true, false, false], &[])
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar from a week mask and a set of unnamed holidays.
+    ///
+    /// # Arguments
+    /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+    /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+    ///   days since the UNIX epoch.
+    pub fn new(week_mask: [bool; 7], holidays: &[i32]) -> Self {
+        let mut calendar = Self {
+            week_mask,
+            custom_holidays: holidays.to_vec(),
+            holiday_sets: vec![],
+            half_days: vec![],
+            holidays: vec![],
+        };
+        calendar.update_holidays();
+        calendar
+    }
+
+    /// Add a named set of holidays, replacing an existing set with the same name.
+    pub fn with_holiday_set(mut self, name: PlSmallStr, holidays: &[i32]) -> Self {
+        match self.holiday_sets.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, set)) => *set = holidays.to_vec(),
+            None => self.holiday_sets.push((name, holidays.to_vec())),
+        }
+        self.update_holidays();
+        self
+    }
+
+    /// Mark days on which business hours are shortened. They still count as business days.
+    pub fn with_half_days(mut self, half_days: &[i32]) -> Self {
+        let mut half_days = half_days.to_vec();
+        half_days.sort_unstable();
+        half_days.dedup();
+        self.half_days = half_days;
+        self
+    }
+
+    pub fn week_mask(&self) -> [bool; 7] {
+        self.week_mask
+    }
+
+    /// All holidays that fall on a business day of the week mask, sorted.
+    pub fn holidays(&self) -> &[i32] {
+        &self.holidays
+    }
+
+    pub fn holiday_set(&self, name: &str) -> Option<&[i32]> {
+        self.holiday_sets
+            .iter()
+            .find(|(n, _)| n.as_str() == name)
+            .map(|(_, set)| set.as_slice())
+    }
+
+    pub fn holiday_set_names(&self) -> impl Iterator<Item = &PlSmallStr> {
+        self.holiday_sets.iter().map(|(name, _)| name)
+    }
+
+    pub fn half_days(&self) -> &[i32] {
+        &self.half_days
+    }
+
+    fn update_holidays(&mut self) {
+        let holidays = self
+            .custom_holidays
+            .iter()
+            .chain(self.holiday_sets.iter().flat_map(|(_, set)| set))
+            .copied()
+            .collect::<Vec<_>>();
+        self.holidays = normalise_holidays(&holidays, &self.week_mask);
+    }
+
+    pub fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.week_mask.iter().any(|&x| x),
+            ComputeError: "`week_mask` must have at least one business day"
+        );
+        Ok(())
+    }
+
+    fn n_business_days_in_week_mask(&self) -> i32 {
+        self.week_mask.iter().filter(|&x| *x).count() as i32
+    }
+
+    pub fn is_business_day(&self, date: i32) -> bool {
+        self.week_mask[get_day_of_week(date)] && self.holidays.binary_search(&date).is_err()
+    }
+
+    pub fn is_half_day(&self, date: i32) -> bool {
+        self.is_business_day(date) && self.half_days.binary_search(&date).is_ok()
+    }
+
+    /// The first business day on or after `date`.
+    ///
+    /// The week mask must contain at least one business day, see [`BusinessCalendar::validate`].
+    pub fn roll_forward(&self, date: i32) -> i32 {
+        roll_start_date(date, Roll::Forward, self).unwrap().0
+    }
+
+    /// The last business day on or before `date`.
+    ///
+    /// The week mask must contain at least one business day, see [`BusinessCalendar::validate`].
+    pub fn roll_backward(&self, date: i32) -> i32 {
+        roll_start_date(date, Roll::Backward, self).unwrap().0
+    }
+
+    /// Add `n` business days to `date`, first rolling `date` onto a business day.
+    pub fn add_business_days(&self, date: i32, n: i32, roll: Roll) -> PolarsResult<i32> {
+        let (date, day_of_week) = roll_start_date(date, roll, self)?;
+        Ok(add_business_days_impl(
+            date,
+            day_of_week,
+            n,
+            self,
+            self.n_business_days_in_week_mask(),
+        ))
+    }
+
+    /// Move `n` business days away from `date`.
+    ///
+    /// A date that isn't a business day counts as the first step, so e.g. a Saturday moved
+    /// forward by one business day lands on Monday.
+    pub fn offset(&self, date: i32, n: i32) -> i32 {
+        if n == 0 || self.is_business_day(date) {
+            return self.add_business_days(date, n, Roll::Raise).unwrap();
+        }
+        if n > 0 {
+            let date = self.roll_forward(date);
+            self.add_business_days(date, n - 1, Roll::Raise).unwrap()
+        } else {
+            let date = self.roll_backward(date);
+            self.add_business_days(date, n + 1, Roll::Raise).unwrap()
+        }
+    }
+
+    /// Count the number of business days between `start` and `end`, excluding `end`.
+    pub fn business_day_count(&self, start: i32, end: i32) -> i32 {
+        business_day_count_impl(start, end, self, self.n_business_days_in_week_mask())
+    }
+}
+
 /// Count the number of business days between `start` and `end`, excluding `end`.
 ///
 /// # Arguments
 /// - `start`: Series holding start dates.
 /// - `end`: Series holding end dates.
-/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
-/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
-///   days since the UNIX epoch.
+/// - `calendar`: the week mask and holidays that determine which days are business days.
 pub fn business_day_count(
     start: &Series,
     end: &Series,
-    week_mask: [bool; 7],
-    holidays: &[i32],
+    calendar: &BusinessCalendar,
 ) -> PolarsResult<Series> {
-    if !week_mask.iter().any(|&x| x) {
-        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
-    }
+    calendar.validate()?;
 
-    let holidays = normalise_holidays(holidays, &week_mask);
     let start_dates = start.date()?;
     let end_dates = end.date()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+    let n_business_days_in_week_mask = calendar.n_business_days_in_week_mask();
 
     let out = match (start_dates.len(), end_dates.len()) {
         (_, 1) => {
@@ -49,9 +210,8 @@ pub fn business_day_count(
                     business_day_count_impl(
                         start_date,
                         end_date,
-                        &week_mask,
+                        calendar,
                         n_business_days_in_week_mask,
-                        &holidays,
                     )
                 })
             } else {
@@ -64,9 +224,8 @@ pub fn business_day_count(
                     business_day_count_impl(
                         start_date,
                         end_date,
-                        &week_mask,
+                        calendar,
                         n_business_days_in_week_mask,
-                        &holidays,
                     )
                 })
             } else {
@@ -74,13 +233,7 @@ pub fn business_day_count(
             }
         },
         _ => binary_elementwise_values(start_dates, end_dates, |start_date, end_date| {
-            business_day_count_impl(
-                start_date,
-                end_date,
-                &week_mask,
-                n_business_days_in_week_mask,
-                &holidays,
-            )
+            business_day_count_impl(start_date, end_date, calendar, n_business_days_in_week_mask)
         }),
     };
     Ok(out.into_series())
@@ -91,10 +244,11 @@ pub fn business_day_count(
 fn business_day_count_impl(
     mut start_date: i32,
     mut end_date: i32,
-    week_mask: &[bool; 7],
+    calendar: &BusinessCalendar,
     n_business_days_in_week_mask: i32,
-    holidays: &[i32],
 ) -> i32 {
+    let week_mask = &calendar.week_mask;
+    let holidays = calendar.holidays.as_slice();
     let swapped = start_date > end_date;
     if swapped {
         (start_date, end_date) = (end_date, start_date);
@@ -125,42 +279,24 @@ fn business_day_count_impl(
     }
 }
 
-/// Add a given number of business days.
-///
-/// # Arguments
-/// - `start`: Series holding start dates.
-/// - `n`: Number of business days to add.
-/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
-/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
-///   days since the UNIX epoch.
-/// - `roll`: what to do when the start date doesn't land on a business day:
-///   - `Roll::Forward`: roll forward to the next business day.
-///   - `Roll::Backward`: roll backward to the previous business day.
-///   - `Roll::Raise`: raise an error.
-pub fn add_business_days(
-    start: &Series,
-    n: &Series,
-    week_mask: [bool; 7],
-    holidays: &[i32],
-    roll: Roll,
-) -> PolarsResult<Series> {
-    if !week_mask.iter().any(|&x| x) {
-        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
-    }
-
+/// Apply `f` to the dates of `start`. The time of day of datetimes is kept, and time zone
+/// aware datetimes are handled in their local time.
+fn apply_on_dates<F>(start: &Series, f: F) -> PolarsResult<Series>
+where
+    F: Fn(&Series) -> PolarsResult<Series>,
+{
     match start.dtype() {
-        DataType::Date => {},
+        DataType::Date => f(start),
         #[cfg(feature = "dtype-datetime")]
         DataType::Datetime(time_unit, None) => {
-            let result_date =
-                add_business_days(&start.cast(&DataType::Date)?, n, week_mask, holidays, roll)?;
+            let result_date = f(&start.cast(&DataType::Date)?)?;
             let start_time = start
                 .cast(&DataType::Time)?
                 .cast(&DataType::Duration(*time_unit))?;
-            return std::ops::Add::add(
+            std::ops::Add::add(
                 result_date.cast(&DataType::Datetime(*time_unit, None))?,
                 start_time,
-            );
+            )
         },
         #[cfg(feature = "timezones")]
         DataType::Datetime(time_unit, Some(time_zone)) => {
@@ -170,13 +306,7 @@ pub fn add_business_days(
                 &StringChunked::from_iter(std::iter::once("raise")),
                 NonExistent::Raise,
             )?;
-            let result_date = add_business_days(
-                &start_naive.cast(&DataType::Date)?,
-                n,
-                week_mask,
-                holidays,
-                roll,
-            )?;
+            let result_date = f(&start_naive.cast(&DataType::Date)?)?;
             let start_time = start_naive
                 .cast(&DataType::Time)?
                 .cast(&DataType::Duration(*time_unit))?;
@@ -190,12 +320,40 @@ pub fn add_business_days(
                 &StringChunked::from_iter(std::iter::once("raise")),
                 NonExistent::Raise,
             )?;
-            return Ok(result_tz_aware.into_series());
+            Ok(result_tz_aware.into_series())
         },
         _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
     }
+}
 
-    let holidays = normalise_holidays(holidays, &week_mask);
+/// Add a given number of business days.
+///
+/// # Arguments
+/// - `start`: Series holding start dates.
+/// - `n`: Number of business days to add.
+/// - `calendar`: the week mask and holidays that determine which days are business days.
+/// - `roll`: what to do when the start date doesn't land on a business day:
+///   - `Roll::Forward`: roll forward to the next business day.
+///   - `Roll::Backward`: roll backward to the previous business day.
+///   - `Roll::Raise`: raise an error.
+pub fn add_business_days(
+    start: &Series,
+    n: &Series,
+    calendar: &BusinessCalendar,
+    roll: Roll,
+) -> PolarsResult<Series> {
+    calendar.validate()?;
+    apply_on_dates(start, |start| {
+        add_business_days_to_dates(start, n, calendar, roll)
+    })
+}
+
+fn add_business_days_to_dates(
+    start: &Series,
+    n: &Series,
+    calendar: &BusinessCalendar,
+    roll: Roll,
+) -> PolarsResult<Series> {
     let start_dates = start.date()?;
     let n = match &n.dtype() {
         DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32)?,
@@ -205,21 +363,19 @@ pub fn add_business_days(
         },
     };
     let n = n.i32()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+    let n_business_days_in_week_mask = calendar.n_business_days_in_week_mask();
 
     let out: Int32Chunked = match (start_dates.len(), n.len()) {
         (_, 1) => {
             if let Some(n) = n.get(0) {
                 start_dates.try_apply_nonnull_values_generic(|start_date| {
-                    let (start_date, day_of_week) =
-                        roll_start_date(start_date, roll, &week_mask, &holidays)?;
+                    let (start_date, day_of_week) = roll_start_date(start_date, roll, calendar)?;
                     Ok::<i32, PolarsError>(add_business_days_impl(
                         start_date,
                         day_of_week,
                         n,
-                        &week_mask,
+                        calendar,
                         n_business_days_in_week_mask,
-                        &holidays,
                     ))
                 })?
             } else {
@@ -228,16 +384,14 @@ pub fn add_business_days(
         },
         (1, _) => {
             if let Some(start_date) = start_dates.get(0) {
-                let (start_date, day_of_week) =
-                    roll_start_date(start_date, roll, &week_mask, &holidays)?;
+                let (start_date, day_of_week) = roll_start_date(start_date, roll, calendar)?;
                 n.apply_values(|n| {
                     add_business_days_impl(
                         start_date,
                         day_of_week,
                         n,
-                        &week_mask,
+                        calendar,
                         n_business_days_in_week_mask,
-                        &holidays,
                     )
                 })
             } else {
@@ -247,15 +401,13 @@ pub fn add_business_days(
         _ => try_binary_elementwise(start_dates, n, |opt_start_date, opt_n| {
             match (opt_start_date, opt_n) {
                 (Some(start_date), Some(n)) => {
-                    let (start_date, day_of_week) =
-                        roll_start_date(start_date, roll, &week_mask, &holidays)?;
+                    let (start_date, day_of_week) = roll_start_date(start_date, roll, calendar)?;
                     Ok::<Option<i32>, PolarsError>(Some(add_business_days_impl(
                         start_date,
                         day_of_week,
                         n,
-                        &week_mask,
+                        calendar,
                         n_business_days_in_week_mask,
-                        &holidays,
                     )))
                 },
                 _ => Ok(None),
@@ -265,16 +417,70 @@ pub fn add_business_days(
     Ok(out.into_date().into_series())
 }
 
+/// Whether the dates (or the local dates of datetimes) are business days.
+pub fn is_business_day(dates: &Series, calendar: &BusinessCalendar) -> PolarsResult<Series> {
+    map_local_dates(dates, calendar, |date| calendar.is_business_day(date))
+}
+
+/// Whether the dates (or the local dates of datetimes) are half-days of `calendar`.
+pub fn is_half_day(dates: &Series, calendar: &BusinessCalendar) -> PolarsResult<Series> {
+    map_local_dates(dates, calendar, |date| calendar.is_half_day(date))
+}
+
+fn map_local_dates(
+    dates: &Series,
+    calendar: &BusinessCalendar,
+    f: impl Fn(i32) -> bool,
+) -> PolarsResult<Series> {
+    calendar.validate()?;
+    let dates = match dates.dtype() {
+        DataType::Date => dates.clone(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => dates.cast(&DataType::Date)?,
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => replace_time_zone(
+            dates.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Date)?,
+        dt => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dt),
+    };
+    let out: BooleanChunked = dates
+        .date()?
+        .apply_nonnull_values_generic(DataType::Boolean, f);
+    Ok(out.into_series())
+}
+
+/// Move dates that aren't business days onto one, keeping the time of day of datetimes.
+///
+/// `Roll::Raise` leaves business days untouched and raises on any other date.
+pub fn roll_business_day(
+    dates: &Series,
+    calendar: &BusinessCalendar,
+    roll: Roll,
+) -> PolarsResult<Series> {
+    calendar.validate()?;
+    apply_on_dates(dates, |dates| {
+        let out: Int32Chunked = dates.date()?.try_apply_nonnull_values_generic(|date| {
+            roll_start_date(date, roll, calendar).map(|(date, _)| date)
+        })?;
+        Ok(out.into_date().into_series())
+    })
+}
+
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L265-L353
 fn add_business_days_impl(
     mut date: i32,
     mut day_of_week: usize,
     mut n: i32,
-    week_mask: &[bool; 7],
+    calendar: &BusinessCalendar,
     n_business_days_in_week_mask: i32,
-    holidays: &[i32],
 ) -> i32 {
+    let week_mask = &calendar.week_mask;
+    let holidays = calendar.holidays.as_slice();
     if n > 0 {
         let holidays_begin = find_first_ge_index(holidays, date);
         date += (n / n_business_days_in_week_mask) * 7;
@@ -319,14 +525,15 @@ fn add_business_days_impl(
 fn roll_start_date(
     mut date: i32,
     roll: Roll,
-    week_mask: &[bool; 7],
-    holidays: &[i32],
+    calendar: &BusinessCalendar,
 ) -> PolarsResult<(i32, usize)> {
+    let week_mask = &calendar.week_mask;
+    let is_holiday = |date: i32| calendar.holidays.binary_search(&date).is_ok();
     let mut day_of_week = get_day_of_week(date);
     match roll {
         Roll::Raise => {
             // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-            if holidays.contains(&date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
+            if is_holiday(date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
                 let date = DateTime::from_timestamp(date as i64 * SECONDS_IN_DAY, 0)
                     .unwrap()
                     .format("%Y-%m-%d");
@@ -337,14 +544,14 @@ fn roll_start_date(
         },
         Roll::Forward => {
             // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-            while holidays.contains(&date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
+            while is_holiday(date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
                 date += 1;
                 day_of_week = increment_day_of_week(day_of_week);
             }
         },
         Roll::Backward => {
             // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-            while holidays.contains(&date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
+            while is_holiday(date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
                 date -= 1;
                 day_of_week = decrement_day_of_week(day_of_week);
             }
@@ -5,10 +5,9 @@ use arrow::bitmap::MutableBitmap;
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::export::num::{NumCast, Zero};
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
-use super::{linear_itp, nearest_itp};
+use super::spline::interpolate_spline;
+use super::{apply_limits, linear_itp, nearest_itp, InterpolateOptions, InterpolationMethod};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -191,28 +190,30 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
-}
-
-pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
-    match method {
+pub fn interpolate(s: &Series, options: impl Into<InterpolateOptions>) -> PolarsResult<Series> {
+    let options = options.into();
+    let filled = match options.method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
-    }
+        InterpolationMethod::Previous => s.fill_null(FillNullStrategy::Forward(None))?,
+        InterpolationMethod::Next => s.fill_null(FillNullStrategy::Backward(None))?,
+        InterpolationMethod::Cubic | InterpolationMethod::Pchip => {
+            let x: Vec<f64> = (0..s.len()).map(|i| i as f64).collect();
+            interpolate_spline(s, &x, options.method)
+        },
+    };
+    apply_limits(s, filled, &options)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::series::InterpolationLimitArea;
 
     #[test]
     fn test_interpolate() {
         let ca = UInt32Chunked::new("".into(), &[Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -220,7 +221,7 @@ mod test {
         );
 
         let ca = UInt32Chunked::new("".into(), &[None, Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -231,7 +232,7 @@ mod test {
             "".into(),
             &[None, Some(1), None, None, Some(4), Some(5), None],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -249,7 +250,7 @@ mod test {
             "".into(),
             &[None, Some(1), None, None, Some(4), Some(5), None],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -260,7 +261,7 @@ mod test {
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("".into(), &[Some(4), None, None, Some(1)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -288,7 +289,7 @@ mod test {
                 Some(4660f32),
             ],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f32().unwrap();
 
         assert_eq!(
@@ -310,4 +311,77 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_interpolate_previous_next_limit() {
+        let s = Int32Chunked::new("".into(), &[None, Some(1), None, None, None, Some(5), None])
+            .into_series();
+
+        let out = interpolate(&s, InterpolationMethod::Previous).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[None, Some(1), Some(1), Some(1), Some(1), Some(5), None]
+        );
+
+        let options = InterpolateOptions {
+            method: InterpolationMethod::Next,
+            limit: Some(2),
+            limit_area: InterpolationLimitArea::Both,
+        };
+        let out = interpolate(&s, options).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(1), None, Some(5), Some(5), Some(5), None]
+        );
+
+        let options = InterpolateOptions {
+            method: InterpolationMethod::Linear,
+            limit: Some(1),
+            limit_area: InterpolationLimitArea::Inside,
+        };
+        let out = interpolate(&s, options).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(1.0), Some(2.0), None, None, Some(5.0), None]
+        );
+
+        let options = InterpolateOptions {
+            method: InterpolationMethod::Linear,
+            limit: None,
+            limit_area: InterpolationLimitArea::Outside,
+        };
+        let out = interpolate(&s, options).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(1.0), None, None, None, Some(5.0), Some(5.0)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_spline() {
+        let s =
+            Float64Chunked::new("".into(), &[Some(0.0), Some(1.0), None, Some(9.0)]).into_series();
+
+        let out = interpolate(&s, InterpolationMethod::Cubic).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.0), Some(1.0), Some(4.25), Some(9.0)]
+        );
+
+        let out = interpolate(&s, InterpolationMethod::Pchip).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.0), Some(1.0), Some(3.875), Some(9.0)]
+        );
+
+        // With two valid values both splines reduce to a straight line.
+        let s = Int64Chunked::new("".into(), &[Some(0), None, Some(2)]).into_series();
+        for method in [InterpolationMethod::Cubic, InterpolationMethod::Pchip] {
+            let out = interpolate(&s, method).unwrap();
+            assert_eq!(
+                Vec::from(out.f64().unwrap()),
+                &[Some(0.0), Some(1.0), Some(2.0)]
+            );
+        }
+    }
 }
@@ -7,7 +7,8 @@ use polars_core::export::num::{NumCast, Zero};
 use polars_core::prelude::*;
 use polars_utils::slice::SliceAble;
 
-use super::linear_itp;
+use super::spline::interpolate_spline;
+use super::{apply_limits, linear_itp, InterpolateOptions, InterpolationMethod};
 
 /// # Safety
 /// - `x` must be non-empty.
@@ -263,7 +264,7 @@ where
     }
 }
 
-pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
+fn interpolate_by_linear(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
     polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
 
     fn func<T, F>(
@@ -327,13 +328,15 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
             func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_by_linear(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_by_linear(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_by_linear(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
@@ -342,3 +345,74 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
         },
     }
 }
+
+pub fn interpolate_by(
+    s: &Column,
+    by: &Column,
+    by_is_sorted: bool,
+    options: impl Into<InterpolateOptions>,
+) -> PolarsResult<Column> {
+    let options = options.into();
+    if options == InterpolateOptions::default() {
+        return interpolate_by_linear(s, by, by_is_sorted);
+    }
+    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+    polars_ensure!(
+        by.dtype().is_numeric() || matches!(by.dtype(), DataType::Date | DataType::Datetime(_, _)),
+        InvalidOperation: "expected `by` to be Date, Datetime or numeric, got {}", by.dtype()
+    );
+
+    // Gaps and limits are defined along `by`, so work on the sorted data and restore the order
+    // afterwards.
+    if !by_is_sorted {
+        let sorting_indices = by.as_materialized_series().arg_sort(Default::default());
+        let out = interpolate_by(
+            &s.take(&sorting_indices)?,
+            &by.take(&sorting_indices)?,
+            true,
+            options,
+        )?;
+        return out.take(&sorting_indices.arg_sort(Default::default()));
+    }
+
+    let series = s.as_materialized_series();
+    let x = by.to_physical_repr().cast(&DataType::Float64)?;
+    let x = x.f64()?.rechunk();
+    let x = x.cont_slice()?;
+    let filled = match options.method {
+        InterpolationMethod::Linear => interpolate_by_linear(s, by, true)?
+            .as_materialized_series()
+            .clone(),
+        InterpolationMethod::Nearest => nearest_by(series, x)?,
+        InterpolationMethod::Previous => series.fill_null(FillNullStrategy::Forward(None))?,
+        InterpolationMethod::Next => series.fill_null(FillNullStrategy::Backward(None))?,
+        InterpolationMethod::Cubic | InterpolationMethod::Pchip => {
+            interpolate_spline(series, x, options.method)
+        },
+    };
+    apply_limits(series, filled, &options).map(Column::from)
+}
+
+/// Take every null between two valid values from whichever of the two is closest in `x`.
+fn nearest_by(s: &Series, x: &[f64]) -> PolarsResult<Series> {
+    let mut indices: Vec<IdxSize> = (0..s.len() as IdxSize).collect();
+    let mut low = None;
+    for (high, valid) in s.is_not_null().into_no_null_iter().enumerate() {
+        if !valid {
+            continue;
+        }
+        if let Some(low) = low {
+            for i in low + 1..high {
+                // Ties go to the next value, as in `interpolate`.
+                indices[i] = if x[i] - x[low] < x[high] - x[i] {
+                    low
+                } else {
+                    high
+                } as IdxSize;
+            }
+        }
+        low = Some(high);
+    }
+    s.take(&IdxCa::from_vec(PlSmallStr::EMPTY, indices))
+}
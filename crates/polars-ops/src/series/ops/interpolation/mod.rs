@@ -1,8 +1,147 @@
 use std::ops::{Add, Div, Mul, Sub};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[cfg(feature = "interpolate")]
 pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
+mod spline;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationMethod {
+    #[default]
+    Linear,
+    Nearest,
+    /// Fill with the last valid value before the gap.
+    Previous,
+    /// Fill with the first valid value after the gap.
+    Next,
+    /// Natural cubic spline through the valid values.
+    Cubic,
+    /// Monotone piecewise cubic Hermite interpolation, which does not overshoot the data.
+    Pchip,
+}
+
+/// Which nulls an interpolation is allowed to fill.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationLimitArea {
+    /// Only fill nulls that are surrounded by valid values.
+    #[default]
+    Inside,
+    /// Only fill nulls before the first or after the last valid value.
+    Outside,
+    /// Fill nulls both inside and outside the valid values.
+    Both,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InterpolateOptions {
+    pub method: InterpolationMethod,
+    /// Maximum number of consecutive nulls to fill. The remainder of a longer gap stays null.
+    pub limit: Option<IdxSize>,
+    pub limit_area: InterpolationLimitArea,
+}
+
+impl From<InterpolationMethod> for InterpolateOptions {
+    fn from(method: InterpolationMethod) -> Self {
+        Self {
+            method,
+            ..Default::default()
+        }
+    }
+}
+
+/// Extend `filled` past the first and last valid values if the options ask for it, and null out
+/// every originally missing value of `original` the options do not allow to be filled.
+///
+/// Nulls before the first valid value get the first valid value, nulls after the last valid value
+/// get the last one. `Previous` and `Next` only fill in their own direction. A `limit` counts from
+/// the valid value the fill is taken from.
+fn apply_limits(
+    original: &Series,
+    filled: Series,
+    options: &InterpolateOptions,
+) -> PolarsResult<Series> {
+    let InterpolateOptions {
+        method,
+        limit,
+        limit_area,
+    } = *options;
+    let len = original.len();
+    if !original.has_nulls() || original.null_count() == len {
+        return Ok(filled);
+    }
+    let directional = matches!(
+        method,
+        InterpolationMethod::Previous | InterpolationMethod::Next
+    );
+    // `Previous` and `Next` already filled their side of the boundary.
+    let filled = if limit_area != InterpolationLimitArea::Inside
+        && !directional
+        && is_interpolatable(original.dtype())
+    {
+        filled
+            .fill_null(FillNullStrategy::Forward(None))?
+            .fill_null(FillNullStrategy::Backward(None))?
+    } else {
+        filled
+    };
+
+    let needs_mask = limit.is_some()
+        || match limit_area {
+            InterpolationLimitArea::Inside => directional,
+            InterpolationLimitArea::Outside => true,
+            InterpolationLimitArea::Both => false,
+        };
+    if !needs_mask {
+        return Ok(filled);
+    }
+
+    let mut keep: Vec<bool> = original.is_not_null().into_no_null_iter().collect();
+    let mut i = 0;
+    while i < len {
+        if keep[i] {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < len && !keep[i] {
+            i += 1;
+        }
+        let end = i;
+        let inside = start > 0 && end < len;
+        let allowed = match limit_area {
+            InterpolationLimitArea::Inside => inside,
+            InterpolationLimitArea::Outside => !inside,
+            InterpolationLimitArea::Both => true,
+        };
+        if !allowed {
+            continue;
+        }
+        let n = limit.map_or(end - start, |limit| (limit as usize).min(end - start));
+        if start == 0 || (method == InterpolationMethod::Next && end < len) {
+            keep[end - n..end].fill(true);
+        } else {
+            keep[start..start + n].fill(true);
+        }
+    }
+    let mask = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+    filled.zip_with(
+        &mask,
+        &Series::full_null(filled.name().clone(), len, filled.dtype()),
+    )
+}
+
+/// Whether values of this type can be computed from their neighbours.
+fn is_interpolatable(dtype: &DataType) -> bool {
+    dtype.is_numeric() || dtype.is_temporal()
+}
 
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
@@ -0,0 +1,167 @@
+use polars_core::prelude::*;
+
+use super::{is_interpolatable, InterpolationMethod};
+
+/// Interpolate the nulls between the valid values of `s` with a piecewise cubic, where `x` holds
+/// the (ascending) coordinate of every value.
+///
+/// Numeric input produces floats, temporal input is cast back to its logical type.
+pub(super) fn interpolate_spline(s: &Series, x: &[f64], method: InterpolationMethod) -> Series {
+    let logical = s.dtype();
+    if !is_interpolatable(logical) {
+        return s.clone();
+    }
+    let y = s.to_physical_repr().cast(&DataType::Float64).unwrap();
+    let out = spline_impl(y.f64().unwrap(), x, method).into_series();
+    if logical.is_temporal() || logical == &DataType::Float32 {
+        out.cast(logical).unwrap()
+    } else {
+        out
+    }
+}
+
+fn spline_impl(ca: &Float64Chunked, x: &[f64], method: InterpolationMethod) -> Float64Chunked {
+    debug_assert_eq!(ca.len(), x.len());
+    let mut knots = Vec::new();
+    let mut knot_x = Vec::new();
+    let mut knot_y = Vec::new();
+    for (i, opt_v) in ca.iter().enumerate() {
+        if let Some(v) = opt_v {
+            knots.push(i);
+            knot_x.push(x[i]);
+            knot_y.push(v);
+        }
+    }
+    if knots.len() < 2 || knots.len() == ca.len() {
+        return ca.clone();
+    }
+
+    let slopes = match method {
+        InterpolationMethod::Cubic => natural_spline_slopes(&knot_x, &knot_y),
+        InterpolationMethod::Pchip => pchip_slopes(&knot_x, &knot_y),
+        _ => unreachable!(),
+    };
+
+    let mut out: Vec<Option<f64>> = ca.iter().collect();
+    for k in 0..knots.len() - 1 {
+        let h = knot_x[k + 1] - knot_x[k];
+        for i in knots[k] + 1..knots[k + 1] {
+            let t = (x[i] - knot_x[k]) / h;
+            out[i] = Some(hermite(
+                t,
+                h,
+                knot_y[k],
+                knot_y[k + 1],
+                slopes[k],
+                slopes[k + 1],
+            ));
+        }
+    }
+    Float64Chunked::from_iter_options(ca.name().clone(), out.into_iter())
+}
+
+/// Evaluate the cubic Hermite polynomial on `[x_k, x_k + h]` at relative position `t`.
+#[inline]
+fn hermite(t: f64, h: f64, y0: f64, y1: f64, d0: f64, d1: f64) -> f64 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+        + (t3 - 2.0 * t2 + t) * h * d0
+        + (-2.0 * t3 + 3.0 * t2) * y1
+        + (t3 - t2) * h * d1
+}
+
+fn secants(x: &[f64], y: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+    let delta = y
+        .windows(2)
+        .zip(&h)
+        .map(|(w, h)| (w[1] - w[0]) / h)
+        .collect();
+    (h, delta)
+}
+
+/// First derivatives at the knots of the natural cubic spline (zero second derivative at both
+/// ends), found by solving the tridiagonal system for the second derivatives.
+fn natural_spline_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let (h, delta) = secants(x, y);
+    if n == 2 {
+        return vec![delta[0]; 2];
+    }
+
+    // Thomas algorithm over the interior knots.
+    let m = n - 2;
+    let mut c_prime = vec![0.0; m];
+    let mut d_prime = vec![0.0; m];
+    for j in 0..m {
+        let a = h[j];
+        let b = 2.0 * (h[j] + h[j + 1]);
+        let c = h[j + 1];
+        let d = 6.0 * (delta[j + 1] - delta[j]);
+        if j == 0 {
+            c_prime[j] = c / b;
+            d_prime[j] = d / b;
+        } else {
+            let denom = b - a * c_prime[j - 1];
+            c_prime[j] = c / denom;
+            d_prime[j] = (d - a * d_prime[j - 1]) / denom;
+        }
+    }
+    let mut second = vec![0.0; n];
+    for j in (0..m).rev() {
+        second[j + 1] = d_prime[j] - c_prime[j] * second[j + 2];
+    }
+
+    let mut slopes = Vec::with_capacity(n);
+    for i in 0..n - 1 {
+        slopes.push(delta[i] - h[i] * (2.0 * second[i] + second[i + 1]) / 6.0);
+    }
+    slopes.push(delta[n - 2] + h[n - 2] * (second[n - 2] + 2.0 * second[n - 1]) / 6.0);
+    slopes
+}
+
+/// First derivatives at the knots following Fritsch-Carlson: a weighted harmonic mean of the
+/// neighbouring secants, or zero at local extrema, which keeps the interpolant monotone.
+fn pchip_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let (h, delta) = secants(x, y);
+    if n == 2 {
+        return vec![delta[0]; 2];
+    }
+
+    let mut slopes = vec![0.0; n];
+    for k in 1..n - 1 {
+        if sign(delta[k - 1]) * sign(delta[k]) > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            slopes[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+        }
+    }
+    slopes[0] = pchip_end_slope(h[0], h[1], delta[0], delta[1]);
+    slopes[n - 1] = pchip_end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    slopes
+}
+
+/// One-sided three-point estimate, clamped so the end interval stays shape preserving.
+fn pchip_end_slope(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+    let d = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+    if sign(d) != sign(delta0) {
+        0.0
+    } else if sign(delta0) != sign(delta1) && d.abs() > 3.0 * delta0.abs() {
+        3.0 * delta0
+    } else {
+        d
+    }
+}
+
+#[inline]
+fn sign(v: f64) -> f64 {
+    if v > 0.0 {
+        1.0
+    } else if v < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use polars_core::prelude::*;
 
 pub fn negate(s: &Series) -> PolarsResult<Series> {
@@ -9,6 +11,8 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
         Int16 => s.i16().unwrap().wrapping_neg().into_series(),
         Int32 => s.i32().unwrap().wrapping_neg().into_series(),
         Int64 => s.i64().unwrap().wrapping_neg().into_series(),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => s.i128().unwrap().wrapping_neg().into_series(),
         Float32 => s.f32().unwrap().wrapping_neg().into_series(),
         Float64 => s.f64().unwrap().wrapping_neg().into_series(),
         #[cfg(feature = "dtype-decimal")]
@@ -27,6 +31,19 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let ca = s.interval().unwrap();
+            let out: Int128Chunked = ca.physical().apply_values(|v| {
+                let v = i128_to_interval(v);
+                interval_to_i128(months_days_ns::new(
+                    v.months().wrapping_neg(),
+                    v.days().wrapping_neg(),
+                    v.ns().wrapping_neg(),
+                ))
+            });
+            out.into_interval().into_series()
+        },
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
@@ -10,10 +10,10 @@ use {
 use crate::series::ops::SeriesSealed;
 
 #[cfg(feature = "moment")]
-fn rolling_skew<T>(
+fn rolling_moment<T>(
     ca: &ChunkedArray<T>,
     window_size: usize,
-    bias: bool,
+    moment: &dyn Fn(&Series) -> Option<f64>,
 ) -> PolarsResult<ChunkedArray<T>>
 where
     ChunkedArray<T>: IntoSeries,
@@ -27,36 +27,55 @@ where
             // SAFETY: dtype is correct.
             unsafe {
                 us.with_array(arr, |us| {
-                    us.as_ref()
-                        .skew(bias)
-                        .unwrap()
-                        .map(|flt| T::Native::from_f64(flt).unwrap())
+                    moment(us.as_ref()).map(|flt| T::Native::from_f64(flt).unwrap())
                 })
             }
         })
     })
 }
 
+#[cfg(feature = "moment")]
+fn rolling_moment_series(
+    s: &Series,
+    window_size: usize,
+    name: &str,
+    moment: &dyn Fn(&Series) -> Option<f64>,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Float64 => {
+            let ca = s.f64().unwrap();
+            rolling_moment(ca, window_size, moment).map(|ca| ca.into_series())
+        },
+        DataType::Float32 => {
+            let ca = s.f32().unwrap();
+            rolling_moment(ca, window_size, moment).map(|ca| ca.into_series())
+        },
+        dt if dt.is_numeric() => {
+            let s = s.cast(&DataType::Float64).unwrap();
+            rolling_moment_series(&s, window_size, name, moment)
+        },
+        dt => polars_bail!(op = format!("`{name}`"), dt),
+    }
+}
+
 pub trait RollingSeries: SeriesSealed {
     #[cfg(feature = "moment")]
     fn rolling_skew(&self, window_size: usize, bias: bool) -> PolarsResult<Series> {
-        let s = self.as_series();
+        rolling_moment_series(self.as_series(), window_size, "rolling_skew", &|s| {
+            s.skew(bias).unwrap()
+        })
+    }
 
-        match s.dtype() {
-            DataType::Float64 => {
-                let ca = s.f64().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            DataType::Float32 => {
-                let ca = s.f32().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            dt if dt.is_numeric() => {
-                let s = s.cast(&DataType::Float64).unwrap();
-                s.rolling_skew(window_size, bias)
-            },
-            dt => polars_bail!(opq = rolling_skew, dt),
-        }
+    #[cfg(feature = "moment")]
+    fn rolling_kurtosis(
+        &self,
+        window_size: usize,
+        fisher: bool,
+        bias: bool,
+    ) -> PolarsResult<Series> {
+        rolling_moment_series(self.as_series(), window_size, "rolling_kurtosis", &|s| {
+            s.kurtosis(fisher, bias).unwrap()
+        })
     }
 }
 
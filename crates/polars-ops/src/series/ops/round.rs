@@ -37,31 +37,22 @@ pub trait RoundSeries: SeriesSealed {
         }
         #[cfg(feature = "dtype-decimal")]
         if let Some(ca) = s.try_decimal() {
-            let precision = ca.precision();
             let scale = ca.scale() as u32;
             if scale <= decimals {
                 return Ok(ca.clone().into_series());
             }
 
-            let decimal_delta = scale - decimals;
-            let multiplier = 10i128.pow(decimal_delta);
-            let threshold = multiplier / 2;
-
+            // Like SQL, round half away from zero and drop the rounded-off digits from the scale.
+            let DataType::Decimal(precision, Some(new_scale)) =
+                decimal_round_dtype(ca.precision(), ca.scale(), decimals)
+            else {
+                unreachable!()
+            };
+            let divisor = 10i128.pow(scale - decimals);
             let ca = ca
-                .apply_values(|v| {
-                    // We use rounding=ROUND_HALF_EVEN
-                    let rem = v % multiplier;
-                    let is_v_floor_even = ((v - rem) / multiplier) % 2 == 0;
-                    let threshold = threshold + i128::from(is_v_floor_even);
-                    let round_offset = if rem.abs() >= threshold {
-                        multiplier
-                    } else {
-                        0
-                    };
-                    let round_offset = if v < 0 { -round_offset } else { round_offset };
-                    v - rem + round_offset
-                })
-                .into_decimal_unchecked(precision, scale as usize);
+                .physical()
+                .apply_values(|v| decimal_div_round(v, divisor).unwrap())
+                .into_decimal_unchecked(precision, new_scale);
 
             return Ok(ca.into_series());
         }
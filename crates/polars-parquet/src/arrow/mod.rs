@@ -5,4 +5,5 @@ pub mod write;
 #[cfg_attr(docsrs, doc(cfg(feature = "bloom_filter")))]
 pub use crate::parquet::bloom_filter;
 
-const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The key under which the arrow schema is stored in the key/value metadata of a file.
+pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
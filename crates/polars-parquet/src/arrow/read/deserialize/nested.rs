@@ -156,6 +156,30 @@ pub fn columns_to_iter_recursive(
             .collect_n(filter)
             .map(|(s, a)| (s, Box::new(a) as Box<_>))?
         },
+        Primitive(Float16) => {
+            init.push(InitNested::Primitive(field.is_nullable));
+            types.pop();
+            let (nested, array) = PageNestedDecoder::new(
+                columns.pop().unwrap(),
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init,
+            )?
+            .collect_n(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(2)
+                .map(|v| arrow::types::f16::from_le_bytes([v[0], v[1]]))
+                .collect::<Vec<_>>();
+            let array: Box<dyn Array> = Box::new(PrimitiveArray::new(
+                field.dtype().clone(),
+                values.into(),
+                array.validity().cloned(),
+            ));
+
+            (nested, array)
+        },
         Primitive(Float32) => {
             init.push(InitNested::Primitive(field.is_nullable));
             types.pop();
@@ -1,7 +1,7 @@
 use arrow::array::{Array, DictionaryArray, DictionaryKey, FixedSizeBinaryArray, PrimitiveArray};
 use arrow::datatypes::{ArrowDataType, IntervalUnit, TimeUnit};
 use arrow::match_integer_type;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, i256, months_days_ns, NativeType};
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
 
@@ -142,6 +142,28 @@ pub fn page_iter_to_array(
                 validity,
             )?)
         },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            let n = 16;
+            let array = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+            )?
+            .collect_n(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(n)
+                .map(super::super::convert_months_days_ns)
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            Box::new(PrimitiveArray::<months_days_ns>::try_new(
+                dtype.clone(),
+                values.into(),
+                validity,
+            )?)
+        },
         (PhysicalType::Int32, Decimal(_, _)) => Box::new(PageDecoder::new(
             pages,
             dtype,
@@ -184,6 +206,29 @@ pub fn page_iter_to_array(
                 validity,
             )?)
         },
+        (PhysicalType::FixedLenByteArray(16), Int128 | UInt128) => {
+            // 128-bit integers are stored as big-endian 16-byte values.
+            let array = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(16),
+                fixed_size_binary::BinaryDecoder { size: 16 },
+            )?
+            .collect_n(filter)?;
+            let validity = array.validity().cloned();
+            let values = array.values().chunks_exact(16);
+
+            if matches!(dtype.to_logical_type(), Int128) {
+                let values = values
+                    .map(|value| i128::from_be_bytes(value.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                Box::new(PrimitiveArray::<i128>::try_new(dtype, values.into(), validity)?)
+            } else {
+                let values = values
+                    .map(|value| u128::from_be_bytes(value.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                Box::new(PrimitiveArray::<u128>::try_new(dtype, values.into(), validity)?)
+            }
+        },
         (PhysicalType::Int32, Decimal256(_, _)) => Box::new(PageDecoder::new(
             pages,
             dtype,
@@ -277,7 +322,7 @@ pub fn page_iter_to_array(
         .collect_n(filter)?),
 
         // Float16
-        (PhysicalType::FixedLenByteArray(2), Float32) => {
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
             let mut fsb_array = PageDecoder::new(
@@ -294,11 +339,10 @@ pub fn page_iter_to_array(
             let values = values.map(|v| {
                 // SAFETY: We know that `v` is always of size two.
                 let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
-                let v = arrow::types::f16::from_le_bytes(le_bytes);
-                v.to_f32()
+                arrow::types::f16::from_le_bytes(le_bytes)
             }).collect();
 
-            let array = PrimitiveArray::<f32>::new(dtype, values, validity);
+            let array = PrimitiveArray::<arrow::types::f16>::new(dtype, values, validity);
 
             Box::new(array)
         },
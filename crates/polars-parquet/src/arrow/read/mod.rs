@@ -17,17 +17,20 @@ use futures::{AsyncRead, AsyncSeek};
 use polars_error::PolarsResult;
 pub use schema::{infer_schema, FileMetadata};
 
+pub use super::ARROW_SCHEMA_META_KEY;
 use crate::parquet::error::ParquetResult;
 #[cfg(feature = "async")]
 pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_metadata_async};
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
+    encryption::{DecryptionKeyRetriever, FileDecryptionProperties},
     error::ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
-        decompress, get_column_iterator, read_metadata as _read_metadata, BasicDecompressor,
+        decompress, get_column_iterator, read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption, BasicDecompressor,
         MutStreamingIterator, PageReader, ReadColumnIterator, State,
     },
     schema::types::{
@@ -58,6 +61,15 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting it with `decryption` if the file is
+/// encrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_read_metadata_with_decryption(reader, decryption)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -73,6 +85,14 @@ fn convert_days_ms(value: &[u8]) -> arrow::types::days_ms {
     )
 }
 
+fn convert_months_days_ns(value: &[u8]) -> arrow::types::months_days_ns {
+    arrow::types::months_days_ns(
+        i32::from_le_bytes(value[0..4].try_into().unwrap()),
+        i32::from_le_bytes(value[4..8].try_into().unwrap()),
+        i64::from_le_bytes(value[8..16].try_into().unwrap()),
+    )
+}
+
 fn convert_i128(value: &[u8], n: usize) -> i128 {
     // Copy the fixed-size byte value to the start of a 16 byte stack
     // allocated buffer, then use an arithmetic right shift to fill in
@@ -35,20 +35,13 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
                 convert_field(field);
             }
         },
-        Float16 => dtype = Float32,
         Binary | LargeBinary => dtype = BinaryView,
         Utf8 | LargeUtf8 => dtype = Utf8View,
         Dictionary(_, ref mut dtype, _) | Extension(_, ref mut dtype, _) => {
             let dtype = dtype.as_mut();
             *dtype = convert_dtype(std::mem::take(dtype));
         },
-        Map(mut field, _ordered) => {
-            // Polars doesn't support Map.
-            // A map is physically a `List<Struct<K, V>>`
-            // So we read as list.
-            convert_field(field.as_mut());
-            dtype = LargeList(field);
-        },
+        Map(ref mut field, _) => convert_field(field.as_mut()),
         _ => {},
     }
 
@@ -1,9 +1,9 @@
 use arrow::array::*;
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_error::PolarsResult;
 
-use super::super::{convert_days_ms, convert_i128};
+use super::super::{convert_days_ms, convert_i128, convert_months_days_ns};
 use crate::arrow::read::convert_i256;
 use crate::parquet::statistics::FixedLenStatistics;
 
@@ -28,6 +28,27 @@ pub(super) fn push_i128(
     Ok(())
 }
 
+pub(super) fn push_u128(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<u128>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<u128>>()
+        .unwrap();
+
+    let convert = |x: &[u8]| u128::from_be_bytes(x.try_into().unwrap());
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert)));
+
+    Ok(())
+}
+
 pub(super) fn push_i256_with_i128(
     from: Option<&FixedLenStatistics>,
     n: usize,
@@ -140,3 +161,23 @@ pub(super) fn push_days_ms(
 
     Ok(())
 }
+
+pub(super) fn push_months_days_ns(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+        .unwrap();
+
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert_months_days_ns)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert_months_days_ns)));
+
+    Ok(())
+}
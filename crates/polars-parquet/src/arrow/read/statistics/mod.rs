@@ -8,6 +8,7 @@ use arrow::with_match_primitive_type_full;
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
 
+use crate::parquet::read::page_index::ColumnIndex;
 use crate::parquet::schema::types::{
     PhysicalType as ParquetPhysicalType, PrimitiveType as ParquetPrimitiveType,
 };
@@ -425,6 +426,9 @@ fn push(
         Interval(IntervalUnit::DayTime) => {
             fixlen::push_days_ms(rmap!(from, expect_as_fixedlen), min, max)
         },
+        Interval(IntervalUnit::MonthDayNano) => {
+            fixlen::push_months_days_ns(rmap!(from, expect_as_fixedlen), min, max)
+        },
         UInt8 => primitive::push(rmap!(from, expect_as_int32), min, max, |x: i32| Ok(x as u8)),
         UInt16 => primitive::push(
             rmap!(from, expect_as_int32),
@@ -502,6 +506,10 @@ fn push(
                 })
             }
         },
+        // Half-precision statistics are widened to `f32` by `cast_statistics`.
+        Float16 => primitive::push(rmap!(from, expect_as_float), min, max, |x: f32| {
+            Ok(f16::from_f32(x))
+        }),
         Float32 => primitive::push::<f32, f32, _>(rmap!(from, expect_as_float), min, max, Ok),
         Float64 => primitive::push::<f64, f64, _>(rmap!(from, expect_as_double), min, max, Ok),
         Decimal(_, _) => match physical_type {
@@ -519,6 +527,18 @@ fn push(
             },
             _ => unreachable!(),
         },
+        Int128 => match physical_type {
+            PPT::FixedLenByteArray(16) => {
+                fixlen::push_i128(rmap!(from, expect_as_fixedlen), 16, min, max)
+            },
+            other => polars_bail!(nyi = "Can't decode Int128 type from parquet type {other:?}"),
+        },
+        UInt128 => match physical_type {
+            PPT::FixedLenByteArray(16) => {
+                fixlen::push_u128(rmap!(from, expect_as_fixedlen), min, max)
+            },
+            other => polars_bail!(nyi = "Can't decode UInt128 type from parquet type {other:?}"),
+        },
         Decimal256(_, _) => match physical_type {
             PPT::Int32 => primitive::push(rmap!(from, expect_as_int32), min, max, |x: i32| {
                 Ok(i256(I256::new(x.into())))
@@ -558,7 +578,7 @@ pub fn cast_statistics(
     use {ArrowDataType as DT, PrimitiveLogicalType as PT};
 
     match (primitive_type.logical_type, output_type) {
-        (Some(PT::Float16), DT::Float32) => {
+        (Some(PT::Float16), DT::Float16 | DT::Float32) => {
             let statistics = statistics.expect_fixedlen();
 
             let primitive_type = primitive_type.clone();
@@ -614,3 +634,62 @@ pub fn deserialize<'a>(
 
     Ok(statistics.into())
 }
+
+/// Deserializes the page statistics in the [`ColumnIndex`] of `column` into [`Statistics`]
+/// associated from `field`'s name, with one value per data page.
+///
+/// `field` must not be nested, i.e. `column` must be its only column chunk.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_column_index(
+    field: &Field,
+    column: &ColumnChunkMetadata,
+    column_index: &ColumnIndex,
+) -> PolarsResult<Statistics> {
+    let mut statistics = MutableStatistics::try_new(field)?;
+
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages || column_index.max_values.len() != num_pages {
+        polars_bail!(ComputeError: "the column index of '{}' is out of specification", field.name);
+    }
+
+    let mut stats = (0..num_pages)
+        .map(|i| {
+            let is_null_page = column_index.null_pages[i];
+            let page_stats = crate::parquet::statistics::Statistics::deserialize(
+                &polars_parquet_format::Statistics {
+                    max: None,
+                    min: None,
+                    null_count: column_index
+                        .null_counts
+                        .as_ref()
+                        .and_then(|counts| counts.get(i).copied()),
+                    distinct_count: None,
+                    max_value: (!is_null_page).then(|| column_index.max_values[i].clone()),
+                    min_value: (!is_null_page).then(|| column_index.min_values[i].clone()),
+                    is_max_value_exact: None,
+                    is_min_value_exact: None,
+                },
+                primitive_type.clone(),
+            )?;
+            Ok((
+                Some(cast_statistics(page_stats, primitive_type, &field.dtype)),
+                primitive_type.clone(),
+            ))
+        })
+        .collect::<PolarsResult<VecDeque<(Option<ParquetStatistics>, ParquetPrimitiveType)>>>()?;
+
+    for _ in 0..num_pages {
+        push(
+            &mut stats,
+            statistics.min_value.as_mut(),
+            statistics.max_value.as_mut(),
+            statistics.distinct_count.as_mut(),
+            statistics.null_count.as_mut(),
+        )?;
+    }
+
+    Ok(statistics.into())
+}
@@ -5,7 +5,8 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{to_parquet_schema, ThriftFileMetadata, WriteOptions};
-use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
+use crate::parquet::encryption::FileEncryptionProperties;
+use crate::parquet::metadata::{KeyValue, SchemaDescriptor, SortingColumn};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
 /// Attaches [`ArrowSchema`] to `key_value_metadata`
@@ -70,11 +71,28 @@ impl<W: Write> FileWriter<W> {
         })
     }
 
+    /// Encrypts the file with parquet modular encryption.
+    pub fn with_encryption(mut self, encryption: FileEncryptionProperties) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(encryption)?;
+        Ok(self)
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIterColumns<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Adds a bloom filter for the leaf column `column` of the last written row group.
+    #[cfg(feature = "bloom_filter")]
+    pub fn add_bloom_filter(&mut self, column: usize, bitset: Vec<u8>) -> PolarsResult<()> {
+        Ok(self.writer.add_bloom_filter(column, bitset)?)
+    }
+
+    /// Sets the leaf columns by which the last written row group is sorted.
+    pub fn set_sorting_columns(&mut self, sorting_columns: Vec<SortingColumn>) -> PolarsResult<()> {
+        Ok(self.writer.set_sorting_columns(sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
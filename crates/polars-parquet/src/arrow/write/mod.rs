@@ -29,16 +29,18 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, f16, i256, months_days_ns, NativeType};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
 pub use utils::write_def_levels;
 
+pub use super::ARROW_SCHEMA_META_KEY;
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
+pub use crate::parquet::encryption::{ColumnEncryptionKey, FileEncryptionProperties};
 pub use crate::parquet::metadata::{
-    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, ThriftFileMetadata,
+    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, SortingColumn, ThriftFileMetadata,
 };
 pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::types::PrimitiveType as ParquetPrimitiveType;
@@ -279,12 +281,29 @@ pub fn get_max_length(nested: &[Nested]) -> usize {
     length
 }
 
+/// Converts a primitive array to a [`FixedSizeBinaryArray`] of its big-endian values.
+fn to_be_fixed_size_binary<T: NativeType>(array: &dyn Array) -> Box<dyn Array> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let size = size_of::<T>();
+    let mut values = Vec::<u8>::with_capacity(size * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(x.to_be_bytes().as_ref()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(size),
+        values.into(),
+        array.validity().cloned(),
+    )
+    .boxed()
+}
+
 /// Returns an iterator of [`Page`].
 pub fn array_to_pages(
     primitive_array: &dyn Array,
     type_: ParquetPrimitiveType,
     nested: &[Nested],
-    options: WriteOptions,
+    mut options: WriteOptions,
     mut encoding: Encoding,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_logical_type() {
@@ -298,6 +317,65 @@ pub fn array_to_pages(
             )
         });
     };
+    // Write 128-bit integers with the physical layout of their parquet type.
+    let converted;
+    let primitive_array = match primitive_array.dtype() {
+        ArrowDataType::Int128 => {
+            // Byte-wise min/max statistics don't follow the order of negative values.
+            options.statistics.min_value = false;
+            options.statistics.max_value = false;
+            converted = to_be_fixed_size_binary::<i128>(primitive_array);
+            converted.as_ref()
+        },
+        ArrowDataType::UInt128 => {
+            converted = to_be_fixed_size_binary::<u128>(primitive_array);
+            converted.as_ref()
+        },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = primitive_array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(16 * array.len());
+            array.values().iter().for_each(|x| {
+                values.extend_from_slice(&x.months().to_le_bytes());
+                values.extend_from_slice(&x.days().to_le_bytes());
+                values.extend_from_slice(&x.ns().to_le_bytes());
+            });
+            // Intervals have no total order, so min/max statistics can't be used for pruning.
+            options.statistics.min_value = false;
+            options.statistics.max_value = false;
+            converted = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(16),
+                values.into(),
+                array.validity().cloned(),
+            )
+            .boxed();
+            converted.as_ref()
+        },
+        ArrowDataType::Float16 => {
+            let array = primitive_array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(2 * array.len());
+            array
+                .values()
+                .iter()
+                .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+            // Byte-wise min/max statistics don't follow the order of the floats.
+            options.statistics.min_value = false;
+            options.statistics.max_value = false;
+            converted = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                values.into(),
+                array.validity().cloned(),
+            )
+            .boxed();
+            converted.as_ref()
+        },
+        _ => primitive_array,
+    };
     if let Encoding::RleDictionary = encoding {
         // Only take this path for primitive columns
         if matches!(nested.first(), Some(Nested::Primitive(_))) {
@@ -459,16 +537,22 @@ pub fn array_to_page_simple(
                 encoding,
             )
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            )
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            )
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
@@ -972,6 +1056,19 @@ fn array_to_page_nested(
                 fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
             }
         },
+        FixedSizeBinary(_) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::nested_array_to_page(array, options, type_, nested, statistics)
+        },
         other => polars_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -235,9 +235,11 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+            // The entries are written as the repeated `key_value` group, which holds the key and
+            // value columns directly. That group adds no definition level of its own.
+            let fields = if let ParquetType::GroupType { fields, .. } = type_ {
                 if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
+                    fields
                 } else {
                     polars_bail!(InvalidOperation:
                         "Parquet type must be a group for a map array",
@@ -248,13 +250,26 @@ fn to_nested_recursive(
                     "Parquet type must be a group for a map array",
                 )
             };
+            let entries = array
+                .field()
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            parents.push(Nested::Struct(StructNested {
+                is_optional: false,
+                validity: None,
+                length: entries.len(),
+            }));
+
+            for (type_, array) in fields.iter().zip(entries.values()) {
+                to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+            }
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
@@ -584,7 +599,7 @@ mod tests {
     use super::super::{FieldInfo, ParquetPhysicalType};
     use super::*;
     use crate::parquet::schema::types::{
-        GroupLogicalType, PrimitiveConvertedType, PrimitiveLogicalType,
+        GroupConvertedType, GroupLogicalType, PrimitiveConvertedType, PrimitiveLogicalType,
     };
     use crate::parquet::schema::Repetition;
 
@@ -885,12 +900,12 @@ mod tests {
 
         let type_ = ParquetType::GroupType {
             field_info: FieldInfo {
-                name: "kv".into(),
-                repetition: Repetition::Optional,
+                name: "key_value".into(),
+                repetition: Repetition::Repeated,
                 id: None,
             },
             logical_type: None,
-            converted_type: None,
+            converted_type: Some(GroupConvertedType::MapKeyValue),
             fields: vec![
                 ParquetType::PrimitiveType(ParquetPrimitiveType {
                     field_info: FieldInfo {
@@ -922,17 +937,8 @@ mod tests {
                 id: None,
             },
             logical_type: Some(GroupLogicalType::Map),
-            converted_type: None,
-            fields: vec![ParquetType::GroupType {
-                field_info: FieldInfo {
-                    name: "map".into(),
-                    repetition: Repetition::Repeated,
-                    id: None,
-                },
-                logical_type: None,
-                converted_type: None,
-                fields: vec![type_],
-            }],
+            converted_type: Some(GroupConvertedType::Map),
+            fields: vec![type_],
         };
 
         let a = to_nested(&array, &type_).unwrap();
@@ -946,7 +952,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
                 vec![
@@ -955,7 +961,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
             ]
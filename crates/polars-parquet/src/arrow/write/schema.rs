@@ -1,4 +1,4 @@
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::engine::general_purpose;
 use base64::Engine as _;
@@ -55,9 +55,9 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
             .map(|field| convert_field(field.clone()))
             .map(|x| (x.name.clone(), x))
             .collect();
-        schema_to_bytes(&schema, &default_ipc_fields(schema.iter_values()))
+        schema_to_bytes(&schema, &default_ipc_fields(schema.iter_values()), None)
     } else {
-        schema_to_bytes(schema, &default_ipc_fields(schema.iter_values()))
+        schema_to_bytes(schema, &default_ipc_fields(schema.iter_values()), None)
     };
 
     // manually prepending the length to the schema as arrow uses the legacy IPC format
@@ -129,6 +129,14 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        ArrowDataType::Float16 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(2),
+            repetition,
+            None,
+            Some(PrimitiveLogicalType::Float16),
+            None,
+        )?),
         ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::Float,
@@ -314,6 +322,19 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        // Parquet has no 128-bit integer types, so `Int128` and `UInt128` are written as
+        // plain 16-byte big-endian values. A `DECIMAL(38, 0)` cannot hold the full `i128`
+        // range.
+        //
+        // The parquet interval type only has millisecond precision, so `MonthDayNano`
+        // intervals are written as plain 16-byte values as well.
+        ArrowDataType::Int128
+        | ArrowDataType::UInt128
+        | ArrowDataType::Interval(IntervalUnit::MonthDayNano) => to_parquet_type(&Field::new(
+            name.clone(),
+            ArrowDataType::FixedSizeBinary(16),
+            field.is_nullable,
+        )),
         ArrowDataType::Decimal(precision, scale) => {
             let precision = *precision;
             let scale = *scale;
@@ -410,21 +431,31 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 None,
             ))
         },
-        ArrowDataType::Map(f, _) => Ok(ParquetType::from_group(
-            name,
-            repetition,
-            Some(GroupConvertedType::Map),
-            Some(GroupLogicalType::Map),
-            vec![ParquetType::from_group(
-                PlSmallStr::from_static("map"),
-                Repetition::Repeated,
-                None,
-                None,
-                vec![to_parquet_type(f)?],
+        ArrowDataType::Map(f, _) => {
+            // The repeated `key_value` group holds the key and value columns directly.
+            let ArrowDataType::Struct(entries) = f.dtype().to_logical_type() else {
+                polars_bail!(InvalidOperation: "map entries must be a struct, got {:?}", f.dtype())
+            };
+            let entries = entries
+                .iter()
+                .map(to_parquet_type)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    Some(GroupConvertedType::MapKeyValue),
+                    None,
+                    entries,
+                    None,
+                )],
                 None,
-            )],
-            None,
-        )),
+            ))
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     }
 }
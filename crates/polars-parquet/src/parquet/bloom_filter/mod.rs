@@ -2,10 +2,13 @@
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{deserialize, read};
 pub use split_block::{insert, is_in_set};
+pub(crate) use write::write_encrypted;
+pub use write::{optimal_num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +71,29 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn write_and_read() {
+        let mut bitset = vec![0; optimal_num_bytes(100, 0.01)];
+        assert_eq!(bitset.len(), 128);
+        for a in 0..100i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut buffer = vec![];
+        let length = write(&mut buffer, &bitset).unwrap();
+        assert_eq!(length as usize, buffer.len());
+
+        let mut prot = polars_parquet_format::thrift::protocol::TCompactInputProtocol::new(
+            buffer.as_slice(),
+            usize::MAX,
+        );
+        let header =
+            polars_parquet_format::BloomFilterHeader::read_from_in_protocol(&mut prot).unwrap();
+        assert_eq!(header.num_bytes as usize, bitset.len());
+
+        let mut read = vec![];
+        deserialize(&buffer, &mut read).unwrap();
+        assert_eq!(read, bitset);
+    }
 }
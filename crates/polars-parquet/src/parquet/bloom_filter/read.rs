@@ -10,17 +10,18 @@ use crate::parquet::error::ParquetResult;
 use crate::parquet::metadata::ColumnChunkMetadata;
 
 /// Reads the bloom filter associated to [`ColumnChunkMetadata`] into `bitset`.
-/// Results in an empty `bitset` if there is no associated bloom filter or the algorithm is not supported.
+/// Results in an empty `bitset` if there is no associated bloom filter, the algorithm is not
+/// supported or the column is encrypted.
 /// # Error
 /// Errors if the column contains no metadata or the filter can't be read or deserialized.
 pub fn read<R: Read + Seek>(
     column_metadata: &ColumnChunkMetadata,
-    mut reader: &mut R,
+    reader: &mut R,
     bitset: &mut Vec<u8>,
 ) -> ParquetResult<()> {
     let offset = column_metadata.metadata().bloom_filter_offset;
 
-    let offset = if let Some(offset) = offset {
+    let offset = if let Some(offset) = offset.filter(|_| !column_metadata.is_encrypted()) {
         offset as u64
     } else {
         bitset.clear();
@@ -28,6 +29,18 @@ pub fn read<R: Read + Seek>(
     };
     reader.seek(SeekFrom::Start(offset))?;
 
+    read_header_and_bitset(reader, bitset)
+}
+
+/// Deserializes a bloom filter from `bytes`, which start at the header of the filter, into `bitset`.
+/// Results in an empty `bitset` if the algorithm is not supported.
+/// # Error
+/// Errors if the filter can't be deserialized.
+pub fn deserialize(mut bytes: &[u8], bitset: &mut Vec<u8>) -> ParquetResult<()> {
+    read_header_and_bitset(&mut bytes, bitset)
+}
+
+fn read_header_and_bitset<R: Read>(mut reader: &mut R, bitset: &mut Vec<u8>) -> ParquetResult<()> {
     // deserialize header
     let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
     let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
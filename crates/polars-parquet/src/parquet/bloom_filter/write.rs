@@ -0,0 +1,62 @@
+use std::io::Write;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::encryption::{ChunkEncryptor, ModuleType};
+use crate::parquet::error::ParquetResult;
+
+/// The minimum size of a bitset: a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// The maximum size of a bitset, as recommended by the specification.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a bitset that holds `ndv` distinct values with a false positive
+/// probability of at most `fpp`.
+///
+/// The size is a power of two between 32 bytes and 128MiB.
+pub fn optimal_num_bytes(ndv: usize, fpp: f64) -> usize {
+    // From the specification: m = -8 * ndv / ln(1 - fpp ^ (1 / 8))
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
+fn header(bitset: &[u8]) -> ParquetResult<BloomFilterHeader> {
+    Ok(BloomFilterHeader::new(
+        bitset.len().try_into()?,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    ))
+}
+
+/// Writes the header and `bitset` of a split block bloom filter to `writer`.
+/// Returns the number of bytes written.
+pub fn write<W: Write>(mut writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let mut protocol = TCompactOutputProtocol::new(&mut writer);
+    let header_len = header(bitset)?.write_to_out_protocol(&mut protocol)?;
+    writer.write_all(bitset)?;
+    Ok((header_len + bitset.len()) as u64)
+}
+
+/// Writes the header and `bitset` of a split block bloom filter to `writer` as two encrypted
+/// modules. Returns the number of bytes written.
+pub(crate) fn write_encrypted<W: Write>(
+    writer: &mut W,
+    bitset: &[u8],
+    encryptor: ChunkEncryptor<'_>,
+) -> ParquetResult<u64> {
+    let mut plain_header = vec![];
+    header(bitset)?.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut plain_header))?;
+    let header = encryptor.encrypt(ModuleType::BloomFilterHeader, None, &plain_header)?;
+    let bitset = encryptor.encrypt(ModuleType::BloomFilterBitset, None, bitset)?;
+    writer.write_all(&header)?;
+    writer.write_all(&bitset)?;
+    Ok((header.len() + bitset.len()) as u64)
+}
@@ -0,0 +1,19 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes an iterator of values according to parquet's `BYTE_STREAM_SPLIT`.
+/// # Implementation
+/// The `k`-th byte of every value is written to the `k`-th of `size_of::<T>()` streams, which
+/// are laid out one after the other in `buffer`.
+pub fn encode<T: NativeType, I: ExactSizeIterator<Item = T>>(iterator: I, buffer: &mut Vec<u8>) {
+    let element_size = size_of::<T>();
+    let num_elements = iterator.len();
+    let start = buffer.len();
+    buffer.resize(start + num_elements * element_size, 0);
+
+    let streams = &mut buffer[start..];
+    for (i, v) in iterator.enumerate() {
+        for (n, byte) in v.to_le_bytes().into_iter().enumerate() {
+            streams[num_elements * n + i] = byte;
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! AES-GCM encryption of parquet modules.
+use polars_utils::mmap::MemReader;
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// Length of the nonce that precedes the ciphertext of a module.
+#[cfg(feature = "encryption")]
+pub(crate) const NONCE_LEN: usize = 12;
+/// Length of the authentication tag that follows the ciphertext of a module.
+#[cfg(feature = "encryption")]
+pub(crate) const TAG_LEN: usize = 16;
+/// Length of the little-endian length prefix of a module.
+pub(crate) const SIZE_LEN: usize = 4;
+
+/// An AES-GCM cipher with a 128 or 256 bit key.
+///
+/// Modules are encrypted as `length | nonce | ciphertext | tag`, where `length` is the
+/// little-endian length of the remainder of the module.
+pub(crate) struct AesGcm {
+    #[cfg(feature = "encryption")]
+    key: ring::aead::LessSafeKey,
+}
+
+impl std::fmt::Debug for AesGcm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AesGcm")
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl AesGcm {
+    pub fn try_new(key: &[u8]) -> ParquetResult<Self> {
+        use ring::aead::{LessSafeKey, UnboundKey, AES_128_GCM, AES_256_GCM};
+
+        let algorithm = match key.len() {
+            16 => &AES_128_GCM,
+            32 => &AES_256_GCM,
+            24 => {
+                return Err(ParquetError::not_supported(
+                    "AES-GCM encryption with 192 bit keys",
+                ))
+            },
+            len => {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "An encryption key must be 16, 24 or 32 bytes long, got {len} bytes"
+                )))
+            },
+        };
+        let key = UnboundKey::new(algorithm, key)
+            .map_err(|_| ParquetError::InvalidParameter("Invalid encryption key".to_string()))?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    /// Encrypts `plaintext` into a module with a random nonce.
+    pub fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        use ring::aead::{Aad, Nonce};
+
+        let mut nonce = [0u8; NONCE_LEN];
+        random_bytes(&mut nonce)?;
+
+        let module_len = NONCE_LEN + plaintext.len() + TAG_LEN;
+        let mut module = Vec::with_capacity(SIZE_LEN + module_len);
+        module.extend_from_slice(&u32::try_from(module_len)?.to_le_bytes());
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(plaintext);
+
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut module[SIZE_LEN + NONCE_LEN..],
+            )
+            .map_err(|_| ParquetError::oos("Failed to encrypt a module"))?;
+        module.extend_from_slice(tag.as_ref());
+        Ok(module)
+    }
+
+    /// Decrypts a module, including its length prefix.
+    pub fn decrypt(&self, module: &[u8], aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        use ring::aead::{Aad, Nonce};
+
+        if module.len() < SIZE_LEN + NONCE_LEN + TAG_LEN {
+            return Err(ParquetError::oos("An encrypted module is too short"));
+        }
+        let module_len = u32::from_le_bytes(module[..SIZE_LEN].try_into().unwrap()) as usize;
+        if module_len != module.len() - SIZE_LEN {
+            return Err(ParquetError::oos(
+                "The length of an encrypted module does not match its size",
+            ));
+        }
+        let nonce = Nonce::try_assume_unique_for_key(&module[SIZE_LEN..SIZE_LEN + NONCE_LEN])
+            .map_err(|_| ParquetError::oos("Invalid nonce of an encrypted module"))?;
+
+        let mut plaintext = module[SIZE_LEN + NONCE_LEN..].to_vec();
+        let len = self
+            .key
+            .open_in_place(nonce, Aad::from(aad), &mut plaintext)
+            .map_err(|_| {
+                ParquetError::oos("Failed to decrypt a module, the key or AAD prefix may be wrong")
+            })?
+            .len();
+        plaintext.truncate(len);
+        Ok(plaintext)
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+impl AesGcm {
+    pub fn try_new(_key: &[u8]) -> ParquetResult<Self> {
+        Err(ParquetError::FeatureNotActive(
+            crate::parquet::error::Feature::Encryption,
+            "encrypt or decrypt parquet files".to_string(),
+        ))
+    }
+
+    pub fn encrypt(&self, _plaintext: &[u8], _aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        unreachable!()
+    }
+
+    pub fn decrypt(&self, _module: &[u8], _aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        unreachable!()
+    }
+}
+
+/// Fills `buffer` with cryptographically secure random bytes.
+#[cfg(feature = "encryption")]
+pub(crate) fn random_bytes(buffer: &mut [u8]) -> ParquetResult<()> {
+    use ring::rand::{SecureRandom, SystemRandom};
+
+    SystemRandom::new()
+        .fill(buffer)
+        .map_err(|_| ParquetError::oos("Failed to generate random bytes"))
+}
+
+#[cfg(not(feature = "encryption"))]
+pub(crate) fn random_bytes(_buffer: &mut [u8]) -> ParquetResult<()> {
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "encrypt parquet files".to_string(),
+    ))
+}
+
+/// Returns the module, including its length prefix, at the start of `buffer`.
+pub(crate) fn module_at_start(buffer: &[u8]) -> ParquetResult<&[u8]> {
+    let len = buffer
+        .get(..SIZE_LEN)
+        .map(|prefix| u32::from_le_bytes(prefix.try_into().unwrap()) as usize);
+    len.and_then(|len| buffer.get(..SIZE_LEN + len))
+        .ok_or_else(|| ParquetError::oos("An encrypted module is truncated"))
+}
+
+/// Reads the next module, including its length prefix, from `reader`.
+pub(crate) fn read_module(reader: &mut MemReader) -> ParquetResult<Vec<u8>> {
+    let prefix = reader.read_slice(SIZE_LEN);
+    if prefix.len() != SIZE_LEN {
+        return Err(ParquetError::oos("An encrypted module is truncated"));
+    }
+    let len = u32::from_le_bytes(prefix[..].try_into().unwrap()) as usize;
+    let body = reader.read_slice(len);
+    if body.len() != len {
+        return Err(ParquetError::oos("An encrypted module is truncated"));
+    }
+    let mut module = Vec::with_capacity(SIZE_LEN + len);
+    module.extend_from_slice(&prefix);
+    module.extend_from_slice(&body);
+    Ok(module)
+}
@@ -0,0 +1,452 @@
+//! Parquet modular encryption with the `AES_GCM_V1` algorithm.
+//!
+//! Files are written in encrypted footer mode. Files in plaintext footer mode can be read, but
+//! their footer signature is not verified.
+mod cipher;
+mod properties;
+
+use std::sync::Arc;
+
+pub(crate) use cipher::read_module;
+use cipher::{module_at_start, random_bytes, AesGcm};
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    AesGcmV1, ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+pub use properties::{
+    ColumnEncryptionKey, DecryptionKeyRetriever, FileDecryptionProperties, FileEncryptionProperties,
+};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::ColumnDescriptor;
+
+/// Length of the unique part of the AAD of a file.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// The kinds of modules that are encrypted, which are part of their AAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+    #[cfg(feature = "bloom_filter")]
+    BloomFilterHeader = 8,
+    #[cfg(feature = "bloom_filter")]
+    BloomFilterBitset = 9,
+}
+
+fn ordinal(ordinal: usize, name: &str) -> ParquetResult<[u8; 2]> {
+    let ordinal = i16::try_from(ordinal).map_err(|_| {
+        ParquetError::oos(format!(
+            "Encrypted files can contain at most {} {name}s",
+            i16::MAX as usize + 1
+        ))
+    })?;
+    Ok(ordinal.to_le_bytes())
+}
+
+/// Returns the AAD of a module of a column chunk. Only (dictionary) pages and their headers have a
+/// page ordinal.
+fn module_aad(
+    file_aad: &[u8],
+    module: ModuleType,
+    row_group: usize,
+    column: usize,
+    page: Option<usize>,
+) -> ParquetResult<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module as u8);
+    aad.extend_from_slice(&ordinal(row_group, "row group")?);
+    aad.extend_from_slice(&ordinal(column, "column")?);
+    if let Some(page) = page {
+        aad.extend_from_slice(&ordinal(page, "page")?);
+    }
+    Ok(aad)
+}
+
+fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 1);
+    aad.extend_from_slice(file_aad);
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// Whether the key given for `column` applies to the column with `descriptor`.
+fn matches_column(column: &str, descriptor: &ColumnDescriptor) -> bool {
+    let path = &descriptor.path_in_schema;
+    column == path[0].as_str() || column == path.join(".")
+}
+
+/// Encrypts the modules of a file.
+pub(crate) struct FileEncryptor {
+    footer_cipher: AesGcm,
+    file_aad: Arc<[u8]>,
+    algorithm: EncryptionAlgorithm,
+    footer_key_metadata: Option<Vec<u8>>,
+    /// The encryptors of the leaf columns, which are `None` for columns that are not encrypted.
+    columns: Vec<Option<ColumnEncryptor>>,
+}
+
+impl FileEncryptor {
+    pub fn try_new(
+        properties: FileEncryptionProperties,
+        columns: &[ColumnDescriptor],
+    ) -> ParquetResult<Self> {
+        let footer_cipher = AesGcm::try_new(&properties.footer_key)?;
+        let mut aad_file_unique = vec![0; AAD_FILE_UNIQUE_LEN];
+        random_bytes(&mut aad_file_unique)?;
+
+        let prefix = properties.aad_prefix.as_deref().unwrap_or_default();
+        let file_aad: Arc<[u8]> = [prefix, &aad_file_unique].concat().into();
+        let algorithm = EncryptionAlgorithm::AESGCMV1(AesGcmV1::new(
+            properties
+                .aad_prefix
+                .clone()
+                .filter(|_| properties.store_aad_prefix),
+            aad_file_unique,
+            properties
+                .aad_prefix
+                .as_ref()
+                .map(|_| !properties.store_aad_prefix),
+        ));
+
+        for key in &properties.column_keys {
+            if !columns.iter().any(|c| matches_column(&key.column, c)) {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "The encryption key of column '{}' does not match any column",
+                    key.column
+                )));
+            }
+        }
+
+        let columns = if properties.column_keys.is_empty() {
+            let encryptor = ColumnEncryptor {
+                cipher: Arc::new(AesGcm::try_new(&properties.footer_key)?),
+                file_aad: file_aad.clone(),
+                crypto_metadata: ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                    EncryptionWithFooterKey {},
+                ),
+            };
+            vec![Some(encryptor); columns.len()]
+        } else {
+            columns
+                .iter()
+                .map(|descriptor| {
+                    let Some(key) = properties
+                        .column_keys
+                        .iter()
+                        .find(|key| matches_column(&key.column, descriptor))
+                    else {
+                        return Ok(None);
+                    };
+                    let path_in_schema = descriptor
+                        .path_in_schema
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect();
+                    Ok(Some(ColumnEncryptor {
+                        cipher: Arc::new(AesGcm::try_new(&key.key)?),
+                        file_aad: file_aad.clone(),
+                        crypto_metadata: ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                            EncryptionWithColumnKey::new(path_in_schema, key.key_metadata.clone()),
+                        ),
+                    }))
+                })
+                .collect::<ParquetResult<_>>()?
+        };
+
+        Ok(Self {
+            footer_cipher,
+            file_aad,
+            algorithm,
+            footer_key_metadata: properties.footer_key_metadata,
+            columns,
+        })
+    }
+
+    /// Returns the encryptor of a column chunk, or `None` if its column is not encrypted.
+    pub fn chunk(&self, row_group: usize, column: usize) -> Option<ChunkEncryptor<'_>> {
+        self.columns[column]
+            .as_ref()
+            .map(|encryptor| ChunkEncryptor {
+                encryptor,
+                row_group,
+                column,
+            })
+    }
+
+    /// The metadata that precedes the encrypted footer.
+    pub fn file_crypto_metadata(&self) -> FileCryptoMetaData {
+        FileCryptoMetaData::new(self.algorithm.clone(), self.footer_key_metadata.clone())
+    }
+
+    pub fn encrypt_footer(&self, footer: &[u8]) -> ParquetResult<Vec<u8>> {
+        self.footer_cipher
+            .encrypt(footer, &footer_aad(&self.file_aad))
+    }
+}
+
+/// Encrypts the modules of a leaf column.
+#[derive(Debug, Clone)]
+struct ColumnEncryptor {
+    cipher: Arc<AesGcm>,
+    file_aad: Arc<[u8]>,
+    crypto_metadata: ColumnCryptoMetaData,
+}
+
+/// Encrypts the modules of a single column chunk.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChunkEncryptor<'a> {
+    encryptor: &'a ColumnEncryptor,
+    row_group: usize,
+    column: usize,
+}
+
+impl ChunkEncryptor<'_> {
+    pub fn crypto_metadata(&self) -> &ColumnCryptoMetaData {
+        &self.encryptor.crypto_metadata
+    }
+
+    /// Whether the column metadata has to be encrypted separately from the footer.
+    pub fn has_column_key(&self) -> bool {
+        matches!(
+            self.encryptor.crypto_metadata,
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(_)
+        )
+    }
+
+    pub fn encrypt(
+        &self,
+        module: ModuleType,
+        page: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(
+            &self.encryptor.file_aad,
+            module,
+            self.row_group,
+            self.column,
+            page,
+        )?;
+        self.encryptor.cipher.encrypt(plaintext, &aad)
+    }
+}
+
+/// Decrypts the footer and column metadata of a file.
+pub(crate) struct FileDecryptor<'a> {
+    properties: &'a FileDecryptionProperties,
+    file_aad: Arc<[u8]>,
+    footer_key_metadata: Option<Vec<u8>>,
+    /// The ciphers of the keys that were used so far, by key metadata.
+    ciphers: PlHashMap<Option<Vec<u8>>, Arc<AesGcm>>,
+}
+
+impl<'a> FileDecryptor<'a> {
+    pub fn try_new(
+        algorithm: &EncryptionAlgorithm,
+        footer_key_metadata: Option<Vec<u8>>,
+        properties: &'a FileDecryptionProperties,
+    ) -> ParquetResult<Self> {
+        let EncryptionAlgorithm::AESGCMV1(algorithm) = algorithm else {
+            return Err(ParquetError::not_supported(
+                "the AES_GCM_CTR_V1 encryption algorithm",
+            ));
+        };
+        let prefix = match (&algorithm.aad_prefix, &properties.aad_prefix) {
+            (_, Some(prefix)) => prefix.as_slice(),
+            (Some(prefix), None) => prefix.as_slice(),
+            (None, None) if algorithm.supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "The file requires an AAD prefix to be decrypted".to_string(),
+                ))
+            },
+            (None, None) => &[],
+        };
+        let file_aad = [
+            prefix,
+            algorithm.aad_file_unique.as_deref().unwrap_or_default(),
+        ]
+        .concat()
+        .into();
+        Ok(Self {
+            properties,
+            file_aad,
+            footer_key_metadata,
+            ciphers: PlHashMap::new(),
+        })
+    }
+
+    fn cipher(
+        &mut self,
+        explicit_key: Option<&[u8]>,
+        key_metadata: Option<&[u8]>,
+        what: &str,
+    ) -> ParquetResult<Arc<AesGcm>> {
+        if let Some(key) = explicit_key {
+            return Ok(Arc::new(AesGcm::try_new(key)?));
+        }
+        let key_metadata = key_metadata.map(|m| m.to_vec());
+        if let Some(cipher) = self.ciphers.get(&key_metadata) {
+            return Ok(cipher.clone());
+        }
+        let (Some(retriever), Some(metadata)) = (&self.properties.key_retriever, &key_metadata)
+        else {
+            return Err(ParquetError::InvalidParameter(format!(
+                "No decryption key was given for {what}"
+            )));
+        };
+        let cipher = Arc::new(AesGcm::try_new(&retriever.retrieve_key(metadata)?)?);
+        self.ciphers.insert(key_metadata, cipher.clone());
+        Ok(cipher)
+    }
+
+    fn footer_cipher(&mut self) -> ParquetResult<Arc<AesGcm>> {
+        let key_metadata = self.footer_key_metadata.clone();
+        self.cipher(
+            self.properties.footer_key.as_deref(),
+            key_metadata.as_deref(),
+            "the footer",
+        )
+    }
+
+    /// Decrypts the footer module at the start of `buffer`.
+    pub fn decrypt_footer(&mut self, buffer: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = footer_aad(&self.file_aad);
+        self.footer_cipher()?
+            .decrypt(module_at_start(buffer)?, &aad)
+    }
+
+    /// Returns the decryptor of an encrypted column chunk and decrypts its metadata if it is
+    /// encrypted separately from the footer.
+    pub fn decrypt_column_chunk(
+        &mut self,
+        column_chunk: &mut ColumnChunk,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<ColumnDecryptor> {
+        let crypto_metadata = column_chunk
+            .crypto_metadata
+            .as_ref()
+            .expect("column chunk to be encrypted");
+        let decryptor = self.column_decryptor(crypto_metadata, row_group, column)?;
+        if let Some(metadata) = column_chunk.encrypted_column_metadata.take() {
+            let metadata = decryptor.decrypt(ModuleType::ColumnMetaData, None, &metadata)?;
+            let mut prot =
+                TCompactInputProtocol::new(metadata.as_slice(), metadata.len() * 2 + 1024);
+            column_chunk.meta_data = Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+        }
+        Ok(decryptor)
+    }
+
+    fn column_decryptor(
+        &mut self,
+        crypto_metadata: &ColumnCryptoMetaData,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<ColumnDecryptor> {
+        let cipher = match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => self.footer_cipher()?,
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(key) => {
+                let path = key.path_in_schema.join(".");
+                let explicit_key = self
+                    .properties
+                    .column_keys
+                    .iter()
+                    .find(|(name, _)| *name == path || key.path_in_schema.first() == Some(name))
+                    .map(|(_, key)| key.clone());
+                self.cipher(
+                    explicit_key.as_deref(),
+                    key.key_metadata.as_deref(),
+                    &format!("column '{path}'"),
+                )?
+            },
+        };
+        Ok(ColumnDecryptor {
+            cipher,
+            file_aad: self.file_aad.clone(),
+            row_group,
+            column,
+        })
+    }
+}
+
+/// Decrypts the modules of a column chunk.
+pub struct ColumnDecryptor {
+    cipher: Arc<AesGcm>,
+    file_aad: Arc<[u8]>,
+    row_group: usize,
+    column: usize,
+}
+
+impl ColumnDecryptor {
+    pub(crate) fn decrypt(
+        &self,
+        module: ModuleType,
+        page: Option<usize>,
+        ciphertext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(&self.file_aad, module, self.row_group, self.column, page)?;
+        self.cipher.decrypt(ciphertext, &aad)
+    }
+}
+
+impl std::fmt::Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("row_group", &self.row_group)
+            .field("column", &self.column)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ColumnDecryptor {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cipher, &other.cipher)
+            && self.file_aad == other.file_aad
+            && self.row_group == other.row_group
+            && self.column == other.column
+    }
+}
+
+impl Eq for ColumnDecryptor {}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::cipher::{NONCE_LEN, SIZE_LEN, TAG_LEN};
+    use super::*;
+
+    #[test]
+    fn module_aad_layout() {
+        let aad = module_aad(b"file", ModuleType::DataPageHeader, 1, 258, Some(3)).unwrap();
+        assert_eq!(aad, [b'f', b'i', b'l', b'e', 4, 1, 0, 2, 1, 3, 0]);
+        let aad = module_aad(b"file", ModuleType::ColumnIndex, 0, 1, None).unwrap();
+        assert_eq!(aad, [b'f', b'i', b'l', b'e', 6, 0, 0, 1, 0]);
+        assert_eq!(footer_aad(b"file"), [b'f', b'i', b'l', b'e', 0]);
+        assert!(module_aad(b"", ModuleType::DataPage, 1 << 15, 0, Some(0)).is_err());
+    }
+
+    #[test]
+    fn encrypt_decrypt_module() {
+        let cipher = AesGcm::try_new(b"0123456789012345").unwrap();
+        let module = cipher.encrypt(b"plaintext", b"aad").unwrap();
+        assert_eq!(module.len(), SIZE_LEN + NONCE_LEN + 9 + TAG_LEN);
+        assert_eq!(
+            u32::from_le_bytes(module[..4].try_into().unwrap()) as usize,
+            module.len() - SIZE_LEN
+        );
+        assert_eq!(cipher.decrypt(&module, b"aad").unwrap(), b"plaintext");
+        // Authentication fails with a different AAD or key.
+        assert!(cipher.decrypt(&module, b"other").is_err());
+        let other = AesGcm::try_new(b"1234567890123450").unwrap();
+        assert!(other.decrypt(&module, b"aad").is_err());
+        assert!(AesGcm::try_new(b"short").is_err());
+    }
+}
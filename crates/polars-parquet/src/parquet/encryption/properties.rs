@@ -0,0 +1,243 @@
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::parquet::error::ParquetResult;
+
+/// The key of a column that is encrypted with its own key.
+///
+/// The key itself is never serialized.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnEncryptionKey {
+    /// Dot separated path of the column in the parquet schema. The name of a top-level column
+    /// selects all of its leaf columns.
+    pub column: String,
+    /// The AES key, 16 or 32 bytes long.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub key: Vec<u8>,
+    /// Metadata stored in the file that allows readers to retrieve the key.
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+impl ColumnEncryptionKey {
+    pub fn new(column: impl Into<String>, key: Vec<u8>) -> Self {
+        Self {
+            column: column.into(),
+            key,
+            key_metadata: None,
+        }
+    }
+
+    pub fn with_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+}
+
+impl std::fmt::Debug for ColumnEncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnEncryptionKey")
+            .field("column", &self.column)
+            .field("key_metadata", &self.key_metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How to encrypt a parquet file with modular encryption.
+///
+/// The footer is always encrypted, with the AES-GCM algorithm. If no column keys are given,
+/// all columns are encrypted with the footer key. Otherwise, only the columns with a key are
+/// encrypted.
+///
+/// The keys are never serialized, a deserialized instance must get its keys again before it can
+/// be used.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FileEncryptionProperties {
+    /// The AES key of the footer, 16 or 32 bytes long.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub footer_key: Vec<u8>,
+    /// Metadata stored in the file that allows readers to retrieve the footer key.
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// The columns that are encrypted with their own key.
+    pub column_keys: Vec<ColumnEncryptionKey>,
+    /// A prefix of the additional authenticated data of all modules, e.g. the name of a table,
+    /// which protects against swapping files.
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Whether to store the AAD prefix in the file. If it is not stored, readers must supply it.
+    pub store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key,
+            footer_key_metadata: None,
+            column_keys: vec![],
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    pub fn with_footer_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.footer_key_metadata = Some(key_metadata);
+        self
+    }
+
+    pub fn with_column_key(mut self, column_key: ColumnEncryptionKey) -> Self {
+        self.column_keys.push(column_key);
+        self
+    }
+
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>, store_aad_prefix: bool) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self.store_aad_prefix = store_aad_prefix;
+        self
+    }
+}
+
+impl std::fmt::Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileEncryptionProperties")
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field("column_keys", &self.column_keys)
+            .field("aad_prefix", &self.aad_prefix)
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Retrieves decryption keys from the key metadata that is stored in encrypted files, e.g. from
+/// a key management service.
+pub trait DecryptionKeyRetriever: Send + Sync {
+    /// Returns the key that is identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// The keys to decrypt a parquet file with modular encryption.
+///
+/// Explicit keys take precedence over keys of the key retriever. The keys are never serialized.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FileDecryptionProperties {
+    /// The AES key of the footer.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub footer_key: Option<Vec<u8>>,
+    /// The AES keys of columns, by their dot separated path in the parquet schema or the name of
+    /// their top-level column.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub column_keys: Vec<(String, Vec<u8>)>,
+    /// Retrieves the keys that are not given explicitly.
+    #[cfg_attr(feature = "serde", serde(with = "serde_key_retriever"))]
+    pub key_retriever: Option<Arc<dyn DecryptionKeyRetriever>>,
+    /// The AAD prefix of files that don't store it.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key: Some(footer_key),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_key_retriever(key_retriever: Arc<dyn DecryptionKeyRetriever>) -> Self {
+        Self {
+            key_retriever: Some(key_retriever),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_column_key(mut self, column: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys.push((column.into(), key));
+        self
+    }
+
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+}
+
+impl std::fmt::Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .column_keys
+            .iter()
+            .map(|(column, _)| column)
+            .collect::<Vec<_>>();
+        f.debug_struct("FileDecryptionProperties")
+            .field("columns", &columns)
+            .field("key_retriever", &self.key_retriever.is_some())
+            .field("aad_prefix", &self.aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for FileDecryptionProperties {
+    fn eq(&self, other: &Self) -> bool {
+        let retriever_eq = match (&self.key_retriever, &other.key_retriever) {
+            (Some(l), Some(r)) => Arc::ptr_eq(l, r),
+            (None, None) => true,
+            _ => false,
+        };
+        retriever_eq
+            && self.footer_key == other.footer_key
+            && self.column_keys == other.column_keys
+            && self.aad_prefix == other.aad_prefix
+    }
+}
+
+impl Eq for FileDecryptionProperties {}
+
+impl Hash for FileDecryptionProperties {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.footer_key.hash(state);
+        self.column_keys.hash(state);
+        self.aad_prefix.hash(state);
+        self.key_retriever
+            .as_ref()
+            .map(|r| Arc::as_ptr(r) as *const () as usize)
+            .hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_key_retriever {
+    use std::sync::Arc;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::DecryptionKeyRetriever;
+
+    pub fn serialize<S>(
+        key_retriever: &Option<Arc<dyn DecryptionKeyRetriever>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::Error;
+
+        match key_retriever {
+            Some(_) => Err(S::Error::custom(
+                "cannot serialize a decryption key retriever",
+            )),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<Option<Arc<dyn DecryptionKeyRetriever>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<()>::deserialize(deserializer)?;
+        Ok(None)
+    }
+}
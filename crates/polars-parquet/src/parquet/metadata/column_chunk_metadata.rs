@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -39,6 +42,8 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde_types")]
@@ -76,6 +81,7 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -172,10 +178,37 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the byte range of the [`ColumnIndex`](polars_parquet_format::ColumnIndex) of this
+    /// column chunk within the file, if it has one.
+    pub fn column_index_range(&self) -> Option<core::ops::Range<u64>> {
+        let offset = u64::try_from(self.column_chunk.column_index_offset?).ok()?;
+        let length = u64::try_from(self.column_chunk.column_index_length?).ok()?;
+        Some(offset..offset + length)
+    }
+
+    /// Returns the byte range of the [`OffsetIndex`](polars_parquet_format::OffsetIndex) of this
+    /// column chunk within the file, if it has one.
+    pub fn offset_index_range(&self) -> Option<core::ops::Range<u64>> {
+        let offset = u64::try_from(self.column_chunk.offset_index_offset?).ok()?;
+        let length = u64::try_from(self.column_chunk.offset_index_length?).ok()?;
+        Some(offset..offset + length)
+    }
+
+    /// Whether this column chunk is encrypted with parquet modular encryption.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    /// Decrypts the modules of this column chunk, if it is encrypted.
+    pub fn decryptor(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryptor.as_ref()
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
         column_chunk: ColumnChunk,
+        decryptor: Option<Arc<ColumnDecryptor>>,
     ) -> ParquetResult<Self> {
         // validate metadata
         if let Some(meta) = &column_chunk.meta_data {
@@ -194,6 +227,7 @@ impl ColumnChunkMetadata {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor,
         })
     }
 
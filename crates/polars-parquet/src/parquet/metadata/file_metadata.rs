@@ -3,6 +3,7 @@ use polars_parquet_format::ColumnOrder as TColumnOrder;
 use super::column_order::ColumnOrder;
 use super::schema_descriptor::SchemaDescriptor;
 use super::RowGroupMetadata;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::ParquetError;
 use crate::parquet::metadata::get_sort_order;
 pub use crate::parquet::thrift_format::KeyValue;
@@ -66,6 +67,15 @@ impl FileMetadata {
     /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] into this struct
     pub fn try_from_thrift(
         metadata: polars_parquet_format::FileMetaData,
+    ) -> Result<Self, ParquetError> {
+        Self::try_from_thrift_with_decryptor(metadata, None)
+    }
+
+    /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] into this struct, decrypting
+    /// the metadata of encrypted columns with `decryptor`.
+    pub(crate) fn try_from_thrift_with_decryptor(
+        metadata: polars_parquet_format::FileMetaData,
+        mut decryptor: Option<&mut FileDecryptor<'_>>,
     ) -> Result<Self, ParquetError> {
         let schema_descr = SchemaDescriptor::try_from_thrift(&metadata.schema)?;
 
@@ -74,8 +84,14 @@ impl FileMetadata {
         let row_groups = metadata
             .row_groups
             .into_iter()
-            .map(|rg| {
-                let md = RowGroupMetadata::try_from_thrift(&schema_descr, rg)?;
+            .enumerate()
+            .map(|(i, rg)| {
+                let md = RowGroupMetadata::try_from_thrift(
+                    &schema_descr,
+                    rg,
+                    i,
+                    decryptor.as_deref_mut(),
+                )?;
                 max_row_group_height = max_row_group_height.max(md.num_rows());
                 Ok(md)
             })
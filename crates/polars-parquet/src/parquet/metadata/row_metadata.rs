@@ -9,6 +9,7 @@ use polars_utils::unitvec;
 
 use super::column_chunk_metadata::{column_metadata_byte_range, ColumnChunkMetadata};
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 type ColumnLookup = PlHashMap<PlSmallStr, UnitVec<usize>>;
@@ -98,11 +99,34 @@ impl RowGroupMetadata {
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
-        rg: RowGroup,
+        mut rg: RowGroup,
+        ordinal: usize,
+        mut decryptor: Option<&mut FileDecryptor<'_>>,
     ) -> ParquetResult<RowGroupMetadata> {
         if schema_descr.columns().len() != rg.columns.len() {
             return Err(ParquetError::oos(format!("The number of columns in the row group ({}) must be equal to the number of columns in the schema ({})", rg.columns.len(), schema_descr.columns().len())));
         }
+
+        let decryptors = rg
+            .columns
+            .iter_mut()
+            .enumerate()
+            .map(|(i, column_chunk)| {
+                if column_chunk.crypto_metadata.is_none() {
+                    return Ok(None);
+                }
+                let Some(decryptor) = decryptor.as_deref_mut() else {
+                    return Err(ParquetError::InvalidParameter(
+                        "The file contains encrypted columns, decryption properties are required to read it"
+                            .to_string(),
+                    ));
+                };
+                decryptor
+                    .decrypt_column_chunk(column_chunk, ordinal, i)
+                    .map(|decryptor| Some(Arc::new(decryptor)))
+            })
+            .collect::<ParquetResult<Vec<_>>>()?;
+
         let total_byte_size = rg.total_byte_size.try_into()?;
         let num_rows = rg.num_rows.try_into()?;
 
@@ -122,10 +146,14 @@ impl RowGroupMetadata {
             .columns
             .into_iter()
             .zip(schema_descr.columns())
+            .zip(decryptors)
             .enumerate()
-            .map(|(i, (column_chunk, descriptor))| {
-                let column =
-                    ColumnChunkMetadata::try_from_thrift(descriptor.clone(), column_chunk)?;
+            .map(|(i, ((column_chunk, descriptor), decryptor))| {
+                let column = ColumnChunkMetadata::try_from_thrift(
+                    descriptor.clone(),
+                    column_chunk,
+                    decryptor,
+                )?;
 
                 column_lookup.add_column(i, &column);
 
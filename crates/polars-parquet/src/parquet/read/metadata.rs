@@ -2,10 +2,13 @@ use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
-use polars_parquet_format::FileMetaData as TFileMetadata;
+use polars_parquet_format::{FileCryptoMetaData, FileMetaData as TFileMetadata};
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
+use crate::parquet::encryption::{FileDecryptionProperties, FileDecryptor};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> i32 {
@@ -37,6 +40,24 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetada
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetadata> {
+    read_metadata_with_size_and_decryption(reader, file_size, None)
+}
+
+/// Reads a [`FileMetadata`] from the reader, located at the end of the file, decrypting it with
+/// `decryption` if the file is encrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    read_metadata_with_size_and_decryption(reader, file_size, decryption)
+}
+
+fn read_metadata_with_size_and_decryption<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> ParquetResult<FileMetadata> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,7 +76,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    let encrypted_footer = buffer[default_end_len - 4..] == PARQUET_ENCRYPTED_MAGIC;
+    if !encrypted_footer && buffer[default_end_len - 4..] != PARQUET_MAGIC {
         return Err(ParquetError::oos("The file must end with PAR1"));
     }
 
@@ -88,7 +110,7 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    deserialize_metadata_with_decryption(reader, max_size, encrypted_footer, decryption)
 }
 
 /// Parse loaded metadata bytes
@@ -98,3 +120,48 @@ pub fn deserialize_metadata<R: Read>(reader: R, max_size: usize) -> ParquetResul
 
     FileMetadata::try_from_thrift(metadata)
 }
+
+/// Parse loaded metadata bytes of a file that may be encrypted, where `encrypted_footer` is whether
+/// the file ends with `PARE`.
+pub fn deserialize_metadata_with_decryption(
+    mut buffer: &[u8],
+    max_size: usize,
+    encrypted_footer: bool,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    if !encrypted_footer {
+        let mut prot = TCompactInputProtocol::new(buffer, max_size);
+        let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+        // files with a plaintext footer can still contain encrypted columns
+        let mut decryptor = match (&metadata.encryption_algorithm, decryption) {
+            (Some(algorithm), Some(decryption)) => Some(FileDecryptor::try_new(
+                algorithm,
+                metadata.footer_signing_key_metadata.clone(),
+                decryption,
+            )?),
+            _ => None,
+        };
+        return FileMetadata::try_from_thrift_with_decryptor(metadata, decryptor.as_mut());
+    }
+
+    let Some(decryption) = decryption else {
+        return Err(ParquetError::InvalidParameter(
+            "The file has an encrypted footer, decryption properties are required to read it"
+                .to_string(),
+        ));
+    };
+    let crypto_metadata = {
+        let mut prot = TCompactInputProtocol::new(&mut buffer, max_size);
+        FileCryptoMetaData::read_from_in_protocol(&mut prot)?
+    };
+    let mut decryptor = FileDecryptor::try_new(
+        &crypto_metadata.encryption_algorithm,
+        crypto_metadata.key_metadata,
+        decryption,
+    )?;
+    // the remainder of the footer is the encrypted metadata
+    let metadata = decryptor.decrypt_footer(buffer)?;
+    let mut prot = TCompactInputProtocol::new(metadata.as_slice(), max_size);
+    let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+    FileMetadata::try_from_thrift_with_decryptor(metadata, Some(&mut decryptor))
+}
@@ -3,6 +3,7 @@ mod compression;
 pub mod levels;
 mod metadata;
 mod page;
+pub mod page_index;
 #[cfg(feature = "async")]
 mod stream;
 
@@ -10,7 +11,10 @@ use std::io::{Seek, SeekFrom};
 
 pub use column::*;
 pub use compression::{decompress, BasicDecompressor};
-pub use metadata::{deserialize_metadata, read_metadata, read_metadata_with_size};
+pub use metadata::{
+    deserialize_metadata, deserialize_metadata_with_decryption, read_metadata,
+    read_metadata_with_decryption, read_metadata_with_size,
+};
 #[cfg(feature = "async")]
 pub use page::{get_page_stream, get_page_stream_from_column_start};
 pub use page::{PageIterator, PageMetaData, PageReader};
@@ -1,11 +1,12 @@
 use std::io::Seek;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_utils::mmap::{MemReader, MemSlice};
 
 use super::PageIterator;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{read_module, ColumnDecryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -26,6 +27,8 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// Decrypts the pages if the column is encrypted
+    pub decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 impl PageMetaData {
@@ -41,6 +44,7 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            decryptor: None,
         }
     }
 }
@@ -52,6 +56,7 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            decryptor: column.decryptor().cloned(),
         }
     }
 }
@@ -81,6 +86,15 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Decrypts the pages of encrypted columns.
+    decryptor: Option<Arc<ColumnDecryptor>>,
+
+    // The number of data pages we have seen so far, which is part of the AAD of encrypted pages.
+    num_data_pages: usize,
+
+    // Whether no page was read yet. Only the first page can be a dictionary page.
+    is_first_page: bool,
 }
 
 impl PageReader {
@@ -114,6 +128,9 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            decryptor: reader_meta.decryptor,
+            num_data_pages: 0,
+            is_first_page: true,
         }
     }
 
@@ -137,7 +154,7 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -146,6 +163,49 @@ impl PageReader {
             return Ok(None);
         }
 
+        let buffer = self.read_page(&page_header)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+}
+
+impl PageReader {
+    /// Reads the next page header, decrypting it if the column is encrypted.
+    fn read_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryptor) = &self.decryptor else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let module = read_module(&mut self.reader)?;
+        let dict_header = self
+            .is_first_page
+            .then(|| {
+                decryptor
+                    .decrypt(ModuleType::DictionaryPageHeader, None, &module)
+                    .ok()
+            })
+            .flatten();
+        let header = match dict_header {
+            Some(header) => header,
+            None => decryptor.decrypt(
+                ModuleType::DataPageHeader,
+                Some(self.num_data_pages),
+                &module,
+            )?,
+        };
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), self.max_page_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Reads the (compressed) page that belongs to `page_header`, decrypting it if the column is
+    /// encrypted.
+    fn read_page(&mut self, page_header: &ParquetPageHeader) -> ParquetResult<MemSlice> {
         let read_size: usize = page_header.compressed_page_size.try_into()?;
 
         if read_size > self.max_page_size {
@@ -160,13 +220,23 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
-            } else {
-                unreachable!()
-            }
-        })
+        self.is_first_page = false;
+        let page_type = page_header.type_.try_into()?;
+        let Some(decryptor) = &self.decryptor else {
+            return Ok(buffer);
+        };
+        let buffer = match page_type {
+            PageType::DictionaryPage => {
+                decryptor.decrypt(ModuleType::DictionaryPage, None, &buffer)?
+            },
+            PageType::DataPage | PageType::DataPageV2 => {
+                let page =
+                    decryptor.decrypt(ModuleType::DataPage, Some(self.num_data_pages), &buffer)?;
+                self.num_data_pages += 1;
+                page
+            },
+        };
+        Ok(MemSlice::from_vec(buffer))
     }
 }
 
@@ -210,23 +280,11 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
-    let read_size: usize = page_header.compressed_page_size.try_into()?;
-
-    if read_size > reader.max_page_size {
-        return Err(ParquetError::WouldOverAllocate);
-    }
-
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page(&page_header)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
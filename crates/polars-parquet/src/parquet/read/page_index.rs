@@ -0,0 +1,28 @@
+//! Deserialization of the [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md)
+//! of column chunks.
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::ParquetResult;
+
+/// Every serialized list element takes at least one byte and is accounted for with the size of a
+/// `usize`, which bounds the allocations while deserializing `bytes`.
+fn max_size(bytes: &[u8]) -> usize {
+    bytes.len() * (size_of::<usize>() + 1)
+}
+
+/// Deserializes the [`ColumnIndex`] of a column chunk from `bytes`, which must span
+/// [`crate::parquet::metadata::ColumnChunkMetadata::column_index_range`].
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = max_size(bytes);
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes the [`OffsetIndex`] of a column chunk from `bytes`, which must span
+/// [`crate::parquet::metadata::ColumnChunkMetadata::offset_index_range`].
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = max_size(bytes);
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
@@ -15,6 +15,7 @@ use super::statistics::reduce;
 use super::DynStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ChunkEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -25,6 +26,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<ChunkEncryptor<'_>>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,14 +38,25 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut num_data_pages = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let page_encryptor = encryptor.map(|encryptor| (encryptor, num_data_pages));
+        let spec = write_page(writer, offset, compressed_page, page_encryptor)?;
+        if let CompressedPage::Data(_) = compressed_page {
+            num_data_pages += 1;
+        }
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    if let Some(encryptor) = encryptor {
+        // The metadata of encrypted columns is only stored in the (encrypted) footer.
+        column_chunk.crypto_metadata = Some(encryptor.crypto_metadata().clone());
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
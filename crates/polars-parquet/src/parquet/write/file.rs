@@ -3,21 +3,29 @@ use std::io::Write;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
 use polars_parquet_format::RowGroup;
 
-use super::indexes::{write_column_index, write_offset_index};
+use super::indexes::{has_page_statistics, write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    ChunkEncryptor, FileEncryptionProperties, FileEncryptor, ModuleType,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetadata};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC};
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+fn start_encrypted_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
+    writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+    Ok(PARQUET_ENCRYPTED_MAGIC.len() as u64)
+}
+
 pub(super) fn end_file<W: Write>(
     mut writer: &mut W,
     metadata: &ThriftFileMetadata,
@@ -39,6 +47,47 @@ pub(super) fn end_file<W: Write>(
     Ok(metadata_len as u64 + FOOTER_SIZE)
 }
 
+/// Writes the crypto metadata and the encrypted metadata of a file, followed by the footer.
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetadata,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut footer = vec![];
+    encryptor
+        .file_crypto_metadata()
+        .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))?;
+    let mut plain_metadata = vec![];
+    metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut plain_metadata))?;
+    footer.extend(encryptor.encrypt_footer(&plain_metadata)?);
+
+    let footer_len: i32 = footer.len().try_into()?;
+    writer.write_all(&footer)?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+    writer.flush()?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
+/// Writes `module` to `writer`, encrypted if `encryptor` is given.
+fn write_module<W: Write>(
+    writer: &mut W,
+    module: &[u8],
+    encryptor: Option<(ChunkEncryptor<'_>, ModuleType)>,
+) -> ParquetResult<u64> {
+    match encryptor {
+        None => {
+            writer.write_all(module)?;
+            Ok(module.len() as u64)
+        },
+        Some((encryptor, module_type)) => {
+            let module = encryptor.encrypt(module_type, None, module)?;
+            writer.write_all(&module)?;
+            Ok(module.len() as u64)
+        },
+    }
+}
+
 fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<polars_parquet_format::ColumnOrder> {
     // We only include ColumnOrder for leaf nodes.
     // Currently only supported ColumnOrder is TypeDefinedOrder so we set this
@@ -66,6 +115,11 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// Bloom filters per row group, as pairs of leaf column index and bitset
+    #[cfg(feature = "bloom_filter")]
+    bloom_filters: Vec<Vec<(usize, Vec<u8>)>>,
+    /// Encrypts the file, if it is written with modular encryption
+    encryptor: Option<FileEncryptor>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
@@ -125,11 +179,23 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            #[cfg(feature = "bloom_filter")]
+            bloom_filters: vec![],
+            encryptor: None,
             state: State::Initialised,
             metadata: None,
         }
     }
 
+    /// Encrypts the file with parquet modular encryption.
+    ///
+    /// # Errors
+    /// Returns an error if a key is invalid or a column key does not match any column.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        self.encryptor = Some(FileEncryptor::try_new(properties, self.schema.columns())?);
+        Ok(self)
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -138,7 +204,10 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = match self.encryptor {
+                Some(_) => start_encrypted_file(&mut self.writer)?,
+                None => start_file(&mut self.writer)?,
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -166,10 +235,56 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        #[cfg(feature = "bloom_filter")]
+        self.bloom_filters.push(vec![]);
+        Ok(())
+    }
+
+    /// Adds a bloom filter for the leaf column `column` of the last written row group.
+    ///
+    /// The bloom filters are written together with the footer of the file.
+    #[cfg(feature = "bloom_filter")]
+    pub fn add_bloom_filter(&mut self, column: usize, bitset: Vec<u8>) -> ParquetResult<()> {
+        let Some(bloom_filters) = self.bloom_filters.last_mut() else {
+            return Err(ParquetError::InvalidParameter(
+                "A bloom filter can only be added after a row group was written".to_string(),
+            ));
+        };
+        if column >= self.schema.columns().len() {
+            return Err(ParquetError::InvalidParameter(format!(
+                "Column {column} of the bloom filter is out of bounds"
+            )));
+        }
+        bloom_filters.push((column, bitset));
+        Ok(())
+    }
+
+    /// Sets the leaf columns by which the last written row group is sorted.
+    pub fn set_sorting_columns(
+        &mut self,
+        sorting_columns: Vec<SortingColumn>,
+    ) -> ParquetResult<()> {
+        let Some(row_group) = self.row_groups.last_mut() else {
+            return Err(ParquetError::InvalidParameter(
+                "Sorting columns can only be set after a row group was written".to_string(),
+            ));
+        };
+        let n_columns = self.schema.columns().len();
+        if let Some(column) = sorting_columns
+            .iter()
+            .find(|column| !(0..n_columns as i32).contains(&column.column_idx))
+        {
+            return Err(ParquetError::InvalidParameter(format!(
+                "Sorting column {} is out of bounds",
+                column.column_idx
+            )));
+        }
+        row_group.sorting_columns = (!sorting_columns.is_empty()).then_some(sorting_columns);
         Ok(())
     }
 
@@ -188,22 +303,66 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        #[cfg(feature = "bloom_filter")]
+        self.row_groups
+            .iter_mut()
+            .zip(std::mem::take(&mut self.bloom_filters))
+            .enumerate()
+            .try_for_each(|(row_group, (group, bloom_filters))| {
+                bloom_filters.into_iter().try_for_each(|(column, bitset)| {
+                    use crate::parquet::bloom_filter;
+
+                    let offset = self.offset;
+                    let encryptor = self
+                        .encryptor
+                        .as_ref()
+                        .and_then(|encryptor| encryptor.chunk(row_group, column));
+                    self.offset += match encryptor {
+                        Some(encryptor) => {
+                            bloom_filter::write_encrypted(&mut self.writer, &bitset, encryptor)?
+                        },
+                        None => bloom_filter::write(&mut self.writer, &bitset)?,
+                    };
+                    let metadata = group.columns[column].meta_data.as_mut().unwrap();
+                    metadata.bloom_filter_offset = Some(offset as i64);
+                    metadata.bloom_filter_length = Some((self.offset - offset) as i32);
+                    ParquetResult::Ok(())
+                })
+            })?;
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
                 .iter_mut()
                 .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
+                .enumerate()
+                .try_for_each(|(row_group, (group, pages))| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .enumerate()
+                        .try_for_each(|(column_ordinal, (column, pages))| {
+                            if !has_page_statistics(pages) {
+                                return Ok(());
+                            }
+                            let mut index = vec![];
+                            write_column_index(&mut index, pages)?;
+                            let encryptor = self
+                                .encryptor
+                                .as_ref()
+                                .and_then(|encryptor| encryptor.chunk(row_group, column_ordinal));
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
+                            self.offset += write_module(
+                                &mut self.writer,
+                                &index,
+                                encryptor.map(|encryptor| (encryptor, ModuleType::ColumnIndex)),
+                            )?;
                             let length = self.offset - offset;
                             column.column_index_length = Some(length as i32);
                             ParquetResult::Ok(())
-                        },
-                    )?;
+                        })?;
                     ParquetResult::Ok(())
                 })?;
         };
@@ -212,21 +371,56 @@ impl<W: Write> FileWriter<W> {
         self.row_groups
             .iter_mut()
             .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
+            .enumerate()
+            .try_for_each(|(row_group, (group, pages))| {
                 group
                     .columns
                     .iter_mut()
                     .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
+                    .enumerate()
+                    .try_for_each(|(column_ordinal, (column, pages))| {
+                        let mut index = vec![];
+                        write_offset_index(&mut index, pages)?;
+                        let encryptor = self
+                            .encryptor
+                            .as_ref()
+                            .and_then(|encryptor| encryptor.chunk(row_group, column_ordinal));
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
+                        self.offset += write_module(
+                            &mut self.writer,
+                            &index,
+                            encryptor.map(|encryptor| (encryptor, ModuleType::OffsetIndex)),
+                        )?;
                         column.offset_index_length = Some((self.offset - offset) as i32);
                         ParquetResult::Ok(())
                     })?;
                 ParquetResult::Ok(())
             })?;
 
+        // the metadata of columns with their own key is encrypted separately from the footer
+        if let Some(encryptor) = &self.encryptor {
+            for (row_group, group) in self.row_groups.iter_mut().enumerate() {
+                for (column_ordinal, column) in group.columns.iter_mut().enumerate() {
+                    let Some(encryptor) = encryptor
+                        .chunk(row_group, column_ordinal)
+                        .filter(|encryptor| encryptor.has_column_key())
+                    else {
+                        continue;
+                    };
+                    let mut plain_metadata = vec![];
+                    column.meta_data.take().unwrap().write_to_out_protocol(
+                        &mut TCompactOutputProtocol::new(&mut plain_metadata),
+                    )?;
+                    column.encrypted_column_metadata = Some(encryptor.encrypt(
+                        ModuleType::ColumnMetaData,
+                        None,
+                        &plain_metadata,
+                    )?);
+                }
+            }
+        }
+
         let metadata = ThriftFileMetadata::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
@@ -239,7 +433,10 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => end_encrypted_file(&mut self.writer, &metadata, encryptor)?,
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -1,4 +1,5 @@
 mod serialize;
 mod write;
 
+pub(crate) use serialize::has_page_statistics;
 pub use write::*;
@@ -3,6 +3,15 @@ use polars_parquet_format::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocatio
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::write::page::{is_data_page, PageWriteSpec};
 
+/// Returns whether every data page has statistics, which are required to build a column index.
+/// Columns can be written without statistics when they are configured per column.
+pub(crate) fn has_page_statistics(pages: &[PageWriteSpec]) -> bool {
+    pages
+        .iter()
+        .filter(|x| is_data_page(x))
+        .all(|spec| spec.statistics.is_some())
+}
+
 pub fn serialize_column_index(pages: &[PageWriteSpec]) -> ParquetResult<ColumnIndex> {
     let mut null_pages = Vec::with_capacity(pages.len());
     let mut min_values = Vec::with_capacity(pages.len());
@@ -8,6 +8,7 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ChunkEncryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -50,32 +51,59 @@ pub struct PageWriteSpec {
     pub statistics: Option<Statistics>,
 }
 
+/// Writes a page to `writer`. If the page is encrypted, `encryptor` holds the encryptor of its
+/// column chunk and the ordinal of the page among the data pages of the chunk.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    encryptor: Option<(ChunkEncryptor<'_>, usize)>,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+    let (header_size, bytes_written) = match encryptor {
+        None => {
+            let header_size = write_page_header(writer, &header)?;
+            writer.write_all(buffer)?;
+            (header_size, header_size + buffer.len() as u64)
         },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+        Some((encryptor, page_ordinal)) => {
+            let (page_module, header_module, page_ordinal) = match &compressed_page {
+                CompressedPage::Data(_) => (
+                    ModuleType::DataPage,
+                    ModuleType::DataPageHeader,
+                    Some(page_ordinal),
+                ),
+                CompressedPage::Dict(_) => (
+                    ModuleType::DictionaryPage,
+                    ModuleType::DictionaryPageHeader,
+                    None,
+                ),
+            };
+            let page = encryptor.encrypt(page_module, page_ordinal, buffer)?;
+            // The size of an encrypted page includes the overhead of the encryption.
+            header.compressed_page_size = maybe_bytes(0, page.len())?.1;
+
+            let mut plain_header = vec![];
+            write_page_header(&mut plain_header, &header)?;
+            let encrypted_header = encryptor.encrypt(header_module, page_ordinal, &plain_header)?;
+            writer.write_all(&encrypted_header)?;
+            writer.write_all(&page)?;
+            let header_size = encrypted_header.len() as u64;
+            (header_size, header_size + page.len() as u64)
         },
     };
 
@@ -9,6 +9,7 @@ use super::column_chunk::write_column_chunk;
 use super::column_chunk::write_column_chunk_async;
 use super::page::{is_data_page, PageWriteSpec};
 use super::{DynIter, DynStreamingIterator};
+use crate::parquet::encryption::FileEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, ColumnDescriptor};
 use crate::parquet::page::CompressedPage;
@@ -76,6 +77,7 @@ pub fn write_row_group<
     descriptors: &[ColumnDescriptor],
     columns: DynIter<'a, std::result::Result<DynStreamingIterator<'a, CompressedPage, E>, E>>,
     ordinal: usize,
+    encryptor: Option<&FileEncryptor>,
 ) -> ParquetResult<(RowGroup, Vec<Vec<PageWriteSpec>>, u64)>
 where
     W: Write,
@@ -86,9 +88,12 @@ where
 
     let initial = offset;
     let columns = column_iter
-        .map(|(descriptor, page_iter)| {
+        .enumerate()
+        .map(|(column_ordinal, (descriptor, page_iter))| {
+            let encryptor =
+                encryptor.and_then(|encryptor| encryptor.chunk(ordinal, column_ordinal));
             let (column, page_specs, size) =
-                write_column_chunk(writer, offset, descriptor, page_iter?)?;
+                write_column_chunk(writer, offset, descriptor, page_iter?, encryptor)?;
             offset += size;
             Ok((column, page_specs))
         })
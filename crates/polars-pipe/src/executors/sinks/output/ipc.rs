@@ -3,7 +3,6 @@ use std::path::Path;
 use crossbeam_channel::bounded;
 use polars_core::prelude::*;
 use polars_io::ipc::IpcWriterOptions;
-use polars_io::prelude::*;
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::pipeline::morsels_per_sink;
@@ -13,9 +12,7 @@ impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
         let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
+        let writer = options.to_writer(file).batched(schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
@@ -50,9 +47,7 @@ impl IpcCloudSink {
     ) -> PolarsResult<FilesSink> {
         polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
             let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
-            let writer = IpcWriter::new(cloud_writer)
-                .with_compression(ipc_options.compression)
-                .batched(schema)?;
+            let writer = ipc_options.to_writer(cloud_writer).batched(schema)?;
 
             let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
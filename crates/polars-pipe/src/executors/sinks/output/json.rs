@@ -26,7 +26,7 @@ impl JsonSink {
         _schema: &Schema,
     ) -> PolarsResult<FilesSink> {
         let file = std::fs::File::create(path)?;
-        let writer = BatchedWriter::new(file);
+        let writer = BatchedWriter::new(file).with_options(&options);
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
 
@@ -0,0 +1,250 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_io::partition::PartitionedWriteOptions;
+use polars_plan::prelude::FileType;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+type WriterFactory = Box<dyn Fn(File, &Schema) -> PolarsResult<Box<dyn SinkWriter + Send>> + Send>;
+
+fn writer_factory(file_type: &FileType) -> (WriterFactory, &'static str, bool) {
+    match file_type.clone() {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(options) => {
+            let maintain_order = options.maintain_order;
+            (
+                Box::new(move |file, schema| {
+                    // Don't write in parallel, see the comment on `ParquetSink`.
+                    let writer = options
+                        .to_writer(file)
+                        .set_parallel(false)
+                        .batched(schema)?;
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+                "parquet",
+                maintain_order,
+            )
+        },
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(options) => {
+            let maintain_order = options.maintain_order;
+            (
+                Box::new(move |file, schema| {
+                    let writer = options.to_writer(file).batched(schema)?;
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+                "ipc",
+                maintain_order,
+            )
+        },
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => {
+            let maintain_order = options.maintain_order;
+            (
+                Box::new(move |file, schema| {
+                    let writer = options.to_writer(file).n_threads(1).batched(schema)?;
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+                "csv",
+                maintain_order,
+            )
+        },
+        #[cfg(feature = "json")]
+        FileType::Json(options) => {
+            let maintain_order = options.maintain_order;
+            (
+                Box::new(move |file, _schema| {
+                    let writer = polars_io::json::BatchedWriter::new(file).with_options(&options);
+                    Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+                }),
+                "jsonl",
+                maintain_order,
+            )
+        },
+    }
+}
+
+/// The number of partition files that are kept open at the same time.
+const MAX_OPEN_FILES: usize = 8;
+
+/// The file currently being written for a single partition.
+struct PartitionFile {
+    dir: PathBuf,
+    next_idx: usize,
+    writer: Option<Box<dyn SinkWriter + Send>>,
+    rows_written: usize,
+    /// Estimated in-memory size of the rows written to the current file.
+    bytes_written: usize,
+}
+
+/// Splits every batch on the partition keys and appends the parts to an open file per
+/// partition, starting a new file once `max_rows_per_file` or `chunk_size` is reached.
+///
+/// At most [`MAX_OPEN_FILES`] files are open at once. When another one is needed, the least
+/// recently used file is finished and its partition continues in a new file.
+struct PartitionedWriter {
+    base_path: PathBuf,
+    options: PartitionedWriteOptions,
+    file_schema: Schema,
+    extension: &'static str,
+    create_writer: WriterFactory,
+    partitions: PlHashMap<PathBuf, PartitionFile>,
+    /// The partitions with an open file, from least to most recently used.
+    open_files: VecDeque<PathBuf>,
+}
+
+impl PartitionedWriter {
+    fn open_file(&mut self, path_part: &Path) -> PolarsResult<()> {
+        if self.open_files.len() >= MAX_OPEN_FILES {
+            let lru = self.open_files.pop_front().unwrap();
+            let partition = self.partitions.get_mut(&lru).unwrap();
+            if let Some(mut writer) = partition.writer.take() {
+                writer._finish()?;
+            }
+        }
+
+        let partition = self.partitions.get_mut(path_part).unwrap();
+        let path = partition
+            .dir
+            .join(self.options.file_name(partition.next_idx, self.extension));
+        let file = File::create(path)?;
+        partition.writer = Some((self.create_writer)(file, &self.file_schema)?);
+        partition.next_idx += 1;
+        partition.rows_written = 0;
+        partition.bytes_written = 0;
+        self.open_files.push_back(path_part.to_path_buf());
+        Ok(())
+    }
+
+    fn write_part(&mut self, df: DataFrame) -> PolarsResult<()> {
+        let path_part = self.options.partition_path(&df)?;
+        if let Some(pos) = self.open_files.iter().position(|p| p == &path_part) {
+            let p = self.open_files.remove(pos).unwrap();
+            self.open_files.push_back(p);
+        } else if !self.partitions.contains_key(&path_part) {
+            let dir = self.base_path.join(&path_part);
+            let next_idx = self.options.init_partition_dir(&dir, self.extension)?;
+            self.partitions.insert(
+                path_part.clone(),
+                PartitionFile {
+                    dir,
+                    next_idx,
+                    writer: None,
+                    rows_written: 0,
+                    bytes_written: 0,
+                },
+            );
+        }
+
+        let df = self.options.file_columns(df);
+        let bytes_per_row = (df.estimated_size() / df.height().max(1)).max(1);
+        let mut offset = 0;
+        while offset < df.height() {
+            if self.partitions[&path_part].writer.is_none() {
+                self.open_file(&path_part)?;
+            }
+            let partition = self.partitions.get_mut(&path_part).unwrap();
+            let mut len = df.height() - offset;
+            if let Some(max_rows) = self.options.max_rows_per_file {
+                len = len.min(max_rows - partition.rows_written);
+            }
+            // Write at least one row to every file.
+            let rows_left_in_chunk = (self
+                .options
+                .chunk_size
+                .saturating_sub(partition.bytes_written)
+                / bytes_per_row)
+                .max(1);
+            len = len.min(rows_left_in_chunk);
+
+            let part = df.slice(offset as i64, len);
+            let writer = partition.writer.as_mut().unwrap();
+            writer._write_batch(&part)?;
+            partition.rows_written += part.height();
+            partition.bytes_written += part.height() * bytes_per_row;
+            offset += part.height();
+
+            if Some(partition.rows_written) == self.options.max_rows_per_file
+                || partition.bytes_written >= self.options.chunk_size
+            {
+                writer._finish()?;
+                partition.writer = None;
+                self.open_files.retain(|p| p != &path_part);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for PartitionedWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        for part in self.options.split(df)? {
+            self.write_part(part)?;
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        for partition in self.partitions.values_mut() {
+            if let Some(mut writer) = partition.writer.take() {
+                writer._finish()?;
+            }
+        }
+        self.open_files.clear();
+        Ok(())
+    }
+}
+
+pub struct PartitionedSink {}
+impl PartitionedSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        file_type: &FileType,
+        options: PartitionedWriteOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        options.validate(schema)?;
+        options.init_base_dir(path)?;
+
+        let mut file_schema = schema.clone();
+        if !options.include_partition_columns {
+            for name in &options.partition_by {
+                file_schema.shift_remove(name);
+            }
+        }
+        let (create_writer, extension, maintain_order) = writer_factory(file_type);
+
+        let writer = PartitionedWriter {
+            base_path: path.to_path_buf(),
+            options,
+            file_schema,
+            extension,
+            create_writer,
+            partitions: PlHashMap::new(),
+            open_files: VecDeque::with_capacity(MAX_OPEN_FILES),
+        };
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
@@ -122,7 +122,7 @@ impl ParquetSource {
         let batched_reader = {
             let file = std::fs::File::open(path).unwrap();
 
-            let mut reader = ParquetReader::new(file);
+            let mut reader = ParquetReader::new(file).with_decryption(options.decryption.clone());
 
             if index == 0 {
                 if let Some(md) = self.first_metadata.clone() {
@@ -195,12 +195,12 @@ where
                     match &file_type {
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(ParquetSink::new(path, options.clone(), input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "ipc")]
                         FileType::Ipc(options) => {
-                            Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(IpcSink::new(path, options.clone(), input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "csv")]
@@ -210,13 +210,29 @@ where
                         },
                         #[cfg(feature = "json")]
                         FileType::Json(options) => {
-                            Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(JsonSink::new(path, options.clone(), input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[allow(unreachable_patterns)]
                         _ => unreachable!(),
                     }
                 },
+                #[cfg(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                ))]
+                SinkType::Partitioned {
+                    path,
+                    file_type,
+                    options,
+                } => Box::new(PartitionedSink::new(
+                    path.as_ref().as_path(),
+                    file_type,
+                    options.clone(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn SinkTrait>,
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud {
                     #[cfg(any(feature = "parquet", feature = "ipc"))]
@@ -231,7 +247,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -239,7 +255,7 @@ where
                         FileType::Ipc(ipc_options) => Box::new(IpcCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *ipc_options,
+                            ipc_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -6,25 +6,53 @@ pub struct DateLikeNameSpace(pub(crate) Expr);
 impl DateLikeNameSpace {
     /// Add a given number of business days.
     #[cfg(feature = "business")]
-    pub fn add_business_days(
-        self,
-        n: Expr,
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
-        roll: Roll,
-    ) -> Expr {
+    pub fn add_business_days(self, n: Expr, calendar: BusinessCalendar, roll: Roll) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::Business(BusinessFunction::AddBusinessDay {
-                week_mask,
-                holidays,
-                roll,
-            }),
+            FunctionExpr::Business(BusinessFunction::AddBusinessDay { calendar, roll }),
             &[n],
             false,
             None,
         )
     }
 
+    /// Whether each date is a business day according to `calendar`.
+    #[cfg(feature = "business")]
+    pub fn is_business_day(self, calendar: BusinessCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                calendar,
+            }))
+    }
+
+    /// Whether each date is a half-day according to `calendar`.
+    #[cfg(feature = "business")]
+    pub fn is_half_day(self, calendar: BusinessCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsHalfDay {
+                calendar,
+            }))
+    }
+
+    /// Roll dates that aren't business days forward to the next business day.
+    #[cfg(feature = "business")]
+    pub fn roll_forward(self, calendar: BusinessCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::RollBusinessDay {
+                calendar,
+                roll: Roll::Forward,
+            }))
+    }
+
+    /// Roll dates that aren't business days backward to the previous business day.
+    #[cfg(feature = "business")]
+    pub fn roll_backward(self, calendar: BusinessCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::RollBusinessDay {
+                calendar,
+                roll: Roll::Backward,
+            }))
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -98,6 +126,39 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsoYear))
     }
 
+    /// Get the fiscal year of a Date/Datetime, named after the calendar year in which the fiscal
+    /// year ends.
+    pub fn fiscal_year(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::FiscalYear(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal quarter of a Date/Datetime.
+    pub fn fiscal_quarter(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::FiscalQuarter(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal period (1 to 12) of a Date/Datetime.
+    pub fn fiscal_period(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::FiscalPeriod(
+                calendar,
+            )))
+    }
+
+    /// Get the week of the fiscal year of a Date/Datetime.
+    pub fn fiscal_week(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::FiscalWeek(
+                calendar,
+            )))
+    }
+
     /// Get the month of a Date/Datetime.
     pub fn month(self) -> Expr {
         self.0
@@ -204,8 +265,14 @@ impl DateLikeNameSpace {
 
     /// Truncate the Datetime/Date range into buckets.
     pub fn truncate(self, every: Expr) -> Expr {
+        self.truncate_with_calendar(every, FiscalCalendar::default())
+    }
+
+    /// Truncate the Datetime/Date range into buckets, where fiscal durations such as `"1fq"`
+    /// follow `calendar`.
+    pub fn truncate_with_calendar(self, every: Expr, calendar: FiscalCalendar) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate(calendar)),
             &[every],
             false,
             None,
@@ -254,8 +321,28 @@ impl DateLikeNameSpace {
     /// This will take leap years/ months into account.
     #[cfg(feature = "offset_by")]
     pub fn offset_by(self, by: Expr) -> Expr {
+        self.offset_by_with_options(by, OffsetByOptions::default())
+    }
+
+    /// Offset this `Date/Datetime` by a given offset [`Duration`], where fiscal durations such as
+    /// `"1fq"` follow `calendar`.
+    #[cfg(feature = "offset_by")]
+    pub fn offset_by_with_calendar(self, by: Expr, calendar: FiscalCalendar) -> Expr {
+        self.offset_by_with_options(
+            by,
+            OffsetByOptions {
+                fiscal_calendar: Some(calendar),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Offset this `Date/Datetime` by a given offset [`Duration`], where business-day and fiscal
+    /// durations such as `"3bd"` and `"1fq"` follow the calendars of `options`.
+    #[cfg(feature = "offset_by")]
+    pub fn offset_by_with_options(self, by: Expr, options: OffsetByOptions) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy),
+            FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy(options)),
             &[by],
             false,
             None,
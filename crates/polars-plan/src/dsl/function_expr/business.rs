@@ -1,26 +1,31 @@
 use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
-use polars_ops::prelude::Roll;
+use polars_ops::prelude::{BusinessCalendar, Roll};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::dsl::SpecialEq;
-use crate::map_as_slice;
 use crate::prelude::ColumnsUdf;
+use crate::{map, map_as_slice};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum BusinessFunction {
     #[cfg(feature = "business")]
-    BusinessDayCount {
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
-    },
+    BusinessDayCount { calendar: BusinessCalendar },
     #[cfg(feature = "business")]
     AddBusinessDay {
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        calendar: BusinessCalendar,
+        roll: Roll,
+    },
+    #[cfg(feature = "business")]
+    IsBusinessDay { calendar: BusinessCalendar },
+    #[cfg(feature = "business")]
+    IsHalfDay { calendar: BusinessCalendar },
+    #[cfg(feature = "business")]
+    RollBusinessDay {
+        calendar: BusinessCalendar,
         roll: Roll,
     },
 }
@@ -33,6 +38,17 @@ impl Display for BusinessFunction {
             &BusinessDayCount { .. } => "business_day_count",
             #[cfg(feature = "business")]
             &AddBusinessDay { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &IsBusinessDay { .. } => "is_business_day",
+            #[cfg(feature = "business")]
+            &IsHalfDay { .. } => "is_half_day",
+            #[cfg(feature = "business")]
+            &RollBusinessDay {
+                roll: Roll::Backward,
+                ..
+            } => "roll_backward",
+            #[cfg(feature = "business")]
+            &RollBusinessDay { .. } => "roll_forward",
         };
         write!(f, "{s}")
     }
@@ -42,20 +58,19 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
         use BusinessFunction::*;
         match func {
             #[cfg(feature = "business")]
-            BusinessDayCount {
-                week_mask,
-                holidays,
-            } => {
-                map_as_slice!(business_day_count, week_mask, &holidays)
+            BusinessDayCount { calendar } => {
+                map_as_slice!(business_day_count, &calendar)
             },
             #[cfg(feature = "business")]
-            AddBusinessDay {
-                week_mask,
-                holidays,
-                roll,
-            } => {
-                map_as_slice!(add_business_days, week_mask, &holidays, roll)
+            AddBusinessDay { calendar, roll } => {
+                map_as_slice!(add_business_days, &calendar, roll)
             },
+            #[cfg(feature = "business")]
+            IsBusinessDay { calendar } => map!(is_business_day, &calendar),
+            #[cfg(feature = "business")]
+            IsHalfDay { calendar } => map!(is_half_day, &calendar),
+            #[cfg(feature = "business")]
+            RollBusinessDay { calendar, roll } => map!(roll_business_day, &calendar, roll),
         }
     }
 }
@@ -63,16 +78,14 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
 #[cfg(feature = "business")]
 pub(super) fn business_day_count(
     s: &[Column],
-    week_mask: [bool; 7],
-    holidays: &[i32],
+    calendar: &BusinessCalendar,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
     polars_ops::prelude::business_day_count(
         start.as_materialized_series(),
         end.as_materialized_series(),
-        week_mask,
-        holidays,
+        calendar,
     )
     .map(Column::from)
 }
@@ -80,8 +93,7 @@ pub(super) fn business_day_count(
 #[cfg(feature = "business")]
 pub(super) fn add_business_days(
     s: &[Column],
-    week_mask: [bool; 7],
-    holidays: &[i32],
+    calendar: &BusinessCalendar,
     roll: Roll,
 ) -> PolarsResult<Column> {
     let start = &s[0];
@@ -89,9 +101,28 @@ pub(super) fn add_business_days(
     polars_ops::prelude::add_business_days(
         start.as_materialized_series(),
         n.as_materialized_series(),
-        week_mask,
-        holidays,
+        calendar,
         roll,
     )
     .map(Column::from)
 }
+
+#[cfg(feature = "business")]
+pub(super) fn is_business_day(c: &Column, calendar: &BusinessCalendar) -> PolarsResult<Column> {
+    polars_ops::prelude::is_business_day(c.as_materialized_series(), calendar).map(Column::from)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn is_half_day(c: &Column, calendar: &BusinessCalendar) -> PolarsResult<Column> {
+    polars_ops::prelude::is_half_day(c.as_materialized_series(), calendar).map(Column::from)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn roll_business_day(
+    c: &Column,
+    calendar: &BusinessCalendar,
+    roll: Roll,
+) -> PolarsResult<Column> {
+    polars_ops::prelude::roll_business_day(c.as_materialized_series(), calendar, roll)
+        .map(Column::from)
+}
@@ -40,6 +40,8 @@ pub(super) mod dtypes {
                 Int32 => Int32,
                 UInt32 => UInt32,
                 UInt64 => UInt64,
+                Int128 => Int128,
+                UInt128 => UInt128,
                 Float32 => Float32,
                 Float64 => Float64,
                 Unknown(kind) => match kind {
@@ -7,7 +7,7 @@ use polars_time::base_utc_offset as base_utc_offset_fn;
 #[cfg(feature = "timezones")]
 use polars_time::dst_offset as dst_offset_fn;
 #[cfg(feature = "offset_by")]
-use polars_time::impl_offset_by;
+use polars_time::impl_offset_by_with_options;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +21,10 @@ pub enum TemporalFunction {
     Year,
     IsLeapYear,
     IsoYear,
+    FiscalYear(FiscalCalendar),
+    FiscalQuarter(FiscalCalendar),
+    FiscalPeriod(FiscalCalendar),
+    FiscalWeek(FiscalCalendar),
     Quarter,
     Month,
     Week,
@@ -50,9 +54,9 @@ pub enum TemporalFunction {
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
-    Truncate,
+    Truncate(FiscalCalendar),
     #[cfg(feature = "offset_by")]
-    OffsetBy,
+    OffsetBy(OffsetByOptions),
     #[cfg(feature = "month_start")]
     MonthStart,
     #[cfg(feature = "month_end")]
@@ -76,7 +80,8 @@ impl TemporalFunction {
         use TemporalFunction::*;
         match self {
             Millennium | Century => mapper.with_dtype(DataType::Int8),
-            Year | IsoYear => mapper.with_dtype(DataType::Int32),
+            Year | IsoYear | FiscalYear(_) => mapper.with_dtype(DataType::Int32),
+            FiscalQuarter(_) | FiscalPeriod(_) | FiscalWeek(_) => mapper.with_dtype(DataType::Int8),
             OrdinalDay => mapper.with_dtype(DataType::Int16),
             Month | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
                 mapper.with_dtype(DataType::Int8)
@@ -105,9 +110,9 @@ impl TemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
-            Truncate => mapper.with_same_dtype(),
+            Truncate(_) => mapper.with_same_dtype(),
             #[cfg(feature = "offset_by")]
-            OffsetBy => mapper.with_same_dtype(),
+            OffsetBy(_) => mapper.with_same_dtype(),
             #[cfg(feature = "month_start")]
             MonthStart => mapper.with_same_dtype(),
             #[cfg(feature = "month_end")]
@@ -146,6 +151,10 @@ impl Display for TemporalFunction {
             Year => "year",
             IsLeapYear => "is_leap_year",
             IsoYear => "iso_year",
+            FiscalYear(_) => "fiscal_year",
+            FiscalQuarter(_) => "fiscal_quarter",
+            FiscalPeriod(_) => "fiscal_period",
+            FiscalWeek(_) => "fiscal_week",
             Quarter => "quarter",
             Month => "month",
             Week => "week",
@@ -175,9 +184,9 @@ impl Display for TemporalFunction {
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
-            Truncate => "truncate",
+            Truncate(_) => "truncate",
             #[cfg(feature = "offset_by")]
-            OffsetBy => "offset_by",
+            OffsetBy(_) => "offset_by",
             #[cfg(feature = "month_start")]
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
@@ -219,6 +228,26 @@ pub(super) fn iso_year(s: &Column) -> PolarsResult<Column> {
         .iso_year()
         .map(|ca| ca.into_column())
 }
+pub(super) fn fiscal_year(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_year(calendar)
+        .map(|ca| ca.into_column())
+}
+pub(super) fn fiscal_quarter(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_quarter(calendar)
+        .map(|ca| ca.into_column())
+}
+pub(super) fn fiscal_period(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_period(calendar)
+        .map(|ca| ca.into_column())
+}
+pub(super) fn fiscal_week(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_week(calendar)
+        .map(|ca| ca.into_column())
+}
 pub(super) fn month(s: &Column) -> PolarsResult<Column> {
     s.as_materialized_series()
         .month()
@@ -437,7 +466,7 @@ pub(super) fn cast_time_unit(s: &Column, tu: TimeUnit) -> PolarsResult<Column> {
     }
 }
 
-pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn truncate(s: &[Column], calendar: &FiscalCalendar) -> PolarsResult<Column> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
@@ -446,11 +475,17 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate_with_calendar(tz.parse::<Tz>().ok().as_ref(), every, Some(calendar))?
+                .into_column(),
+            _ => time_series
+                .datetime()?
+                .truncate_with_calendar(None, every, Some(calendar))?
                 .into_column(),
-            _ => time_series.datetime()?.truncate(None, every)?.into_column(),
         },
-        DataType::Date => time_series.date()?.truncate(None, every)?.into_column(),
+        DataType::Date => time_series
+            .date()?
+            .truncate_with_calendar(None, every, Some(calendar))?
+            .into_column(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     };
     out.set_sorted_flag(time_series.is_sorted_flag());
@@ -458,8 +493,13 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "offset_by")]
-pub(super) fn offset_by(s: &[Column]) -> PolarsResult<Column> {
-    impl_offset_by(s[0].as_materialized_series(), s[1].as_materialized_series()).map(Column::from)
+pub(super) fn offset_by(s: &[Column], options: &OffsetByOptions) -> PolarsResult<Column> {
+    impl_offset_by_with_options(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        options,
+    )
+    .map(Column::from)
 }
 
 #[cfg(feature = "month_start")]
@@ -22,15 +22,15 @@ pub(super) fn pct_change(s: &[Column]) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "interpolate")]
-pub(super) fn interpolate(s: &Column, method: InterpolationMethod) -> PolarsResult<Column> {
-    Ok(polars_ops::prelude::interpolate(s.as_materialized_series(), method).into())
+pub(super) fn interpolate(s: &Column, options: InterpolateOptions) -> PolarsResult<Column> {
+    polars_ops::prelude::interpolate(s.as_materialized_series(), options).map(Column::from)
 }
 
 #[cfg(feature = "interpolate_by")]
-pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn interpolate_by(s: &[Column], options: InterpolateOptions) -> PolarsResult<Column> {
     let by = &s[1];
     let by_is_sorted = by.as_materialized_series().is_sorted(Default::default())?;
-    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
+    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted, options)
 }
 
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
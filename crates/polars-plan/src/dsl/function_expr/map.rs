@@ -0,0 +1,97 @@
+use polars_ops::chunked_array::map::{map_from_lists, MapNameSpace};
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MapFunction {
+    Get,
+    Keys,
+    Values,
+    ContainsKey,
+    FromLists,
+}
+
+impl MapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use MapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(_, value) => Ok(value.as_ref().clone()),
+                dt => polars_bail!(InvalidOperation: "expected Map type, got: {}", dt),
+            }),
+            Keys => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(key, _) => Ok(DataType::List(key.clone())),
+                dt => polars_bail!(InvalidOperation: "expected Map type, got: {}", dt),
+            }),
+            Values => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(_, value) => Ok(DataType::List(value.clone())),
+                dt => polars_bail!(InvalidOperation: "expected Map type, got: {}", dt),
+            }),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            FromLists => mapper.try_map_dtypes(|dtypes| match dtypes {
+                [DataType::List(key), DataType::List(value)] => Ok(DataType::Map(
+                    Box::new(key.as_ref().clone()),
+                    Box::new(value.as_ref().clone()),
+                )),
+                _ => polars_bail!(
+                    InvalidOperation: "expected two List inputs to create a map, got: {:?}", dtypes
+                ),
+            }),
+        }
+    }
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            Keys => "keys",
+            Values => "values",
+            ContainsKey => "contains_key",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: MapFunction) -> Self {
+        use MapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            Keys => map!(keys),
+            Values => map!(values),
+            ContainsKey => map_as_slice!(contains_key),
+            FromLists => map_as_slice!(from_lists),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_keys().map(|ca| ca.into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_values().map(|ca| ca.into_column())
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_contains_key(key).map(|ca| ca.into_column())
+}
+
+pub(super) fn from_lists(s: &[Column]) -> PolarsResult<Column> {
+    let keys = s[0].list()?;
+    let values = s[1].list()?;
+    map_from_lists(keys, values).map(|ca| ca.into_column())
+}
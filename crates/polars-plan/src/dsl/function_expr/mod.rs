@@ -37,6 +37,8 @@ mod fused;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -83,6 +85,8 @@ pub(crate) use correlation::CorrelationMethod;
 #[cfg(feature = "fused")]
 pub(crate) use fused::FusedOperator;
 pub(crate) use list::ListFunction;
+#[cfg(feature = "dtype-map")]
+pub(crate) use map::MapFunction;
 use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
@@ -126,6 +130,8 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -245,9 +251,9 @@ pub enum FunctionExpr {
     #[cfg(feature = "pct_change")]
     PctChange,
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate(InterpolateOptions),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolateOptions),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -378,6 +384,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -409,7 +417,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -607,6 +615,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -703,7 +713,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -879,6 +889,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
@@ -952,8 +964,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Quantile(options) => map!(rolling::rolling_quantile, options.clone()),
                     Var(options) => map!(rolling::rolling_var, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
+                    Median(options) => map!(rolling::rolling_median, options.clone()),
+                    Mad(options) => map!(rolling::rolling_mad, options.clone()),
+                    Rank(options) => map!(rolling::rolling_rank, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
+                    #[cfg(feature = "moment")]
+                    Kurtosis {
+                        window_size,
+                        fisher,
+                        bias,
+                    } => map!(rolling::rolling_kurtosis, window_size, fisher, bias),
                     #[cfg(feature = "cov")]
                     CorrCov {
                         rolling_options,
@@ -982,6 +1003,21 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                     VarBy(options) => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy(options) => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    #[cfg(feature = "moment")]
+                    SkewBy(options) => map_as_slice!(rolling_by::rolling_skew_by, options.clone()),
+                    #[cfg(feature = "cov")]
+                    CorrCovBy {
+                        rolling_options,
+                        ddof,
+                        is_corr,
+                    } => {
+                        map_as_slice!(
+                            rolling_by::rolling_corr_cov_by,
+                            rolling_options.clone(),
+                            ddof,
+                            is_corr
+                        )
+                    },
                 }
             },
             #[cfg(feature = "hist")]
@@ -1062,12 +1098,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "pct_change")]
             PctChange => map_as_slice!(dispatch::pct_change),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => {
-                map!(dispatch::interpolate, method)
+            Interpolate(options) => {
+                map!(dispatch::interpolate, options)
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {
-                map_as_slice!(dispatch::interpolate_by)
+            InterpolateBy(options) => {
+                map_as_slice!(dispatch::interpolate_by, options)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -18,8 +18,17 @@ pub enum RollingFunction {
     Quantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
+    Median(RollingOptionsFixedWindow),
+    Mad(RollingOptionsFixedWindow),
+    Rank(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(usize, bool),
+    #[cfg(feature = "moment")]
+    Kurtosis {
+        window_size: usize,
+        fisher: bool,
+        bias: bool,
+    },
     #[cfg(feature = "cov")]
     CorrCov {
         rolling_options: RollingOptionsFixedWindow,
@@ -41,8 +50,13 @@ impl Display for RollingFunction {
             Quantile(_) => "rolling_quantile",
             Var(_) => "rolling_var",
             Std(_) => "rolling_std",
+            Median(_) => "rolling_median",
+            Mad(_) => "rolling_mad",
+            Rank(_) => "rolling_rank",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
+            #[cfg(feature = "moment")]
+            Kurtosis { .. } => "rolling_kurtosis",
             #[cfg(feature = "cov")]
             CorrCov { is_corr, .. } => {
                 if *is_corr {
@@ -68,6 +82,16 @@ impl Hash for RollingFunction {
                 window_size.hash(state);
                 bias.hash(state)
             },
+            #[cfg(feature = "moment")]
+            Kurtosis {
+                window_size,
+                fisher,
+                bias,
+            } => {
+                window_size.hash(state);
+                fisher.hash(state);
+                bias.hash(state)
+            },
             #[cfg(feature = "cov")]
             CorrCov { is_corr, .. } => {
                 is_corr.hash(state);
@@ -129,6 +153,30 @@ pub(super) fn rolling_std(s: &Column, options: RollingOptionsFixedWindow) -> Pol
         .map(Column::from)
 }
 
+pub(super) fn rolling_median(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_median(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_mad(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_mad(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_rank(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_rank(options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_skew(s: &Column, window_size: usize, bias: bool) -> PolarsResult<Column> {
     // @scalar-opt
@@ -137,6 +185,19 @@ pub(super) fn rolling_skew(s: &Column, window_size: usize, bias: bool) -> Polars
         .map(Column::from)
 }
 
+#[cfg(feature = "moment")]
+pub(super) fn rolling_kurtosis(
+    s: &Column,
+    window_size: usize,
+    fisher: bool,
+    bias: bool,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_kurtosis(window_size, fisher, bias)
+        .map(Column::from)
+}
+
 #[cfg(feature = "cov")]
 fn det_count_x_y(window_size: usize, len: usize, dtype: &DataType) -> Series {
     match dtype {
@@ -1,6 +1,8 @@
 use polars_time::chunkedarray::*;
 
 use super::*;
+#[cfg(feature = "cov")]
+use crate::dsl::pow::pow;
 
 #[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +14,15 @@ pub enum RollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    #[cfg(feature = "moment")]
+    SkewBy(RollingOptionsDynamicWindow),
+    #[cfg(feature = "cov")]
+    CorrCovBy {
+        rolling_options: RollingOptionsDynamicWindow,
+        ddof: u8,
+        // Whether is Corr or Cov
+        is_corr: bool,
+    },
 }
 
 impl Display for RollingFunctionBy {
@@ -26,6 +37,16 @@ impl Display for RollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            #[cfg(feature = "moment")]
+            SkewBy(_) => "rolling_skew_by",
+            #[cfg(feature = "cov")]
+            CorrCovBy { is_corr, .. } => {
+                if *is_corr {
+                    "rolling_corr_by"
+                } else {
+                    "rolling_cov_by"
+                }
+            },
         };
 
         write!(f, "{name}")
@@ -35,6 +56,11 @@ impl Display for RollingFunctionBy {
 impl Hash for RollingFunctionBy {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
+        #[cfg(feature = "cov")]
+        if let RollingFunctionBy::CorrCovBy { ddof, is_corr, .. } = self {
+            ddof.hash(state);
+            is_corr.hash(state);
+        }
     }
 }
 
@@ -107,3 +133,68 @@ pub(super) fn rolling_std_by(
         .rolling_std_by(s[1].as_materialized_series(), options)
         .map(Column::from)
 }
+
+#[cfg(feature = "moment")]
+pub(super) fn rolling_skew_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_skew_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+#[cfg(feature = "cov")]
+pub(super) fn rolling_corr_cov_by(
+    s: &[Column],
+    rolling_options: RollingOptionsDynamicWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> PolarsResult<Column> {
+    let mut x = s[0].as_materialized_series().clone();
+    let mut y = s[1].as_materialized_series().clone();
+    let by = s[2].as_materialized_series();
+
+    if !x.dtype().is_float() {
+        x = x.cast(&DataType::Float64)?;
+    }
+    if !y.dtype().is_float() {
+        y = y.cast(&DataType::Float64)?;
+    }
+    let dtype = x.dtype().clone();
+
+    let mean_x_y = (&x * &y)?.rolling_mean_by(by, rolling_options.clone())?;
+    let mean_x = x.rolling_mean_by(by, rolling_options.clone())?;
+    let mean_y = y.rolling_mean_by(by, rolling_options.clone())?;
+    let count_x_y = x
+        .is_not_null()
+        .cast(&dtype)?
+        .rolling_sum_by(by, rolling_options.clone())?;
+    let ddof_s = Series::new(PlSmallStr::EMPTY, &[AnyValue::from(ddof).cast(&dtype)]);
+
+    let numerator =
+        ((mean_x_y - (mean_x * mean_y)?)? * (count_x_y.clone() / (count_x_y - ddof_s)?)?)?;
+
+    if is_corr {
+        let var_options = RollingOptionsDynamicWindow {
+            fn_params: Some(RollingFnParams::Var(RollingVarParams { ddof })),
+            ..rolling_options
+        };
+        let var_x = x.rolling_var_by(by, var_options.clone())?;
+        let var_y = y.rolling_var_by(by, var_options)?;
+
+        let base = (var_x * var_y)?;
+        let sc = Scalar::new(
+            base.dtype().clone(),
+            AnyValue::Float64(0.5).cast(&dtype).into_static(),
+        );
+        let denominator = pow(&mut [base.into_column(), sc.into_column("".into())])?
+            .unwrap()
+            .take_materialized_series();
+
+        Ok((numerator / denominator)?.into_column())
+    } else {
+        Ok(numerator.into_column())
+    }
+}
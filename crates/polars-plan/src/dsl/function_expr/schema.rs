@@ -20,6 +20,8 @@ impl FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -35,6 +37,10 @@ impl FunctionExpr {
             Business(func) => match func {
                 BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
+                BusinessFunction::IsBusinessDay { .. } | BusinessFunction::IsHalfDay { .. } => {
+                    mapper.with_dtype(DataType::Boolean)
+                },
+                BusinessFunction::RollBusinessDay { .. } => mapper.with_same_dtype(),
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
@@ -65,11 +71,11 @@ impl FunctionExpr {
                 use RollingFunction::*;
                 match rolling_func {
                     Min(_) | Max(_) | Sum(_) => mapper.with_same_dtype(),
-                    Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
+                    Mean(_) | Quantile(_) | Var(_) | Std(_) | Median(_) | Mad(_) | Rank(_) => mapper.map_to_float_dtype(),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
-                    Skew(..) => mapper.map_to_float_dtype(),
+                    Skew(..) | Kurtosis { .. } => mapper.map_to_float_dtype(),
                 }
             },
             #[cfg(feature = "rolling_window_by")]
@@ -78,6 +84,10 @@ impl FunctionExpr {
                 match rolling_func {
                     MinBy(_) | MaxBy(_) | SumBy(_) => mapper.with_same_dtype(),
                     MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) => mapper.map_to_float_dtype(),
+                    #[cfg(feature = "moment")]
+                    SkewBy(_) => mapper.map_to_float_dtype(),
+                    #[cfg(feature = "cov")]
+                    CorrCovBy { .. } => mapper.map_to_float_dtype(),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -184,12 +194,23 @@ impl FunctionExpr {
                 _ => DataType::Float64,
             }),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
-                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+            Interpolate(options) => match options.method {
+                InterpolationMethod::Linear
+                | InterpolationMethod::Cubic
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Nearest
+                | InterpolationMethod::Previous
+                | InterpolationMethod::Next => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(),
+            InterpolateBy(options) => match options.method {
+                InterpolationMethod::Linear
+                | InterpolationMethod::Cubic
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Nearest
+                | InterpolationMethod::Previous
+                | InterpolationMethod::Next => mapper.with_same_dtype(),
+            },
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
@@ -217,7 +238,13 @@ impl FunctionExpr {
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
-            Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
+            Round { decimals } => mapper.map_dtype(|dt| match dt {
+                #[cfg(feature = "dtype-decimal")]
+                DataType::Decimal(p, Some(s)) => decimal_round_dtype(*p, *s, *decimals),
+                dt => dt.clone(),
+            }),
+            #[cfg(feature = "round_series")]
+            RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
@@ -419,7 +446,7 @@ impl<'a> FieldsMapper<'a> {
     /// Map to a float supertype.
     pub fn map_to_float_dtype(&self) -> PolarsResult<Field> {
         self.map_dtype(|dtype| match dtype {
-            DataType::Float32 => DataType::Float32,
+            DataType::Float32 | DataType::Float16 => DataType::Float32,
             _ => DataType::Float64,
         })
     }
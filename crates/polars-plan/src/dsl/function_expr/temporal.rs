@@ -10,6 +10,10 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Year => map!(datetime::year),
             IsLeapYear => map!(datetime::is_leap_year),
             IsoYear => map!(datetime::iso_year),
+            FiscalYear(calendar) => map!(datetime::fiscal_year, &calendar),
+            FiscalQuarter(calendar) => map!(datetime::fiscal_quarter, &calendar),
+            FiscalPeriod(calendar) => map!(datetime::fiscal_period, &calendar),
+            FiscalWeek(calendar) => map!(datetime::fiscal_week, &calendar),
             Month => map!(datetime::month),
             Quarter => map!(datetime::quarter),
             Week => map!(datetime::week),
@@ -39,12 +43,12 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
             CastTimeUnit(tu) => map!(datetime::cast_time_unit, tu),
-            Truncate => {
-                map_as_slice!(datetime::truncate)
+            Truncate(calendar) => {
+                map_as_slice!(datetime::truncate, &calendar)
             },
             #[cfg(feature = "offset_by")]
-            OffsetBy => {
-                map_as_slice!(datetime::offset_by)
+            OffsetBy(options) => {
+                map_as_slice!(datetime::offset_by, &options)
             },
             #[cfg(feature = "month_start")]
             MonthStart => map!(datetime::month_start),
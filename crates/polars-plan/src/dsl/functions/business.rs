@@ -1,20 +1,12 @@
 use super::*;
 
 #[cfg(feature = "dtype-date")]
-pub fn business_day_count(
-    start: Expr,
-    end: Expr,
-    week_mask: [bool; 7],
-    holidays: Vec<i32>,
-) -> Expr {
+pub fn business_day_count(start: Expr, end: Expr, calendar: BusinessCalendar) -> Expr {
     let input = vec![start, end];
 
     Expr::Function {
         input,
-        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
-            week_mask,
-            holidays,
-        }),
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount { calendar }),
         options: FunctionOptions {
             flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
             ..Default::default()
@@ -99,3 +99,48 @@ pub fn rolling_corr(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
 pub fn rolling_cov(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
     dispatch_corr_cov(x, y, options, false)
 }
+
+#[cfg(all(feature = "rolling_window_by", feature = "cov"))]
+fn dispatch_corr_cov_by(
+    x: Expr,
+    y: Expr,
+    by: Expr,
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> Expr {
+    Expr::Function {
+        input: vec![x, y, by],
+        function: FunctionExpr::RollingExprBy(RollingFunctionBy::CorrCovBy {
+            rolling_options: options,
+            ddof,
+            is_corr,
+        }),
+        options: Default::default(),
+    }
+}
+
+/// Compute the rolling pearson correlation between two columns, with windows based on another
+/// column.
+#[cfg(all(feature = "rolling_window_by", feature = "cov"))]
+pub fn rolling_corr_by(
+    x: Expr,
+    y: Expr,
+    by: Expr,
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+) -> Expr {
+    dispatch_corr_cov_by(x, y, by, options, ddof, true)
+}
+
+/// Compute the rolling covariance between two columns, with windows based on another column.
+#[cfg(all(feature = "rolling_window_by", feature = "cov"))]
+pub fn rolling_cov_by(
+    x: Expr,
+    y: Expr,
+    by: Expr,
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+) -> Expr {
+    dispatch_corr_cov_by(x, y, by, options, ddof, false)
+}
@@ -0,0 +1,48 @@
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value stored under `key` in every map, or null if the key is missing.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0
+            .map_many_private(FunctionExpr::MapExpr(MapFunction::Get), &[key], false, None)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_private(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Check whether every map contains `key`.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::MapExpr(MapFunction::ContainsKey),
+            &[key],
+            false,
+            None,
+        )
+    }
+}
+
+/// Create a map from a list column of keys and a list column of values.
+///
+/// Every row must hold as many keys as values, and keys cannot be null.
+pub fn map_from_lists(keys: Expr, values: Expr) -> Expr {
+    Expr::Function {
+        input: vec![keys, values],
+        function: FunctionExpr::MapExpr(MapFunction::FromLists),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            ..Default::default()
+        },
+    }
+}
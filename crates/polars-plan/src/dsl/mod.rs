@@ -23,6 +23,8 @@ mod from;
 pub mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
@@ -51,6 +53,8 @@ pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use name::*;
@@ -1295,7 +1299,13 @@ impl Expr {
     #[cfg(feature = "interpolate")]
     /// Fill null values using interpolation.
     pub fn interpolate(self, method: InterpolationMethod) -> Expr {
-        self.apply_private(FunctionExpr::Interpolate(method))
+        self.interpolate_with_options(method.into())
+    }
+
+    #[cfg(feature = "interpolate")]
+    /// Fill null values using interpolation, restricting which nulls are filled.
+    pub fn interpolate_with_options(self, options: InterpolateOptions) -> Expr {
+        self.apply_private(FunctionExpr::Interpolate(options))
     }
 
     #[cfg(feature = "rolling_window_by")]
@@ -1317,7 +1327,14 @@ impl Expr {
     #[cfg(feature = "interpolate_by")]
     /// Fill null values using interpolation.
     pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::InterpolateBy, &[by], false, false)
+        self.interpolate_by_with_options(by, Default::default())
+    }
+
+    #[cfg(feature = "interpolate_by")]
+    /// Fill null values using interpolation along the values of `by`, restricting which nulls
+    /// are filled.
+    pub fn interpolate_by_with_options(self, by: Expr, options: InterpolateOptions) -> Expr {
+        self.apply_many_private(FunctionExpr::InterpolateBy(options), &[by], false, false)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1389,6 +1406,19 @@ impl Expr {
         self.rolling_quantile_by(by, QuantileMethod::Linear, 0.5, options)
     }
 
+    /// Apply a rolling skew based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    #[cfg(feature = "moment")]
+    pub fn rolling_skew_by(
+        self,
+        by: Expr,
+        bias: bool,
+        mut options: RollingOptionsDynamicWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Skew(RollingSkewParams { bias }));
+        self.finish_rolling_by(by, options, RollingFunctionBy::SkewBy)
+    }
+
     /// Apply a rolling minimum.
     ///
     /// See: [`RollingAgg::rolling_min`]
@@ -1426,7 +1456,25 @@ impl Expr {
     /// See: [`RollingAgg::rolling_median`]
     #[cfg(feature = "rolling_window")]
     pub fn rolling_median(self, options: RollingOptionsFixedWindow) -> Expr {
-        self.rolling_quantile(QuantileMethod::Linear, 0.5, options)
+        self.finish_rolling(options, RollingFunction::Median)
+    }
+
+    /// Apply a rolling median absolute deviation.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mad(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Mad)
+    }
+
+    /// Compute the rank of every value within its rolling window, as a fraction of the number
+    /// of non-null values in that window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_rank(
+        self,
+        method: RollingRankMethod,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Rank(RollingRankParams { method }));
+        self.finish_rolling(options, RollingFunction::Rank)
     }
 
     /// Apply a rolling quantile.
@@ -1469,6 +1517,17 @@ impl Expr {
         )))
     }
 
+    /// Apply a rolling kurtosis.
+    #[cfg(feature = "rolling_window")]
+    #[cfg(feature = "moment")]
+    pub fn rolling_kurtosis(self, window_size: usize, fisher: bool, bias: bool) -> Expr {
+        self.apply_private(FunctionExpr::RollingExpr(RollingFunction::Kurtosis {
+            window_size,
+            fisher,
+            bias,
+        }))
+    }
+
     #[cfg(feature = "rolling_window")]
     /// Apply a custom function over a rolling/ moving window of the array.
     /// This has quite some dynamic dispatch, so prefer rolling_min, max, mean, sum over this.
@@ -1954,6 +2013,12 @@ impl Expr {
         cat::CategoricalNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
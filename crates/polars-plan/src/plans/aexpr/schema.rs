@@ -3,6 +3,11 @@ use recursive::recursive;
 use super::*;
 
 fn float_type(field: &mut Field) {
+    // Half-precision floats are aggregated as `Float32`.
+    if let DataType::Float16 = field.dtype {
+        field.coerce(DataType::Float32);
+        return;
+    }
     let should_coerce = match &field.dtype {
         DataType::Float32 => false,
         #[cfg(feature = "dtype-decimal")]
@@ -235,6 +240,8 @@ impl AExpr {
                         let dt = match field.dtype() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(p, Some(s)) => Some(decimal_sum_dtype(*p, *s)),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -256,6 +263,8 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(p, Some(s)) => field.coerce(decimal_mean_dtype(p, s)),
                             _ => float_type(&mut field),
                         }
                         Ok(field)
@@ -460,6 +469,10 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Duration(_)) | (Duration(_), Interval) => Interval,
                 (Datetime(tu, _), Date) | (Date, Datetime(tu, _)) => Duration(*tu),
                 // T - T != T if T is a datetime / date
                 (Datetime(tul, _), Datetime(tur, _)) => Duration(get_time_units(tul, tur)),
@@ -506,7 +519,7 @@ fn get_arithmetic_field(
                         other_dtype.leaf_dtype(),
                     )?)
                 },
-                (left, right) => try_get_supertype(left, right)?,
+                (left, right) => get_arithmetic_supertype(left, right, op)?,
             }
         },
         Operator::Plus => {
@@ -518,6 +531,12 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Datetime(_, _) | Date) => right_type,
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Duration(_)) | (Duration(_), Interval) => Interval,
                 (_, Datetime(_, _))
                 | (Datetime(_, _), _)
                 | (_, Date)
@@ -555,7 +574,7 @@ fn get_arithmetic_field(
                         other_dtype.leaf_dtype(),
                     )?)
                 },
-                (left, right) => try_get_supertype(left, right)?,
+                (left, right) => get_arithmetic_supertype(left, right, op)?,
             }
         },
         _ => {
@@ -640,7 +659,7 @@ fn get_arithmetic_field(
                 },
             }
 
-            try_get_supertype(&left_field.dtype, &right_type)?
+            get_arithmetic_supertype(&left_field.dtype, &right_type, op)?
         },
     };
 
@@ -648,6 +667,51 @@ fn get_arithmetic_field(
     Ok(left_field)
 }
 
+fn get_arithmetic_supertype(
+    left: &DataType,
+    right: &DataType,
+    op: Operator,
+) -> PolarsResult<DataType> {
+    #[cfg(feature = "dtype-decimal")]
+    if let Some(dtype) = get_decimal_arithmetic_dtype(left, right, op)? {
+        return Ok(dtype);
+    }
+    #[cfg(not(feature = "dtype-decimal"))]
+    let _ = op;
+    try_get_supertype(left, right)
+}
+
+/// The output type of arithmetic on decimals, following the SQL precision and scale rules.
+///
+/// Returns `None` if the operation doesn't produce a decimal.
+#[cfg(feature = "dtype-decimal")]
+fn get_decimal_arithmetic_dtype(
+    left: &DataType,
+    right: &DataType,
+    op: Operator,
+) -> PolarsResult<Option<DataType>> {
+    use DataType::*;
+    let (left, right) = match (left, right) {
+        (Decimal(_, _), Decimal(_, _)) => (left.clone(), right.clone()),
+        (Decimal(_, _), _) | (_, Decimal(_, _)) => {
+            // Both sides get cast to the supertype before the operation.
+            let st = try_get_supertype(left, right)?;
+            (st.clone(), st)
+        },
+        _ => return Ok(None),
+    };
+    let (Decimal(p1, Some(s1)), Decimal(p2, Some(s2))) = (left, right) else {
+        return Ok(None);
+    };
+    let dtype = match op {
+        Operator::Plus | Operator::Minus => decimal_add_dtype(p1, s1, p2, s2),
+        Operator::Multiply => decimal_mul_dtype(p1, s1, p2, s2)?,
+        Operator::Divide | Operator::TrueDivide => decimal_div_dtype(p1, s1, p2, s2),
+        _ => return Ok(None),
+    };
+    Ok(Some(dtype))
+}
+
 fn get_truediv_field(
     left: Node,
     right: Node,
@@ -661,6 +725,10 @@ fn get_truediv_field(
     // TODO: Re-investigate this. A lot of "_" is being used on the RHS match because this code
     // originally (mostly) only looked at the LHS dtype.
     let out_type = match (left_field.dtype(), right_field.dtype()) {
+        #[cfg(feature = "dtype-decimal")]
+        (l, r) if l.is_decimal() || r.is_decimal() => {
+            get_arithmetic_supertype(l, r, Operator::TrueDivide)?
+        },
         (l @ List(a), r @ List(b))
             if ![a, b]
                 .into_iter()
@@ -1,7 +1,12 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "ipc_streaming"
+))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
@@ -10,7 +15,12 @@ use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 use polars_io::HiveOptions;
-#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "ipc_streaming"
+))]
 use polars_io::RowIndex;
 
 #[cfg(feature = "python")]
@@ -90,6 +100,7 @@ impl DslBuilder {
         glob: bool,
         include_file_paths: Option<PlSmallStr>,
         allow_missing_columns: bool,
+        decryption: Option<polars_io::parquet::read::FileDecryptionProperties>,
     ) -> PolarsResult<Self> {
         let options = FileScanOptions {
             with_columns: None,
@@ -113,6 +124,7 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    decryption,
                 },
                 cloud_options,
                 metadata: None,
@@ -160,6 +172,37 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "ipc_streaming")]
+    pub fn scan_ipc_stream(
+        sources: ScanSources,
+        n_rows: Option<usize>,
+        cache: bool,
+        row_index: Option<RowIndex>,
+        rechunk: bool,
+        cloud_options: Option<CloudOptions>,
+        include_file_paths: Option<PlSmallStr>,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            file_options: FileScanOptions {
+                with_columns: None,
+                cache,
+                slice: n_rows.map(|x| (0, x)),
+                rechunk,
+                row_index,
+                file_counter: Default::default(),
+                hive_options: Default::default(),
+                glob: true,
+                include_file_paths,
+                allow_missing_columns: false,
+            },
+            scan_type: FileScan::IpcStream { cloud_options },
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
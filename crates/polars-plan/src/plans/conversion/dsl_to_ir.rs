@@ -161,6 +161,17 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                 }
 
+                if file_options.hive_options.partition_scheme.is_some() {
+                    match file_options.hive_options.enabled {
+                        None => file_options.hive_options.enabled = Some(true),
+                        Some(false) => polars_bail!(
+                            ComputeError:
+                            "a partition scheme was given but hive_partitioning was disabled"
+                        ),
+                        Some(true) => {},
+                    }
+                }
+
                 let sources = match &scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet {
@@ -172,6 +183,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         ref cloud_options, ..
                     } => sources
                         .expand_paths_with_hive_update(&mut file_options, cloud_options.as_ref())?,
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { cloud_options } => {
+                        sources.expand_paths(&file_options, cloud_options.as_ref())?
+                    },
                     #[cfg(feature = "csv")]
                     FileScan::Csv {
                         ref cloud_options, ..
@@ -188,6 +203,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         cloud_options,
                         metadata,
                     } => {
+                        if options.decryption.is_some() && sources.is_cloud_url() {
+                            polars_bail!(nyi = "decryption of parquet files in cloud storage");
+                        }
+
                         if let Some(schema) = &options.schema {
                             // We were passed a schema, we don't have to call `parquet_file_info`,
                             // but this does mean we don't have `row_estimation` and `first_metadata`.
@@ -202,6 +221,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             let (file_info, md) = scans::parquet_file_info(
                                 &sources,
                                 &file_options,
+                                options,
                                 cloud_options.as_ref(),
                             )
                             .map_err(|e| e.context(failed_here!(parquet scan)))?;
@@ -222,6 +242,11 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         *metadata = Some(md);
                         file_info
                     },
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { cloud_options } => {
+                        scans::ipc_stream_file_info(&sources, &file_options, cloud_options.as_ref())
+                            .map_err(|e| e.context(failed_here!(ipc stream scan)))?
+                    },
                     #[cfg(feature = "csv")]
                     FileScan::Csv {
                         options,
@@ -277,6 +302,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             Either::Right(v) => v.as_ref(),
                         },
                         file_options.hive_options.try_parse_dates,
+                        file_options.hive_options.partition_scheme.as_deref(),
                     )?
                 } else {
                     None
@@ -298,6 +324,8 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         FileScan::Parquet { .. } => true,
                         #[cfg(feature = "ipc")]
                         FileScan::Ipc { .. } => true,
+                        #[cfg(feature = "ipc_streaming")]
+                        FileScan::IpcStream { .. } => true,
                         #[cfg(feature = "csv")]
                         FileScan::Csv { .. } => true,
                         #[cfg(feature = "json")]
@@ -8,7 +8,7 @@ use polars_io::RowIndex;
 
 use super::*;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "ipc_streaming"))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
         let _ = schema.insert_at_index(0, rc.name.clone(), IDX_DTYPE);
@@ -32,6 +32,7 @@ fn prepare_schemas(mut schema: Schema, row_index: Option<&RowIndex>) -> (SchemaR
 pub(super) fn parquet_file_info(
     sources: &ScanSources,
     file_options: &FileScanOptions,
+    options: &ParquetOptions,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
@@ -57,7 +58,8 @@ pub(super) fn parquet_file_info(
                 .first()
                 .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 source"))?;
             let memslice = first_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_decryption(options.decryption.clone());
             (
                 reader.schema()?,
                 Some(reader.num_rows()?),
@@ -131,6 +133,55 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "ipc_streaming")]
+pub(super) fn ipc_stream_file_info(
+    sources: &ScanSources,
+    file_options: &FileScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    // The stream format has no footer, so remote sources are downloaded as a whole.
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = arrow::io::ipc::read::read_stream_metadata(&mut std::io::Cursor::new(memslice))?;
+    let reader_schema = Arc::new(metadata.schema);
+
+    Ok(FileInfo::new(
+        prepare_output_schema(
+            Schema::from_arrow_schema(reader_schema.as_ref()),
+            file_options.row_index.as_ref(),
+        ),
+        Some(Either::Left(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub(super) fn csv_file_info(
     sources: &ScanSources,
@@ -143,6 +194,8 @@ pub(super) fn csv_file_info(
     use polars_core::error::feature_gated;
     use polars_core::{config, POOL};
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
+    use polars_io::prelude::_csv_read_internal::CountLines;
+    use polars_io::utils::compression::maybe_decompress_bytes_head;
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -178,7 +231,25 @@ pub(super) fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        // Compressed files are only decompressed as far as needed for schema inference.
+        let bytes = match csv_options.infer_schema_length {
+            Some(n) => {
+                let parse_options = csv_options.parse_options.as_ref();
+                let line_counter =
+                    CountLines::new(parse_options.quote_char, parse_options.eol_char);
+                let n_lines = csv_options.skip_rows
+                    + csv_options.has_header as usize
+                    + csv_options.skip_rows_after_header
+                    + n;
+
+                maybe_decompress_bytes_head(&memslice, owned, n_lines, |bytes| {
+                    let (count, position) = line_counter.count(bytes);
+                    (count, position + 1)
+                })?
+            },
+            None => maybe_decompress_bytes(&memslice, owned)?,
+        };
+        let mut reader = std::io::Cursor::new(bytes);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -264,6 +335,7 @@ pub(super) fn ndjson_file_info(
 ) -> PolarsResult<FileInfo> {
     use polars_core::config;
     use polars_core::error::feature_gated;
+    use polars_io::utils::compression::{count_eol, maybe_decompress_bytes_head};
 
     let Some(first) = sources.first() else {
         polars_bail!(ComputeError: "expected at least 1 source");
@@ -303,10 +375,34 @@ pub(super) fn ndjson_file_info(
         }
     } else {
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
-
-        let schema =
-            polars_io::ndjson::infer_schema(&mut reader, ndjson_options.infer_schema_length)?;
+        // Compressed files are only decompressed as far as needed for schema inference.
+        let bytes = match ndjson_options.infer_schema_length {
+            Some(n) => maybe_decompress_bytes_head(&memslice, owned, n.get(), |bytes| {
+                count_eol(bytes, b'\n')
+            })?,
+            None => maybe_decompress_bytes(&memslice, owned)?,
+        };
+        let mut reader = std::io::Cursor::new(bytes);
+
+        let (schema, conflicts) = polars_io::ndjson::infer_schema_with_conflicts(
+            &mut reader,
+            ndjson_options.infer_schema_length,
+            ndjson_options.conflict_resolution,
+        )?;
+        // Values of these fields are rewritten as strings, let the user know which ones.
+        if !conflicts.is_empty()
+            && matches!(
+                ndjson_options.conflict_resolution,
+                polars_io::json::JsonConflictResolution::String
+                    | polars_io::json::JsonConflictResolution::JsonEncoded
+            )
+        {
+            let conflicts = conflicts.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+            polars_warn!(
+                "conflicting types found during JSON schema inference, read as strings: {}",
+                conflicts.join(", ")
+            );
+        }
 
         prepare_schemas(schema, file_options.row_index.as_ref())
     };
@@ -115,6 +115,37 @@ fn err_date_str_compare() -> PolarsResult<()> {
     }
 }
 
+/// Rewrite `temporal ± interval` to an `offset_by`, which respects calendars and time zones.
+#[cfg(feature = "dtype-interval")]
+fn offset_by_interval(
+    expr_arena: &mut Arena<AExpr>,
+    node_temporal: Node,
+    node_interval: Node,
+    negate: bool,
+) -> AExpr {
+    let options = FunctionOptions {
+        collect_groups: ApplyOptions::ElementWise,
+        ..Default::default()
+    };
+    let node_interval = if negate {
+        expr_arena.add(AExpr::Function {
+            input: vec![ExprIR::from_node(node_interval, expr_arena)],
+            function: FunctionExpr::Negate,
+            options,
+        })
+    } else {
+        node_interval
+    };
+    AExpr::Function {
+        input: vec![
+            ExprIR::from_node(node_temporal, expr_arena),
+            ExprIR::from_node(node_interval, expr_arena),
+        ],
+        function: FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy(Default::default())),
+        options,
+    }
+}
+
 pub(super) fn process_binary(
     expr_arena: &mut Arena<AExpr>,
     lp_arena: &Arena<IR>,
@@ -212,6 +243,38 @@ pub(super) fn process_binary(
     if op.is_arithmetic() {
         match (&type_left, &type_right) {
             (Duration(_), Duration(_)) => return Ok(None),
+            // Decimal arithmetic derives its output type from both operand types.
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(_, _), Decimal(_, _))
+                if matches!(
+                    op,
+                    Operator::Plus
+                        | Operator::Minus
+                        | Operator::Multiply
+                        | Operator::Divide
+                        | Operator::TrueDivide
+                ) =>
+            {
+                return Ok(None)
+            },
+            #[cfg(feature = "dtype-interval")]
+            (Datetime(_, _) | Date, Interval) if matches!(op, Operator::Plus | Operator::Minus) => {
+                return Ok(Some(offset_by_interval(
+                    expr_arena,
+                    node_left,
+                    node_right,
+                    op == Operator::Minus,
+                )))
+            },
+            #[cfg(feature = "dtype-interval")]
+            (Interval, Datetime(_, _) | Date) if op == Operator::Plus => {
+                return Ok(Some(offset_by_interval(
+                    expr_arena, node_right, node_left, false,
+                )))
+            },
+            // Fall through to the supertype cast of the duration.
+            #[cfg(feature = "dtype-interval")]
+            (Interval, Duration(_)) | (Duration(_), Interval) => {},
             (Duration(_), r) if r.is_numeric() => return Ok(None),
             (String, a) | (a, String) if a.is_numeric() => {
                 polars_bail!(InvalidOperation: "arithmetic on string and numeric not allowed, try an explicit cast first")
@@ -33,6 +33,10 @@ pub enum FileScan {
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<arrow::io::ipc::read::FileMetadata>,
     },
+    #[cfg(feature = "ipc_streaming")]
+    IpcStream {
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+    },
     #[cfg(feature = "json")]
     NDJson {
         options: NDJsonReadOptions,
@@ -85,6 +89,11 @@ impl PartialEq for FileScan {
                     ..
                 },
             ) => l == r && c_l == c_r,
+            #[cfg(feature = "ipc_streaming")]
+            (
+                FileScan::IpcStream { cloud_options: l },
+                FileScan::IpcStream { cloud_options: r },
+            ) => l == r,
             #[cfg(feature = "json")]
             (
                 FileScan::NDJson {
@@ -133,6 +142,8 @@ impl Hash for FileScan {
                 options.hash(state);
                 cloud_options.hash(state);
             },
+            #[cfg(feature = "ipc_streaming")]
+            FileScan::IpcStream { cloud_options } => cloud_options.hash(state),
             #[cfg(feature = "json")]
             FileScan::NDJson {
                 options,
@@ -167,6 +178,8 @@ impl FileScan {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => _file_options.row_index.is_some(),
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => _file_options.row_index.is_some(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => _file_options.row_index.is_some(),
             #[allow(unreachable_patterns)]
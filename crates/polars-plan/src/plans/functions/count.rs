@@ -4,10 +4,11 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "ipc_streaming"
 ))]
 use polars_core::error::feature_gated;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "json", feature = "ipc_streaming"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::{
@@ -16,7 +17,7 @@ use polars_io::csv::read::{
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::parquet::read::ParquetAsyncReader;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetReader;
+use polars_io::parquet::read::{ParquetOptions, ParquetReader};
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
 #[cfg(any(feature = "json", feature = "parquet"))]
@@ -34,7 +35,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "ipc_streaming"
     )))]
     {
         unreachable!()
@@ -44,7 +46,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "ipc_streaming"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -54,9 +57,11 @@ pub fn count_rows(
                 cloud_options,
             } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
-            FileScan::Parquet { cloud_options, .. } => {
-                count_rows_parquet(sources, cloud_options.as_ref())
-            },
+            FileScan::Parquet {
+                options,
+                cloud_options,
+                ..
+            } => count_rows_parquet(sources, options, cloud_options.as_ref()),
             #[cfg(feature = "ipc")]
             FileScan::Ipc {
                 options,
@@ -68,6 +73,10 @@ pub fn count_rows(
                 cloud_options.as_ref(),
                 metadata.as_ref(),
             ),
+            #[cfg(feature = "ipc_streaming")]
+            FileScan::IpcStream { cloud_options } => {
+                count_rows_ipc_stream(sources, cloud_options.as_ref())
+            },
             #[cfg(feature = "json")]
             FileScan::NDJson {
                 options,
@@ -123,6 +132,7 @@ fn count_all_rows_csv(
 #[cfg(feature = "parquet")]
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
+    options: &ParquetOptions,
     #[allow(unused)] cloud_options: Option<&CloudOptions>,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
@@ -141,7 +151,9 @@ pub(super) fn count_rows_parquet(
         sources
             .iter()
             .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .with_decryption(options.decryption.clone())
+                    .num_rows()
             })
             .sum::<PolarsResult<usize>>()
     }
@@ -213,13 +225,54 @@ async fn count_rows_cloud_ipc(
         .map(|rows| rows.iter().map(|v| *v as usize).sum())
 }
 
+#[cfg(feature = "ipc_streaming")]
+pub(super) fn count_rows_ipc_stream(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use arrow::io::ipc::read::{get_stream_row_count, read_stream_metadata};
+    use polars_core::config;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    (0..sources.len())
+        .map(|i| {
+            let memslice =
+                sources
+                    .at(i)
+                    .to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            let mut reader = std::io::Cursor::new(memslice);
+            read_stream_metadata(&mut reader)?;
+            get_stream_row_count(&mut reader).map(|v| v as usize)
+        })
+        .sum::<PolarsResult<usize>>()
+}
+
 #[cfg(feature = "json")]
 pub(super) fn count_rows_ndjson(
     sources: &ScanSources,
     cloud_options: Option<&CloudOptions>,
 ) -> PolarsResult<usize> {
     use polars_core::config;
-    use polars_io::utils::compression::maybe_decompress_bytes;
 
     if sources.is_empty() {
         return Ok(0);
@@ -253,11 +306,7 @@ pub(super) fn count_rows_ndjson(
             let memslice =
                 source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
 
-            let owned = &mut vec![];
-            let reader = polars_io::ndjson::core::JsonLineReader::new(std::io::Cursor::new(
-                maybe_decompress_bytes(&memslice[..], owned)?,
-            ));
-            reader.count()
+            polars_io::ndjson::core::count_rows(&memslice[..])
         })
         .sum()
 }
@@ -67,13 +67,14 @@ pub fn hive_partitions_from_paths(
     schema: Option<SchemaRef>,
     reader_schema: &Schema,
     try_parse_dates: bool,
+    partition_scheme: Option<&str>,
 ) -> PolarsResult<Option<Arc<Vec<HivePartitions>>>> {
     let Some(path) = paths.first() else {
         return Ok(None);
     };
 
     let sep = separator(path);
-    let path_string = path.to_str().unwrap();
+    let scheme = partition_scheme.map(PartitionScheme::try_new).transpose()?;
 
     fn parse_hive_string_and_decode(part: &'_ str) -> Option<(&'_ str, std::borrow::Cow<'_, str>)> {
         let (k, v) = parse_hive_string(part)?;
@@ -99,8 +100,30 @@ pub fn hive_partitions_from_paths(
         }};
     }
 
+    // The (name, value) pairs of every path.
+    let hive_parts = paths
+        .iter()
+        .map(|path| {
+            let path = path.to_str().unwrap();
+            match &scheme {
+                Some(scheme) => scheme.extract(&path[hive_start_idx..], sep, path),
+                None => Ok(get_hive_parts_iter!(path).collect()),
+            }
+        })
+        .collect::<PolarsResult<Vec<Vec<_>>>>()?;
+
+    // Columns with a format in the partition scheme always get the dtype of their format.
+    let formatted_dtypes = scheme
+        .as_ref()
+        .map(|scheme| scheme.formatted_dtypes())
+        .unwrap_or_default();
+
     let hive_schema = if let Some(ref schema) = schema {
-        Arc::new(get_hive_parts_iter!(path_string).map(|(name, _)| {
+        Arc::new(hive_parts[0].iter().map(|(name, _)| {
+                if let Some(dtype) = formatted_dtypes.get(name) {
+                    return Ok(Field::new(PlSmallStr::from_str(name), dtype.clone()));
+                }
+
                 let Some(dtype) = schema.get(name) else {
                     polars_bail!(
                         SchemaFieldNotFound:
@@ -123,7 +146,13 @@ pub fn hive_partitions_from_paths(
         let mut schema_inference_map: PlHashMap<&str, PlHashSet<DataType>> =
             PlHashMap::with_capacity(16);
 
-        for (name, _) in get_hive_parts_iter!(path_string) {
+        for (name, _) in hive_parts[0].iter() {
+            let name = *name;
+            if let Some(dtype) = formatted_dtypes.get(name) {
+                hive_schema.insert_at_index(hive_schema.len(), name.into(), dtype.clone())?;
+                continue;
+            }
+
             // If the column is also in the file we can use the dtype stored there.
             if let Some(dtype) = reader_schema.get(name) {
                 let dtype = if !try_parse_dates && dtype.is_temporal() {
@@ -145,8 +174,8 @@ pub fn hive_partitions_from_paths(
         }
 
         if !schema_inference_map.is_empty() {
-            for path in paths {
-                for (name, value) in get_hive_parts_iter!(path.to_str().unwrap()) {
+            for parts in hive_parts.iter() {
+                for (name, value) in parts {
                     let Some(entry) = schema_inference_map.get_mut(name) else {
                         continue;
                     };
@@ -167,19 +196,25 @@ pub fn hive_partitions_from_paths(
         Arc::new(hive_schema)
     };
 
+    // Formatted columns are parsed separately, so they are buffered as strings.
+    let mut buffer_schema = hive_schema.as_ref().clone();
+    for name in formatted_dtypes.keys() {
+        if let Ok(dtype) = buffer_schema.try_get_mut(name) {
+            *dtype = DataType::String;
+        }
+    }
+
     let mut buffers = polars_io::csv::read::buffer::init_buffers(
         &(0..hive_schema.len()).collect::<Vec<_>>(),
         paths.len(),
-        hive_schema.as_ref(),
+        &buffer_schema,
         None,
         polars_io::prelude::CsvEncoding::Utf8,
         false,
     )?;
 
-    for path in paths {
-        let path = path.to_str().unwrap();
-
-        for (name, value) in get_hive_parts_iter!(path) {
+    for (path, parts) in paths.iter().zip(hive_parts.iter()) {
+        for (name, value) in parts {
             let Some(index) = hive_schema.index_of(name) else {
                 polars_bail!(
                     SchemaFieldNotFound:
@@ -205,6 +240,14 @@ pub fn hive_partitions_from_paths(
         .map(|x| x.into_series())
         .collect::<PolarsResult<Vec<_>>>()?;
 
+    if let Some(scheme) = &scheme {
+        for s in buffers.iter_mut() {
+            if let Some(format) = scheme.format_of(s.name()) {
+                *s = parse_formatted_partition_values(s, format)?;
+            }
+        }
+    }
+
     buffers.sort_by_key(|s| reader_schema.index_of(s.name()).unwrap_or(usize::MAX));
 
     #[allow(clippy::needless_range_loop)]
@@ -264,3 +307,284 @@ fn parse_hive_string(part: &'_ str) -> Option<(&'_ str, &'_ str)> {
 
     Some((name, value))
 }
+
+enum SchemePart {
+    Literal(String),
+    /// Index into [`PartitionScheme::columns`].
+    Placeholder(usize),
+}
+
+struct SchemeColumn {
+    name: PlSmallStr,
+    /// `chrono` format of the values, joined with `/` if the column appears multiple times.
+    format: Option<String>,
+}
+
+/// A user-provided layout of the directories containing the files of a partitioned dataset,
+/// e.g. `{year}/{month}/{day}` or `region={region}/{date:%Y%m%d}`.
+///
+/// Every `/`-separated segment of the scheme is matched against the corresponding directory
+/// directly above the file. `{name}` captures a value whose dtype is inferred like regular Hive
+/// values; `{name:format}` parses the value with a `chrono` format into a `Date`, or a
+/// `Datetime` if the format contains a time. A column may appear multiple times, in which case
+/// its values and formats are joined with `/`. `{year}`, `{month}`, `{day}`, `{hour}`,
+/// `{minute}` and `{second}` are shorthands for the corresponding parts of a `date` column.
+struct PartitionScheme {
+    segments: Vec<Vec<SchemePart>>,
+    columns: Vec<SchemeColumn>,
+}
+
+impl PartitionScheme {
+    fn try_new(scheme: &str) -> PolarsResult<Self> {
+        let mut segments = vec![];
+        let mut columns: Vec<SchemeColumn> = vec![];
+
+        for segment in scheme.trim_matches('/').split('/') {
+            let mut parts = vec![];
+            let mut rest = segment;
+
+            while !rest.is_empty() {
+                let Some(start) = rest.find('{') else {
+                    parts.push(SchemePart::Literal(rest.to_string()));
+                    break;
+                };
+                if start > 0 {
+                    parts.push(SchemePart::Literal(rest[..start].to_string()));
+                }
+                let Some(len) = rest[start..].find('}') else {
+                    polars_bail!(ComputeError: "unclosed '{{' in partition scheme '{}'", scheme)
+                };
+                let placeholder = &rest[start + 1..start + len];
+                rest = &rest[start + len + 1..];
+
+                polars_ensure!(
+                    !matches!(parts.last(), Some(SchemePart::Placeholder(_))),
+                    ComputeError: "partition scheme '{}' contains adjacent placeholders", scheme
+                );
+
+                let (name, format) = match placeholder.split_once(':') {
+                    Some((name, format)) => (name, Some(format)),
+                    None => match placeholder {
+                        "year" => ("date", Some("%Y")),
+                        "month" => ("date", Some("%m")),
+                        "day" => ("date", Some("%d")),
+                        "hour" => ("date", Some("%H")),
+                        "minute" => ("date", Some("%M")),
+                        "second" => ("date", Some("%S")),
+                        name => (name, None),
+                    },
+                };
+                polars_ensure!(
+                    !name.is_empty(),
+                    ComputeError: "partition scheme '{}' contains an unnamed placeholder", scheme
+                );
+
+                let idx = match columns.iter().position(|c| c.name == name) {
+                    Some(idx) => {
+                        let column = &mut columns[idx];
+                        match (&mut column.format, format) {
+                            (Some(existing), Some(format)) => {
+                                existing.push('/');
+                                existing.push_str(format);
+                            },
+                            _ => polars_bail!(
+                                ComputeError:
+                                "partition column '{}' appears multiple times in partition scheme \
+                                '{}' but does not have a format in every placeholder",
+                                name, scheme
+                            ),
+                        }
+                        idx
+                    },
+                    None => {
+                        columns.push(SchemeColumn {
+                            name: PlSmallStr::from_str(name),
+                            format: format.map(str::to_string),
+                        });
+                        columns.len() - 1
+                    },
+                };
+                parts.push(SchemePart::Placeholder(idx));
+            }
+
+            segments.push(parts);
+        }
+
+        Ok(Self { segments, columns })
+    }
+
+    fn format_of(&self, name: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .find(|c| c.name == name)
+            .and_then(|c| c.format.as_deref())
+    }
+
+    fn formatted_dtypes(&self) -> PlHashMap<&str, DataType> {
+        self.columns
+            .iter()
+            .filter_map(|c| Some((c.name.as_str(), format_dtype(c.format.as_deref()?))))
+            .collect()
+    }
+
+    /// Extract the partition values from the directories of `path_part`, which is the part of
+    /// `path` after the Hive start index.
+    fn extract<'a>(
+        &'a self,
+        path_part: &'a str,
+        sep: &[char],
+        path: &str,
+    ) -> PolarsResult<Vec<(&'a str, std::borrow::Cow<'a, str>)>> {
+        let mismatch = || {
+            polars_err!(
+                ComputeError: "path does not match the partition scheme: {}", path
+            )
+        };
+
+        let dirs = path_part.split(sep).collect::<Vec<_>>();
+        let n_dirs = dirs.len().saturating_sub(1);
+        if n_dirs < self.segments.len() {
+            return Err(mismatch());
+        }
+        let dirs = &dirs[n_dirs - self.segments.len()..n_dirs];
+
+        let mut values: Vec<Option<std::borrow::Cow<'a, str>>> =
+            (0..self.columns.len()).map(|_| None).collect();
+
+        for (parts, dir) in self.segments.iter().zip(dirs) {
+            let mut rest = *dir;
+
+            for (i, part) in parts.iter().enumerate() {
+                match part {
+                    SchemePart::Literal(literal) => {
+                        rest = rest.strip_prefix(literal.as_str()).ok_or_else(mismatch)?;
+                    },
+                    SchemePart::Placeholder(idx) => {
+                        let end = match parts.get(i + 1) {
+                            Some(SchemePart::Literal(literal)) => {
+                                rest.find(literal.as_str()).ok_or_else(mismatch)?
+                            },
+                            _ => rest.len(),
+                        };
+                        let value = percent_encoding::percent_decode(rest[..end].as_bytes())
+                            .decode_utf8()
+                            .map_err(|_| mismatch())?;
+                        rest = &rest[end..];
+
+                        let slot = &mut values[*idx];
+                        *slot = Some(match slot.take() {
+                            Some(prev) => format!("{}/{}", prev, value).into(),
+                            None => value,
+                        });
+                    },
+                }
+            }
+
+            if !rest.is_empty() {
+                return Err(mismatch());
+            }
+        }
+
+        Ok(self
+            .columns
+            .iter()
+            .zip(values)
+            .map(|(c, v)| (c.name.as_str(), v.unwrap_or_default()))
+            .collect())
+    }
+}
+
+/// The dtype of the values of a partition scheme column with the given `chrono` format.
+fn format_dtype(format: &str) -> DataType {
+    const TIME_SPECIFIERS: [&str; 11] = [
+        "%H", "%I", "%k", "%l", "%M", "%S", "%T", "%R", "%X", "%c", "%s",
+    ];
+
+    if TIME_SPECIFIERS.iter().any(|s| format.contains(s)) {
+        DataType::Datetime(TimeUnit::Microseconds, None)
+    } else {
+        DataType::Date
+    }
+}
+
+/// Parse the string values of a partition scheme column using its `chrono` format.
+#[cfg(feature = "temporal")]
+fn parse_formatted_partition_values(s: &Series, format: &str) -> PolarsResult<Series> {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    // `chrono` requires a full date (and an hour and minute for datetimes), so we complete the
+    // format with the parts a partition scheme such as `{year}/{month}` leaves out.
+    let mut format = format.to_string();
+    let mut suffix = String::new();
+    let has_any = |format: &str, specifiers: &[&str]| specifiers.iter().any(|s| format.contains(s));
+    if !has_any(&format, &["%m", "%b", "%B", "%j", "%F", "%D", "%c", "%s"]) {
+        format.push_str("/%m");
+        suffix.push_str("/01");
+    }
+    if !has_any(&format, &["%d", "%e", "%j", "%F", "%D", "%c", "%s"]) {
+        format.push_str("/%d");
+        suffix.push_str("/01");
+    }
+
+    let dtype = format_dtype(&format);
+    if matches!(dtype, DataType::Datetime(_, _))
+        && !has_any(&format, &["%M", "%T", "%R", "%X", "%c", "%s"])
+    {
+        format.push_str(" %M");
+        suffix.push_str(" 00");
+    }
+
+    let ca = s.str()?;
+    let parse_err = |v: &str| {
+        polars_err!(
+            ComputeError: "could not parse partition value '{}' of column '{}' with format '{}'",
+            v, s.name(), format
+        )
+    };
+
+    let out = match dtype {
+        DataType::Date => {
+            let out: Int32Chunked = ca
+                .iter()
+                .map(|opt_v| {
+                    opt_v
+                        .map(|v| {
+                            NaiveDate::parse_from_str(&format!("{v}{suffix}"), &format)
+                                .map(|d| {
+                                    (d - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days()
+                                        as i32
+                                })
+                                .map_err(|_| parse_err(v))
+                        })
+                        .transpose()
+                })
+                .collect::<PolarsResult<_>>()?;
+            out.with_name(s.name().clone()).into_date().into_series()
+        },
+        _ => {
+            let out: Int64Chunked = ca
+                .iter()
+                .map(|opt_v| {
+                    opt_v
+                        .map(|v| {
+                            NaiveDateTime::parse_from_str(&format!("{v}{suffix}"), &format)
+                                .map(|dt| dt.and_utc().timestamp_micros())
+                                .map_err(|_| parse_err(v))
+                        })
+                        .transpose()
+                })
+                .collect::<PolarsResult<_>>()?;
+            out.with_name(s.name().clone())
+                .into_datetime(TimeUnit::Microseconds, None)
+                .into_series()
+        },
+    };
+    Ok(out)
+}
+
+#[cfg(not(feature = "temporal"))]
+fn parse_formatted_partition_values(_s: &Series, _format: &str) -> PolarsResult<Series> {
+    polars_bail!(
+        ComputeError: "parsing formatted partition values requires the 'temporal' feature"
+    )
+}
@@ -39,6 +39,13 @@ impl IR {
             Sink { payload, .. } => match payload {
                 SinkType::Memory => "sink (memory)",
                 SinkType::File { .. } => "sink (file)",
+                #[cfg(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                ))]
+                SinkType::Partitioned { .. } => "sink (partitioned)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
             },
@@ -356,6 +356,13 @@ impl<'a> TreeFmtNode<'a> {
                             match payload {
                                 SinkType::Memory => "SINK (memory)",
                                 SinkType::File { .. } => "SINK (file)",
+                                #[cfg(any(
+                                    feature = "parquet",
+                                    feature = "ipc",
+                                    feature = "csv",
+                                    feature = "json"
+                                ))]
+                                SinkType::Partitioned { .. } => "SINK (partitioned)",
                                 #[cfg(feature = "cloud")]
                                 SinkType::Cloud { .. } => "SINK (cloud)",
                             },
@@ -61,6 +61,9 @@ pub enum LiteralValue {
     Duration(i64, TimeUnit),
     #[cfg(feature = "dtype-time")]
     Time(i64),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(i32, i32, i64),
     Series(SpecialEq<Series>),
     OtherScalar(Scalar),
     // Used for dynamic languages
@@ -142,6 +145,10 @@ impl LiteralValue {
             DateTime(v, tu, tz) => AnyValue::Datetime(*v, *tu, tz.as_ref()),
             #[cfg(feature = "dtype-time")]
             Time(v) => AnyValue::Time(*v),
+            #[cfg(feature = "dtype-interval")]
+            Interval(months, days, ns) => {
+                AnyValue::Interval(arrow::types::months_days_ns::new(*months, *days, *ns))
+            },
             Series(_) => return None,
             Int(v) => materialize_dyn_int(*v),
             Float(v) => AnyValue::Float64(*v),
@@ -219,6 +226,8 @@ impl LiteralValue {
             LiteralValue::Null => DataType::Null,
             #[cfg(feature = "dtype-time")]
             LiteralValue::Time(_) => DataType::Time,
+            #[cfg(feature = "dtype-interval")]
+            LiteralValue::Interval(_, _, _) => DataType::Interval,
             LiteralValue::Int(v) => DataType::Unknown(UnknownKind::Int(*v)),
             LiteralValue::Float(_) => DataType::Unknown(UnknownKind::Float),
             LiteralValue::StrCat(_) => DataType::Unknown(UnknownKind::Str),
@@ -317,6 +326,8 @@ impl From<AnyValue<'_>> for LiteralValue {
             AnyValue::Duration(value, tu) => LiteralValue::Duration(value, tu),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(v) => LiteralValue::Time(v),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => LiteralValue::Interval(v.months(), v.days(), v.ns()),
             AnyValue::List(l) => Self::Series(SpecialEq::new(l)),
             AnyValue::StringOwned(o) => Self::String(o),
             #[cfg(feature = "dtype-categorical")]
@@ -441,6 +452,17 @@ impl Literal for ChronoDuration {
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+impl Literal for arrow::types::months_days_ns {
+    fn lit(self) -> Expr {
+        Expr::Literal(LiteralValue::Interval(
+            self.months(),
+            self.days(),
+            self.ns(),
+        ))
+    }
+}
+
 #[cfg(feature = "dtype-duration")]
 impl Literal for Duration {
     fn lit(self) -> Expr {
@@ -417,6 +417,8 @@ impl ProjectionPushDown {
                     FileScan::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { .. } => true,
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScan::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
@@ -10,9 +10,16 @@ use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
-use polars_io::json::JsonWriterOptions;
+use polars_io::json::{JsonConflictResolution, JsonWriterOptions};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+use polars_io::partition::PartitionedWriteOptions;
 use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
@@ -296,6 +303,17 @@ pub enum SinkType {
         path: Arc<PathBuf>,
         file_type: FileType,
     },
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv",
+        feature = "json"
+    ))]
+    Partitioned {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        options: PartitionedWriteOptions,
+    },
     #[cfg(feature = "cloud")]
     Cloud {
         uri: Arc<String>,
@@ -403,4 +421,5 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    pub conflict_resolution: JsonConflictResolution,
 }
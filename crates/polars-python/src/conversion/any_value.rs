@@ -49,10 +49,12 @@ pub(crate) fn any_value_into_py_object(av: AnyValue, py: Python) -> PyObject {
         AnyValue::UInt16(v) => v.into_py(py),
         AnyValue::UInt32(v) => v.into_py(py),
         AnyValue::UInt64(v) => v.into_py(py),
+        AnyValue::UInt128(v) => v.into_py(py),
         AnyValue::Int8(v) => v.into_py(py),
         AnyValue::Int16(v) => v.into_py(py),
         AnyValue::Int32(v) => v.into_py(py),
         AnyValue::Int64(v) => v.into_py(py),
+        AnyValue::Int128(v) => v.into_py(py),
         AnyValue::Float32(v) => v.into_py(py),
         AnyValue::Float64(v) => v.into_py(py),
         AnyValue::Null => py.None(),
@@ -94,6 +96,7 @@ pub(crate) fn any_value_into_py_object(av: AnyValue, py: Python) -> PyObject {
             time_delta.into_py(py)
         },
         AnyValue::Time(v) => nanos_since_midnight_to_naivetime(v).into_py(py),
+        AnyValue::Interval(v) => (v.months(), v.days(), v.ns()).into_py(py),
         AnyValue::Array(v, _) | AnyValue::List(v) => PySeries::new(v).to_list(),
         ref av @ AnyValue::Struct(_, _, flds) => struct_dict(py, av._iter_struct_av(), flds),
         AnyValue::StructOwned(payload) => struct_dict(py, payload.0.into_iter(), &payload.1),
@@ -95,6 +95,18 @@ pub(crate) fn time_to_pyobject_iter(
         .map(move |opt_v| opt_v.map(nanos_since_midnight_to_naivetime))
 }
 
+/// Intervals have no Python equivalent, so they become `(months, days, nanoseconds)` tuples.
+pub(crate) fn interval_to_pyobject_iter(
+    ca: &IntervalChunked,
+) -> impl '_ + ExactSizeIterator<Item = Option<(i32, i32, i64)>> {
+    ca.0.iter().map(move |opt_v| {
+        opt_v.map(|v| {
+            let v = i128_to_interval(v);
+            (v.months(), v.days(), v.ns())
+        })
+    })
+}
+
 impl ToPyObject for Wrap<&DateChunked> {
     fn to_object(&self, py: Python) -> PyObject {
         let iter = self.0.into_iter().map(|opt_v| opt_v.map(date32_to_date));
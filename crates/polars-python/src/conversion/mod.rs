@@ -200,6 +200,10 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int64")).unwrap();
                 class.call0().unwrap().into()
             },
+            DataType::Int128 => {
+                let class = pl.getattr(intern!(py, "Int128")).unwrap();
+                class.call0().unwrap().into()
+            },
             DataType::UInt8 => {
                 let class = pl.getattr(intern!(py, "UInt8")).unwrap();
                 class.call0().unwrap().into()
@@ -216,6 +220,14 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "UInt64")).unwrap();
                 class.call0().unwrap().into()
             },
+            DataType::UInt128 => {
+                let class = pl.getattr(intern!(py, "UInt128")).unwrap();
+                class.call0().unwrap().into()
+            },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16")).unwrap();
+                class.call0().unwrap().into()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32")).unwrap();
                 class.call0().unwrap().into()
@@ -290,6 +302,7 @@ impl ToPyObject for Wrap<DataType> {
                 class.call1((series,)).unwrap().into()
             },
             DataType::Time => pl.getattr(intern!(py, "Time")).unwrap().into(),
+            DataType::Interval => pl.getattr(intern!(py, "Interval")).unwrap().into(),
             DataType::Struct(fields) => {
                 let field_class = pl.getattr(intern!(py, "Field")).unwrap();
                 let iter = fields.iter().map(|fld| {
@@ -301,6 +314,12 @@ impl ToPyObject for Wrap<DataType> {
                 let struct_class = pl.getattr(intern!(py, "Struct")).unwrap();
                 struct_class.call1((fields,)).unwrap().into()
             },
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map")).unwrap();
+                let key = Wrap(*key.clone()).to_object(py);
+                let value = Wrap(*value.clone()).to_object(py);
+                class.call1((key, value)).unwrap().into()
+            },
             DataType::Null => {
                 let class = pl.getattr(intern!(py, "Null")).unwrap();
                 class.call0().unwrap().into()
@@ -350,10 +369,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Int16" => DataType::Int16,
                     "Int32" => DataType::Int32,
                     "Int64" => DataType::Int64,
+                    "Int128" => DataType::Int128,
                     "UInt8" => DataType::UInt8,
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "UInt128" => DataType::UInt128,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -365,10 +387,12 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Time" => DataType::Time,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
+                    "Interval" => DataType::Interval,
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
                     "Struct" => DataType::Struct(vec![]),
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
                     "Object" => DataType::Object(OBJECT_NAME, None),
@@ -384,10 +408,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "Int16" => DataType::Int16,
             "Int32" => DataType::Int32,
             "Int64" => DataType::Int64,
+            "Int128" => DataType::Int128,
             "UInt8" => DataType::UInt8,
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "UInt128" => DataType::UInt128,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -407,6 +434,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             },
             "Date" => DataType::Date,
             "Time" => DataType::Time,
+            "Interval" => DataType::Interval,
             "Datetime" => {
                 let time_unit = ob.getattr(intern!(py, "time_unit")).unwrap();
                 let time_unit = time_unit.extract::<Wrap<TimeUnit>>()?.0;
@@ -436,6 +464,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let size = size.extract::<usize>()?;
                 DataType::Array(Box::new(inner.0), size)
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
             "Struct" => {
                 let fields = ob.getattr(intern!(py, "fields"))?;
                 let fields = fields
@@ -741,9 +776,28 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "previous" => InterpolationMethod::Previous,
+            "next" => InterpolationMethod::Next,
+            "cubic" => InterpolationMethod::Cubic,
+            "pchip" => InterpolationMethod::Pchip,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'previous', 'next', 'cubic', 'pchip'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<InterpolationLimitArea> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "inside" => InterpolationLimitArea::Inside,
+            "outside" => InterpolationLimitArea::Outside,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `limit_area` must be one of {{'inside', 'outside'}}, got {v}",
                 )))
             },
         };
@@ -751,6 +805,19 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
     }
 }
 
+/// Python passes `limit_area=None` for no restriction.
+pub(crate) fn interpolate_options(
+    method: Wrap<InterpolationMethod>,
+    limit: Option<IdxSize>,
+    limit_area: Option<Wrap<InterpolationLimitArea>>,
+) -> InterpolateOptions {
+    InterpolateOptions {
+        method: method.0,
+        limit,
+        limit_area: limit_area.map_or(InterpolationLimitArea::Both, |area| area.0),
+    }
+}
+
 #[cfg(feature = "avro")]
 impl<'py> FromPyObject<'py> for Wrap<Option<AvroCompression>> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -907,6 +974,23 @@ impl<'py> FromPyObject<'py> for Wrap<ListToStructWidthStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<Ambiguous> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "earliest" => Ambiguous::Earliest,
+            "latest" => Ambiguous::Latest,
+            "null" => Ambiguous::Null,
+            "raise" => Ambiguous::Raise,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`ambiguous` must be one of {{'earliest', 'latest', 'null', 'raise'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<NonExistent> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1005,6 +1089,22 @@ impl<'py> FromPyObject<'py> for Wrap<QuantileMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<RollingRankMethod> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "average" => RollingRankMethod::Average,
+            "min" => RollingRankMethod::Min,
+            "max" => RollingRankMethod::Max,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "rolling rank `method` must be one of {{'average', 'min', 'max'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1040,6 +1140,57 @@ impl<'py> FromPyObject<'py> for Wrap<Roll> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<BusinessCalendar> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let (week_mask, holidays, holiday_sets, half_days): (
+            [bool; 7],
+            Vec<i32>,
+            Vec<(String, Vec<i32>)>,
+            Vec<i32>,
+        ) = ob.call_method0("_to_epoch_days")?.extract()?;
+        let calendar = holiday_sets.into_iter().fold(
+            BusinessCalendar::new(week_mask, &holidays).with_half_days(&half_days),
+            |calendar, (name, holidays)| calendar.with_holiday_set(name.into(), &holidays),
+        );
+        Ok(Wrap(calendar))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<FiscalCalendar> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(start_month) = ob.extract::<u8>() {
+            return Ok(Wrap(FiscalCalendar::Monthly { start_month }));
+        }
+        let (pattern, end_month, week_end, year_end): (PyBackedStr, u8, u8, PyBackedStr) =
+            ob.call_method0("_to_tuple")?.extract()?;
+        let pattern = match &*pattern {
+            "4-4-5" => RetailPattern::P445,
+            "4-5-4" => RetailPattern::P454,
+            "5-4-4" => RetailPattern::P544,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`pattern` must be one of {{'4-4-5', '4-5-4', '5-4-4'}}, got {v}",
+                )))
+            },
+        };
+        let year_end = match &*year_end {
+            "last" => RetailYearEnd::Last,
+            "nearest" => RetailYearEnd::Nearest,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`year_end` must be one of {{'last', 'nearest'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(FiscalCalendar::Retail {
+            pattern,
+            end_month,
+            week_end,
+            year_end,
+        }))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<TimeUnit> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
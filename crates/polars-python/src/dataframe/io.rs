@@ -409,6 +409,10 @@ impl PyDataFrame {
                     row_group_size,
                     data_page_size,
                     maintain_order: true,
+                    bloom_filters: vec![],
+                    column_options: vec![],
+                    key_value_metadata: Default::default(),
+                    encryption: None,
                 };
                 write_partitioned_dataset(
                     &mut self.df,
@@ -20,6 +20,7 @@ pub(crate) enum PyDataType {
     UInt16,
     UInt32,
     UInt64,
+    Float16,
     Float32,
     Float64,
     Bool,
@@ -37,6 +38,10 @@ pub(crate) enum PyDataType {
     Decimal(Option<usize>, usize),
     Array(usize),
     Enum(Utf8ViewArray),
+    Int128,
+    UInt128,
+    Interval,
+    Map,
 }
 
 impl From<&DataType> for PyDataType {
@@ -47,10 +52,13 @@ impl From<&DataType> for PyDataType {
             DataType::Int16 => Int16,
             DataType::Int32 => Int32,
             DataType::Int64 => Int64,
+            DataType::Int128 => Int128,
             DataType::UInt8 => UInt8,
             DataType::UInt16 => UInt16,
             DataType::UInt32 => UInt32,
             DataType::UInt64 => UInt64,
+            DataType::UInt128 => UInt128,
+            DataType::Float16 => Float16,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::Decimal(p, s) => Decimal(*p, s.expect("unexpected null decimal scale")),
@@ -63,11 +71,13 @@ impl From<&DataType> for PyDataType {
             DataType::Datetime(tu, tz) => Datetime(*tu, tz.clone()),
             DataType::Duration(tu) => Duration(*tu),
             DataType::Time => Time,
+            DataType::Interval => Interval,
             #[cfg(feature = "object")]
             DataType::Object(_, _) => Object,
             DataType::Categorical(_, _) => Categorical,
             DataType::Enum(rev_map, _) => Enum(rev_map.as_ref().unwrap().get_categories().clone()),
             DataType::Struct(_) => Struct,
+            DataType::Map(_, _) => Map,
             DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset => {
                 panic!("null or unknown not expected here")
             },
@@ -89,10 +99,13 @@ impl From<PyDataType> for DataType {
             PyDataType::Int16 => Int16,
             PyDataType::Int32 => Int32,
             PyDataType::Int64 => Int64,
+            PyDataType::Int128 => Int128,
             PyDataType::UInt8 => UInt8,
             PyDataType::UInt16 => UInt16,
             PyDataType::UInt32 => UInt32,
             PyDataType::UInt64 => UInt64,
+            PyDataType::UInt128 => UInt128,
+            PyDataType::Float16 => Float16,
             PyDataType::Float32 => Float32,
             PyDataType::Float64 => Float64,
             PyDataType::Bool => Boolean,
@@ -103,6 +116,7 @@ impl From<PyDataType> for DataType {
             PyDataType::Datetime(tu, tz) => Datetime(tu, tz),
             PyDataType::Duration(tu) => Duration(tu),
             PyDataType::Time => Time,
+            PyDataType::Interval => Interval,
             #[cfg(feature = "object")]
             PyDataType::Object => Object(OBJECT_NAME, None),
             PyDataType::Categorical => Categorical(None, Default::default()),
@@ -110,6 +124,7 @@ impl From<PyDataType> for DataType {
             PyDataType::Struct => Struct(vec![]),
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
+            PyDataType::Map => Map(DataType::Null.into(), DataType::Null.into()),
         }
     }
 }
@@ -9,23 +9,52 @@ impl PyExpr {
     fn dt_add_business_days(
         &self,
         n: PyExpr,
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        calendar: Wrap<BusinessCalendar>,
         roll: Wrap<Roll>,
     ) -> Self {
         self.inner
             .clone()
             .dt()
-            .add_business_days(n.inner, week_mask, holidays, roll.0)
+            .add_business_days(n.inner, calendar.0, roll.0)
             .into()
     }
 
+    fn dt_is_business_day(&self, calendar: Wrap<BusinessCalendar>) -> Self {
+        self.inner.clone().dt().is_business_day(calendar.0).into()
+    }
+
+    fn dt_is_half_day(&self, calendar: Wrap<BusinessCalendar>) -> Self {
+        self.inner.clone().dt().is_half_day(calendar.0).into()
+    }
+
+    fn dt_roll_forward(&self, calendar: Wrap<BusinessCalendar>) -> Self {
+        self.inner.clone().dt().roll_forward(calendar.0).into()
+    }
+
+    fn dt_roll_backward(&self, calendar: Wrap<BusinessCalendar>) -> Self {
+        self.inner.clone().dt().roll_backward(calendar.0).into()
+    }
+
     fn dt_to_string(&self, format: &str) -> Self {
         self.inner.clone().dt().to_string(format).into()
     }
 
-    fn dt_offset_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().dt().offset_by(by.inner).into()
+    #[pyo3(signature = (by, calendar=None, fiscal_calendar=None))]
+    fn dt_offset_by(
+        &self,
+        by: PyExpr,
+        calendar: Option<Wrap<BusinessCalendar>>,
+        fiscal_calendar: Option<Wrap<FiscalCalendar>>,
+    ) -> Self {
+        let options = OffsetByOptions {
+            calendar: calendar.map(|c| c.0),
+            fiscal_calendar: fiscal_calendar.map(|c| c.0),
+        };
+        self.inner
+            .clone()
+            .dt()
+            .offset_by_with_options(by.inner, options)
+            .into()
     }
 
     fn dt_epoch_seconds(&self) -> Self {
@@ -74,8 +103,16 @@ impl PyExpr {
             .into()
     }
 
-    fn dt_truncate(&self, every: Self) -> Self {
-        self.inner.clone().dt().truncate(every.inner).into()
+    #[pyo3(signature = (every, fiscal_calendar=None))]
+    fn dt_truncate(&self, every: Self, fiscal_calendar: Option<Wrap<FiscalCalendar>>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .truncate_with_calendar(
+                every.inner,
+                fiscal_calendar.map(|c| c.0).unwrap_or_default(),
+            )
+            .into()
     }
 
     fn dt_month_start(&self) -> Self {
@@ -121,6 +158,18 @@ impl PyExpr {
     fn dt_iso_year(&self) -> Self {
         self.inner.clone().dt().iso_year().into()
     }
+    fn dt_fiscal_year(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_year(calendar.0).into()
+    }
+    fn dt_fiscal_quarter(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_quarter(calendar.0).into()
+    }
+    fn dt_fiscal_period(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_period(calendar.0).into()
+    }
+    fn dt_fiscal_week(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_week(calendar.0).into()
+    }
     fn dt_quarter(&self) -> Self {
         self.inner.clone().dt().quarter().into()
     }
@@ -8,7 +8,7 @@ use polars_core::series::IsSorted;
 use pyo3::class::basic::CompareOp;
 use pyo3::prelude::*;
 
-use crate::conversion::{parse_fill_null_strategy, vec_extract_wrapped, Wrap};
+use crate::conversion::{interpolate_options, parse_fill_null_strategy, vec_extract_wrapped, Wrap};
 use crate::error::PyPolarsErr;
 use crate::map::lazy::map_single;
 use crate::PyExpr;
@@ -730,11 +730,29 @@ impl PyExpr {
         let dtypes = vec_extract_wrapped(dtypes);
         self.inner.clone().exclude_dtype(&dtypes).into()
     }
-    fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
-        self.inner.clone().interpolate(method.0).into()
+    #[pyo3(signature = (method, limit, limit_area))]
+    fn interpolate(
+        &self,
+        method: Wrap<InterpolationMethod>,
+        limit: Option<IdxSize>,
+        limit_area: Option<Wrap<InterpolationLimitArea>>,
+    ) -> Self {
+        let options = interpolate_options(method, limit, limit_area);
+        self.inner.clone().interpolate_with_options(options).into()
     }
-    fn interpolate_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().interpolate_by(by.inner).into()
+    #[pyo3(signature = (by, method, limit, limit_area))]
+    fn interpolate_by(
+        &self,
+        by: PyExpr,
+        method: Wrap<InterpolationMethod>,
+        limit: Option<IdxSize>,
+        limit_area: Option<Wrap<InterpolationLimitArea>>,
+    ) -> Self {
+        let options = interpolate_options(method, limit, limit_area);
+        self.inner
+            .clone()
+            .interpolate_by_with_options(by.inner, options)
+            .into()
     }
 
     fn lower_bound(&self) -> Self {
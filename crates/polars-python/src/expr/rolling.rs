@@ -323,10 +323,69 @@ impl PyExpr {
             .into())
     }
 
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_mad(&self, window_size: usize, min_periods: Option<usize>, center: bool) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_mad(options).into()
+    }
+
+    #[pyo3(signature = (method, window_size, min_periods, center))]
+    fn rolling_rank(
+        &self,
+        method: Wrap<RollingRankMethod>,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_rank(method.0, options).into()
+    }
+
     fn rolling_skew(&self, window_size: usize, bias: bool) -> Self {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
 
+    #[pyo3(signature = (by, window_size, bias, min_periods, closed))]
+    fn rolling_skew_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        bias: bool,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_skew_by(by.inner, bias, options)
+            .into())
+    }
+
+    fn rolling_kurtosis(&self, window_size: usize, fisher: bool, bias: bool) -> Self {
+        self.inner
+            .clone()
+            .rolling_kurtosis(window_size, fisher, bias)
+            .into()
+    }
+
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
     fn rolling_map(
         &self,
@@ -1,16 +1,13 @@
 use polars::lazy::dsl;
+use polars::prelude::BusinessCalendar;
 use pyo3::prelude::*;
 
+use crate::conversion::Wrap;
 use crate::PyExpr;
 
 #[pyfunction]
-pub fn business_day_count(
-    start: PyExpr,
-    end: PyExpr,
-    week_mask: [bool; 7],
-    holidays: Vec<i32>,
-) -> PyExpr {
+pub fn business_day_count(start: PyExpr, end: PyExpr, calendar: Wrap<BusinessCalendar>) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    dsl::business_day_count(start, end, calendar.0).into()
 }
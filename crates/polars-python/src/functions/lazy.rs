@@ -59,6 +59,46 @@ pub fn rolling_cov(
     .into()
 }
 
+#[pyfunction]
+#[pyo3(signature = (x, y, by, window_size, min_periods, closed, ddof))]
+pub fn rolling_corr_by(
+    x: PyExpr,
+    y: PyExpr,
+    by: PyExpr,
+    window_size: &str,
+    min_periods: usize,
+    closed: Wrap<ClosedWindow>,
+    ddof: u8,
+) -> PyResult<PyExpr> {
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+        min_periods,
+        closed_window: closed.0,
+        fn_params: None,
+    };
+    Ok(dsl::rolling_corr_by(x.inner, y.inner, by.inner, options, ddof).into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (x, y, by, window_size, min_periods, closed, ddof))]
+pub fn rolling_cov_by(
+    x: PyExpr,
+    y: PyExpr,
+    by: PyExpr,
+    window_size: &str,
+    min_periods: usize,
+    closed: Wrap<ClosedWindow>,
+    ddof: u8,
+) -> PyResult<PyExpr> {
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+        min_periods,
+        closed_window: closed.0,
+        fn_params: None,
+    };
+    Ok(dsl::rolling_cov_by(x.inner, y.inner, by.inner, options, ddof).into())
+}
+
 #[pyfunction]
 pub fn arg_sort_by(
     by: Vec<PyExpr>,
@@ -13,7 +13,9 @@ use super::utils::{
     create_borrowed_np_array, dtype_supports_view, polars_dtype_to_np_temporal_dtype,
     reshape_numpy_array, series_contains_null,
 };
-use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
+use crate::conversion::chunked_array::{
+    decimal_to_pyobject_iter, interval_to_pyobject_iter, time_to_pyobject_iter,
+};
 use crate::conversion::ObjectValue;
 use crate::series::PySeries;
 
@@ -194,8 +196,20 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        Float16 => numeric_series_to_numpy::<Float32Type, f32>(py, &s.cast(&Float32).unwrap()),
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
+        // NumPy has no 128-bit integers, so these become Python ints.
+        Int128 => {
+            let ca = s.i128().unwrap();
+            let values = ca.iter().map(|v| v.into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
+        UInt128 => {
+            let ca = s.u128().unwrap();
+            let values = ca.iter().map(|v| v.into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
         Boolean => boolean_series_to_numpy(py, s),
         Date => date_series_to_numpy(py, s),
         Datetime(tu, _) => {
@@ -226,6 +240,11 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
                 },
             }
         },
+        Interval => {
+            let ca = s.interval().unwrap();
+            let values = interval_to_pyobject_iter(ca).map(|v| v.into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
         Time => {
             let ca = s.time().unwrap();
             let values = time_to_pyobject_iter(ca).map(|v| v.into_py(py));
@@ -252,6 +271,10 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             PyArray1::from_iter_bound(py, values).into_py(py)
         },
         List(_) => list_series_to_numpy(py, s, writable),
+        Map(_, _) => {
+            let s = s.map().unwrap().physical().clone().into_series();
+            list_series_to_numpy(py, &s, writable)
+        },
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
             let ca = s.struct_().unwrap();
@@ -3,6 +3,7 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
 use polars::io::{HiveOptions, RowIndex};
+use polars::prelude::BusinessCalendar;
 use polars::time::*;
 use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
@@ -303,6 +304,7 @@ impl PyLazyFrame {
             hive_start_idx: 0,
             schema: hive_schema,
             try_parse_dates: try_parse_hive_dates,
+            partition_scheme: None,
         };
 
         let mut args = ScanArgsParquet {
@@ -319,6 +321,7 @@ impl PyLazyFrame {
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
             allow_missing_columns,
+            decryption: None,
         };
 
         let sources = sources.0;
@@ -380,6 +383,7 @@ impl PyLazyFrame {
             hive_start_idx: 0,
             schema: hive_schema.map(|x| Arc::new(x.0)),
             try_parse_dates: try_parse_hive_dates,
+            partition_scheme: None,
         };
 
         let mut args = ScanArgsIpc {
@@ -691,6 +695,10 @@ impl PyLazyFrame {
             row_group_size,
             data_page_size,
             maintain_order,
+            bloom_filters: vec![],
+            column_options: vec![],
+            key_value_metadata: Default::default(),
+            encryption: None,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -714,6 +722,7 @@ impl PyLazyFrame {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
             maintain_order,
+            ..Default::default()
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -783,7 +792,10 @@ impl PyLazyFrame {
     #[cfg(all(feature = "streaming", feature = "json"))]
     #[pyo3(signature = (path, maintain_order))]
     fn sink_json(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
-        let options = JsonWriterOptions { maintain_order };
+        let options = JsonWriterOptions {
+            maintain_order,
+            ..Default::default()
+        };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -857,6 +869,7 @@ impl PyLazyFrame {
         Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
+    #[pyo3(signature = (index_column, every, period, offset, label, include_boundaries, closed, group_by, start_by, calendar, fiscal_calendar, ambiguous, non_existent))]
     fn group_by_dynamic(
         &mut self,
         index_column: PyExpr,
@@ -868,6 +881,10 @@ impl PyLazyFrame {
         closed: Wrap<ClosedWindow>,
         group_by: Vec<PyExpr>,
         start_by: Wrap<StartBy>,
+        calendar: Option<Wrap<BusinessCalendar>>,
+        fiscal_calendar: Option<Wrap<FiscalCalendar>>,
+        ambiguous: Wrap<Ambiguous>,
+        non_existent: Wrap<NonExistent>,
     ) -> PyResult<PyLazyGroupBy> {
         let closed_window = closed.0;
         let group_by = group_by
@@ -886,6 +903,10 @@ impl PyLazyFrame {
                 include_boundaries,
                 closed_window,
                 start_by: start_by.0,
+                ambiguous: ambiguous.0,
+                non_existent: non_existent.0,
+                calendar: calendar.map(|c| c.0),
+                fiscal_calendar: fiscal_calendar.map(|c| c.0),
                 ..Default::default()
             },
         );
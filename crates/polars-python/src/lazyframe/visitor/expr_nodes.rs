@@ -3,9 +3,9 @@ use polars::datatypes::TimeUnit;
 use polars::prelude::InequalityOperator;
 use polars::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
-use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "search_sorted")]
 use polars_ops::series::SearchSortedSide;
+use polars_ops::series::{InterpolationLimitArea, InterpolationMethod};
 use polars_plan::dsl::function_expr::rolling::RollingFunction;
 use polars_plan::dsl::function_expr::rolling_by::RollingFunctionBy;
 use polars_plan::dsl::{BooleanFunction, StringFunction, TemporalFunction};
@@ -487,6 +487,16 @@ impl PyDynamicGroupOptions {
         let result: &str = self.inner.start_by.into();
         Ok(result.to_object(py))
     }
+    #[getter]
+    fn ambiguous(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let result: &str = self.inner.ambiguous.into();
+        Ok(result.to_object(py))
+    }
+    #[getter]
+    fn non_existent(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let result: &str = self.inner.non_existent.into();
+        Ok(result.to_object(py))
+    }
 }
 
 #[pyclass(name = "GroupbyOptions")]
@@ -527,6 +537,25 @@ impl PyGroupbyOptions {
     }
 }
 
+fn interpolation_method_str(method: InterpolationMethod) -> &'static str {
+    match method {
+        InterpolationMethod::Linear => "linear",
+        InterpolationMethod::Nearest => "nearest",
+        InterpolationMethod::Previous => "previous",
+        InterpolationMethod::Next => "next",
+        InterpolationMethod::Cubic => "cubic",
+        InterpolationMethod::Pchip => "pchip",
+    }
+}
+
+fn interpolation_limit_area_str(limit_area: InterpolationLimitArea) -> Option<&'static str> {
+    match limit_area {
+        InterpolationLimitArea::Inside => Some("inside"),
+        InterpolationLimitArea::Outside => Some("outside"),
+        InterpolationLimitArea::Both => None,
+    }
+}
+
 pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
     let result = match expr {
         AExpr::Explode(_) => return Err(PyNotImplementedError::new_err("explode")),
@@ -622,6 +651,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     value: ns.to_object(py),
                     dtype,
                 },
+                Interval(months, days, ns) => Literal {
+                    value: (months, days, ns).to_object(py),
+                    dtype,
+                },
                 Series(s) => Literal {
                     value: PySeries::new((**s).clone()).into_py(py),
                     dtype,
@@ -804,6 +837,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"))
                 },
+                FunctionExpr::MapExpr(_) => return Err(PyNotImplementedError::new_err("map expr")),
                 FunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"))
                 },
@@ -1042,8 +1076,20 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::TimeStamp(time_unit) => {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py(py)
                     },
-                    TemporalFunction::Truncate => (PyTemporalFunction::Truncate,).into_py(py),
-                    TemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py(py),
+                    TemporalFunction::Truncate(calendar) if *calendar == Default::default() => {
+                        (PyTemporalFunction::Truncate,).into_py(py)
+                    },
+                    TemporalFunction::OffsetBy(options) if *options == Default::default() => {
+                        (PyTemporalFunction::OffsetBy,).into_py(py)
+                    },
+                    TemporalFunction::FiscalYear(_)
+                    | TemporalFunction::FiscalQuarter(_)
+                    | TemporalFunction::FiscalPeriod(_)
+                    | TemporalFunction::FiscalWeek(_)
+                    | TemporalFunction::Truncate(_)
+                    | TemporalFunction::OffsetBy(_) => {
+                        return Err(PyNotImplementedError::new_err("custom calendar"))
+                    },
                     TemporalFunction::MonthStart => (PyTemporalFunction::MonthStart,).into_py(py),
                     TemporalFunction::MonthEnd => (PyTemporalFunction::MonthEnd,).into_py(py),
                     #[cfg(feature = "timezones")]
@@ -1189,9 +1235,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunction::Std(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std"))
                     },
+                    RollingFunction::Median(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling median"))
+                    },
+                    RollingFunction::Mad(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling mad"))
+                    },
+                    RollingFunction::Rank(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling rank"))
+                    },
                     RollingFunction::Skew(_, _) => {
                         return Err(PyNotImplementedError::new_err("rolling skew"))
                     },
+                    RollingFunction::Kurtosis { .. } => {
+                        return Err(PyNotImplementedError::new_err("rolling kurtosis"))
+                    },
                     RollingFunction::CorrCov { .. } => {
                         return Err(PyNotImplementedError::new_err("rolling cor_cov"))
                     },
@@ -1218,6 +1276,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunctionBy::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"))
                     },
+                    RollingFunctionBy::SkewBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling skew by"))
+                    },
+                    RollingFunctionBy::CorrCovBy { .. } => {
+                        return Err(PyNotImplementedError::new_err("rolling cor_cov by"))
+                    },
                 },
                 FunctionExpr::ShiftAndFill => ("shift_and_fill",).to_object(py),
                 FunctionExpr::Shift => ("shift",).to_object(py),
@@ -1266,15 +1330,20 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     .to_object(py),
                 #[cfg(feature = "pct_change")]
                 FunctionExpr::PctChange => ("pct_change",).to_object(py),
-                FunctionExpr::Interpolate(method) => (
+                FunctionExpr::Interpolate(options) => (
                     "interpolate",
-                    match method {
-                        InterpolationMethod::Linear => "linear",
-                        InterpolationMethod::Nearest => "nearest",
-                    },
+                    interpolation_method_str(options.method),
+                    options.limit,
+                    interpolation_limit_area_str(options.limit_area),
+                )
+                    .to_object(py),
+                FunctionExpr::InterpolateBy(options) => (
+                    "interpolate_by",
+                    interpolation_method_str(options.method),
+                    options.limit,
+                    interpolation_limit_area_str(options.limit_area),
                 )
                     .to_object(py),
-                FunctionExpr::InterpolateBy => ("interpolate_by",).to_object(py),
                 FunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).to_object(py)
                 },
@@ -50,12 +50,14 @@ impl PySeries {
         match self.series.dtype() {
             Boolean => Ok(Wrap(
                 py.allow_threads(|| self.series.cast(&DataType::UInt8).unwrap().mean_reduce())
+                    .map_err(PyPolarsErr::from)?
                     .as_any_value(),
             )
             .into_py(py)),
             // For non-numeric output types we require mean_reduce.
-            dt if dt.is_temporal() => Ok(Wrap(
+            dt if dt.is_temporal() || dt.is_decimal() => Ok(Wrap(
                 py.allow_threads(|| self.series.mean_reduce())
+                    .map_err(PyPolarsErr::from)?
                     .as_any_value(),
             )
             .into_py(py)),
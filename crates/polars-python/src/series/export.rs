@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyList};
 
 use super::PySeries;
+use crate::conversion::chunked_array::interval_to_pyobject_iter;
 use crate::interop;
 use crate::interop::arrow::to_py::series_to_stream;
 use crate::prelude::*;
@@ -22,10 +23,16 @@ impl PySeries {
                     DataType::UInt16 => PyList::new_bound(py, series.u16().unwrap()),
                     DataType::UInt32 => PyList::new_bound(py, series.u32().unwrap()),
                     DataType::UInt64 => PyList::new_bound(py, series.u64().unwrap()),
+                    DataType::UInt128 => PyList::new_bound(py, series.u128().unwrap()),
                     DataType::Int8 => PyList::new_bound(py, series.i8().unwrap()),
                     DataType::Int16 => PyList::new_bound(py, series.i16().unwrap()),
                     DataType::Int32 => PyList::new_bound(py, series.i32().unwrap()),
                     DataType::Int64 => PyList::new_bound(py, series.i64().unwrap()),
+                    DataType::Int128 => PyList::new_bound(py, series.i128().unwrap()),
+                    DataType::Float16 => {
+                        let s = series.cast(&DataType::Float32).unwrap();
+                        PyList::new_bound(py, s.f32().unwrap())
+                    },
                     DataType::Float32 => PyList::new_bound(py, series.f32().unwrap()),
                     DataType::Float64 => PyList::new_bound(py, series.f64().unwrap()),
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -59,6 +66,11 @@ impl PySeries {
                         }
                         v
                     },
+                    DataType::Map(_, _) => {
+                        // Maps become lists of `{"key": ..., "value": ...}` entries.
+                        let ca = series.map().unwrap();
+                        return to_list_recursive(py, &ca.physical().clone().into_series());
+                    },
                     DataType::Array(_, _) => {
                         let v = PyList::empty_bound(py);
                         let ca = series.array().unwrap();
@@ -103,6 +115,10 @@ impl PySeries {
                         let ca = series.duration().unwrap();
                         return Wrap(ca).to_object(py);
                     },
+                    DataType::Interval => {
+                        let ca = series.interval().unwrap();
+                        PyList::new_bound(py, interval_to_pyobject_iter(ca))
+                    },
                     DataType::Binary => {
                         let ca = series.binary().unwrap();
                         return Wrap(ca).to_object(py);
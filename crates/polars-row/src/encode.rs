@@ -851,10 +851,12 @@ pub fn fixed_size(dtype: &ArrowDataType, dict: Option<&RowEncodingCatOrder>) ->
             _ => return None,
         },
         UInt64 => u64::ENCODED_LEN,
+        UInt128 => u128::ENCODED_LEN,
         Int8 => i8::ENCODED_LEN,
         Int16 => i16::ENCODED_LEN,
         Int32 => i32::ENCODED_LEN,
         Int64 => i64::ENCODED_LEN,
+        Int128 => i128::ENCODED_LEN,
         Decimal(precision, _) => decimal::len_from_precision(*precision),
         Float32 => f32::ENCODED_LEN,
         Float64 => f64::ENCODED_LEN,
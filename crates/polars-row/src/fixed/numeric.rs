@@ -61,6 +61,7 @@ encode_unsigned!(1, u8);
 encode_unsigned!(2, u16);
 encode_unsigned!(4, u32);
 encode_unsigned!(8, u64);
+encode_unsigned!(16, u128);
 
 // toggle the sign bit and then encode as big indian
 macro_rules! encode_signed {
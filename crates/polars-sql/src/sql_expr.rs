@@ -306,7 +306,16 @@ impl SQLExprVisitor<'_> {
             Some(s) if s.contains('-') => {
                 polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
             },
-            Some(s) => Ok(lit(Duration::parse_interval(s))),
+            Some(s) => {
+                // Only intervals with a calendar component need the `Interval` type, fixed
+                // intervals remain durations so that they can be compared to other durations.
+                let duration = Duration::try_parse_interval(s)?;
+                Ok(if duration.months() == 0 {
+                    lit(duration)
+                } else {
+                    lit(duration.to_interval())
+                })
+            },
             None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
         }
     }
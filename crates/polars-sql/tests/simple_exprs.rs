@@ -1,7 +1,6 @@
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
 use polars_sql::*;
-use polars_time::Duration;
 
 fn create_sample_df() -> DataFrame {
     let a = Column::new(
@@ -196,7 +195,7 @@ fn test_literal_exprs() {
             'foo' as string_lit,
             true as bool_lit,
             null as null_lit,
-            interval '1 quarter 2 weeks 1 day 50 seconds' as duration_lit
+            interval '1 quarter 2 weeks 1 day 50 seconds' as interval_lit
         FROM df"#;
     let df_sql = context.execute(sql).unwrap().collect().unwrap();
     let df_pl = df
@@ -207,13 +206,71 @@ fn test_literal_exprs() {
             lit("foo").alias("string_lit"),
             lit(true).alias("bool_lit"),
             lit(NULL).alias("null_lit"),
-            lit(Duration::parse("1q2w1d50s")).alias("duration_lit"),
+            lit(months_days_ns::new(3, 15, 50_000_000_000)).alias("interval_lit"),
         ])
         .collect()
         .unwrap();
     assert!(df_sql.equals_missing(&df_pl));
 }
 
+#[test]
+fn test_interval_arithmetic() {
+    let df = df! {
+        "dt" => ["2024-01-31", "2024-02-29"],
+    }
+    .unwrap()
+    .lazy()
+    .select([col("dt").cast(DataType::Date)])
+    .collect()
+    .unwrap();
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+    let sql = r#"
+        SELECT
+            dt + INTERVAL '1 month' AS next_month,
+            dt - INTERVAL '1 month 1 day' AS prev_month
+        FROM df"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "next_month" => ["2024-02-29", "2024-03-29"],
+        "prev_month" => ["2023-12-30", "2024-01-28"],
+    }
+    .unwrap()
+    .lazy()
+    .select([all().cast(DataType::Date)])
+    .collect()
+    .unwrap();
+    assert!(df_sql.equals(&expected));
+}
+
+#[test]
+fn test_fixed_interval_is_duration() {
+    let df = df! {
+        "start" => [0i64, 0],
+        "end" => [1_800_000_000i64, 7_200_000_000],
+    }
+    .unwrap()
+    .lazy()
+    .select([all().cast(DataType::Datetime(TimeUnit::Microseconds, None))])
+    .collect()
+    .unwrap();
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+    let sql = r#"
+        SELECT
+            "end" - "start" + INTERVAL '1 hour' AS elapsed
+        FROM df
+        WHERE "end" - "start" > INTERVAL '1 hour'"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    let elapsed = df_sql.column("elapsed").unwrap();
+    assert_eq!(elapsed.dtype(), &DataType::Duration(TimeUnit::Microseconds));
+    assert_eq!(
+        elapsed.get(0).unwrap(),
+        AnyValue::Duration(3 * 3_600_000_000, TimeUnit::Microseconds)
+    );
+    assert_eq!(df_sql.height(), 1);
+}
+
 #[test]
 fn test_implicit_date_string() {
     let df = df! {
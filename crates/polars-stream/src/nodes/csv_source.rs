@@ -15,7 +15,7 @@ use polars_io::prelude::_csv_read_internal::{
 };
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{CommentPrefix, CsvEncoding, CsvReadOptions};
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::ChunkedDecompressor;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_io::RowIndex;
 use polars_plan::plans::{FileInfo, ScanSources};
@@ -41,6 +41,10 @@ struct LineBatch {
     path_name: Option<PlSmallStr>,
 }
 
+/// Number of decompressed bytes per pipeline that are decompressed at once when reading
+/// compressed files.
+const DECOMPRESSION_BLOCK_SIZE_PER_PIPELINE: usize = 1 << 22;
+
 type AsyncTaskData = (
     Vec<crate::async_primitives::connector::Receiver<LineBatch>>,
     Arc<ChunkReader>,
@@ -333,129 +337,153 @@ impl CsvSourceNode {
                         );
                     }
                     let (mem_slice, path_name) = v?;
-                    let mem_slice = {
-                        let mut out = vec![];
-                        maybe_decompress_bytes(&mem_slice, &mut out)?;
-
-                        if out.is_empty() {
-                            mem_slice
-                        } else {
-                            MemSlice::from_vec(out)
-                        }
-                    };
 
-                    let bytes = mem_slice.as_ref();
-
-                    let i = find_starting_point(
-                        bytes,
-                        quote_char,
-                        eol_char,
-                        schema_len,
-                        skip_rows_before_header,
-                        skip_rows_after_header,
-                        comment_prefix,
-                        has_header,
+                    // Compressed files are decompressed one block at a time, every block ending
+                    // on a line boundary.
+                    let mut decompressor = ChunkedDecompressor::try_new(
+                        mem_slice.clone(),
+                        num_pipelines * DECOMPRESSION_BLOCK_SIZE_PER_PIPELINE,
                     )?;
-
-                    let mut bytes = &bytes[i..];
-
-                    let mut chunk_size = {
-                        let max_chunk_size = 16 * 1024 * 1024;
-                        let chunk_size = if global_slice.is_some() {
-                            max_chunk_size
-                        } else {
-                            std::cmp::min(bytes.len() / n_parts_hint, max_chunk_size)
-                        };
-
-                        // Use a small min chunk size to catch failures in tests.
-                        #[cfg(debug_assertions)]
-                        let min_chunk_size = 64;
-                        #[cfg(not(debug_assertions))]
-                        let min_chunk_size = 1024 * 4;
-                        std::cmp::max(chunk_size, min_chunk_size)
+                    let next_block = |decompressor: &mut ChunkedDecompressor| {
+                        decompressor.next_block(|bytes| {
+                            let (count, position) = line_counter.count(bytes);
+                            (count > 0).then_some(position + 1)
+                        })
                     };
 
-                    loop {
-                        if bytes.is_empty() {
-                            break;
-                        }
-
-                        let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
-                        let (count, position) = if count == 0 {
-                            (1, bytes.len())
+                    let mut mem_slice = match decompressor.as_mut() {
+                        Some(decompressor) => next_block(decompressor)?
+                            .map(MemSlice::from_vec)
+                            .unwrap_or_default(),
+                        None => mem_slice,
+                    };
+                    let mut is_first_block = true;
+
+                    'blocks: loop {
+                        let bytes = mem_slice.as_ref();
+
+                        let i = if is_first_block {
+                            find_starting_point(
+                                bytes,
+                                quote_char,
+                                eol_char,
+                                schema_len,
+                                skip_rows_before_header,
+                                skip_rows_after_header,
+                                comment_prefix,
+                                has_header,
+                            )?
                         } else {
-                            let pos = (position + 1).min(bytes.len()); // +1 for '\n'
-                            (count, pos)
+                            0
                         };
 
-                        let slice_start = bytes.as_ptr() as usize - mem_slice.as_ptr() as usize;
-
-                        bytes = &bytes[position..];
+                        let mut bytes = &bytes[i..];
 
-                        let current_row_offset = *current_row_offset_ref;
-                        *current_row_offset_ref += count;
+                        let mut chunk_size = {
+                            let max_chunk_size = 16 * 1024 * 1024;
+                            let chunk_size = if global_slice.is_some() {
+                                max_chunk_size
+                            } else {
+                                std::cmp::min(bytes.len() / n_parts_hint, max_chunk_size)
+                            };
 
-                        let slice = if let Some(global_slice) = &global_slice {
-                            match SplitSlicePosition::split_slice_at_file(
-                                current_row_offset,
-                                count,
-                                global_slice.clone(),
-                            ) {
-                                // Note that we don't check that the skipped line batches actually contain this many
-                                // lines.
-                                SplitSlicePosition::Before => continue,
-                                SplitSlicePosition::Overlapping(offset, len) => (offset, len),
-                                SplitSlicePosition::After => break 'main,
-                            }
-                        } else {
-                            // (0, 0) is interpreted as no slicing
-                            (0, 0)
+                            // Use a small min chunk size to catch failures in tests.
+                            #[cfg(debug_assertions)]
+                            let min_chunk_size = 64;
+                            #[cfg(not(debug_assertions))]
+                            let min_chunk_size = 1024 * 4;
+                            std::cmp::max(chunk_size, min_chunk_size)
                         };
 
-                        let mut mem_slice_this_chunk =
-                            mem_slice.slice(slice_start..slice_start + position);
+                        loop {
+                            if bytes.is_empty() {
+                                break;
+                            }
 
-                        let morsel_seq = *morsel_seq_ref;
-                        *morsel_seq_ref = morsel_seq.successor();
+                            let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
+                            let (count, position) = if count == 0 {
+                                (1, bytes.len())
+                            } else {
+                                let pos = (position + 1).min(bytes.len()); // +1 for '\n'
+                                (count, pos)
+                            };
 
-                        let Some(mut indexed_wait_group) = wait_groups.next().await else {
-                            break;
-                        };
+                            let slice_start = bytes.as_ptr() as usize - mem_slice.as_ptr() as usize;
+
+                            bytes = &bytes[position..];
+
+                            let current_row_offset = *current_row_offset_ref;
+                            *current_row_offset_ref += count;
+
+                            let slice = if let Some(global_slice) = &global_slice {
+                                match SplitSlicePosition::split_slice_at_file(
+                                    current_row_offset,
+                                    count,
+                                    global_slice.clone(),
+                                ) {
+                                    // Note that we don't check that the skipped line batches actually contain this many
+                                    // lines.
+                                    SplitSlicePosition::Before => continue,
+                                    SplitSlicePosition::Overlapping(offset, len) => (offset, len),
+                                    SplitSlicePosition::After => break 'main,
+                                }
+                            } else {
+                                // (0, 0) is interpreted as no slicing
+                                (0, 0)
+                            };
 
-                        let mut path_name = path_name.clone();
+                            let mut mem_slice_this_chunk =
+                                mem_slice.slice(slice_start..slice_start + position);
 
-                        loop {
-                            use crate::async_primitives::connector::SendError;
-
-                            let channel_index = indexed_wait_group.index();
-                            let wait_token = indexed_wait_group.token();
-
-                            match line_batch_senders[channel_index].try_send(LineBatch {
-                                bytes: mem_slice_this_chunk,
-                                n_lines: count,
-                                slice,
-                                row_offset: current_row_offset,
-                                morsel_seq,
-                                wait_token,
-                                path_name,
-                            }) {
-                                Ok(_) => {
-                                    wait_groups.push(indexed_wait_group.wait());
-                                    break;
-                                },
-                                Err(SendError::Closed(v)) => {
-                                    mem_slice_this_chunk = v.bytes;
-                                    path_name = v.path_name;
-                                },
-                                Err(SendError::Full(_)) => unreachable!(),
-                            }
+                            let morsel_seq = *morsel_seq_ref;
+                            *morsel_seq_ref = morsel_seq.successor();
 
-                            let Some(v) = wait_groups.next().await else {
-                                break 'main; // All channels closed
+                            let Some(mut indexed_wait_group) = wait_groups.next().await else {
+                                break 'blocks;
                             };
 
-                            indexed_wait_group = v;
+                            let mut path_name = path_name.clone();
+
+                            loop {
+                                use crate::async_primitives::connector::SendError;
+
+                                let channel_index = indexed_wait_group.index();
+                                let wait_token = indexed_wait_group.token();
+
+                                match line_batch_senders[channel_index].try_send(LineBatch {
+                                    bytes: mem_slice_this_chunk,
+                                    n_lines: count,
+                                    slice,
+                                    row_offset: current_row_offset,
+                                    morsel_seq,
+                                    wait_token,
+                                    path_name,
+                                }) {
+                                    Ok(_) => {
+                                        wait_groups.push(indexed_wait_group.wait());
+                                        break;
+                                    },
+                                    Err(SendError::Closed(v)) => {
+                                        mem_slice_this_chunk = v.bytes;
+                                        path_name = v.path_name;
+                                    },
+                                    Err(SendError::Full(_)) => unreachable!(),
+                                }
+
+                                let Some(v) = wait_groups.next().await else {
+                                    break 'main; // All channels closed
+                                };
+
+                                indexed_wait_group = v;
+                            }
                         }
+                        let Some(block) =
+                            decompressor.as_mut().map(next_block).transpose()?.flatten()
+                        else {
+                            break;
+                        };
+                        mem_slice = MemSlice::from_vec(block);
+                        is_first_block = false;
                     }
                 }
 
@@ -9,10 +9,9 @@ use polars_core::utils::arrow::io::ipc::write::{
     default_ipc_fields, dictionaries_to_encode, encode_dictionary, encode_record_batch,
     DictionaryTracker, EncodedData, WriteOptions,
 };
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
 use polars_expr::state::ExecutionState;
-use polars_io::ipc::{IpcWriter, IpcWriterOptions};
-use polars_io::SerWriter;
+use polars_io::ipc::IpcWriterOptions;
 use polars_utils::priority::Priority;
 
 use crate::async_primitives::distributor_channel::distributor_channel;
@@ -40,16 +39,22 @@ impl IpcSinkNode {
         path: &Path,
         write_options: &IpcWriterOptions,
     ) -> PolarsResult<Self> {
+        polars_ensure!(
+            write_options.record_batch_size != Some(0),
+            InvalidOperation: "record batch size must be greater than 0"
+        );
         Ok(Self {
             path: path.to_path_buf(),
 
             input_schema,
-            write_options: *write_options,
+            write_options: write_options.clone(),
 
-            compat_level: CompatLevel::newest(), // @TODO: make this accessible from outside
+            compat_level: write_options.compat_level,
 
             num_encoders: 1,
-            chunk_size: get_ideal_morsel_size(), // @TODO: change to something more appropriate
+            chunk_size: write_options
+                .record_batch_size
+                .unwrap_or_else(get_ideal_morsel_size),
         })
     }
 }
@@ -101,6 +106,7 @@ impl ComputeNode for IpcSinkNode {
 
         let options = WriteOptions {
             compression: self.write_options.compression.map(Into::into),
+            dictionary_deltas: self.write_options.dictionary_deltas,
         };
 
         let slf = &*self;
@@ -115,6 +121,7 @@ impl ComputeNode for IpcSinkNode {
             let mut dictionary_tracker = DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                emit_deltas: options.dictionary_deltas,
             };
 
             // Search for Dictionary fields and which need to handled in special ways when encoding
@@ -197,10 +204,11 @@ impl ComputeNode for IpcSinkNode {
                     let mut encoded_message = EncodedData::default();
 
                     // Encode the dictionaries and record batch.
-                    for (dict_id, dict_array) in dicts_to_encode {
+                    for (dict_id, dict_array, is_delta) in dicts_to_encode {
                         encode_dictionary(
                             dict_id,
                             dict_array.as_ref(),
+                            is_delta,
                             &options,
                             &mut encoded_dictionaries,
                         )?;
@@ -224,7 +232,7 @@ impl ComputeNode for IpcSinkNode {
         let io_runtime = polars_io::pl_async::get_runtime();
 
         let path = self.path.clone();
-        let write_options = self.write_options;
+        let write_options = self.write_options.clone();
         let input_schema = self.input_schema.clone();
 
         let io_task = io_runtime.spawn(async move {
@@ -237,8 +245,8 @@ impl ComputeNode for IpcSinkNode {
                 .open(path.as_path())
                 .await?;
             let writer = BufWriter::new(file.into_std().await);
-            let mut writer = IpcWriter::new(writer)
-                .with_compression(write_options.compression)
+            let mut writer = write_options
+                .to_writer(writer)
                 .with_parallel(false)
                 .batched(&input_schema)?;
 
@@ -0,0 +1,294 @@
+use std::io::Cursor;
+use std::ops::Range;
+use std::sync::Arc;
+
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::Column;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_core::utils::arrow::array::TryExtend;
+use polars_core::utils::arrow::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
+use polars_error::{polars_ensure, PolarsResult};
+use polars_expr::state::ExecutionState;
+use polars_io::utils::columns_to_projection;
+use polars_io::RowIndex;
+use polars_plan::plans::{FileInfo, ScanSources};
+use polars_plan::prelude::FileScanOptions;
+use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::IdxSize;
+
+use crate::morsel::{get_ideal_morsel_size, SourceToken};
+use crate::nodes::{
+    ComputeNode, JoinHandle, Morsel, MorselSeq, PortState, TaskPriority, TaskScope,
+};
+use crate::pipe::{RecvPort, SendPort};
+
+/// Reads the Arrow IPC stream format.
+///
+/// Contrary to the IPC file format, the stream format has no footer with the offsets of the
+/// record batches, so the sources are walked message-by-message by a single task.
+pub struct IpcStreamSourceNode {
+    sources: ScanSources,
+
+    config: IpcStreamSourceNodeConfig,
+
+    /// Every phase we need to be able to continue from where we left off, so we save the state of
+    /// the reader task.
+    state: IpcStreamSourceNodeState,
+}
+
+struct IpcStreamSourceNodeConfig {
+    row_index: Option<RowIndex>,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+
+    rechunk: bool,
+    include_file_paths: Option<PlSmallStr>,
+}
+
+struct IpcStreamSourceNodeState {
+    morsel_seq: u64,
+    row_idx_offset: IdxSize,
+
+    slice: Range<usize>,
+
+    source_idx: usize,
+    source: Option<Source>,
+
+    /// The part of a decoded record batch that was not yet sent when a phase ended.
+    pending: Option<DataFrame>,
+}
+
+struct Source {
+    file_path: Option<PlSmallStr>,
+    reader: StreamReader<Cursor<MemSlice>>,
+    schema: Schema,
+}
+
+impl IpcStreamSourceNode {
+    pub fn new(
+        sources: ScanSources,
+        _file_info: FileInfo,
+        file_options: FileScanOptions,
+    ) -> PolarsResult<Self> {
+        assert!(!sources.is_empty());
+
+        let FileScanOptions {
+            slice,
+            with_columns,
+            cache: _,
+            row_index,
+            rechunk,
+            file_counter: _,
+            hive_options: _,
+            glob: _,
+            include_file_paths,
+            allow_missing_columns: _,
+        } = file_options;
+
+        let state = IpcStreamSourceNodeState {
+            morsel_seq: 0,
+            row_idx_offset: row_index.as_ref().map_or(0, |ri| ri.offset),
+
+            // Always create a slice. If no slice was given, just make the biggest slice possible.
+            slice: slice.map_or(0..usize::MAX, |(offset, length)| {
+                let offset = offset as usize;
+                offset..offset + length
+            }),
+
+            source_idx: 0,
+            source: None,
+            pending: None,
+        };
+
+        Ok(IpcStreamSourceNode {
+            sources,
+            config: IpcStreamSourceNodeConfig {
+                row_index,
+                with_columns,
+                rechunk,
+                include_file_paths,
+            },
+            state,
+        })
+    }
+}
+
+impl IpcStreamSourceNodeConfig {
+    /// Open a source and position the reader right after the schema message.
+    fn open(&self, sources: &ScanSources, source_idx: usize) -> PolarsResult<Source> {
+        let run_async = sources.is_cloud_url() || config::force_async();
+
+        let source = sources.at(source_idx);
+        let file_path = self
+            .include_file_paths
+            .as_ref()
+            .map(|_| source.to_include_path_name().into());
+
+        let mut cursor = Cursor::new(source.to_memslice_async_assume_latest(run_async)?);
+        let metadata = read_stream_metadata(&mut cursor)?;
+
+        let projection = self
+            .with_columns
+            .as_ref()
+            .map(|cols| columns_to_projection(cols, &metadata.schema))
+            .transpose()?;
+        let reader = StreamReader::new(cursor, metadata, projection);
+        let schema = Schema::from_arrow_schema(reader.schema());
+
+        Ok(Source {
+            file_path,
+            reader,
+            schema,
+        })
+    }
+
+    /// Add the row index and file path columns to a (sliced) record batch.
+    fn finish_df(
+        &self,
+        mut df: DataFrame,
+        row_idx_offset: IdxSize,
+        file_path: Option<&PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
+        if self.rechunk {
+            df.rechunk_mut();
+        }
+
+        if let Some(RowIndex { name, offset: _ }) = &self.row_index {
+            df = df.with_row_index(name.clone(), Some(row_idx_offset))?;
+        }
+
+        if let Some(col) = self.include_file_paths.as_ref() {
+            df.with_column(Column::new_scalar(
+                col.clone(),
+                file_path.unwrap().clone().into(),
+                df.height(),
+            ))?;
+        }
+
+        Ok(df)
+    }
+}
+
+impl ComputeNode for IpcStreamSourceNode {
+    fn name(&self) -> &str {
+        "ipc_stream_source"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.is_empty());
+        assert_eq!(send.len(), 1);
+
+        if self.state.pending.is_none()
+            && (self.state.slice.is_empty() || self.state.source_idx >= self.sources.len())
+        {
+            send[0] = PortState::Done;
+        }
+
+        if send[0] != PortState::Done {
+            send[0] = PortState::Ready;
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.is_empty());
+        assert_eq!(send_ports.len(), 1);
+
+        let max_morsel_size = get_ideal_morsel_size();
+        let source_token = SourceToken::new();
+
+        let config = &self.config;
+        let sources = &self.sources;
+        let state = &mut self.state;
+
+        let mut sender = send_ports[0].take().unwrap().serial();
+
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            loop {
+                // Send out the last decoded record batch, which might be left over from the
+                // previous phase.
+                if let Some(mut df) = state.pending.take() {
+                    while df.height() > 0 {
+                        let morsel = df.slice(0, max_morsel_size);
+                        df = df.slice(max_morsel_size as i64, usize::MAX);
+
+                        let seq = MorselSeq::new(state.morsel_seq);
+                        state.morsel_seq += 1;
+                        let morsel = Morsel::new(morsel, seq, source_token.clone());
+                        if sender.send(morsel).await.is_err() {
+                            return Ok(());
+                        }
+
+                        if source_token.stop_requested() {
+                            if df.height() > 0 {
+                                state.pending = Some(df);
+                            }
+                            return Ok(());
+                        }
+                    }
+                }
+
+                if state.slice.is_empty() || state.source_idx >= sources.len() {
+                    break;
+                }
+
+                let source = match state.source {
+                    Some(ref mut source) => source,
+                    None => state
+                        .source
+                        .insert(config.open(sources, state.source_idx)?),
+                };
+
+                let record_batch = match source.reader.next().transpose()? {
+                    None => {
+                        state.source = None;
+                        state.source_idx += 1;
+                        continue;
+                    },
+                    Some(StreamState::Waiting) => continue,
+                    Some(StreamState::Some(record_batch)) => record_batch,
+                };
+
+                let height = record_batch.len();
+                polars_ensure!(
+                    IdxSize::try_from(height).is_ok_and(|h| state.row_idx_offset.checked_add(h).is_some()),
+                    ComputeError: "IPC stream produces more than 2^32 rows; \
+                    consider compiling with polars-bigidx feature (polars-u64-idx package on python)"
+                );
+
+                // Skip record batches that fall entirely before the slice.
+                if state.slice.start >= height {
+                    state.slice = state.slice.start - height..state.slice.end - height;
+                    state.row_idx_offset += height as IdxSize;
+                    continue;
+                }
+
+                let mut df = DataFrame::empty_with_schema(&source.schema);
+                df.try_extend(std::iter::once(record_batch))?;
+
+                let offset = state.slice.start;
+                let length = (height - offset).min(state.slice.len());
+                let df = config.finish_df(
+                    df.slice(offset as i64, length),
+                    state.row_idx_offset + offset as IdxSize,
+                    source.file_path.as_ref(),
+                )?;
+
+                state.slice = 0..state.slice.len() - length;
+                state.row_idx_offset += height as IdxSize;
+                state.pending = Some(df);
+            }
+
+            PolarsResult::Ok(())
+        }));
+    }
+}
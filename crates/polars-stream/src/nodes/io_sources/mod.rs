@@ -1 +1,3 @@
 pub mod ipc;
+#[cfg(feature = "ipc_streaming")]
+pub mod ipc_stream;
@@ -3,10 +3,14 @@ use std::sync::Arc;
 
 use polars_core::prelude::{ArrowSchema, PlHashMap};
 use polars_core::series::IsSorted;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::utils::operation_exceeded_idxsize_msg;
 use polars_error::{polars_err, PolarsResult};
 use polars_io::predicates::PhysicalIoExpr;
-use polars_io::prelude::_internal::read_this_row_group;
+use polars_io::prelude::_internal::{
+    bloom_filter_ranges, page_index_ranges, read_this_row_group,
+    read_this_row_group_with_bloom_filters, select_pages, select_sparse_pages,
+};
 use polars_io::prelude::{create_sorting_map, FileMetadata};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_io::utils::slice::SplitSlicePosition;
@@ -19,12 +23,123 @@ use super::mem_prefetch_funcs;
 use super::row_group_decode::SharedFileState;
 use crate::utils::task_handles_ext;
 
+/// Evaluates the predicate on the statistics of a row group, and then on its bloom filters if the
+/// statistics were not sufficient.
+async fn should_read_row_group(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    row_group_metadata: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    byte_source: &DynByteSource,
+) -> PolarsResult<bool> {
+    if !read_this_row_group(predicate, row_group_metadata, schema)? {
+        return Ok(false);
+    }
+
+    let mut ranges = bloom_filter_ranges(predicate, row_group_metadata);
+    if ranges.is_empty() {
+        return Ok(true);
+    }
+    let bloom_filters = byte_source.get_ranges(&mut ranges).await?;
+    read_this_row_group_with_bloom_filters(predicate, row_group_metadata, schema, &bloom_filters)
+}
+
+/// Evaluates the predicate on the page index of a row group and returns a mask of the rows that
+/// may match it.
+async fn select_row_group_pages(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    row_group_metadata: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    byte_source: &DynByteSource,
+) -> PolarsResult<Option<Bitmap>> {
+    let mut ranges = page_index_ranges(predicate, row_group_metadata);
+    if ranges.is_empty() {
+        return Ok(None);
+    }
+    let page_indexes = byte_source.get_ranges(&mut ranges).await?;
+    select_pages(predicate, row_group_metadata, schema, &page_indexes)
+}
+
+/// Fetches only the data pages that contain rows selected by `row_mask` for the column chunks of
+/// `columns` that have an offset index.
+///
+/// Columns with nested fields are always fetched in full, as all their column chunks are decoded
+/// with the same filter.
+async fn fetch_sparse_columns(
+    row_group_metadata: &RowGroupMetadata,
+    columns: &[PlSmallStr],
+    row_mask: &Bitmap,
+    byte_source: &DynByteSource,
+) -> PolarsResult<PlHashMap<usize, SparseColumnChunk>> {
+    let column_chunks = columns
+        .iter()
+        .filter_map(|name| {
+            let mut iter = row_group_metadata.columns_under_root_iter(name)?;
+            (iter.len() == 1).then(|| iter.next().unwrap())
+        })
+        .filter(|col| !col.is_encrypted() && col.offset_index_range().is_some())
+        .collect::<Vec<_>>();
+    if column_chunks.is_empty() {
+        return Ok(PlHashMap::default());
+    }
+
+    let mut ranges = column_chunks
+        .iter()
+        .map(|col| {
+            let range = col.offset_index_range().unwrap();
+            range.start as usize..range.end as usize
+        })
+        .collect::<Vec<_>>();
+    let offset_indexes = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut sparse_pages = Vec::with_capacity(column_chunks.len());
+    for col in column_chunks {
+        let offset_index = &offset_indexes[&(col.offset_index_range().unwrap().start as usize)];
+        if let Some(pages) = select_sparse_pages(col, offset_index, row_mask)? {
+            sparse_pages.push((col.byte_range().start as usize, pages));
+        }
+    }
+
+    let mut ranges = sparse_pages
+        .iter()
+        .flat_map(|(_, pages)| pages.byte_ranges.iter().cloned())
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    Ok(sparse_pages
+        .into_iter()
+        .map(|(column_start, pages)| {
+            let mut bytes =
+                Vec::with_capacity(pages.byte_ranges.iter().map(|range| range.len()).sum());
+            for range in &pages.byte_ranges {
+                bytes.extend_from_slice(&bytes_map[&range.start]);
+            }
+            let chunk = SparseColumnChunk {
+                bytes: MemSlice::from_vec(bytes),
+                rows: pages.rows,
+            };
+            (column_start, chunk)
+        })
+        .collect())
+}
+
+/// A column chunk of which only the data pages that contain selected rows were fetched.
+pub(super) struct SparseColumnChunk {
+    /// The dictionary page, if any, followed by the fetched data pages.
+    pub(super) bytes: MemSlice,
+    /// The rows of the row group in the fetched data pages.
+    pub(super) rows: Vec<std::ops::Range<usize>>,
+}
+
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
 pub(super) struct RowGroupData {
     pub(super) fetched_bytes: FetchedBytes,
+    /// Column chunks that were fetched sparsely, by the start of their byte range.
+    pub(super) sparse_columns: PlHashMap<usize, SparseColumnChunk>,
     pub(super) path_index: usize,
     pub(super) row_offset: usize,
     pub(super) slice: Option<(usize, usize)>,
+    /// The rows within `slice` that may match the predicate according to the page index.
+    pub(super) page_mask: Option<Bitmap>,
     pub(super) file_max_row_group_height: usize,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: PlHashMap<usize, IsSorted>,
@@ -93,11 +208,14 @@ impl RowGroupDataFetcher {
                 self.current_row_group_idx += 1;
 
                 if self.use_statistics
-                    && !match read_this_row_group(
+                    && !match should_read_row_group(
                         self.predicate.as_deref(),
                         &row_group_metadata,
                         self.reader_schema.as_ref(),
-                    ) {
+                        self.current_byte_source.as_ref(),
+                    )
+                    .await
+                    {
                         Ok(v) => v,
                         Err(e) => return Some(Err(e)),
                     }
@@ -156,6 +274,44 @@ impl RowGroupDataFetcher {
                     None
                 };
 
+                let page_mask = if self.use_statistics {
+                    match select_row_group_pages(
+                        self.predicate.as_deref(),
+                        &row_group_metadata,
+                        self.reader_schema.as_ref(),
+                        self.current_byte_source.as_ref(),
+                    )
+                    .await
+                    {
+                        Ok(v) => v,
+                        Err(e) => return Some(Err(e)),
+                    }
+                } else {
+                    None
+                };
+                // Rows outside of the slice are never selected.
+                let page_mask = page_mask.map(|page_mask| match slice {
+                    Some((offset, len)) => {
+                        let mut mask = MutableBitmap::with_capacity(num_rows);
+                        mask.extend_constant(offset, false);
+                        mask.extend_from_bitmap(&page_mask.sliced(offset, len));
+                        mask.extend_constant(num_rows - offset - len, false);
+                        mask.freeze()
+                    },
+                    None => page_mask,
+                });
+
+                if page_mask.as_ref().is_some_and(|mask| mask.set_bits() == 0) {
+                    if self.verbose {
+                        eprintln!(
+                            "[ParquetSource]: Predicate pushdown: \
+                            Skipped all pages of row group {} in file {} ({} rows)",
+                            current_row_group_idx, self.current_path_index, num_rows
+                        );
+                    }
+                    continue;
+                }
+
                 let current_byte_source = self.current_byte_source.clone();
                 let projection = self.projection.clone();
                 let current_shared_file_state = self.current_shared_file_state.clone();
@@ -163,8 +319,25 @@ impl RowGroupDataFetcher {
                 let io_runtime = polars_io::pl_async::get_runtime();
                 let current_path_index = self.current_path_index;
                 let current_max_row_group_height = self.current_max_row_group_height;
+                let reader_schema = self.reader_schema.clone();
 
                 let handle = io_runtime.spawn(async move {
+                    let sparse_columns = match (&page_mask, current_byte_source.as_ref()) {
+                        (Some(page_mask), DynByteSource::Cloud(_)) => {
+                            let columns = projection
+                                .clone()
+                                .unwrap_or_else(|| reader_schema.iter_names().cloned().collect());
+                            fetch_sparse_columns(
+                                &row_group_metadata,
+                                &columns,
+                                page_mask,
+                                current_byte_source.as_ref(),
+                            )
+                            .await?
+                        },
+                        _ => PlHashMap::default(),
+                    };
+
                     let fetched_bytes = if let DynByteSource::MemSlice(mem_slice) =
                         current_byte_source.as_ref()
                     {
@@ -201,6 +374,7 @@ impl RowGroupDataFetcher {
                             &row_group_metadata,
                             columns.as_ref(),
                         )
+                        .filter(|range| !sparse_columns.contains_key(&range.start))
                         .collect::<Vec<_>>();
 
                         let n_ranges = ranges.len();
@@ -219,6 +393,7 @@ impl RowGroupDataFetcher {
                         let mut ranges = row_group_metadata
                             .byte_ranges_iter()
                             .map(|x| x.start as usize..x.end as usize)
+                            .filter(|range| !sparse_columns.contains_key(&range.start))
                             .collect::<Vec<_>>();
 
                         let n_ranges = ranges.len();
@@ -232,9 +407,11 @@ impl RowGroupDataFetcher {
 
                     PolarsResult::Ok(RowGroupData {
                         fetched_bytes,
+                        sparse_columns,
                         path_index: current_path_index,
                         row_offset: current_row_offset,
                         slice,
+                        page_mask,
                         file_max_row_group_height: current_max_row_group_height,
                         row_group_metadata,
                         sorting_map,
@@ -1,11 +1,14 @@
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
-    AnyValue, ArrowField, ArrowSchema, BooleanChunked, Column, DataType, IdxCa, IntoColumn,
+    AnyValue, ArrowDataType, ArrowField, ArrowSchema, BooleanChunked, Column, DataType, IdxCa,
+    IntoColumn,
 };
 use polars_core::scalar::Scalar;
 use polars_core::series::{IsSorted, Series};
+use polars_core::utils::arrow::array::{Array, BooleanArray};
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::{polars_bail, PolarsResult};
 use polars_io::hive;
@@ -13,6 +16,7 @@ use polars_io::predicates::PhysicalIoExpr;
 use polars_io::prelude::_internal::calc_prefilter_cost;
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::try_set_sorted_flag;
+use polars_parquet::read::ColumnChunkMetadata;
 use polars_plan::plans::hive::HivePartitions;
 use polars_plan::plans::ScanSources;
 use polars_utils::index::AtomicIdxSize;
@@ -74,18 +78,25 @@ impl RowGroupDecoder {
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
-            out_columns.push(s);
+            out_columns.push(match &row_group_data.page_mask {
+                Some(page_mask) => s.filter(&bitmap_to_mask(
+                    page_mask
+                        .clone()
+                        .sliced(slice_range.start, slice_range.len()),
+                ))?,
+                None => s,
+            });
         }
 
-        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_all_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        let filter = match &row_group_data.page_mask {
+            Some(page_mask) => polars_parquet::read::Filter::Mask(page_mask.clone()),
+            None => polars_parquet::read::Filter::Range(slice_range.clone()),
+        };
+        let projection_height = filter.num_rows();
 
-        let projection_height = slice_range.len();
+        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
+        self.decode_all_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         let shared_file_state = row_group_data
             .shared_file_state
@@ -330,24 +341,7 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
-    let array = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+    let array = deserialize_column_chunks(arrow_field, row_group_data, iter.collect(), filter)?;
 
     assert_eq!(array.len(), expected_num_rows);
 
@@ -367,6 +361,54 @@ fn decode_column(
     Ok(series.into_column())
 }
 
+/// Deserializes the column chunks of `arrow_field`, which may have been fetched sparsely.
+fn deserialize_column_chunks(
+    arrow_field: &ArrowField,
+    row_group_data: &RowGroupData,
+    column_chunks: Vec<&ColumnChunkMetadata>,
+    filter: Option<polars_parquet::read::Filter>,
+) -> PolarsResult<Box<dyn Array>> {
+    if let [col_md] = column_chunks.as_slice() {
+        let column_start = col_md.byte_range().start as usize;
+        if let Some(sparse) = row_group_data.sparse_columns.get(&column_start) {
+            let Some(filter) = filter else {
+                polars_bail!(ComputeError: "sparsely fetched column chunks require a filter")
+            };
+            return polars_io::prelude::_internal::sparse_to_deserializer(
+                col_md,
+                sparse.bytes.clone(),
+                &sparse.rows,
+                arrow_field.clone(),
+                filter,
+            );
+        }
+    }
+
+    let columns_to_deserialize = column_chunks
+        .into_iter()
+        .map(|col_md| {
+            let byte_range = col_md.byte_range();
+
+            (
+                col_md,
+                row_group_data
+                    .fetched_bytes
+                    .get_range(byte_range.start as usize..byte_range.end as usize),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    polars_io::prelude::_internal::to_deserializer(
+        columns_to_deserialize,
+        arrow_field.clone(),
+        filter,
+    )
+}
+
+fn bitmap_to_mask(bitmap: Bitmap) -> BooleanChunked {
+    BooleanChunked::from(BooleanArray::new(ArrowDataType::Boolean, bitmap, None))
+}
+
 /// # Safety
 /// All series in `cols` have the same length.
 async unsafe fn filter_cols(
@@ -467,7 +509,16 @@ impl RowGroupDecoder {
 
         let prefilter_setting = self.use_prefiltered.as_ref().unwrap();
         let row_group_data = Arc::new(row_group_data);
-        let projection_height = row_group_data.row_group_metadata.num_rows();
+        // The live columns are only decoded for the rows selected by the page index.
+        let live_filter = row_group_data
+            .page_mask
+            .clone()
+            .map(polars_parquet::read::Filter::Mask);
+        let projection_height = live_filter
+            .as_ref()
+            .map_or(row_group_data.row_group_metadata.num_rows(), |filter| {
+                filter.num_rows()
+            });
 
         let shared_file_state = row_group_data
             .shared_file_state
@@ -487,7 +538,10 @@ impl RowGroupDecoder {
             row_group_data.as_ref(),
             0..row_group_data.row_group_metadata.num_rows(),
         )? {
-            live_columns.push(s);
+            live_columns.push(match &row_group_data.page_mask {
+                Some(page_mask) => s.filter(&bitmap_to_mask(page_mask.clone()))?,
+                None => s,
+            });
         }
 
         // Materialize file and hive columns in sorted order - this is important for correct merging
@@ -502,7 +556,12 @@ impl RowGroupDecoder {
             .iter()
             .map(|&i| self.projected_arrow_schema.get_at_index(i).unwrap())
             .map(|(_, arrow_field)| {
-                let res = decode_column(arrow_field, &row_group_data, None, projection_height);
+                let res = decode_column(
+                    arrow_field,
+                    &row_group_data,
+                    live_filter.clone(),
+                    projection_height,
+                );
 
                 match res {
                     Ok(c) => c,
@@ -531,9 +590,7 @@ impl RowGroupDecoder {
             live_columns.push(file_path_series.slice(0, projection_height));
         }
 
-        let live_df = unsafe {
-            DataFrame::new_no_checks(row_group_data.row_group_metadata.num_rows(), live_columns)
-        };
+        let live_df = unsafe { DataFrame::new_no_checks(projection_height, live_columns) };
 
         let mask = self
             .physical_predicate
@@ -574,6 +631,20 @@ impl RowGroupDecoder {
 
         assert_eq!(mask_bitmap.len(), projection_height);
 
+        // Scatter the mask of the rows selected by the page index onto all rows of the row group.
+        let (mask_bitmap, mask) = match &row_group_data.page_mask {
+            Some(page_mask) => {
+                let mut values = mask_bitmap.iter();
+                let mask_bitmap: Bitmap = page_mask
+                    .iter()
+                    .map(|selected| selected && values.next().unwrap())
+                    .collect();
+                let mask = bitmap_to_mask(mask_bitmap.clone());
+                (mask_bitmap, Cow::Owned(mask))
+            },
+            None => (mask_bitmap, Cow::Borrowed(mask)),
+        };
+
         let prefilter_cost = calc_prefilter_cost(&mask_bitmap);
         let expected_num_rows = mask_bitmap.set_bits();
 
@@ -589,7 +660,7 @@ impl RowGroupDecoder {
                     &row_group_data,
                     prefilter_cost,
                     prefilter_setting,
-                    mask,
+                    &mask,
                     &mask_bitmap,
                     expected_num_rows,
                 ) {
@@ -652,27 +723,17 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
-    let prefilter = prefilter_setting.should_prefilter(prefilter_cost, &arrow_field.dtype);
+    // Pages that were excluded by the page index are never decoded.
+    let prefilter = prefilter_setting.should_prefilter(prefilter_cost, &arrow_field.dtype)
+        || row_group_data.page_mask.is_some();
 
     let deserialize_filter =
         prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
 
-    let array = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
+    let array = deserialize_column_chunks(
+        arrow_field,
+        row_group_data,
+        iter.collect(),
         deserialize_filter,
     )?;
 
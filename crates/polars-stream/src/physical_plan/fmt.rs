@@ -150,6 +150,8 @@ fn visualize_plan_rec(
                 FileScan::Csv { .. } => "csv-source",
                 #[cfg(feature = "ipc")]
                 FileScan::Ipc { .. } => "ipc-source",
+                #[cfg(feature = "ipc_streaming")]
+                FileScan::IpcStream { .. } => "ipc-stream-source",
                 #[cfg(feature = "json")]
                 FileScan::NDJson { .. } => "ndjson-source",
                 FileScan::Anonymous { .. } => "anonymous-source",
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{InitHashMaps, PlHashMap, PlIndexMap};
 use polars_core::schema::Schema;
-use polars_error::{polars_ensure, PolarsResult};
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{AExpr, FileScan, FunctionIR, IRAggExpr, IR};
 use polars_plan::prelude::{FileType, SinkType};
@@ -227,6 +227,15 @@ pub fn lower_ir(
                     _ => todo!(),
                 }
             },
+            #[cfg(any(
+                feature = "parquet",
+                feature = "ipc",
+                feature = "csv",
+                feature = "json"
+            ))]
+            SinkType::Partitioned { .. } => {
+                polars_bail!(nyi = "partitioned sink in the streaming engine")
+            },
             #[cfg(feature = "cloud")]
             SinkType::Cloud { .. } => todo!(),
         },
@@ -344,6 +353,15 @@ pub fn lower_ir(
                     }
                 }
 
+                #[cfg(feature = "ipc_streaming")]
+                if matches!(scan_type, FileScan::IpcStream { .. })
+                    && file_options.slice.is_some_and(|(offset, _)| offset < 0)
+                {
+                    polars_bail!(
+                        nyi = "negative slice of IPC stream files in the streaming engine"
+                    );
+                }
+
                 // Operation ordering:
                 // * with_row_index() -> slice() -> filter()
 
@@ -351,6 +369,8 @@ pub fn lower_ir(
                 let opt_rewrite_to_nodes = match &scan_type {
                     FileScan::Parquet { .. } => (None, None, None),
                     FileScan::Ipc { .. } => (None, None, predicate.take()),
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { .. } => (None, None, predicate.take()),
                     FileScan::Csv { options, .. } => {
                         if options.parse_options.comment_prefix.is_none()
                             && std::env::var("POLARS_DISABLE_EXPERIMENTAL_CSV_SLICE").as_deref()
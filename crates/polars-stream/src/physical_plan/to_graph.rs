@@ -3,7 +3,7 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use polars_core::prelude::PlRandomState;
 use polars_core::schema::{Schema, SchemaExt};
-use polars_error::PolarsResult;
+use polars_error::{polars_bail, PolarsResult};
 use polars_expr::groups::new_hash_grouper;
 use polars_expr::planner::{create_physical_expr, get_expr_depth_limit, ExpressionConversionState};
 use polars_expr::reduce::into_reduction;
@@ -350,6 +350,12 @@ fn to_graph_rec<'a>(
                         cloud_options,
                         metadata: first_metadata,
                     } => {
+                        if options.decryption.is_some() {
+                            polars_bail!(
+                                nyi = "decryption of parquet files in the streaming engine"
+                            );
+                        }
+
                         if std::env::var("POLARS_DISABLE_PARQUET_SOURCE").as_deref() != Ok("1") {
                             ctx.graph.add_node(
                                 nodes::parquet_source::ParquetSourceNode::new(
@@ -385,6 +391,19 @@ fn to_graph_rec<'a>(
                         )?,
                         [],
                     ),
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { .. } => {
+                        assert!(predicate.is_none());
+
+                        ctx.graph.add_node(
+                            nodes::io_sources::ipc_stream::IpcStreamSourceNode::new(
+                                scan_sources,
+                                file_info,
+                                file_options,
+                            )?,
+                            [],
+                        )
+                    },
                     FileScan::Csv { options, .. } => {
                         assert!(predicate.is_none());
 
@@ -1,3 +1,5 @@
+#[cfg(feature = "rolling_window")]
+use arrow::bitmap::Bitmap;
 use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
 use polars_ops::series::SeriesMethods;
 
@@ -153,6 +155,33 @@ where
     Series::try_from((ca.name().clone(), out))
 }
 
+#[cfg(feature = "rolling_window")]
+#[allow(clippy::type_complexity)]
+fn rolling_order_statistic<T>(
+    ca: &ChunkedArray<T>,
+    options: &RollingOptionsFixedWindow,
+    rolling_fn: &dyn Fn(&[T::Native], Option<&Bitmap>, usize, usize, bool) -> ArrayRef,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    if ca.is_empty() {
+        return Ok(Series::new_empty(ca.name().clone(), ca.dtype()));
+    }
+    let ca = ca.rechunk();
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let arr = rolling_fn(
+        arr.values().as_slice(),
+        arr.validity(),
+        options.window_size,
+        options.min_periods,
+        options.center,
+    );
+    Series::try_from((ca.name().clone(), arr))
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -260,6 +289,96 @@ pub trait SeriesOpsTime: AsSeries {
         })
     }
 
+    /// Apply a rolling median to a Series.
+    ///
+    /// Without weights this takes `O(n log n)` time and `O(n)` extra memory for `n` values,
+    /// independent of the window size. With weights it is computed as the linearly interpolated
+    /// 0.5 quantile.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_median(&self, mut options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        if options.weights.is_some() {
+            options.fn_params = Some(RollingFnParams::Quantile(RollingQuantileParams {
+                prob: 0.5,
+                method: QuantileMethod::Linear,
+            }));
+            return self.rolling_quantile(options);
+        }
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_order_statistic(
+                ca,
+                &options,
+                &rolling::order_statistics::rolling_median,
+            )
+        })
+    }
+
+    /// Apply a rolling median absolute deviation to a Series.
+    ///
+    /// Takes `O(n log² n)` time and `O(n)` extra memory for `n` values.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mad(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(options.weights.is_none(), InvalidOperation: "`weights` are not supported in 'rolling_mad'");
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_order_statistic(
+                ca,
+                &options,
+                &rolling::order_statistics::rolling_mad,
+            )
+        })
+    }
+
+    /// Compute the rank of every value within its window, as a fraction of the number of
+    /// non-null values in that window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(options.weights.is_none(), InvalidOperation: "`weights` are not supported in 'rolling_rank'");
+        let method = match options.fn_params {
+            Some(RollingFnParams::Rank(params)) => params.method,
+            _ => RollingRankMethod::default(),
+        };
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_order_statistic(
+                ca,
+                &options,
+                &|values, validity, window_size, min_periods, center| {
+                    rolling::order_statistics::rolling_rank(
+                        values,
+                        validity,
+                        window_size,
+                        min_periods,
+                        center,
+                        method,
+                    )
+                },
+            )
+        })
+    }
+
+    /// Apply a rolling skew to a Series based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_skew_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_skew,
+            )
+        })
+    }
+
     /// Apply a rolling min to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
     fn rolling_min_by(
@@ -362,3 +362,41 @@ where
         )
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_skew<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    if sorting_indices.is_none() {
+        rolling_apply_agg_window_sorted::<no_nulls::SkewWindow<_>, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            params,
+        )
+    } else {
+        rolling_apply_agg_window::<no_nulls::SkewWindow<_>, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            params,
+            sorting_indices,
+        )
+    }
+}
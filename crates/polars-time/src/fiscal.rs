@@ -0,0 +1,365 @@
+use arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use chrono::{Datelike, NaiveDate};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of fiscal periods in a fiscal year. Durations such as `"1fq"` are counted in periods.
+pub const FISCAL_PERIODS_PER_YEAR: i64 = 12;
+
+/// The lengths in weeks of the three periods of every quarter of a retail calendar.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RetailPattern {
+    #[default]
+    P445,
+    P454,
+    P544,
+}
+
+impl RetailPattern {
+    fn weeks(self) -> [i32; 3] {
+        match self {
+            RetailPattern::P445 => [4, 4, 5],
+            RetailPattern::P454 => [4, 5, 4],
+            RetailPattern::P544 => [5, 4, 4],
+        }
+    }
+}
+
+/// Which day ends a retail year.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RetailYearEnd {
+    /// The last `week_end` weekday of the end month.
+    #[default]
+    Last,
+    /// The `week_end` weekday closest to the last day of the end month, which may fall in the
+    /// first days of the next month.
+    Nearest,
+}
+
+/// A calendar of fiscal years, each made up of twelve fiscal periods.
+///
+/// A fiscal year is named after the calendar year in which it ends, e.g. with
+/// `Monthly { start_month: 10 }` fiscal year 2024 runs from 2023-10-01 to 2024-09-30.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FiscalCalendar {
+    /// Fiscal years of twelve calendar months, starting on the first day of `start_month`
+    /// (1 = January). The periods are the calendar months.
+    Monthly { start_month: u8 },
+    /// A retail calendar of 52 or 53 whole weeks per year, such as the 4-5-4 calendar.
+    ///
+    /// The periods of every quarter are 4 or 5 weeks long following `pattern`, and the extra week
+    /// of a 53-week year is added to the last period.
+    Retail {
+        pattern: RetailPattern,
+        /// Month in which the fiscal year ends (1 = January).
+        end_month: u8,
+        /// ISO weekday on which every week ends (1 = Monday, 7 = Sunday).
+        week_end: u8,
+        year_end: RetailYearEnd,
+    },
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> Self {
+        FiscalCalendar::Monthly { start_month: 1 }
+    }
+}
+
+pub(crate) fn to_naive_date(date: i32) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce_opt(date + EPOCH_DAYS_FROM_CE).expect("out-of-range date")
+}
+
+fn first_of_month(year: i32, month: u32) -> i32 {
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .expect("out-of-range date")
+        .num_days_from_ce()
+        - EPOCH_DAYS_FROM_CE
+}
+
+fn last_of_month(year: i32, month: u32) -> i32 {
+    if month == 12 {
+        first_of_month(year + 1, 1) - 1
+    } else {
+        first_of_month(year, month + 1) - 1
+    }
+}
+
+/// ISO weekday, 1 = Monday. The epoch was a Thursday.
+fn weekday(date: i32) -> i32 {
+    (date + 3).rem_euclid(7) + 1
+}
+
+impl FiscalCalendar {
+    pub fn validate(&self) -> PolarsResult<()> {
+        match *self {
+            FiscalCalendar::Monthly { start_month } => polars_ensure!(
+                (1..=12).contains(&start_month),
+                InvalidOperation: "fiscal `start_month` must be between 1 and 12, got {}", start_month
+            ),
+            FiscalCalendar::Retail {
+                end_month,
+                week_end,
+                ..
+            } => {
+                polars_ensure!(
+                    (1..=12).contains(&end_month),
+                    InvalidOperation: "retail calendar `end_month` must be between 1 and 12, got {}", end_month
+                );
+                polars_ensure!(
+                    (1..=7).contains(&week_end),
+                    InvalidOperation: "retail calendar `week_end` must be an ISO weekday between 1 and 7, got {}", week_end
+                );
+            },
+        }
+        Ok(())
+    }
+
+    /// Last day of retail year `year`.
+    fn retail_year_end(&self, year: i32) -> i32 {
+        let FiscalCalendar::Retail {
+            end_month,
+            week_end,
+            year_end,
+            ..
+        } = *self
+        else {
+            unreachable!()
+        };
+        let last = last_of_month(year, end_month as u32);
+        // Days since the last `week_end` on or before `last`.
+        let back = (weekday(last) - week_end as i32).rem_euclid(7);
+        match year_end {
+            RetailYearEnd::Nearest if back > 3 => last + 7 - back,
+            _ => last - back,
+        }
+    }
+
+    /// First day (in days since the epoch) of fiscal year `year`.
+    pub fn year_start(&self, year: i32) -> i32 {
+        match *self {
+            FiscalCalendar::Monthly { start_month: 1 } => first_of_month(year, 1),
+            FiscalCalendar::Monthly { start_month } => first_of_month(year - 1, start_month as u32),
+            FiscalCalendar::Retail { .. } => self.retail_year_end(year - 1) + 1,
+        }
+    }
+
+    /// Fiscal year of `date`.
+    pub fn year(&self, date: i32) -> i32 {
+        let naive = to_naive_date(date);
+        let year = naive.year();
+        match *self {
+            FiscalCalendar::Monthly { start_month } => {
+                if start_month != 1 && naive.month() >= start_month as u32 {
+                    year + 1
+                } else {
+                    year
+                }
+            },
+            FiscalCalendar::Retail { .. } => {
+                if date <= self.retail_year_end(year - 1) {
+                    year - 1
+                } else if date <= self.retail_year_end(year) {
+                    year
+                } else {
+                    year + 1
+                }
+            },
+        }
+    }
+
+    fn year_and_period(&self, date: i32) -> (i32, i8) {
+        let year = self.year(date);
+        let period = match *self {
+            FiscalCalendar::Monthly { start_month } => {
+                (to_naive_date(date).month() as i32 - start_month as i32).rem_euclid(12) + 1
+            },
+            FiscalCalendar::Retail { pattern, .. } => {
+                let week = (date - self.year_start(year)) / 7;
+                let mut period_end = 0;
+                (1..12)
+                    .find(|period| {
+                        period_end += pattern.weeks()[(period - 1) as usize % 3];
+                        week < period_end
+                    })
+                    .unwrap_or(12)
+            },
+        };
+        (year, period as i8)
+    }
+
+    /// Fiscal period (1 to 12) of `date`.
+    pub fn period(&self, date: i32) -> i8 {
+        self.year_and_period(date).1
+    }
+
+    /// Fiscal quarter (1 to 4) of `date`.
+    pub fn quarter(&self, date: i32) -> i8 {
+        (self.period(date) - 1) / 3 + 1
+    }
+
+    /// Week (1 to 53) of the fiscal year of `date`, where weeks are counted from the first day
+    /// of the fiscal year.
+    pub fn week(&self, date: i32) -> i8 {
+        ((date - self.year_start(self.year(date))) / 7 + 1) as i8
+    }
+
+    /// First day of `period` (1 to 12) of fiscal year `year`.
+    pub fn period_start(&self, year: i32, period: i8) -> i32 {
+        match *self {
+            FiscalCalendar::Monthly { start_month } => {
+                let first_year = year - (start_month != 1) as i32;
+                let months = first_year * 12 + start_month as i32 - 1 + period as i32 - 1;
+                first_of_month(months.div_euclid(12), months.rem_euclid(12) as u32 + 1)
+            },
+            FiscalCalendar::Retail { pattern, .. } => {
+                let weeks: i32 = (0..period as usize - 1)
+                    .map(|p| pattern.weeks()[p % 3])
+                    .sum();
+                self.year_start(year) + 7 * weeks
+            },
+        }
+    }
+
+    /// Number of fiscal periods between the start of fiscal year 0 and the period of `date`.
+    pub(crate) fn period_number(&self, date: i32) -> i64 {
+        let (year, period) = self.year_and_period(date);
+        year as i64 * FISCAL_PERIODS_PER_YEAR + period as i64 - 1
+    }
+
+    /// First day of the period with the given [`FiscalCalendar::period_number`].
+    pub(crate) fn period_number_start(&self, number: i64) -> i32 {
+        self.period_start(
+            number.div_euclid(FISCAL_PERIODS_PER_YEAR) as i32,
+            (number.rem_euclid(FISCAL_PERIODS_PER_YEAR) + 1) as i8,
+        )
+    }
+
+    /// Truncate `date` to the start of its window of `every` fiscal periods. Windows are aligned
+    /// to fiscal years, so `every = 3` gives fiscal quarters.
+    pub fn truncate(&self, date: i32, every: i64) -> i32 {
+        let number = self.period_number(date);
+        self.period_number_start(number - number.rem_euclid(every))
+    }
+
+    /// Move `date` by `n` fiscal periods, keeping its distance to the start of its period but
+    /// clamping it to the end of the target period (like adding months clamps the day of month).
+    pub fn offset(&self, date: i32, n: i64) -> i32 {
+        let number = self.period_number(date);
+        let day = date - self.period_number_start(number);
+        let start = self.period_number_start(number + n);
+        let len = self.period_number_start(number + n + 1) - start;
+        start + day.min(len - 1)
+    }
+}
+
+/// The local dates of a Date or Datetime series.
+pub(crate) fn local_dates(s: &Series) -> PolarsResult<DateChunked> {
+    let dates = match s.dtype() {
+        DataType::Date => s.clone(),
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => polars_ops::prelude::replace_time_zone(
+            s.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Date)?,
+        DataType::Datetime(_, _) => s.cast(&DataType::Date)?,
+        dt => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dt),
+    };
+    Ok(dates.date()?.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    #[test]
+    fn test_monthly_fiscal_calendar() {
+        let calendar = FiscalCalendar::Monthly { start_month: 10 };
+        assert_eq!(calendar.year(date(2023, 9, 30)), 2023);
+        assert_eq!(calendar.year(date(2023, 10, 1)), 2024);
+        assert_eq!(calendar.quarter(date(2023, 10, 1)), 1);
+        assert_eq!(calendar.quarter(date(2024, 1, 15)), 2);
+        assert_eq!(calendar.period(date(2024, 9, 30)), 12);
+        assert_eq!(calendar.year_start(2024), date(2023, 10, 1));
+
+        assert_eq!(calendar.truncate(date(2024, 2, 29), 3), date(2024, 1, 1));
+        assert_eq!(calendar.truncate(date(2024, 2, 29), 12), date(2023, 10, 1));
+        assert_eq!(calendar.offset(date(2024, 1, 31), 1), date(2024, 2, 29));
+        assert_eq!(calendar.offset(date(2024, 1, 31), -4), date(2023, 9, 30));
+    }
+
+    #[test]
+    fn test_retail_calendar() {
+        // The NRF 4-5-4 calendar: years end on the Saturday nearest the end of January.
+        let calendar = FiscalCalendar::Retail {
+            pattern: RetailPattern::P454,
+            end_month: 1,
+            week_end: 6,
+            year_end: RetailYearEnd::Nearest,
+        };
+        calendar.validate().unwrap();
+        // Fiscal 2023 (here: 2024, the year in which it ends) had 53 weeks.
+        assert_eq!(calendar.year_start(2024), date(2023, 1, 29));
+        assert_eq!(calendar.year_start(2025), date(2024, 2, 4));
+        assert_eq!(calendar.year(date(2024, 2, 3)), 2024);
+        assert_eq!(calendar.year(date(2024, 2, 4)), 2025);
+        assert_eq!(calendar.week(date(2024, 2, 3)), 53);
+        assert_eq!(calendar.period(date(2024, 2, 3)), 12);
+
+        // Periods of 4, 5 and 4 weeks.
+        assert_eq!(calendar.period(date(2023, 2, 25)), 1);
+        assert_eq!(calendar.period(date(2023, 2, 26)), 2);
+        assert_eq!(calendar.period(date(2023, 4, 1)), 2);
+        assert_eq!(calendar.period(date(2023, 4, 2)), 3);
+        assert_eq!(calendar.quarter(date(2023, 4, 30)), 2);
+        assert_eq!(calendar.period_start(2024, 4), date(2023, 4, 30));
+
+        assert_eq!(calendar.truncate(date(2023, 6, 1), 3), date(2023, 4, 30));
+        // The 53rd week is clamped when moving to a 4-week period.
+        assert_eq!(calendar.offset(date(2024, 2, 3), 1), date(2024, 3, 2));
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "offset_by"))]
+    fn test_fiscal_truncate_and_offset_by() -> PolarsResult<()> {
+        use crate::prelude::*;
+
+        let dates = Int32Chunked::new("".into(), [date(2024, 2, 29)]).into_date();
+        let every = StringChunked::new("".into(), ["1fq"]);
+        let calendar = FiscalCalendar::Monthly { start_month: 2 };
+
+        // Without a calendar, fiscal years start in January.
+        let out = dates.truncate(None, &every)?;
+        assert_eq!(out.get(0), Some(date(2024, 1, 1)));
+        let out = dates.truncate_with_calendar(None, &every, Some(&calendar))?;
+        assert_eq!(out.get(0), Some(date(2024, 2, 1)));
+
+        let dates = dates.into_series();
+        let offsets = every.into_series();
+        let out = impl_offset_by(&dates, &offsets)?;
+        assert_eq!(out.date()?.get(0), Some(date(2024, 5, 29)));
+        let options = OffsetByOptions {
+            fiscal_calendar: Some(FiscalCalendar::Retail {
+                pattern: RetailPattern::P454,
+                end_month: 1,
+                week_end: 6,
+                year_end: RetailYearEnd::Nearest,
+            }),
+            ..Default::default()
+        };
+        // Fiscal 2025 starts on 2024-02-04, so this is day 26 of the first period.
+        let out = impl_offset_by_with_options(&dates, &offsets, &options)?;
+        assert_eq!(out.date()?.get(0), Some(date(2024, 5, 30)));
+        Ok(())
+    }
+}
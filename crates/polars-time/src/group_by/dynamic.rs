@@ -1,3 +1,4 @@
+use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
 use arrow::legacy::utils::CustomIterTools;
 use polars_core::export::rayon::prelude::*;
@@ -5,6 +6,8 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::flatten::flatten_par;
 use polars_core::POOL;
+#[cfg(feature = "business")]
+use polars_ops::prelude::BusinessCalendar;
 use polars_ops::series::SeriesMethods;
 use polars_utils::idx_vec::IdxVec;
 use polars_utils::pl_str::PlSmallStr;
@@ -12,6 +15,7 @@ use polars_utils::slice::SortedSlice;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::fiscal::local_dates;
 use crate::prelude::*;
 
 #[repr(transparent)]
@@ -34,6 +38,19 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// How to localize window boundaries that are ambiguous in the time zone of the index column
+    /// (e.g. `01:30` when clocks fall back). Windows with a `Null` boundary are skipped.
+    pub ambiguous: Ambiguous,
+    /// How to localize window boundaries that don't exist in the time zone of the index column
+    /// (e.g. `02:30` when clocks spring forward). Windows with a `Null` boundary are skipped.
+    pub non_existent: NonExistent,
+    /// Calendar for windows in business days (e.g. `every = "1bd"`). Defaults to Monday to
+    /// Friday without holidays.
+    #[cfg(feature = "business")]
+    pub calendar: Option<BusinessCalendar>,
+    /// Calendar for windows in fiscal units (e.g. `every = "1fq"`). Defaults to fiscal years
+    /// that start in January.
+    pub fiscal_calendar: Option<FiscalCalendar>,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,6 +64,11 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            ambiguous: Ambiguous::Raise,
+            non_existent: NonExistent::Raise,
+            #[cfg(feature = "business")]
+            calendar: None,
+            fiscal_calendar: None,
         }
     }
 }
@@ -207,6 +229,24 @@ impl Wrap<&DataFrame> {
         ensure_duration_matches_dtype(options.offset, time_type, "offset")?;
         ensure_duration_matches_dtype(options.period, time_type, "period")?;
 
+        if options.every.is_business_days() {
+            #[cfg(feature = "business")]
+            return self.group_by_dynamic_business_days(&time, group_by, options);
+            #[cfg(not(feature = "business"))]
+            polars_bail!(InvalidOperation: "business-day windows require the 'business' feature");
+        }
+        polars_ensure!(
+            !options.period.is_business_days() && !options.offset.is_business_days(),
+            InvalidOperation: "`period` and `offset` can only be in business days if `every` is too"
+        );
+        if options.every.is_fiscal() {
+            return self.group_by_dynamic_fiscal(&time, group_by, options);
+        }
+        polars_ensure!(
+            !options.period.is_fiscal() && !options.offset.is_fiscal(),
+            InvalidOperation: "`period` and `offset` can only be in fiscal units if `every` is too"
+        );
+
         use DataType::*;
         let (dt, tu) = match time_type {
             Datetime(tu, _) => (time.clone(), *tu),
@@ -259,6 +299,169 @@ impl Wrap<&DataFrame> {
         self.impl_group_by_dynamic(dt, group_by, options, tu, time_type)
     }
 
+    /// Group by windows of business days, e.g. `every = "1bd"`.
+    ///
+    /// Dates are numbered by the business days since the epoch, where a day that isn't a
+    /// business day belongs to the business day before it.
+    #[cfg(feature = "business")]
+    fn group_by_dynamic_business_days(
+        &self,
+        time: &Column,
+        group_by: Vec<Column>,
+        options: &DynamicGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupsProxy)> {
+        use polars_ops::prelude::Roll;
+
+        let calendar = options.calendar.clone().unwrap_or_default();
+        calendar.validate()?;
+        polars_ensure!(
+            options.start_by.weekday().is_none(),
+            InvalidOperation: "`start_by` must be 'window' or 'datapoint' for business-day windows"
+        );
+        let to_index_duration = |duration: Duration, name: &str| {
+            polars_ensure!(
+                duration.is_zero() || duration.is_business_days(),
+                InvalidOperation: "`{}` must be in business days (e.g. '1bd') if `every` is, got {}",
+                name, duration
+            );
+            let n = duration.business_days();
+            Ok(Duration::new(if duration.negative() { -n } else { n }))
+        };
+        let index_options = DynamicGroupOptions {
+            every: to_index_duration(options.every, "every")?,
+            period: to_index_duration(options.period, "period")?,
+            offset: to_index_duration(options.offset, "offset")?,
+            ..options.clone()
+        };
+
+        let anchor = calendar.roll_forward(0);
+        self.group_by_dynamic_numbered_dates(
+            time,
+            group_by,
+            options,
+            &index_options,
+            |date| calendar.business_day_count(anchor, calendar.roll_backward(date)) as i64,
+            // `anchor` is a business day, so this can't fail.
+            |n| {
+                calendar
+                    .add_business_days(anchor, n as i32, Roll::Raise)
+                    .unwrap()
+            },
+        )
+    }
+
+    /// Group by windows of fiscal periods, e.g. `every = "1fq"`.
+    ///
+    /// Dates are numbered by the fiscal periods since the start of fiscal year 0, so windows of
+    /// a fiscal quarter or year line up with the fiscal year.
+    fn group_by_dynamic_fiscal(
+        &self,
+        time: &Column,
+        group_by: Vec<Column>,
+        options: &DynamicGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupsProxy)> {
+        let calendar = options.fiscal_calendar.unwrap_or_default();
+        calendar.validate()?;
+        polars_ensure!(
+            options.start_by.weekday().is_none(),
+            InvalidOperation: "`start_by` must be 'window' or 'datapoint' for fiscal windows"
+        );
+        let to_index_duration = |duration: Duration, name: &str| {
+            polars_ensure!(
+                duration.is_zero() || duration.is_fiscal(),
+                InvalidOperation: "`{}` must be in fiscal units (e.g. '1fq') if `every` is, got {}",
+                name, duration
+            );
+            let n = duration.fiscal_periods();
+            Ok(Duration::new(if duration.negative() { -n } else { n }))
+        };
+        let index_options = DynamicGroupOptions {
+            every: to_index_duration(options.every, "every")?,
+            period: to_index_duration(options.period, "period")?,
+            offset: to_index_duration(options.offset, "offset")?,
+            ..options.clone()
+        };
+
+        self.group_by_dynamic_numbered_dates(
+            time,
+            group_by,
+            options,
+            &index_options,
+            |date| calendar.period_number(date),
+            |n| calendar.period_number_start(n),
+        )
+    }
+
+    /// Group by windows over consecutive numbers assigned to the (local) dates of `time`.
+    ///
+    /// `to_number` numbers a date and `to_date` maps a number back to its first date. The windows
+    /// are computed over the numbers with `index_options`, after which the boundaries are mapped
+    /// back to dates (at local midnight for a Datetime column).
+    fn group_by_dynamic_numbered_dates(
+        &self,
+        time: &Column,
+        group_by: Vec<Column>,
+        options: &DynamicGroupOptions,
+        index_options: &DynamicGroupOptions,
+        to_number: impl Fn(i32) -> i64,
+        to_date: impl Fn(i64) -> i32,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupsProxy)> {
+        #[cfg(feature = "timezones")]
+        use polars_ops::prelude::replace_time_zone;
+        use DataType::{Datetime, Int64};
+
+        let numbers: Int64Chunked = local_dates(time.as_materialized_series())?
+            .apply_nonnull_values_generic(Int64, to_number);
+
+        let index_type = Datetime(TimeUnit::Nanoseconds, None);
+        let (numbers, mut keys, groups) = self.impl_group_by_dynamic(
+            numbers.into_column().cast(&index_type)?,
+            group_by,
+            index_options,
+            TimeUnit::Nanoseconds,
+            &index_type,
+        )?;
+
+        let to_dates = |numbers: &Column| -> PolarsResult<Column> {
+            let numbers_i64 = numbers.cast(&Int64)?;
+            let dates: Int32Chunked = numbers_i64
+                .i64()?
+                .apply_nonnull_values_generic(DataType::Int32, &to_date);
+            let dates = dates
+                .into_date()
+                .into_column()
+                .with_name(numbers.name().clone());
+            match time.dtype() {
+                Datetime(tu, tz) => {
+                    let naive = dates.cast(&Datetime(*tu, None))?;
+                    match tz {
+                        #[cfg(feature = "timezones")]
+                        Some(tz) => Ok(replace_time_zone(
+                            naive.datetime()?,
+                            Some(tz),
+                            &StringChunked::from_iter(std::iter::once("raise")),
+                            NonExistent::Raise,
+                        )?
+                        .into_column()),
+                        _ => Ok(naive),
+                    }
+                },
+                _ => Ok(dates),
+            }
+        };
+
+        let time_key = match options.label {
+            Label::DataPoint => unsafe { time.agg_first(&groups) },
+            _ => to_dates(&numbers)?,
+        };
+        for key in keys.iter_mut() {
+            if key.name().as_str() == UP_NAME || key.name().as_str() == LB_NAME {
+                *key = to_dates(key)?;
+            }
+        }
+        Ok((time_key, keys, groups))
+    }
+
     fn impl_group_by_dynamic(
         &self,
         mut dt: Column,
@@ -275,7 +478,8 @@ impl Wrap<&DataFrame> {
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
-        let w = Window::new(options.every, options.period, options.offset);
+        let w = Window::new(options.every, options.period, options.offset)
+            .with_dst_policies(options.ambiguous, options.non_existent);
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
 
@@ -320,7 +524,7 @@ impl Wrap<&DataFrame> {
                 include_lower_bound,
                 include_upper_bound,
                 options.start_by,
-            );
+            )?;
             update_bounds(lower, upper);
             PolarsResult::Ok(GroupsProxy::Slice {
                 groups,
@@ -354,7 +558,7 @@ impl Wrap<&DataFrame> {
                                     include_lower_bound,
                                     include_upper_bound,
                                     options.start_by,
-                                );
+                                )?;
 
                                 Ok((lower, upper, update_subgroups_idx(&sub_groups, base_g)))
                             })
@@ -388,10 +592,10 @@ impl Wrap<&DataFrame> {
                                     include_lower_bound,
                                     include_upper_bound,
                                     options.start_by,
-                                );
-                                (lower, upper, update_subgroups_slice(&sub_groups, *base_g))
+                                )?;
+                                Ok((lower, upper, update_subgroups_slice(&sub_groups, *base_g)))
                             })
-                            .collect::<Vec<_>>();
+                            .collect::<PolarsResult<Vec<_>>>()?;
 
                         let mut capacity = 0;
                         ir.iter_mut().for_each(|(lower, upper, g)| {
@@ -431,7 +635,7 @@ impl Wrap<&DataFrame> {
                                     include_lower_bound,
                                     include_upper_bound,
                                     options.start_by,
-                                );
+                                )?;
                                 Ok(update_subgroups_idx(&sub_groups, base_g))
                             })
                             .collect::<PolarsResult<Vec<_>>>()?;
@@ -453,10 +657,10 @@ impl Wrap<&DataFrame> {
                                     include_lower_bound,
                                     include_upper_bound,
                                     options.start_by,
-                                );
-                                update_subgroups_slice(&sub_groups, *base_g)
+                                )?;
+                                Ok(update_subgroups_slice(&sub_groups, *base_g))
                             })
-                            .collect::<Vec<_>>();
+                            .collect::<PolarsResult<Vec<_>>>()?;
 
                         let groups = flatten_par(&groups);
 
@@ -841,6 +1045,11 @@ mod test {
                     include_boundaries: true,
                     closed_window: ClosedWindow::Both,
                     start_by: Default::default(),
+                    ambiguous: Ambiguous::Raise,
+                    non_existent: NonExistent::Raise,
+                    #[cfg(feature = "business")]
+                    calendar: None,
+                    fiscal_calendar: None,
                 },
             )
             .unwrap();
@@ -961,6 +1170,11 @@ mod test {
                     include_boundaries: true,
                     closed_window: ClosedWindow::Both,
                     start_by: Default::default(),
+                    ambiguous: Ambiguous::Raise,
+                    non_existent: NonExistent::Raise,
+                    #[cfg(feature = "business")]
+                    calendar: None,
+                    fiscal_calendar: None,
                 },
             )
             .unwrap();
@@ -5,6 +5,7 @@ pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
+mod fiscal;
 mod group_by;
 #[cfg(feature = "month_end")]
 mod month_end;
@@ -25,6 +26,7 @@ pub use base_utc_offset::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+pub use fiscal::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 #[cfg(feature = "month_end")]
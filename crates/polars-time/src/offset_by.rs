@@ -1,14 +1,98 @@
 use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
+#[cfg(feature = "business")]
+use polars_ops::prelude::BusinessCalendar;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::Duration;
+use crate::utils::map_local_date;
+use crate::{Duration, FiscalCalendar};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OffsetByOptions {
+    /// Calendar for offsets in business days (e.g. `"3bd"`). Defaults to Monday to Friday
+    /// without holidays.
+    #[cfg(feature = "business")]
+    pub calendar: Option<BusinessCalendar>,
+    /// Calendar for offsets in fiscal units (e.g. `"1fq"`). Defaults to fiscal years that start
+    /// in January.
+    pub fiscal_calendar: Option<FiscalCalendar>,
+}
+
+/// The calendars of [`OffsetByOptions`] with their defaults filled in.
+struct Calendars {
+    #[cfg(feature = "business")]
+    business: BusinessCalendar,
+    fiscal: FiscalCalendar,
+}
+
+impl Calendars {
+    fn try_new(options: &OffsetByOptions) -> PolarsResult<Self> {
+        #[cfg(feature = "business")]
+        let business = options.calendar.clone().unwrap_or_default();
+        #[cfg(feature = "business")]
+        business.validate()?;
+        let fiscal = options.fiscal_calendar.unwrap_or_default();
+        fiscal.validate()?;
+        Ok(Self {
+            #[cfg(feature = "business")]
+            business,
+            fiscal,
+        })
+    }
+}
+
+/// Add a business-day offset such as `"3bd"`, keeping the (local) time of day.
+#[cfg(feature = "business")]
+fn add_business_days(
+    offset: &Duration,
+    t: i64,
+    tu: TimeUnit,
+    time_zone: Option<&Tz>,
+    calendar: &BusinessCalendar,
+) -> PolarsResult<i64> {
+    let n = offset.business_days() as i32;
+    let n = if offset.negative() { -n } else { n };
+    map_local_date(t, tu, time_zone, true, |date| calendar.offset(date, n))
+}
+
+fn add_offset(
+    offset: &Duration,
+    t: i64,
+    tu: TimeUnit,
+    time_zone: Option<&Tz>,
+    calendars: &Calendars,
+) -> PolarsResult<i64> {
+    if offset.is_fiscal() {
+        let n = offset.fiscal_periods();
+        let n = if offset.negative() { -n } else { n };
+        return map_local_date(t, tu, time_zone, true, |date| {
+            calendars.fiscal.offset(date, n)
+        });
+    }
+    if offset.is_business_days() {
+        #[cfg(feature = "business")]
+        return add_business_days(offset, t, tu, time_zone, &calendars.business);
+        #[cfg(not(feature = "business"))]
+        polars_bail!(InvalidOperation: "business-day offsets require the 'business' feature");
+    }
+    match tu {
+        TimeUnit::Milliseconds => offset.add_ms(t, time_zone),
+        TimeUnit::Microseconds => offset.add_us(t, time_zone),
+        TimeUnit::Nanoseconds => offset.add_ns(t, time_zone),
+    }
+}
 
 fn apply_offsets_to_datetime(
     datetime: &Logical<DatetimeType, Int64Type>,
     offsets: &StringChunked,
     time_zone: Option<&Tz>,
+    calendars: &Calendars,
 ) -> PolarsResult<Int64Chunked> {
     match offsets.len() {
         1 => match offsets.get(0) {
@@ -26,39 +110,112 @@ fn apply_offsets_to_datetime(
                     }
                     Ok(datetime.0.clone().wrapping_add_scalar(duration))
                 } else {
-                    let offset_fn = match datetime.time_unit() {
-                        TimeUnit::Milliseconds => Duration::add_ms,
-                        TimeUnit::Microseconds => Duration::add_us,
-                        TimeUnit::Nanoseconds => Duration::add_ns,
-                    };
-                    datetime
-                        .0
-                        .try_apply_nonnull_values_generic(|v| offset_fn(offset, v, time_zone))
+                    let tu = datetime.time_unit();
+                    datetime.0.try_apply_nonnull_values_generic(|v| {
+                        add_offset(offset, v, tu, time_zone, calendars)
+                    })
                 }
             },
             _ => Ok(datetime.0.apply(|_| None)),
         },
         _ => {
-            let offset_fn = match datetime.time_unit() {
-                TimeUnit::Milliseconds => Duration::add_ms,
-                TimeUnit::Microseconds => Duration::add_us,
-                TimeUnit::Nanoseconds => Duration::add_ns,
-            };
+            let tu = datetime.time_unit();
             broadcast_try_binary_elementwise(datetime, offsets, |timestamp_opt, offset_opt| match (
                 timestamp_opt,
                 offset_opt,
             ) {
-                (Some(timestamp), Some(offset)) => {
-                    offset_fn(&Duration::parse(offset), timestamp, time_zone).map(Some)
-                },
+                (Some(timestamp), Some(offset)) => add_offset(
+                    &Duration::parse(offset),
+                    timestamp,
+                    tu,
+                    time_zone,
+                    calendars,
+                )
+                .map(Some),
                 _ => Ok(None),
             })
         },
     }
 }
 
+/// Split an interval into durations that are applied one after the other.
+///
+/// The components of an interval may have different signs, so they can't be
+/// folded into a single [`Duration`].
+#[cfg(feature = "dtype-interval")]
+fn interval_to_durations(interval: months_days_ns) -> [Duration; 3] {
+    [
+        Duration::from_months(interval.months() as i64),
+        Duration::from_days(interval.days() as i64),
+        Duration::from_nsecs(interval.ns()),
+    ]
+}
+
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    broadcast_try_binary_elementwise(datetime, offsets.physical(), |timestamp_opt, offset_opt| {
+        match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => interval_to_durations(i128_to_interval(offset))
+                .iter()
+                .try_fold(timestamp, |t, duration| offset_fn(duration, t, time_zone))
+                .map(Some),
+            _ => Ok(None),
+        }
+    })
+}
+
+fn apply_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &Series,
+    time_zone: Option<&Tz>,
+    calendars: &Calendars,
+) -> PolarsResult<Int64Chunked> {
+    match offsets.dtype() {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => apply_intervals_to_datetime(datetime, offsets.interval()?, time_zone),
+        _ => apply_offsets_to_datetime(datetime, offsets.str()?, time_zone, calendars),
+    }
+}
+
+fn is_constant_offset(offsets: &Series, time_zone: Option<&str>) -> PolarsResult<bool> {
+    if offsets.len() != 1 {
+        return Ok(false);
+    }
+    match offsets.dtype() {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => Ok(offsets.interval()?.get(0).is_some_and(|offset| {
+            interval_to_durations(i128_to_interval(offset))
+                .iter()
+                .all(|duration| duration.is_constant_duration(time_zone))
+        })),
+        _ => Ok(offsets
+            .str()?
+            .get(0)
+            .is_some_and(|offset| Duration::parse(offset).is_constant_duration(time_zone))),
+    }
+}
+
 pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
-    let offsets = offsets.str()?;
+    impl_offset_by_with_options(ts, offsets, &OffsetByOptions::default())
+}
+
+/// Offset `ts` by `offsets`, resolving business-day and fiscal durations such as `"3bd"` and
+/// `"1fq"` against the calendars of `options`.
+pub fn impl_offset_by_with_options(
+    ts: &Series,
+    offsets: &Series,
+    options: &OffsetByOptions,
+) -> PolarsResult<Series> {
+    let calendars = Calendars::try_new(options)?;
     let dtype = ts.dtype();
 
     // Sortedness may not be preserved for non-constant durations,
@@ -69,16 +226,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
         DataType::Datetime(_, tz) => tz.clone(),
         _ => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dtype),
     };
-    let preserve_sortedness = match offsets.len() {
-        1 => match offsets.get(0) {
-            Some(offset) => {
-                let offset = Duration::parse(offset);
-                offset.is_constant_duration(tz.as_deref())
-            },
-            None => false,
-        },
-        _ => false,
-    };
+    let preserve_sortedness = is_constant_offset(offsets, tz.as_deref())?;
 
     let out = match dtype {
         DataType::Date => {
@@ -86,7 +234,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
                 .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap();
             let datetime = ts.datetime().unwrap();
-            let out = apply_offsets_to_datetime(datetime, offsets, None)?;
+            let out = apply_to_datetime(datetime, offsets, None, &calendars)?;
             out.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap()
                 .cast(&DataType::Date)
@@ -96,10 +244,13 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
-                Some(ref tz) => {
-                    apply_offsets_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
-                },
-                _ => apply_offsets_to_datetime(datetime, offsets, None)?,
+                Some(ref tz) => apply_to_datetime(
+                    datetime,
+                    offsets,
+                    tz.parse::<Tz>().ok().as_ref(),
+                    &calendars,
+                )?,
+                _ => apply_to_datetime(datetime, offsets, None, &calendars)?,
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
@@ -5,6 +5,7 @@ use polars_core::prelude::arity::unary_elementwise_values;
 use polars_core::prelude::*;
 
 use crate::chunkedarray::*;
+use crate::fiscal::{local_dates, FiscalCalendar};
 
 pub trait AsSeries {
     fn as_series(&self) -> &Series;
@@ -251,6 +252,36 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Fiscal year of the (local) date, named after the calendar year in which the fiscal year
+    /// ends.
+    fn fiscal_year(&self, calendar: &FiscalCalendar) -> PolarsResult<Int32Chunked> {
+        calendar.validate()?;
+        Ok(local_dates(self.as_series())?
+            .apply_nonnull_values_generic(DataType::Int32, |date| calendar.year(date)))
+    }
+
+    /// Fiscal quarter of the (local) date, ranging from 1 to 4.
+    fn fiscal_quarter(&self, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+        calendar.validate()?;
+        Ok(local_dates(self.as_series())?
+            .apply_nonnull_values_generic(DataType::Int8, |date| calendar.quarter(date)))
+    }
+
+    /// Fiscal period of the (local) date, ranging from 1 to 12.
+    fn fiscal_period(&self, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+        calendar.validate()?;
+        Ok(local_dates(self.as_series())?
+            .apply_nonnull_values_generic(DataType::Int8, |date| calendar.period(date)))
+    }
+
+    /// Week of the fiscal year of the (local) date, counted from the first day of the fiscal
+    /// year. Ranges from 1 to 53.
+    fn fiscal_week(&self, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+        calendar.validate()?;
+        Ok(local_dates(self.as_series())?
+            .apply_nonnull_values_generic(DataType::Int8, |date| calendar.week(date)))
+    }
+
     /// Convert Time into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     fn to_string(&self, format: &str) -> PolarsResult<Series> {
@@ -5,9 +5,24 @@ use polars_core::prelude::*;
 use polars_utils::cache::FastFixedCache;
 
 use crate::prelude::*;
+use crate::utils::map_local_date;
 
 pub trait PolarsTruncate {
     fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        self.truncate_with_calendar(tz, every, None)
+    }
+
+    /// Truncate to windows of `every`. Fiscal durations such as `"1fq"` are resolved against
+    /// `calendar`, which defaults to fiscal years that start in January.
+    fn truncate_with_calendar(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        calendar: Option<&FiscalCalendar>,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
@@ -19,8 +34,22 @@ pub(crate) fn fast_truncate(t: i64, every: i64) -> i64 {
 }
 
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate_with_calendar(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        calendar: Option<&FiscalCalendar>,
+    ) -> PolarsResult<Self> {
+        let calendar = calendar.copied().unwrap_or_default();
         let time_zone = self.time_zone();
+        let tu = self.time_unit();
+        // Fiscal windows start at local midnight of the first day of the window.
+        let truncate_fiscal = |t: i64, every: &Duration| {
+            map_local_date(t, tu, tz, false, |date| {
+                calendar.truncate(date, every.fiscal_periods())
+            })
+        };
+        calendar.validate()?;
         let offset = Duration::new(0);
 
         // Let's check if we can use a fastpath...
@@ -30,7 +59,11 @@ impl PolarsTruncate for DatetimeChunked {
                 if every_parsed.negative {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
-                if (time_zone.is_none() || time_zone.as_deref() == Some("UTC"))
+                if every_parsed.is_fiscal() {
+                    return Ok(self
+                        .try_apply_nonnull_values_generic(|t| truncate_fiscal(t, &every_parsed))?
+                        .into_datetime(tu, time_zone.clone()));
+                } else if (time_zone.is_none() || time_zone.as_deref() == Some("UTC"))
                     && (every_parsed.months() == 0 && every_parsed.weeks() == 0)
                 {
                     // ... yes we can! Weeks, months, and time zones require extra logic.
@@ -84,6 +117,9 @@ impl PolarsTruncate for DatetimeChunked {
                 if every.negative {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
+                if every.is_fiscal() {
+                    return truncate_fiscal(timestamp, &every).map(Some);
+                }
 
                 let w = Window::new(every, every, offset);
                 func(&w, timestamp, tz).map(Some)
@@ -95,7 +131,14 @@ impl PolarsTruncate for DatetimeChunked {
 }
 
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, _tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate_with_calendar(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        calendar: Option<&FiscalCalendar>,
+    ) -> PolarsResult<Self> {
+        let calendar = calendar.copied().unwrap_or_default();
+        calendar.validate()?;
         let offset = Duration::new(0);
         let out = match every.len() {
             1 => {
@@ -104,6 +147,14 @@ impl PolarsTruncate for DateChunked {
                     if every.negative {
                         polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                     }
+                    if every.is_fiscal() {
+                        let n = every.fiscal_periods();
+                        return Ok(self
+                            .apply_nonnull_values_generic(DataType::Int32, |t| {
+                                calendar.truncate(t, n)
+                            })
+                            .into_date());
+                    }
                     let w = Window::new(every, every, offset);
                     self.try_apply_nonnull_values_generic(|t| {
                         Ok((w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
@@ -124,6 +175,9 @@ impl PolarsTruncate for DateChunked {
                         if every.negative {
                             polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                         }
+                        if every.is_fiscal() {
+                            return Ok(Some(calendar.truncate(t, every.fiscal_periods())));
+                        }
 
                         let w = Window::new(every, every, offset);
                         Ok(Some(
@@ -2,14 +2,17 @@
 use arrow::legacy::kernels::{
     convert_to_naive_local, convert_to_naive_local_opt, Ambiguous, NonExistent,
 };
-#[cfg(feature = "timezones")]
 use arrow::legacy::time_zone::Tz;
-#[cfg(feature = "timezones")]
-use chrono::NaiveDateTime;
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+    EPOCH_DAYS_FROM_CE,
+};
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-#[cfg(feature = "timezones")]
-use polars_core::prelude::PolarsResult;
+use chrono::{Datelike, NaiveDateTime, NaiveTime};
+use polars_core::prelude::*;
+
+use crate::fiscal::to_naive_date;
 
 /// Localize datetime according to given time zone.
 ///
@@ -46,3 +49,49 @@ pub(crate) fn unlocalize_datetime(ndt: NaiveDateTime, tz: &Tz) -> NaiveDateTime
     // e.g. '2021-01-01 03:00CDT' -> '2021-01-01 03:00'
     tz.from_utc_datetime(&ndt).naive_local()
 }
+
+/// Apply `f` to the local date of timestamp `t`, either keeping the local time of day or
+/// resetting it to midnight.
+pub(crate) fn map_local_date<F>(
+    t: i64,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+    keep_time: bool,
+    f: F,
+) -> PolarsResult<i64>
+where
+    F: FnOnce(i32) -> i32,
+{
+    let timestamp_to_datetime: fn(i64) -> NaiveDateTime = match tu {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+    let datetime_to_timestamp: fn(NaiveDateTime) -> i64 = match tu {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+    };
+    let ndt = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
+        _ => timestamp_to_datetime(t),
+    };
+
+    let date = f(ndt.date().num_days_from_ce() - EPOCH_DAYS_FROM_CE);
+    let time = if keep_time {
+        ndt.time()
+    } else {
+        NaiveTime::MIN
+    };
+    let ndt = to_naive_date(date).and_time(time);
+
+    Ok(match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => datetime_to_timestamp(
+            try_localize_datetime(ndt, tz, Ambiguous::Raise, NonExistent::Raise)?
+                .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+        ),
+        _ => datetime_to_timestamp(ndt),
+    })
+}
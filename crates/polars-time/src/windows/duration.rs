@@ -9,6 +9,8 @@ use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
     NANOSECONDS,
 };
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use polars_core::datatypes::DataType;
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
@@ -38,6 +40,10 @@ pub struct Duration {
     days: i64,
     // the number of nanoseconds for the duration
     nsecs: i64,
+    // the number of business days for the duration, never combined with other units
+    business_days: i64,
+    // the number of fiscal periods for the duration, never combined with other units
+    fiscal_periods: i64,
     // indicates if the duration is negative
     pub(crate) negative: bool,
     // indicates if an integer string was passed. e.g. "2i"
@@ -65,6 +71,8 @@ impl Neg for Duration {
             weeks: self.weeks,
             days: self.days,
             nsecs: self.nsecs,
+            business_days: self.business_days,
+            fiscal_periods: self.fiscal_periods,
             negative: !self.negative,
             parsed_int: self.parsed_int,
         }
@@ -88,6 +96,18 @@ impl Display for Duration {
         if self.days > 0 {
             write!(f, "{}d", self.days)?
         }
+        if self.business_days > 0 {
+            write!(f, "{}bd", self.business_days)?
+        }
+        if self.fiscal_periods > 0 {
+            if self.fiscal_periods % 12 == 0 {
+                write!(f, "{}fy", self.fiscal_periods / 12)?
+            } else if self.fiscal_periods % 3 == 0 {
+                write!(f, "{}fq", self.fiscal_periods / 3)?
+            } else {
+                write!(f, "{}fp", self.fiscal_periods)?
+            }
+        }
         if self.nsecs > 0 {
             let secs = self.nsecs / NANOSECONDS;
             if secs * NANOSECONDS == self.nsecs {
@@ -113,6 +133,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs: fixed_slots.abs(),
+            business_days: 0,
+            fiscal_periods: 0,
             negative: fixed_slots < 0,
             parsed_int: true,
         }
@@ -143,6 +165,10 @@ impl Duration {
     /// * `mo`: calendar month
     /// * `q`: calendar quarter
     /// * `y`:  calendar year
+    /// * `bd`: business day (only for `offset_by` and `group_by_dynamic`; can't be combined
+    ///   with other units)
+    /// * `fp`, `fq`, `fy`: fiscal period, quarter and year of a [`crate::FiscalCalendar`] (only for
+    ///   `truncate`, `offset_by` and `group_by_dynamic`; can't be combined with other units)
     /// * `i`:  index value (only for {Int32, Int64} dtypes)
     ///
     /// By "calendar day", we mean the corresponding time on the next
@@ -192,6 +218,8 @@ impl Duration {
         let mut weeks = 0;
         let mut days = 0;
         let mut nsecs = 0;
+        let mut business_days = 0;
+        let mut fiscal_periods = 0;
 
         let negative = s.starts_with('-');
         let mut iter = s.char_indices().peekable();
@@ -263,6 +291,10 @@ impl Duration {
                         nsecs += n;
                         parsed_int = true;
                     },
+                    "bd" if !as_interval => business_days += n,
+                    "fp" if !as_interval => fiscal_periods += n,
+                    "fq" if !as_interval => fiscal_periods += n * 3,
+                    "fy" if !as_interval => fiscal_periods += n * 12,
                     _ if as_interval => match &*unit {
                         // interval-only (verbose/sql) matches
                         "nanosecond" | "nanoseconds" => nsecs += n,
@@ -282,15 +314,32 @@ impl Duration {
                         },
                     },
                     _ => {
-                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'");
+                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'bd', 'fy', 'fq', 'fp', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'");
                     },
                 }
                 unit.clear();
             }
         }
 
+        if business_days != 0 {
+            polars_ensure!(
+                months == 0 && weeks == 0 && days == 0 && nsecs == 0 && fiscal_periods == 0 && !parsed_int,
+                InvalidOperation: "business days ('bd') cannot be combined with other units in the {} string '{}'",
+                parse_type, s
+            );
+        }
+        if fiscal_periods != 0 {
+            polars_ensure!(
+                months == 0 && weeks == 0 && days == 0 && nsecs == 0 && !parsed_int,
+                InvalidOperation: "fiscal units ('fy', 'fq', 'fp') cannot be combined with other units in the {} string '{}'",
+                parse_type, s
+            );
+        }
+
         Ok(Duration {
             nsecs: nsecs.abs(),
+            business_days: business_days.abs(),
+            fiscal_periods: fiscal_periods.abs(),
             days: days.abs(),
             weeks: weeks.abs(),
             months: months.abs(),
@@ -360,6 +409,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -373,6 +424,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs: 0,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -386,6 +439,8 @@ impl Duration {
             weeks,
             days: 0,
             nsecs: 0,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -399,6 +454,8 @@ impl Duration {
             weeks: 0,
             days,
             nsecs: 0,
+            business_days: 0,
+            fiscal_periods: 0,
             negative,
             parsed_int: false,
         }
@@ -406,7 +463,12 @@ impl Duration {
 
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.nsecs == 0
+            && self.business_days == 0
+            && self.fiscal_periods == 0
     }
 
     pub fn months_only(&self) -> bool {
@@ -433,6 +495,37 @@ impl Duration {
         self.days
     }
 
+    /// `true` if the duration is a (non-zero) number of business days, e.g. `"3bd"`.
+    pub fn is_business_days(&self) -> bool {
+        self.business_days != 0
+    }
+
+    pub fn business_days(&self) -> i64 {
+        self.business_days
+    }
+
+    /// `true` if the duration is a (non-zero) number of fiscal periods, e.g. `"1fq"`.
+    pub fn is_fiscal(&self) -> bool {
+        self.fiscal_periods != 0
+    }
+
+    /// The number of fiscal periods, where a fiscal quarter is 3 periods and a fiscal year 12.
+    pub fn fiscal_periods(&self) -> i64 {
+        self.fiscal_periods
+    }
+
+    fn ensure_calendar_units(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.business_days == 0,
+            InvalidOperation: "business-day durations ('{}') are only supported by `offset_by` and `group_by_dynamic`", self
+        );
+        polars_ensure!(
+            self.fiscal_periods == 0,
+            InvalidOperation: "fiscal durations ('{}') are only supported by `truncate`, `offset_by` and `group_by_dynamic`", self
+        );
+        Ok(())
+    }
+
     /// Returns whether the duration consists of full days.
     ///
     /// Note that 24 hours is not considered a full day due to possible
@@ -443,11 +536,15 @@ impl Duration {
 
     pub fn is_constant_duration(&self, time_zone: Option<&str>) -> bool {
         if time_zone.is_none() || time_zone == Some("UTC") {
-            self.months == 0
+            self.months == 0 && self.business_days == 0 && self.fiscal_periods == 0
         } else {
             // For non-native, non-UTC time zones, 1 calendar day is not
             // necessarily 24 hours due to daylight savings time.
-            self.months == 0 && self.weeks == 0 && self.days == 0
+            self.months == 0
+                && self.weeks == 0
+                && self.days == 0
+                && self.business_days == 0
+                && self.fiscal_periods == 0
         }
     }
 
@@ -461,27 +558,42 @@ impl Duration {
         self.negative
     }
 
+    /// Convert to a calendar interval of months, days and nanoseconds.
+    ///
+    /// The weeks are folded into the days.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(&self) -> months_days_ns {
+        let sign = if self.negative { -1 } else { 1 };
+        months_days_ns::new(
+            (sign * self.months) as i32,
+            (sign * (self.weeks * 7 + self.days)) as i32,
+            sign * self.nsecs,
+        )
+    }
+
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
-        self.months * 28 * 24 * 3600 * NANOSECONDS
+        (self.months + self.fiscal_periods) * 28 * 24 * 3600 * NANOSECONDS
             + self.weeks * NS_WEEK
-            + self.days * NS_DAY
+            + (self.days + self.business_days) * NS_DAY
             + self.nsecs
     }
 
     #[doc(hidden)]
     pub const fn duration_us(&self) -> i64 {
-        self.months * 28 * 24 * 3600 * MICROSECONDS
-            + (self.weeks * NS_WEEK / 1000 + self.nsecs / 1000 + self.days * NS_DAY / 1000)
+        (self.months + self.fiscal_periods) * 28 * 24 * 3600 * MICROSECONDS
+            + (self.weeks * NS_WEEK / 1000
+                + self.nsecs / 1000
+                + (self.days + self.business_days) * NS_DAY / 1000)
     }
 
     #[doc(hidden)]
     pub const fn duration_ms(&self) -> i64 {
-        self.months * 28 * 24 * 3600 * MILLISECONDS
+        (self.months + self.fiscal_periods) * 28 * 24 * 3600 * MILLISECONDS
             + (self.weeks * NS_WEEK / 1_000_000
                 + self.nsecs / 1_000_000
-                + self.days * NS_DAY / 1_000_000)
+                + (self.days + self.business_days) * NS_DAY / 1_000_000)
     }
 
     #[doc(hidden)]
@@ -781,6 +893,7 @@ impl Duration {
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
+        self.ensure_calendar_units()?;
         match (self.months, self.weeks, self.days, self.nsecs) {
             (0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
             // truncate by ns/us/ms
@@ -882,6 +995,7 @@ impl Duration {
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
+        self.ensure_calendar_units()?;
         let d = self;
         let mut new_t = t;
 
@@ -912,8 +1026,10 @@ impl Duration {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
                 Some(tz) if tz != &chrono_tz::UTC => {
-                    new_t =
-                        datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
+                    new_t = datetime_to_timestamp(unlocalize_datetime(
+                        timestamp_to_datetime(new_t),
+                        tz,
+                    ));
                     new_t += if d.negative { -t_weeks } else { t_weeks };
                     new_t = datetime_to_timestamp(
                         try_localize_datetime(
@@ -935,8 +1051,10 @@ impl Duration {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
                 Some(tz) if tz != &chrono_tz::UTC => {
-                    new_t =
-                        datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
+                    new_t = datetime_to_timestamp(unlocalize_datetime(
+                        timestamp_to_datetime(new_t),
+                        tz,
+                    ));
                     new_t += if d.negative { -t_days } else { t_days };
                     new_t = datetime_to_timestamp(
                         try_localize_datetime(
@@ -1007,6 +1125,8 @@ impl Mul<i64> for Duration {
         self.weeks *= rhs;
         self.days *= rhs;
         self.nsecs *= rhs;
+        self.business_days *= rhs;
+        self.fiscal_periods *= rhs;
         self
     }
 }
@@ -1053,6 +1173,10 @@ pub fn ensure_duration_matches_dtype(
         DataType::Datetime(_, _) | DataType::Date | DataType::Duration(_) | DataType::Time => {
             polars_ensure!(!duration.parsed_int,
                 InvalidOperation: "`{}` duration may not be a parsed integer (i.e. use '2d', not '2i') when working with a temporal column", variable_name);
+            polars_ensure!(!duration.is_business_days() || matches!(dtype, DataType::Datetime(_, _) | DataType::Date),
+                InvalidOperation: "`{}` duration may only be in business days (e.g. '2bd') when working with a Date or Datetime column", variable_name);
+            polars_ensure!(!duration.is_fiscal() || matches!(dtype, DataType::Datetime(_, _) | DataType::Date),
+                InvalidOperation: "`{}` duration may only be in fiscal units (e.g. '1fq') when working with a Date or Datetime column", variable_name);
         },
         _ => {
             polars_bail!(InvalidOperation: "unsupported data type: {} for `{}`, expected UInt64, UInt32, Int64, Int32, Datetime, Date, Duration, or Time", dtype, variable_name)
@@ -1080,6 +1204,19 @@ mod test {
         assert!(out.negative);
         let out = Duration::parse("5w");
         assert_eq!(out.weeks(), 5);
+        let out = Duration::parse("-3bd");
+        assert!(out.is_business_days());
+        assert_eq!(out.business_days(), 3);
+        assert_eq!(format!("{out}"), "-3bd");
+        assert!(Duration::try_parse("1bd2h").is_err());
+        let out = Duration::parse("2fq");
+        assert!(out.is_fiscal());
+        assert_eq!(out.fiscal_periods(), 6);
+        assert_eq!(format!("{out}"), "2fq");
+        assert_eq!(format!("{}", Duration::parse("1fy")), "1fy");
+        assert_eq!(format!("{}", Duration::parse("-5fp")), "-5fp");
+        assert!(Duration::try_parse("1fy1mo").is_err());
+        assert!(Duration::try_parse_interval("1fy").is_err());
     }
 
     #[test]
@@ -78,8 +78,9 @@ fn update_groups_and_bounds(
     lower_bound: &mut Vec<i64>,
     upper_bound: &mut Vec<i64>,
     groups: &mut Vec<[IdxSize; 2]>,
-) {
+) -> PolarsResult<()> {
     'bounds: for bi in bounds_iter {
+        let bi = bi?;
         // find starting point of window
         for &t in &time[start..time.len().saturating_sub(1)] {
             // the window is behind the time values.
@@ -125,6 +126,7 @@ fn update_groups_and_bounds(
         }
         groups.push([start as IdxSize, len as IdxSize])
     }
+    Ok(())
 }
 
 /// Window boundaries are created based on the given `Window`, which is defined by:
@@ -148,7 +150,7 @@ pub fn group_by_windows(
     include_lower_bound: bool,
     include_upper_bound: bool,
     start_by: StartBy,
-) -> (GroupsSlice, Vec<i64>, Vec<i64>) {
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
     let start = time[0];
     // the boundary we define here is not yet correct. It doesn't take 'period' into account
     // and it doesn't have the proper starting point. This boundary is used as a proxy to find
@@ -181,15 +183,13 @@ pub fn group_by_windows(
         #[cfg(feature = "timezones")]
         Some(tz) => {
             update_groups_and_bounds(
-                window
-                    .get_overlapping_bounds_iter(
-                        boundary,
-                        closed_window,
-                        tu,
-                        tz.parse::<Tz>().ok().as_ref(),
-                        start_by,
-                    )
-                    .unwrap(),
+                window.get_overlapping_bounds_iter(
+                    boundary,
+                    closed_window,
+                    tu,
+                    tz.parse::<Tz>().ok().as_ref(),
+                    start_by,
+                )?,
                 start_offset,
                 time,
                 closed_window,
@@ -198,13 +198,11 @@ pub fn group_by_windows(
                 &mut lower_bound,
                 &mut upper_bound,
                 &mut groups,
-            );
+            )?;
         },
         _ => {
             update_groups_and_bounds(
-                window
-                    .get_overlapping_bounds_iter(boundary, closed_window, tu, None, start_by)
-                    .unwrap(),
+                window.get_overlapping_bounds_iter(boundary, closed_window, tu, None, start_by)?,
                 start_offset,
                 time,
                 closed_window,
@@ -213,11 +211,11 @@ pub fn group_by_windows(
                 &mut lower_bound,
                 &mut upper_bound,
                 &mut groups,
-            );
+            )?;
         },
     };
 
-    (groups, lower_bound, upper_bound)
+    Ok((groups, lower_bound, upper_bound))
 }
 
 // t is right at the end of the window
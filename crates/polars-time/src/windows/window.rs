@@ -1,12 +1,13 @@
+use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::*;
 use chrono::NaiveDateTime;
-#[cfg(feature = "timezones")]
-use chrono::TimeZone;
 use now::DateTimeNow;
 use polars_core::prelude::*;
 
 use crate::prelude::*;
+#[cfg(feature = "timezones")]
+use crate::utils::{try_localize_datetime, unlocalize_datetime};
 
 /// Ensure that earliest datapoint (`t`) is in, or in front of, first window.
 ///
@@ -48,6 +49,9 @@ pub struct Window {
     every: Duration,
     period: Duration,
     pub offset: Duration,
+    // How to localize window boundaries that are computed in local wall-clock time.
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 }
 
 impl Window {
@@ -57,9 +61,22 @@ impl Window {
             every,
             period,
             offset,
+            ambiguous: Ambiguous::Raise,
+            non_existent: NonExistent::Raise,
         }
     }
 
+    /// Set how window boundaries that are ambiguous or non-existent in the time zone are
+    /// localized. Windows with a boundary that is localized to `null` are skipped.
+    ///
+    /// Only used when `every` is a calendar duration (days, weeks or months) and the time zone
+    /// isn't UTC; such windows are computed in local wall-clock time.
+    pub fn with_dst_policies(mut self, ambiguous: Ambiguous, non_existent: NonExistent) -> Self {
+        self.ambiguous = ambiguous;
+        self.non_existent = non_existent;
+        self
+    }
+
     /// Truncate the given ns timestamp by the window boundary.
     pub fn truncate_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         self.every.truncate_ns(t, tz)
@@ -189,7 +206,14 @@ pub struct BoundsIter<'a> {
     // boundary per window iterator
     bi: Bounds,
     tu: TimeUnit,
+    // time zone used for the window arithmetic
     tz: Option<&'a Tz>,
+    // if set, `boundary` and `bi` are in local wall-clock time of this time zone and are
+    // localized when they are yielded
+    local_tz: Option<&'a Tz>,
+    // (local, utc) timestamp of the first datapoint, if windows start by datapoint
+    #[cfg(feature = "timezones")]
+    pinned_start: Option<(i64, i64)>,
 }
 impl<'a> BoundsIter<'a> {
     fn new(
@@ -200,147 +224,190 @@ impl<'a> BoundsIter<'a> {
         tz: Option<&'a Tz>,
         start_by: StartBy,
     ) -> PolarsResult<Self> {
-        let bi = match start_by {
-            StartBy::DataPoint => {
-                let mut boundary = boundary;
-                let offset_fn = match tu {
-                    TimeUnit::Nanoseconds => Duration::add_ns,
-                    TimeUnit::Microseconds => Duration::add_us,
-                    TimeUnit::Milliseconds => Duration::add_ms,
-                };
-                boundary.stop = offset_fn(&window.period, boundary.start, tz)?;
-                boundary
-            },
-            StartBy::WindowBound => match tu {
-                TimeUnit::Nanoseconds => {
-                    window.get_earliest_bounds_ns(boundary.start, closed_window, tz)?
-                },
-                TimeUnit::Microseconds => {
-                    window.get_earliest_bounds_us(boundary.start, closed_window, tz)?
-                },
-                TimeUnit::Milliseconds => {
-                    window.get_earliest_bounds_ms(boundary.start, closed_window, tz)?
-                },
-            },
-            _ => {
-                {
-                    #[allow(clippy::type_complexity)]
-                    let (from, to, offset_fn): (
-                        fn(i64) -> NaiveDateTime,
-                        fn(NaiveDateTime) -> i64,
-                        fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
-                    ) = match tu {
-                        TimeUnit::Nanoseconds => (
-                            timestamp_ns_to_datetime,
-                            datetime_to_timestamp_ns,
-                            Duration::add_ns,
-                        ),
-                        TimeUnit::Microseconds => (
-                            timestamp_us_to_datetime,
-                            datetime_to_timestamp_us,
-                            Duration::add_us,
-                        ),
-                        TimeUnit::Milliseconds => (
-                            timestamp_ms_to_datetime,
-                            datetime_to_timestamp_ms,
-                            Duration::add_ms,
-                        ),
-                    };
-                    // find beginning of the week.
-                    let dt = from(boundary.start);
-                    match tz {
-                        #[cfg(feature = "timezones")]
-                        Some(tz) => {
-                            let dt = tz.from_utc_datetime(&dt);
-                            let dt = dt.beginning_of_week();
-                            let dt = dt.naive_utc();
-                            let start = to(dt);
-                            // adjust start of the week based on given day of the week
-                            let start = offset_fn(
-                                &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
-                                start,
-                                Some(tz),
-                            )?;
-                            // apply the 'offset'
-                            let start = offset_fn(&window.offset, start, Some(tz))?;
-                            // make sure the first datapoint has a chance to be included
-                            // and compute the end of the window defined by the 'period'
-                            ensure_t_in_or_in_front_of_window(
-                                window.every,
-                                boundary.start,
-                                offset_fn,
-                                window.period,
-                                start,
-                                closed_window,
-                                Some(tz),
-                            )?
-                        },
-                        _ => {
-                            let tz = chrono::Utc;
-                            let dt = dt.and_local_timezone(tz).unwrap();
-                            let dt = dt.beginning_of_week();
-                            let dt = dt.naive_utc();
-                            let start = to(dt);
-                            // adjust start of the week based on given day of the week
-                            let start = offset_fn(
-                                &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
-                                start,
-                                None,
-                            )
-                            .unwrap();
-                            // apply the 'offset'
-                            let start = offset_fn(&window.offset, start, None).unwrap();
-                            // make sure the first datapoint has a chance to be included
-                            // and compute the end of the window defined by the 'period'
-                            ensure_t_in_or_in_front_of_window(
-                                window.every,
-                                boundary.start,
-                                offset_fn,
-                                window.period,
-                                start,
-                                closed_window,
-                                None,
-                            )?
-                        },
-                    }
-                }
-            },
-        };
+        // Calendar windows are defined in local wall-clock time, so that e.g. daily windows
+        // always start at the same local time, also on days on which DST changes.
+        #[cfg(feature = "timezones")]
+        if let Some(local_tz) = tz.filter(|tz| !window.every.is_constant_duration(Some(tz.name())))
+        {
+            let (from, to) = datetime_conversions(tu);
+            let unlocalize = |t| to(unlocalize_datetime(from(t), local_tz));
+            let local_boundary = Bounds::new(unlocalize(boundary.start), unlocalize(boundary.stop));
+            let bi = initial_bounds(&window, closed_window, local_boundary, tu, None, start_by)?;
+            return Ok(Self {
+                window,
+                boundary: local_boundary,
+                bi,
+                tu,
+                tz: None,
+                local_tz: Some(local_tz),
+                pinned_start: (start_by == StartBy::DataPoint)
+                    .then_some((local_boundary.start, boundary.start)),
+            });
+        }
+
+        let bi = initial_bounds(&window, closed_window, boundary, tu, tz, start_by)?;
         Ok(Self {
             window,
             boundary,
             bi,
             tu,
             tz,
+            local_tz: None,
+            #[cfg(feature = "timezones")]
+            pinned_start: None,
         })
     }
+
+    fn advance(&mut self) -> PolarsResult<()> {
+        let add = add_fn(self.tu);
+        self.bi.start = add(&self.window.every, self.bi.start, self.tz)?;
+        self.bi.stop = add(&self.window.period, self.bi.start, self.tz)?;
+        Ok(())
+    }
+
+    /// Convert bounds in local wall-clock time to UTC. Returns `None` if a boundary is
+    /// localized to `null`.
+    fn localize(&self, bounds: Bounds) -> PolarsResult<Option<Bounds>> {
+        match self.local_tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => {
+                let (from, to) = datetime_conversions(self.tu);
+                let localize = |t| -> PolarsResult<Option<i64>> {
+                    Ok(try_localize_datetime(
+                        from(t),
+                        tz,
+                        self.window.ambiguous,
+                        self.window.non_existent,
+                    )?
+                    .map(to))
+                };
+                let start = match self.pinned_start {
+                    // The first datapoint exists by definition, even if its local time is
+                    // ambiguous.
+                    Some((local, utc)) if local == bounds.start => Some(utc),
+                    _ => localize(bounds.start)?,
+                };
+                let stop = localize(bounds.stop)?;
+                Ok(start
+                    .zip(stop)
+                    .map(|(start, stop)| Bounds::new(start, stop)))
+            },
+            _ => Ok(Some(bounds)),
+        }
+    }
+}
+
+fn add_fn(tu: TimeUnit) -> fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64> {
+    match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn datetime_conversions(tu: TimeUnit) -> (fn(i64) -> NaiveDateTime, fn(NaiveDateTime) -> i64) {
+    match tu {
+        TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+        TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+        TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+    }
+}
+
+/// The bounds of the first window.
+fn initial_bounds(
+    window: &Window,
+    closed_window: ClosedWindow,
+    boundary: Bounds,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+    start_by: StartBy,
+) -> PolarsResult<Bounds> {
+    let offset_fn = add_fn(tu);
+    match start_by {
+        StartBy::DataPoint => {
+            let mut boundary = boundary;
+            boundary.stop = offset_fn(&window.period, boundary.start, tz)?;
+            Ok(boundary)
+        },
+        StartBy::WindowBound => match tu {
+            TimeUnit::Nanoseconds => {
+                window.get_earliest_bounds_ns(boundary.start, closed_window, tz)
+            },
+            TimeUnit::Microseconds => {
+                window.get_earliest_bounds_us(boundary.start, closed_window, tz)
+            },
+            TimeUnit::Milliseconds => {
+                window.get_earliest_bounds_ms(boundary.start, closed_window, tz)
+            },
+        },
+        _ => {
+            let (from, to) = datetime_conversions(tu);
+            // find beginning of the week.
+            let dt = from(boundary.start);
+            let start = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => {
+                    let local_dt = unlocalize_datetime(dt, tz)
+                        .and_utc()
+                        .beginning_of_week()
+                        .naive_utc();
+                    let dt = try_localize_datetime(
+                        local_dt,
+                        tz,
+                        window.ambiguous,
+                        window.non_existent,
+                    )?
+                    .ok_or_else(|| {
+                        polars_err!(
+                            ComputeError: "start of the week '{}' can't be localized in time zone '{}'",
+                            local_dt, tz
+                        )
+                    })?;
+                    to(dt)
+                },
+                _ => to(dt.and_utc().beginning_of_week().naive_utc()),
+            };
+            // adjust start of the week based on given day of the week
+            let start = offset_fn(
+                &Duration::parse(&format!("{}d", start_by.weekday().unwrap())),
+                start,
+                tz,
+            )?;
+            // apply the 'offset'
+            let start = offset_fn(&window.offset, start, tz)?;
+            // make sure the first datapoint has a chance to be included
+            // and compute the end of the window defined by the 'period'
+            ensure_t_in_or_in_front_of_window(
+                window.every,
+                boundary.start,
+                offset_fn,
+                window.period,
+                start,
+                closed_window,
+                tz,
+            )
+        },
+    }
 }
 
 impl Iterator for BoundsIter<'_> {
-    type Item = Bounds;
+    type Item = PolarsResult<Bounds>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.bi.start < self.boundary.stop {
+        while self.bi.start < self.boundary.stop {
             let out = self.bi;
-            match self.tu {
-                // TODO: find some way to propagate error instead of unwrapping?
-                // Issue is that `next` needs to return `Option`.
-                TimeUnit::Nanoseconds => {
-                    self.bi.start = self.window.every.add_ns(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.period.add_ns(self.bi.start, self.tz).unwrap();
-                },
-                TimeUnit::Microseconds => {
-                    self.bi.start = self.window.every.add_us(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.period.add_us(self.bi.start, self.tz).unwrap();
-                },
-                TimeUnit::Milliseconds => {
-                    self.bi.start = self.window.every.add_ms(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.period.add_ms(self.bi.start, self.tz).unwrap();
-                },
+            if let Err(err) = self.advance() {
+                self.bi.start = self.boundary.stop;
+                return Some(Err(err));
+            }
+            match self.localize(out) {
+                Ok(Some(out)) => return Some(Ok(out)),
+                // skip windows with a `null` boundary
+                Ok(None) => continue,
+                Err(err) => return Some(Err(err)),
             }
-            Some(out)
-        } else {
-            None
         }
+        None
     }
 }
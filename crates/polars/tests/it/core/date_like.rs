@@ -171,6 +171,55 @@ fn test_duration_date_arithmetic() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "dtype-interval", feature = "timezones", feature = "lazy"))]
+fn test_interval_datetime_arithmetic() -> PolarsResult<()> {
+    let to_datetime = |time_zone: Option<&str>| {
+        col("dt").str().to_datetime(
+            Some(TimeUnit::Milliseconds),
+            time_zone.map(TimeZone::from),
+            StrptimeOptions::default(),
+            lit("raise"),
+        )
+    };
+    let fmt = |e: Expr| e.dt().to_string("%Y-%m-%d %H:%M");
+    let one_month = lit(months_days_ns::new(1, 0, 0));
+    let month_and_day = lit(months_days_ns::new(1, 1, 0));
+
+    // Months are added on the calendar, clamping to the end of the month.
+    let out = df!["dt" => ["2024-01-31 12:00", "2024-02-29 12:00"]]?
+        .lazy()
+        .select([to_datetime(None)])
+        .select([
+            fmt(col("dt") + one_month.clone()).alias("add"),
+            fmt(one_month + col("dt")).alias("add_rev"),
+            fmt(col("dt") - month_and_day).alias("sub"),
+        ])
+        .collect()?;
+    let expected = df![
+        "add" => ["2024-02-29 12:00", "2024-03-29 12:00"],
+        "add_rev" => ["2024-02-29 12:00", "2024-03-29 12:00"],
+        "sub" => ["2023-12-30 12:00", "2024-01-28 12:00"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // A day is a calendar day, also across daylight saving time transitions.
+    let out = df!["dt" => ["2024-03-30 12:00"]]?
+        .lazy()
+        .select([to_datetime(Some("Europe/Amsterdam"))])
+        .select([
+            fmt(col("dt") + lit(months_days_ns::new(0, 1, 0))).alias("day"),
+            fmt(col("dt") + lit(months_days_ns::new(0, 0, 86_400_000_000_000))).alias("24h"),
+        ])
+        .collect()?;
+    let expected = df![
+        "day" => ["2024-03-31 12:00"],
+        "24h" => ["2024-03-31 13:00"],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 fn assert_series_eq(s1: &Series, s2: &Series) {
     assert!(s1.equals(s2))
 }
@@ -280,3 +280,51 @@ fn test_rolling_var() {
         out, exp_res
     );
 }
+
+#[test]
+fn test_rolling_order_statistics() {
+    let s = Float64Chunked::new(
+        "foo".into(),
+        &[Some(1.0), None, Some(3.0), Some(7.0), Some(3.0)],
+    )
+    .into_series();
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        min_periods: 2,
+        ..Default::default()
+    };
+
+    let out = s.rolling_median(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(2.0), Some(5.0), Some(3.0)]
+    );
+
+    let out = s.rolling_mad(options.clone()).unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(1.0), Some(2.0), Some(0.0)]
+    );
+
+    let out = s
+        .rolling_rank(RollingOptionsFixedWindow {
+            fn_params: Some(RollingFnParams::Rank(RollingRankParams {
+                method: RollingRankMethod::Max,
+            })),
+            ..options.clone()
+        })
+        .unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[None, None, Some(1.0), Some(1.0), Some(2.0 / 3.0)]
+    );
+
+    // Weights are only supported by the median, which then falls back to the quantile kernel.
+    let weighted = RollingOptionsFixedWindow {
+        weights: Some(vec![1.0, 1.0, 1.0]),
+        ..options
+    };
+    let s = s.fill_null(FillNullStrategy::Zero).unwrap();
+    assert!(s.rolling_median(weighted.clone()).is_ok());
+    assert!(s.rolling_mad(weighted).is_err());
+}
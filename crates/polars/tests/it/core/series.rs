@@ -41,3 +41,159 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+fn test_series_128_bit_integers() -> PolarsResult<()> {
+    let big = i64::MAX as i128 * 4;
+    let a = Series::new("a".into(), &[big, -big, 3]);
+    let b = Series::new("b".into(), &[2i128, 2, 3]);
+    assert_eq!(a.dtype(), &DataType::Int128);
+    assert_eq!((&a + &b)?, Series::new("a".into(), &[big + 2, -big + 2, 6]));
+    assert_eq!((&a * &b)?, Series::new("a".into(), &[big * 2, -big * 2, 9]));
+    assert_eq!((&a / &b)?, Series::new("a".into(), &[big / 2, -big / 2, 1]));
+
+    let u = Series::new("u".into(), &[u128::MAX, 0, 1]);
+    assert_eq!(u.dtype(), &DataType::UInt128);
+    assert_eq!(u.max::<u128>()?, Some(u128::MAX));
+    assert_eq!(u.arg_sort(Default::default()).cont_slice()?, &[1, 2, 0]);
+
+    // Values outside of the 64-bit range fail a strict cast.
+    assert!(a.strict_cast(&DataType::Int64).is_err());
+    assert_eq!(
+        b.cast(&DataType::UInt128)?,
+        Series::new("b".into(), &[2u128, 2, 3])
+    );
+    assert_eq!(
+        Series::new("s".into(), &["-170141183460469231731687303715884105728"])
+            .strict_cast(&DataType::Int128)?,
+        Series::new("s".into(), &[i128::MIN])
+    );
+
+    let df = df![
+        "id" => [u128::MAX, 1, u128::MAX],
+        "v" => [1i128, 2, 3],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .group_by([col("id")])
+        .agg([col("v").sum()])
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("id")?.u128()?.get(1), Some(u128::MAX));
+    assert_eq!(out.column("v")?.i128()?.get(1), Some(4));
+
+    // Multi-column sorts go through the row encoding.
+    let out = df.sort(
+        ["id", "v"],
+        SortMultipleOptions::default().with_order_descending(true),
+    )?;
+    assert_eq!(
+        out.column("v")?
+            .i128()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[3, 1, 2]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn test_series_float16() -> PolarsResult<()> {
+    let a = Series::new("a".into(), &[1.5f32, -2.0, 0.1]).cast(&DataType::Float16)?;
+    assert_eq!(a.dtype(), &DataType::Float16);
+    // 0.1 is rounded to the nearest half-precision value.
+    assert_eq!(
+        a.cast(&DataType::Float64)?,
+        Series::new("a".into(), &[1.5f64, -2.0, 0.0999755859375])
+    );
+
+    let b = Series::new("b".into(), &[2i32, 4, 8]).cast(&DataType::Float16)?;
+    let out = (&a * &b)?;
+    assert_eq!(out.dtype(), &DataType::Float16);
+    assert_eq!(
+        out.cast(&DataType::Float64)?,
+        Series::new("a".into(), &[3.0f64, -8.0, 0.7998046875])
+    );
+    let c = Series::new("c".into(), &[1.0f32, 1.0, 1.0]);
+    assert_eq!((&a + &c)?.dtype(), &DataType::Float32);
+
+    // Sorting follows the float values, not the bit patterns.
+    assert_eq!(a.arg_sort(Default::default()).cont_slice()?, &[1, 2, 0]);
+    assert_eq!(a.max_reduce()?.value(), &AnyValue::Float32(1.5));
+
+    let df = DataFrame::new(vec![a.into()])?;
+    let out = df
+        .lazy()
+        .select([
+            (col("a") * lit(2.0)).alias("double"),
+            col("a").sum().alias("sum"),
+            col("a").mean().alias("mean"),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.dtypes(),
+        &[DataType::Float16, DataType::Float16, DataType::Float32]
+    );
+    assert_eq!(
+        out.column("double")?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .get(1),
+        Some(-4.0)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn test_series_float16_total_order() -> PolarsResult<()> {
+    let df = df![
+        "idx" => [0i32, 1, 2, 3, 4],
+        "a" => [1i32, 1, 1, 1, 0],
+        "b" => [-1.0f32, 2.0, -3.0, 0.5, -4.0],
+    ]?
+    .lazy()
+    .with_column(col("b").cast(DataType::Float16))
+    .select([col("idx").sort_by([col("a"), col("b")], Default::default())])
+    .collect()?;
+    assert_eq!(
+        df.column("idx")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[4, 2, 0, 3, 1]
+    );
+
+    // -0.0 and 0.0 are equal, and so are NaNs with different payloads.
+    let nan = f32::from_bits(0x7FC0_0001);
+    let s =
+        Series::new("s".into(), &[0.0f32, -0.0, f32::NAN, nan, -1.0]).cast(&DataType::Float16)?;
+    assert_eq!(s.n_unique()?, 3);
+    assert_eq!(
+        s.unique()?
+            .sort(Default::default())?
+            .cast(&DataType::Float32)?
+            .f32()?
+            .into_no_null_iter()
+            .map(f32::to_bits)
+            .collect::<Vec<_>>(),
+        &[(-1.0f32).to_bits(), 0, f32::NAN.to_bits()]
+    );
+
+    let out = DataFrame::new(vec![s.into()])?
+        .lazy()
+        .group_by([col("s")])
+        .agg([len()])
+        .sort(["s"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("len")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 2, 2]
+    );
+    Ok(())
+}
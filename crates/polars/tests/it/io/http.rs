@@ -0,0 +1,156 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use polars::prelude::*;
+
+/// A minimal HTTP file server that serves `data` and counts the number of body bytes it sent.
+struct FileServer {
+    url: String,
+    bytes_sent: Arc<AtomicUsize>,
+}
+
+impl FileServer {
+    fn new(name: &str, data: Vec<u8>, support_ranges: bool) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/{}", listener.local_addr().unwrap(), name);
+        let bytes_sent = Arc::new(AtomicUsize::new(0));
+        let counter = bytes_sent.clone();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                Self::respond(stream.unwrap(), &data, support_ranges, &counter);
+            }
+        });
+
+        Self { url, bytes_sent }
+    }
+
+    fn respond(mut stream: TcpStream, data: &[u8], support_ranges: bool, counter: &AtomicUsize) {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            if n == 0 {
+                return;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+        let request = String::from_utf8(request).unwrap().to_lowercase();
+
+        let range = request
+            .lines()
+            .find_map(|line| line.strip_prefix("range: bytes="))
+            .filter(|_| support_ranges)
+            .map(|range| {
+                let (start, end) = range.trim().split_once('-').unwrap();
+                let start: usize = start.parse().unwrap();
+                let end = end.parse::<usize>().map_or(data.len(), |end| end + 1);
+                start..end.min(data.len())
+            });
+
+        let (status, headers, body) = match range {
+            Some(range) => (
+                "206 Partial Content",
+                format!(
+                    "Content-Range: bytes {}-{}/{}\r\n",
+                    range.start,
+                    range.end - 1,
+                    data.len()
+                ),
+                &data[range],
+            ),
+            None => ("200 OK", String::new(), data),
+        };
+        let body = if request.starts_with("head") {
+            &[]
+        } else {
+            body
+        };
+
+        let header = format!(
+            "HTTP/1.1 {status}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n",
+            if request.starts_with("head") {
+                data.len()
+            } else {
+                body.len()
+            }
+        );
+        stream.write_all(header.as_bytes()).unwrap();
+        stream.write_all(body).unwrap();
+        counter.fetch_add(body.len(), Ordering::SeqCst);
+    }
+
+    fn bytes_sent(&self) -> usize {
+        self.bytes_sent.load(Ordering::SeqCst)
+    }
+}
+
+/// A frame with a column `a` that is small when compressed and a column `b` that is not.
+fn wide_df() -> PolarsResult<DataFrame> {
+    let n = 20_000u64;
+    df!(
+        "a" => (0..n).map(|i| i / 1000).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15)).collect::<Vec<_>>()
+    )
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_http_range_requests() -> PolarsResult<()> {
+    let mut df = wide_df()?;
+    let mut data = vec![];
+    ParquetWriter::new(&mut data).finish(&mut df)?;
+    let file_size = data.len();
+    let server = FileServer::new("data.parquet", data, true);
+
+    let out = LazyFrame::scan_parquet(&server.url, Default::default())?
+        .select([col("a")])
+        .collect()?;
+    assert!(out.equals(&df.select(["a"])?));
+    // Only the footer and the column chunks of `a` are fetched.
+    assert!(server.bytes_sent() < file_size / 2);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_ipc_http_range_requests() -> PolarsResult<()> {
+    let df = wide_df()?;
+    let mut df = df.slice(0, 10_000).vstack(&df.slice(10_000, 10_000))?;
+    let mut data = vec![];
+    IpcWriter::new(&mut data).finish(&mut df)?;
+    let file_size = data.len();
+    let server = FileServer::new("data.ipc", data, true);
+
+    let out = LazyFrame::scan_ipc(&server.url, Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    // With a row limit only the first record batch is fetched.
+    let bytes_sent = server.bytes_sent();
+    let out = LazyFrame::scan_ipc(&server.url, Default::default())?
+        .limit(10)
+        .collect()?;
+    assert!(out.equals(&df.head(Some(10))));
+    assert!(server.bytes_sent() - bytes_sent < file_size * 3 / 4);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_http_without_range_support() -> PolarsResult<()> {
+    let mut df = wide_df()?;
+    let mut data = vec![];
+    ParquetWriter::new(&mut data).finish(&mut df)?;
+    let server = FileServer::new("data.parquet", data, false);
+
+    let err = LazyFrame::scan_parquet(&server.url, Default::default())
+        .and_then(|lf| lf.collect())
+        .unwrap_err();
+    assert!(err.to_string().contains("does not support range requests"));
+
+    Ok(())
+}
@@ -22,6 +22,191 @@ fn test_ipc_compression_variadic_buffers() {
     assert_eq!(out.shape(), (3, 1));
 }
 
+#[test]
+fn test_ipc_128_bit_integers() -> PolarsResult<()> {
+    let mut df = df![
+        "i" => [Some(i128::MIN), None, Some(i128::MAX)],
+        "u" => [Some(u128::MAX), Some(0), None],
+    ]?;
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    buf.set_position(0);
+    let out = IpcReader::new(buf).finish()?;
+    assert_eq!(out.dtypes(), &[DataType::Int128, DataType::UInt128]);
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_ipc_interval() -> PolarsResult<()> {
+    let s = IntervalChunked::from_intervals(
+        "i".into(),
+        [
+            Some(months_days_ns::new(1, 2, 3)),
+            None,
+            Some(months_days_ns::new(-14, 0, -1)),
+        ],
+    )
+    .into_series();
+    let mut df = DataFrame::new(vec![s.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    buf.set_position(0);
+    let out = IpcReader::new(buf).finish()?;
+    assert_eq!(out.dtypes(), &[DataType::Interval]);
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-f16", feature = "dtype-array"))]
+fn test_ipc_float16() -> PolarsResult<()> {
+    let f =
+        Series::new("f".into(), &[Some(0.5f32), None, Some(-65504.0)]).cast(&DataType::Float16)?;
+    let emb = Series::new("emb".into(), &[0.25f32, -1.0, 2.0, 3.5, 0.0, 1.0])
+        .cast(&DataType::Float16)?
+        .reshape_array(&[ReshapeDimension::Infer, ReshapeDimension::new(2)])?;
+    let mut df = DataFrame::new(vec![f.into(), emb.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    buf.set_position(0);
+    let out = IpcReader::new(buf).finish()?;
+    assert_eq!(
+        out.dtypes(),
+        &[
+            DataType::Float16,
+            DataType::Array(Box::new(DataType::Float16), 2)
+        ]
+    );
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_ipc_map() -> PolarsResult<()> {
+    let entries = |keys: &[&str], values: &[Option<i32>]| {
+        let fields = [
+            Series::new("key".into(), keys),
+            Series::new("value".into(), values),
+        ];
+        StructChunked::from_series(PlSmallStr::EMPTY, keys.len(), fields.iter())
+            .map(|ca| ca.into_series())
+    };
+    let s = Series::new(
+        "m".into(),
+        [
+            entries(&["a", "b"], &[Some(1), None])?,
+            entries(&[], &[])?,
+            entries(&["c"], &[Some(3)])?,
+        ],
+    );
+    let s = s.list()?.clone().into_map()?.into_series();
+    let mut df = DataFrame::new(vec![s.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    buf.set_position(0);
+    let out = IpcReader::new(buf).finish()?;
+    assert_eq!(
+        out.dtypes(),
+        &[DataType::Map(
+            Box::new(DataType::String),
+            Box::new(DataType::Int32)
+        )]
+    );
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+fn test_ipc_key_value_metadata() -> PolarsResult<()> {
+    let mut df = create_df();
+    let key_value_metadata = KeyValueMetadata {
+        file: [("schema_version".into(), "3".into())].into(),
+        columns: [("temp".into(), [("unit".into(), "celsius".into())].into())].into(),
+    };
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf)
+        .with_key_value_metadata(key_value_metadata.clone())
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let mut reader = IpcReader::new(buf);
+    assert_eq!(reader.key_value_metadata()?, key_value_metadata);
+    assert!(df.equals(&reader.finish()?));
+
+    // Files without custom metadata have none.
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+    assert!(IpcReader::new(buf).key_value_metadata()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_ipc_record_batch_size() -> PolarsResult<()> {
+    let mut df = df!["a" => 0..10]?;
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf)
+        .with_record_batch_size(Some(4))
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = arrow::io::ipc::read::read_file_metadata(&mut buf)?;
+    assert_eq!(metadata.blocks.len(), 3);
+    buf.set_position(0);
+    assert!(df.equals(&IpcReader::new(buf).finish()?));
+
+    let mut buf = Cursor::new(Vec::new());
+    let result = IpcWriter::new(&mut buf)
+        .with_record_batch_size(Some(0))
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_ipc_dictionary_deltas() -> PolarsResult<()> {
+    let to_categorical = |values: &[&str]| {
+        let df = df!["a" => values]?;
+        df.lazy()
+            .with_column(col("a").cast(DataType::Categorical(None, Default::default())))
+            .collect()
+    };
+    let first = to_categorical(&["a", "b", "a"])?;
+    // Appends the category "c" to the dictionary of `first`.
+    let second = to_categorical(&["a", "b", "c"])?;
+
+    let write = |dictionary_deltas: bool| -> PolarsResult<Vec<u8>> {
+        let mut buf = Cursor::new(Vec::new());
+        let mut writer = IpcWriter::new(&mut buf)
+            .with_dictionary_deltas(dictionary_deltas)
+            .batched(&first.schema())?;
+        writer.write_batch(&first)?;
+        writer.write_batch(&second)?;
+        writer.finish()?;
+        Ok(buf.into_inner())
+    };
+
+    // A file can't replace a dictionary.
+    assert!(write(false).is_err());
+
+    let out = IpcReader::new(Cursor::new(write(true)?)).finish()?;
+    let expected = df!["a" => ["a", "b", "a", "a", "b", "c"]]?;
+    let out = out
+        .lazy()
+        .with_column(col("a").cast(DataType::String))
+        .collect()?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[cfg(test)]
 pub(crate) fn create_df() -> DataFrame {
     let s0 = Column::new("days".into(), [0, 1, 2, 3, 4].as_ref());
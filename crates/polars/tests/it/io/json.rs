@@ -185,3 +185,178 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(all(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-struct"
+))]
+fn write_json_serialize_options() -> PolarsResult<()> {
+    let mut df = df![
+        "date" => [Some(0i32), None],
+        "datetime" => [Some(1_000i64), Some(86_400_000)],
+        "float" => [Some(1.0f64 / 3.0), None],
+        "s" => [Some("a"), None],
+    ]?;
+    df.try_apply("date", |s| s.cast(&DataType::Date))?;
+    df.try_apply("datetime", |s| {
+        s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+    })?;
+    let st = df.clone().into_struct("nested".into());
+    df.with_column(st.into_series())?;
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_date_format(Some("%d/%m/%Y".into()))
+        .with_datetime_format(Some("%Y-%m-%dT%H:%M:%S%.3f".into()))
+        .with_float_precision(Some(2))
+        .with_omit_null_fields(true)
+        .finish(&mut df)?;
+    let out = String::from_utf8(buf).unwrap();
+    let mut lines = out.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            r#"{"date":"01/01/1970","datetime":"1970-01-01T00:00:01.000","float":0.33,"s":"a","nested":{"date":"01/01/1970","datetime":"1970-01-01T00:00:01.000","float":0.33,"s":"a"}}"#
+        )
+    );
+    assert_eq!(
+        lines.next(),
+        Some(
+            r#"{"datetime":"1970-01-02T00:00:00.000","nested":{"datetime":"1970-01-02T00:00:00.000"}}"#
+        )
+    );
+
+    // A time zone can't be formatted for naive datetimes.
+    let mut buf = vec![];
+    let res = JsonWriter::new(&mut buf)
+        .with_datetime_format(Some("%z".into()))
+        .finish(&mut df);
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn write_json_columnar_pretty() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2],
+        "b" => [Some("x"), None],
+    ]?;
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonColumnar)
+        .finish(&mut df)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        r#"{"a":[1,2],"b":["x",null]}"#
+    );
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::Json)
+        .with_pretty(true)
+        .finish(&mut df.head(Some(1)))?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "[\n  {\n    \"a\": 1,\n    \"b\": \"x\"\n  }\n]\n"
+    );
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonColumnar)
+        .with_pretty(true)
+        .finish(&mut df.clear())?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "{\n  \"a\": [],\n  \"b\": []\n}\n"
+    );
+
+    let res = JsonWriter::new(vec![])
+        .with_json_format(JsonFormat::JsonLines)
+        .with_pretty(true)
+        .finish(&mut df);
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn read_json_conflict_resolution() -> PolarsResult<()> {
+    let json =
+        r#"[{"a": 1, "b": {"c": 1}}, {"a": "x", "b": {"c": [1]}}, {"a": {"d": true}, "b": null}]"#;
+
+    let err = JsonReader::new(Cursor::new(json))
+        .with_conflict_resolution(JsonConflictResolution::Raise)
+        .finish()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("'a': [i64, str, struct[1]]"), "{err}");
+    assert!(err.contains("'b.c': [i64, list[i64]]"), "{err}");
+
+    let (df, conflicts) = JsonReader::new(Cursor::new(json))
+        .with_conflict_resolution(JsonConflictResolution::String)
+        .finish_with_conflicts()?;
+    let paths = conflicts
+        .iter()
+        .map(|c| c.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["a", "b.c"]);
+    let a = df.column("a")?;
+    assert_eq!(a.dtype(), &DataType::String);
+    assert_eq!(a.str()?.get(0), Some("1"));
+    assert_eq!(a.str()?.get(1), Some("x"));
+    assert_eq!(a.str()?.get(2), None);
+
+    let df = JsonReader::new(Cursor::new(json))
+        .with_conflict_resolution(JsonConflictResolution::JsonEncoded)
+        .finish()?;
+    let a = df.column("a")?;
+    assert_eq!(a.str()?.get(2), Some(r#"{"d":true}"#));
+    let c = df.column("b")?.struct_()?.field_by_name("c")?;
+    assert_eq!(c.str()?.get(0), Some("1"));
+    assert_eq!(c.str()?.get(1), Some("[1]"));
+    Ok(())
+}
+
+#[test]
+fn read_ndjson_conflict_resolution() -> PolarsResult<()> {
+    let json = r#"{"a": 1, "b": {"c": 1}}
+{"a": "x", "b": {"c": 2.5}}
+{"a": [1, 2], "b": {"c": "y"}}
+"#;
+
+    let (schema, conflicts) = polars_io::ndjson::infer_schema_with_conflicts(
+        &mut Cursor::new(json),
+        None,
+        JsonConflictResolution::String,
+    )?;
+    let paths = conflicts
+        .iter()
+        .map(|c| c.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["a", "b.c"]);
+    assert_eq!(schema.get("a"), Some(&DataType::String));
+
+    let (df, reader_conflicts) = JsonLineReader::new(Cursor::new(json))
+        .with_conflict_resolution(JsonConflictResolution::JsonEncoded)
+        .finish_with_conflicts()?;
+    assert_eq!(reader_conflicts, conflicts);
+    let a = df.column("a")?.str()?;
+    assert_eq!(Vec::from(a), [Some("1"), Some("x"), Some("[1,2]")]);
+
+    // No conflicts are reported for a given schema.
+    let (_, conflicts) = JsonLineReader::new(Cursor::new(json))
+        .with_schema(Arc::new(df.schema()))
+        .finish_with_conflicts()?;
+    assert!(conflicts.is_empty());
+    let c = df.column("b")?.struct_()?.field_by_name("c")?;
+    assert_eq!(Vec::from(c.str()?), [Some("1"), Some("2.5"), Some("y")]);
+
+    let res = JsonLineReader::new(Cursor::new(json))
+        .with_conflict_resolution(JsonConflictResolution::Raise)
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
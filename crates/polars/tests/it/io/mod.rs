@@ -9,10 +9,14 @@ mod parquet;
 #[cfg(feature = "avro")]
 mod avro;
 
+#[cfg(all(feature = "http", any(feature = "parquet", feature = "ipc")))]
+mod http;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
+#[cfg(any(feature = "ipc", feature = "csv"))]
+mod partitioned;
 
 use polars::prelude::*;
 
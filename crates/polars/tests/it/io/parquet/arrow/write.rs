@@ -140,6 +140,39 @@ fn int64_optional_v2_compressed() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn int64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "int64",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn int64_required_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "int64",
+        "required",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn float64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Snappy,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
 #[test]
 fn utf8_optional_v1() -> PolarsResult<()> {
     round_trip(
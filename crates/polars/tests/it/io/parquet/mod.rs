@@ -206,3 +206,588 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_parquet_column_write_options() -> PolarsResult<()> {
+    use polars_parquet::parquet::compression::Compression;
+    use polars_parquet::parquet::encoding::Encoding;
+
+    let mut df = df! {
+        "ts" => (0..1000i64).collect::<Vec<_>>(),
+        "value" => (0..1000).map(|i| (i as f64).sin()).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("name-{}", i % 3)).collect::<Vec<_>>(),
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_compression(ParquetCompression::Snappy)
+        .with_column_options(vec![
+            ParquetColumnWriteOptions {
+                encoding: Some(ParquetEncoding::DeltaBinaryPacked),
+                statistics: Some(StatisticsOptions::empty()),
+                ..ParquetColumnWriteOptions::new("ts")
+            },
+            ParquetColumnWriteOptions {
+                compression: Some(ParquetCompression::Zstd(None)),
+                encoding: Some(ParquetEncoding::ByteStreamSplit),
+                ..ParquetColumnWriteOptions::new("value")
+            },
+            ParquetColumnWriteOptions {
+                dictionary: Some(false),
+                ..ParquetColumnWriteOptions::new("name")
+            },
+        ])
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(buf);
+    let md = reader.get_metadata()?.clone();
+    let column = |name: &str| {
+        md.row_groups[0]
+            .columns_under_root_iter(name)
+            .unwrap()
+            .next()
+            .unwrap()
+    };
+    let encodings = |name: &str| {
+        column(name)
+            .column_encoding()
+            .iter()
+            .map(|&e| Encoding::try_from(e).unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert!(encodings("ts").contains(&Encoding::DeltaBinaryPacked));
+    assert!(column("ts").statistics().is_none());
+    assert_eq!(column("ts").compression(), Compression::Snappy);
+    assert!(encodings("value").contains(&Encoding::ByteStreamSplit));
+    assert_eq!(column("value").compression(), Compression::Zstd);
+    assert!(column("value").statistics().is_some());
+    assert!(!encodings("name").contains(&Encoding::RleDictionary));
+    assert!(column("name").column_index_range().is_some());
+    assert!(column("ts").column_index_range().is_none());
+
+    let read_df = reader.finish()?;
+    assert!(df.equals(&read_df));
+
+    // Encodings must match the column type.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_column_options(vec![ParquetColumnWriteOptions {
+            encoding: Some(ParquetEncoding::ByteStreamSplit),
+            ..ParquetColumnWriteOptions::new("name")
+        }])
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parquet_column_dictionary_and_statistics_options() -> PolarsResult<()> {
+    use polars_parquet::parquet::encoding::Encoding;
+
+    let flag = BooleanChunked::from_iter_values("flag".into(), (0..100).map(|i| i % 2 == 0));
+    let id = Int64Chunked::from_iter_values("id".into(), (0..100).map(|i| i % 4));
+    let nested = StructChunked::from_series(
+        "nested".into(),
+        100,
+        [flag.clone().into_series(), id.clone().into_series()].iter(),
+    )?;
+    let mut df = DataFrame::new(vec![
+        flag.into_column(),
+        id.into_column(),
+        nested.into_column(),
+    ])?;
+
+    // Only the last column writes statistics, so only it gets a column index.
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_statistics(StatisticsOptions::empty())
+        .with_column_options(vec![
+            ParquetColumnWriteOptions {
+                dictionary: Some(true),
+                ..ParquetColumnWriteOptions::new("nested")
+            },
+            ParquetColumnWriteOptions {
+                dictionary: Some(true),
+                statistics: Some(StatisticsOptions::full()),
+                ..ParquetColumnWriteOptions::new("id")
+            },
+        ])
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(buf);
+    let md = reader.get_metadata()?.clone();
+    let column = |name: &str| {
+        md.row_groups[0]
+            .columns_under_root_iter(name)
+            .unwrap()
+            .next()
+            .unwrap()
+    };
+    let id_encodings = column("id")
+        .column_encoding()
+        .iter()
+        .map(|&e| Encoding::try_from(e).unwrap())
+        .collect::<Vec<_>>();
+    assert!(id_encodings.contains(&Encoding::RleDictionary));
+    assert!(column("id").statistics().is_some());
+    assert!(column("id").column_index_range().is_some());
+    assert!(column("flag").statistics().is_none());
+    assert!(column("flag").column_index_range().is_none());
+    assert!(column("nested").column_index_range().is_none());
+
+    let read_df = reader.finish()?;
+    assert!(df.equals(&read_df));
+
+    // Dictionary encoding must be supported by at least one leaf of the column.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_column_options(vec![ParquetColumnWriteOptions {
+            dictionary: Some(true),
+            ..ParquetColumnWriteOptions::new("flag")
+        }])
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_128_bit_integers() -> PolarsResult<()> {
+    let mut df = df![
+        "i" => [Some(i128::MIN), None, Some(i128::MAX)],
+        "u" => [Some(u128::MAX), Some(0), None],
+    ]?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    // Both are plain 16-byte values, a `DECIMAL(38, 0)` can't hold `i128::MIN`/`i128::MAX`.
+    let mut reader = ParquetReader::new(buf);
+    let md = reader.get_metadata()?.clone();
+    for name in ["i", "u"] {
+        let column = md.row_groups[0]
+            .columns_under_root_iter(name)
+            .unwrap()
+            .next()
+            .unwrap();
+        let type_ = &column.descriptor().descriptor.primitive_type;
+        assert_eq!(type_.physical_type, PhysicalType::FixedLenByteArray(16));
+        assert!(type_.logical_type.is_none());
+    }
+
+    let out = reader.finish()?;
+    assert_eq!(out.dtypes(), &[DataType::Int128, DataType::UInt128]);
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_parquet_interval() -> PolarsResult<()> {
+    let s = IntervalChunked::from_intervals(
+        "i".into(),
+        [
+            Some(months_days_ns::new(1, 2, 3)),
+            None,
+            Some(months_days_ns::new(-14, 0, -1)),
+        ],
+    )
+    .into_series();
+    let mut df = DataFrame::new(vec![s.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let out = ParquetReader::new(buf).finish()?;
+    assert_eq!(out.dtypes(), &[DataType::Interval]);
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-f16", feature = "dtype-array"))]
+fn test_parquet_float16() -> PolarsResult<()> {
+    let f =
+        Series::new("f".into(), &[Some(0.5f32), None, Some(-65504.0)]).cast(&DataType::Float16)?;
+    let emb = Series::new("emb".into(), &[0.25f32, -1.0, 2.0, 3.5, 0.0, 1.0])
+        .cast(&DataType::Float16)?
+        .reshape_array(&[ReshapeDimension::Infer, ReshapeDimension::new(2)])?;
+    let mut df = DataFrame::new(vec![f.into(), emb.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let out = ParquetReader::new(buf).finish()?;
+    assert_eq!(
+        out.dtypes(),
+        &[
+            DataType::Float16,
+            DataType::Array(Box::new(DataType::Float16), 2)
+        ]
+    );
+    assert!(df.equals_missing(&out));
+
+    // Float16 is stored as a fixed-size binary, so its bits can't be hashed as an integer.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_bloom_filters(vec![ParquetBloomFilterOptions {
+            column: "f".into(),
+            fpp: 0.01,
+        }])
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_parquet_map() -> PolarsResult<()> {
+    let entries = |keys: &[&str], values: &[Option<i32>]| {
+        let fields = [
+            Series::new("key".into(), keys),
+            Series::new("value".into(), values),
+        ];
+        StructChunked::from_series(PlSmallStr::EMPTY, keys.len(), fields.iter())
+            .map(|ca| ca.into_series())
+    };
+    let s = Series::new(
+        "m".into(),
+        [
+            entries(&["a", "b"], &[Some(1), None])?,
+            entries(&[], &[])?,
+            entries(&["c"], &[Some(3)])?,
+        ],
+    );
+    let s = s.list()?.clone().into_map()?.into_series();
+    let mut df = DataFrame::new(vec![s.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let out = ParquetReader::new(buf).finish()?;
+    assert_eq!(
+        out.dtypes(),
+        &[DataType::Map(
+            Box::new(DataType::String),
+            Box::new(DataType::Int32)
+        )]
+    );
+    assert!(df.equals_missing(&out));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_key_value_metadata() -> PolarsResult<()> {
+    let mut df = df! {
+        "id" => [1i64, 2, 3],
+        "name" => ["a", "b", "c"],
+    }?;
+    let key_value_metadata = KeyValueMetadata {
+        file: [("lineage".into(), "job-42".into())].into(),
+        columns: [("id".into(), [("unit".into(), "seconds".into())].into())].into(),
+    };
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_key_value_metadata(key_value_metadata.clone())
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(buf);
+    assert_eq!(reader.key_value_metadata()?, key_value_metadata);
+    assert!(df.equals(&reader.finish()?));
+
+    // The arrow schema key is reserved.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_key_value_metadata(KeyValueMetadata {
+            file: [("ARROW:schema".into(), "".into())].into(),
+            ..Default::default()
+        })
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parquet_sorting_columns() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let nested = (1..=4i64)
+        .map(|v| Series::new("".into(), [v]))
+        .collect::<Vec<_>>();
+    let mut id = Series::new("id".into(), [None, Some(1i64), Some(2), Some(3)]);
+    id.set_sorted_flag(IsSorted::Ascending);
+    let mut name = Series::new("name".into(), ["d", "c", "b", "a"]);
+    name.set_sorted_flag(IsSorted::Descending);
+    let mut df = DataFrame::new(vec![
+        Series::new("nested".into(), nested).into(),
+        id.into(),
+        name.into(),
+        Series::new("value".into(), [3i64, 1, 4, 1]).into(),
+    ])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(buf);
+    let md = reader.get_metadata()?.clone();
+    let sorting_columns = md
+        .row_groups
+        .iter()
+        .map(|rg| {
+            rg.sorting_columns()
+                .unwrap()
+                .iter()
+                .map(|c| (c.column_idx, c.descending, c.nulls_first))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sorting_columns,
+        [
+            vec![(1, false, true), (2, true, false)],
+            vec![(1, false, false), (2, true, false)]
+        ]
+    );
+
+    let out = reader.finish()?;
+    assert!(df.equals_missing(&out));
+    assert_eq!(out.column("id")?.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(out.column("name")?.is_sorted_flag(), IsSorted::Descending);
+    assert_eq!(out.column("value")?.is_sorted_flag(), IsSorted::Not);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_parquet_bloom_filter_unsupported_dtype() -> PolarsResult<()> {
+    let mut df = df!["a" => [1i64, 2, 3]]?;
+    df.apply("a", |s| {
+        s.cast(&DataType::Decimal(Some(10), Some(2))).unwrap()
+    })?;
+
+    // Unsupported columns are rejected before anything is written.
+    let mut buf = Vec::new();
+    let result = ParquetWriter::new(&mut buf)
+        .with_bloom_filters(vec![ParquetBloomFilterOptions {
+            column: "a".into(),
+            fpp: 0.01,
+        }])
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::InvalidOperation(_))));
+    assert!(buf.is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "dtype-time"))]
+fn test_parquet_bloom_filter_mismatched_physical_type() -> PolarsResult<()> {
+    use ::arrow::array::PrimitiveArray;
+    use ::arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit as ArrowTimeUnit};
+    use ::arrow::record_batch::RecordBatchT;
+    use polars_parquet::parquet::bloom_filter::{hash_native, insert, optimal_num_bytes};
+    use polars_parquet::write::{
+        CompressionOptions, Encoding, FileWriter, RowGroupIterator, StatisticsOptions, Version,
+        WriteOptions,
+    };
+
+    // A `TIME_MILLIS` column is read as a polars Time in nanoseconds, but its bloom filter holds
+    // the hashes of the 32-bit milliseconds.
+    let millis = [1_000i32, 2_000, 3_000];
+    let array = PrimitiveArray::<i32>::from_slice(millis)
+        .to(ArrowDataType::Time32(ArrowTimeUnit::Millisecond))
+        .boxed();
+    let schema = ArrowSchema::from_iter([Field::new("t".into(), array.dtype().clone(), true)]);
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_page_size: None,
+    };
+    let batches = vec![RecordBatchT::try_new(array.len(), vec![array])];
+    let row_groups = RowGroupIterator::try_new(
+        batches.into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+        let mut bitset = vec![0; optimal_num_bytes(millis.len(), 0.01)];
+        millis
+            .iter()
+            .for_each(|&v| insert(&mut bitset, hash_native(v)));
+        writer.add_bloom_filter(0, bitset)?;
+    }
+    writer.end(None)?;
+
+    let path = std::env::temp_dir().join("polars-parquet-bloom-filter-time-millis.parquet");
+    std::fs::write(&path, writer.into_inner().into_inner())?;
+
+    let two_seconds = Scalar::new(DataType::Time, AnyValue::Time(2_000_000_000));
+    let out = LazyFrame::scan_parquet(&path, Default::default())?
+        .filter(col("t").eq(lit(two_seconds)))
+        .collect()?;
+    assert_eq!(out.height(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "parquet_encryption")]
+mod encryption {
+    use std::sync::Arc;
+
+    use polars_parquet::parquet::error::{ParquetError, ParquetResult};
+
+    use super::*;
+
+    const FOOTER_KEY: &[u8] = b"0123456789012345";
+    const ID_KEY: &[u8] = b"1234567890123450";
+    const FLOAT_KEY: &[u8] = b"1234567890123451";
+
+    struct KeyRetriever;
+
+    impl DecryptionKeyRetriever for KeyRetriever {
+        fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+            match key_metadata {
+                b"kf" => Ok(FOOTER_KEY.to_vec()),
+                b"kc1" => Ok(ID_KEY.to_vec()),
+                b"kc2" => Ok(FLOAT_KEY.to_vec()),
+                _ => Err(ParquetError::InvalidParameter("unknown key".to_string())),
+            }
+        }
+    }
+
+    fn df() -> PolarsResult<DataFrame> {
+        df! {
+            "id" => [1i64, 2, 3, 4],
+            "name" => [Some("a"), None, Some("c"), Some("d")],
+        }
+    }
+
+    fn write(df: &mut DataFrame, encryption: FileEncryptionProperties) -> PolarsResult<Vec<u8>> {
+        let mut buf = Vec::new();
+        ParquetWriter::new(&mut buf)
+            .with_encryption(Some(encryption))
+            .with_row_group_size(Some(2))
+            .finish(df)?;
+        Ok(buf)
+    }
+
+    fn read(buf: &[u8], decryption: Option<FileDecryptionProperties>) -> PolarsResult<DataFrame> {
+        ParquetReader::new(Cursor::new(buf))
+            .with_decryption(decryption)
+            .finish()
+    }
+
+    #[test]
+    fn test_parquet_encryption_uniform() -> PolarsResult<()> {
+        let mut df = df()?;
+        let buf = write(&mut df, FileEncryptionProperties::new(FOOTER_KEY.to_vec()))?;
+        assert_eq!(&buf[..4], b"PARE");
+        assert_eq!(&buf[buf.len() - 4..], b"PARE");
+
+        let out = read(
+            &buf,
+            Some(FileDecryptionProperties::new(FOOTER_KEY.to_vec())),
+        )?;
+        assert!(df.equals_missing(&out));
+
+        assert!(read(&buf, None).is_err());
+        let wrong_key = FileDecryptionProperties::new(ID_KEY.to_vec());
+        assert!(read(&buf, Some(wrong_key)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_encryption_column_keys() -> PolarsResult<()> {
+        let mut df = df()?;
+        let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+            .with_footer_key_metadata(b"kf".to_vec())
+            .with_column_key(
+                ColumnEncryptionKey::new("id", ID_KEY.to_vec()).with_key_metadata(b"kc1".to_vec()),
+            )
+            .with_aad_prefix(b"table".to_vec(), false);
+        let buf = write(&mut df, encryption)?;
+
+        let decryption = FileDecryptionProperties::new(FOOTER_KEY.to_vec())
+            .with_column_key("id", ID_KEY.to_vec())
+            .with_aad_prefix(b"table".to_vec());
+        assert!(df.equals_missing(&read(&buf, Some(decryption))?));
+
+        let decryption = FileDecryptionProperties::from_key_retriever(Arc::new(KeyRetriever))
+            .with_aad_prefix(b"table".to_vec());
+        assert!(df.equals_missing(&read(&buf, Some(decryption.clone()))?));
+
+        // The AAD prefix is not stored in the file, so it must be supplied.
+        let without_prefix = FileDecryptionProperties::from_key_retriever(Arc::new(KeyRetriever));
+        assert!(read(&buf, Some(without_prefix)).is_err());
+        let wrong_prefix = decryption.with_aad_prefix(b"other".to_vec());
+        assert!(read(&buf, Some(wrong_prefix)).is_err());
+
+        // Unknown columns are rejected when writing.
+        let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+            .with_column_key(ColumnEncryptionKey::new("missing", ID_KEY.to_vec()));
+        assert!(write(&mut df, encryption).is_err());
+        Ok(())
+    }
+
+    /// Files written by the arrow-rs parquet writer, with the keys of the parquet-testing files.
+    /// `double_field` is encrypted with `kc1`, `float_field` with `kc2` and the footer with `kf`.
+    #[test]
+    fn test_parquet_encryption_external_files() -> PolarsResult<()> {
+        let expected = df! {
+            "id" => [0i32, 1, 2, 3, 4, 5],
+            "double_field" => [Some(0.0f64), Some(1.1), None, Some(3.3), Some(4.4), Some(5.5)],
+            "float_field" => [Some(0.0f32), Some(1.5), Some(2.5), None, Some(4.5), Some(5.5)],
+            "name" => [Some("a"), Some("b"), Some("c"), Some("d"), None, Some("f")],
+        }?;
+
+        for file in [
+            "encrypt_columns_and_footer.parquet",
+            "encrypt_columns_plaintext_footer.parquet",
+        ] {
+            let dir = env!("CARGO_MANIFEST_DIR");
+            let path = PathBuf::from(dir).join("../../docs/assets/data").join(file);
+            let buf = std::fs::read(path)?;
+
+            let decryption = FileDecryptionProperties::new(FOOTER_KEY.to_vec())
+                .with_column_key("double_field", ID_KEY.to_vec())
+                .with_column_key("float_field", FLOAT_KEY.to_vec());
+            assert!(expected.equals_missing(&read(&buf, Some(decryption))?));
+
+            let decryption = FileDecryptionProperties::from_key_retriever(Arc::new(KeyRetriever));
+            assert!(expected.equals_missing(&read(&buf, Some(decryption))?));
+
+            let wrong_key = FileDecryptionProperties::new(FOOTER_KEY.to_vec())
+                .with_column_key("double_field", FLOAT_KEY.to_vec())
+                .with_column_key("float_field", FLOAT_KEY.to_vec());
+            assert!(read(&buf, Some(wrong_key)).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_scan_parquet_encrypted() -> PolarsResult<()> {
+        let mut df = df()?;
+        let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+            .with_column_key(ColumnEncryptionKey::new("name", ID_KEY.to_vec()));
+        let path = std::env::temp_dir().join("polars-parquet-encrypted.parquet");
+        std::fs::write(&path, write(&mut df, encryption)?)?;
+
+        let args = ScanArgsParquet {
+            decryption: Some(
+                FileDecryptionProperties::new(FOOTER_KEY.to_vec())
+                    .with_column_key("name", ID_KEY.to_vec()),
+            ),
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_parquet(&path, args.clone())?
+            .filter(col("id").gt(lit(1i64)))
+            .collect()?;
+        assert!(df.slice(1, 3).equals_missing(&out));
+
+        let count = LazyFrame::scan_parquet(&path, args)?
+            .select([len()])
+            .collect()?;
+        assert_eq!(count.column("len")?.u32()?.get(0), Some(4));
+
+        assert!(LazyFrame::scan_parquet(&path, Default::default())?
+            .collect()
+            .is_err());
+        Ok(())
+    }
+}
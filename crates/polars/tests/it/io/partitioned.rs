@@ -1,52 +1,299 @@
-use std::io::BufReader;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use polars::io::ipc::{IpcReader, IpcWriterOption};
-use polars::io::prelude::SerReader;
-use polars::io::PartitionedWriter;
-use polars_error::PolarsResult;
-use tempfile;
+use polars::prelude::*;
+
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("polars-partitioned-{}", name));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn sorted_files(dir: &Path) -> PolarsResult<Vec<PathBuf>> {
+    let mut paths = std::fs::read_dir(dir)?
+        .map(|e| Ok(e?.path()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(feature = "csv")]
+fn read_csv(path: &Path) -> PolarsResult<DataFrame> {
+    CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(path.to_path_buf()))?
+        .finish()
+}
 
 #[test]
 #[cfg(feature = "ipc")]
 fn test_ipc_partition() -> PolarsResult<()> {
-    let tmp_dir = tempfile::tempdir()?;
+    let mut df = df!("a" => [1, 1, 2, 3], "b" => [2, 2, 3, 4], "c" => [2, 3, 4, 5])?;
+    let rootdir = test_dir("ipc");
 
-    let df = df!("a" => [1, 1, 2, 3], "b" => [2, 2, 3, 4], "c" => [2, 3, 4, 5]).unwrap();
-    let by = ["a", "b"];
-    let rootdir = tmp_dir.path().join("ipc-partition");
+    write_partitioned_dataset(
+        &mut df,
+        &rootdir,
+        ["a", "b"],
+        &IpcWriterOptions::default(),
+        usize::MAX,
+    )?;
 
-    let option = IpcWriterOption::new();
+    let expected = [
+        ("a=1/b=2", df!("a" => [1, 1], "b" => [2, 2], "c" => [2, 3])?),
+        ("a=2/b=3", df!("a" => [2], "b" => [3], "c" => [4])?),
+        ("a=3/b=4", df!("a" => [3], "b" => [4], "c" => [5])?),
+    ];
 
-    PartitionedWriter::new(option, rootdir.clone(), by).finish(&df)?;
+    for (dir, expected_df) in expected.iter() {
+        let ipc_paths = sorted_files(&rootdir.join(dir))?;
+        assert_eq!(ipc_paths.len(), 1);
+        assert!(ipc_paths[0].ends_with("00000000.ipc"));
+        let file = polars_utils::open_file(&ipc_paths[0])?;
+        let df = IpcReader::new(file).finish()?;
+        assert!(expected_df.equals(&df));
+    }
 
-    let expected_dfs = [
-        df!("a" => [1, 1], "b" => [2, 2], "c" => [2, 3])?,
-        df!("a" => [2], "b" => [3], "c" => [4])?,
-        df!("a" => [3], "b" => [4], "c" => [5])?,
-    ];
+    Ok(())
+}
 
-    let expected: Vec<(PathBuf, DataFrame)> = ["a=1/b=2", "a=2/b=3", "a=3/b=4"]
-        .into_iter()
-        .zip(expected_dfs)
-        .map(|(p, df)| (rootdir.join(p), df))
-        .collect();
+#[test]
+#[cfg(feature = "csv")]
+fn test_csv_partition_options() -> PolarsResult<()> {
+    let mut df = df!("a" => ["x", "x", "x", "y"], "b" => [1, 2, 3, 4])?;
+    let rootdir = test_dir("csv-options");
 
-    for (expected_dir, expected_df) in expected.iter() {
-        assert!(expected_dir.exists());
+    let options = PartitionedWriteOptions::new(["a"])
+        .with_include_partition_columns(false)
+        .with_max_rows_per_file(Some(2))
+        .with_filename_template("data-{i}.{ext}")
+        .with_layout(PartitionLayout::ValueOnly);
+    write_partitioned_dataset_with_options(
+        &mut df,
+        &rootdir,
+        &options,
+        &CsvWriterOptions::default(),
+    )?;
 
-        let ipc_paths = std::fs::read_dir(expected_dir)?
-            .map(|e| {
-                let entry = e?;
-                Ok(entry.path())
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+    let paths = sorted_files(&rootdir.join("x"))?;
+    assert_eq!(paths.len(), 2);
+    assert!(paths[0].ends_with("data-0.csv"));
+    assert!(paths[1].ends_with("data-1.csv"));
+    assert!(read_csv(&paths[0])?.equals(&df!("b" => [1, 2])?));
+    assert!(read_csv(&paths[1])?.equals(&df!("b" => [3])?));
 
-        assert_eq!(ipc_paths.len(), 1);
-        let reader = BufReader::new(polars_utils::open_file(&ipc_paths[0])?);
-        let df = IpcReader::new(reader).finish()?;
-        assert!(expected_df.equals(&df));
+    let paths = sorted_files(&rootdir.join("y"))?;
+    assert_eq!(paths.len(), 1);
+    assert!(read_csv(&paths[0])?.equals(&df!("b" => [4])?));
+
+    // Appending adds files next to the existing ones.
+    let options = options.with_mode(PartitionWriteMode::Append);
+    write_partitioned_dataset_with_options(
+        &mut df,
+        &rootdir,
+        &options,
+        &CsvWriterOptions::default(),
+    )?;
+    assert_eq!(sorted_files(&rootdir.join("x"))?.len(), 4);
+    assert_eq!(sorted_files(&rootdir.join("y"))?.len(), 2);
+
+    // Overwriting only replaces the files with the same names.
+    let options = options.with_mode(PartitionWriteMode::Overwrite);
+    write_partitioned_dataset_with_options(
+        &mut df,
+        &rootdir,
+        &options,
+        &CsvWriterOptions::default(),
+    )?;
+    assert_eq!(sorted_files(&rootdir.join("x"))?.len(), 4);
+    assert_eq!(sorted_files(&rootdir.join("y"))?.len(), 2);
+
+    // Replacing the partitions removes their existing files.
+    let options = options.with_mode(PartitionWriteMode::ReplacePartitions);
+    write_partitioned_dataset_with_options(
+        &mut df,
+        &rootdir,
+        &options,
+        &CsvWriterOptions::default(),
+    )?;
+    assert_eq!(sorted_files(&rootdir.join("x"))?.len(), 2);
+    assert_eq!(sorted_files(&rootdir.join("y"))?.len(), 1);
+
+    let options = options.with_mode(PartitionWriteMode::ErrorIfExists);
+    assert!(write_partitioned_dataset_with_options(
+        &mut df,
+        &rootdir,
+        &options,
+        &CsvWriterOptions::default(),
+    )
+    .is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_partition_invalid_template() -> PolarsResult<()> {
+    let mut df = df!("a" => [1, 2], "b" => [1, 2])?;
+    let options = PartitionedWriteOptions::new(["a"]).with_filename_template("data.csv");
+    let res = write_partitioned_dataset_with_options(
+        &mut df,
+        &test_dir("invalid-template"),
+        &options,
+        &CsvWriterOptions::default(),
+    );
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "streaming"))]
+fn test_sink_partitioned_csv() -> PolarsResult<()> {
+    let df = df!("a" => [1, 2, 1, 2, 1], "b" => [1, 2, 3, 4, 5])?;
+    let rootdir = test_dir("sink-csv");
+
+    let options = PartitionedWriteOptions::new(["a"])
+        .with_include_partition_columns(false)
+        .with_max_rows_per_file(Some(2));
+    let file_type = FileType::Csv(CsvWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    });
+    df.lazy().sink_partitioned(&rootdir, file_type, options)?;
+
+    let paths = sorted_files(&rootdir.join("a=1"))?;
+    assert_eq!(paths.len(), 2);
+    assert!(paths[0].ends_with("00000000.csv"));
+    assert!(read_csv(&paths[0])?.equals(&df!("b" => [1, 3])?));
+    assert!(read_csv(&paths[1])?.equals(&df!("b" => [5])?));
+
+    let paths = sorted_files(&rootdir.join("a=2"))?;
+    assert_eq!(paths.len(), 1);
+    assert!(read_csv(&paths[0])?.equals(&df!("b" => [2, 4])?));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "streaming"))]
+fn test_sink_partitioned_many_partitions() -> PolarsResult<()> {
+    // More partitions than files that are kept open at once.
+    let n_partitions = 20;
+    let b = (0..200).collect::<Vec<i32>>();
+    let a = b.iter().map(|v| v % n_partitions).collect::<Vec<_>>();
+    let df = df!("a" => a, "b" => b)?;
+    let rootdir = test_dir("sink-many");
+
+    let options = PartitionedWriteOptions::new(["a"]).with_include_partition_columns(false);
+    let file_type = FileType::Csv(CsvWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    });
+    df.lazy().sink_partitioned(&rootdir, file_type, options)?;
+
+    for key in 0..n_partitions {
+        let mut values = vec![];
+        for path in sorted_files(&rootdir.join(format!("a={key}")))? {
+            values.extend(read_csv(&path)?.column("b")?.i64()?.into_no_null_iter());
+        }
+        let expected = (0..200)
+            .filter(|v| v % n_partitions == key)
+            .map(i64::from)
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
     }
 
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "json", feature = "streaming"))]
+fn test_sink_partitioned_json_options() -> PolarsResult<()> {
+    let df = df!(
+        "a" => [1, 2, 1],
+        "b" => [Some(1.0f64 / 3.0), Some(2.0), None],
+    )?;
+    let rootdir = test_dir("sink-json");
+
+    let options = PartitionedWriteOptions::new(["a"]).with_include_partition_columns(false);
+    let file_type = FileType::Json(JsonWriterOptions {
+        maintain_order: true,
+        float_precision: Some(2),
+        omit_null_fields: true,
+        ..Default::default()
+    });
+    df.lazy().sink_partitioned(&rootdir, file_type, options)?;
+
+    let paths = sorted_files(&rootdir.join("a=1"))?;
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("00000000.jsonl"));
+    assert_eq!(std::fs::read_to_string(&paths[0])?, "{\"b\":0.33}\n{}\n");
+
+    let paths = sorted_files(&rootdir.join("a=2"))?;
+    assert_eq!(std::fs::read_to_string(&paths[0])?, "{\"b\":2.00}\n");
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "temporal"))]
+fn test_scan_partition_scheme() -> PolarsResult<()> {
+    use polars_core::export::chrono::NaiveDate;
+    use polars_io::HiveOptions;
+
+    let mut df = df!(
+        "y" => ["2024", "2024", "2023"],
+        "m" => ["01", "02", "12"],
+        "v" => [1, 2, 3]
+    )?;
+    let rootdir = test_dir("scan-scheme");
+
+    let options = PartitionedWriteOptions::new(["y", "m"])
+        .with_include_partition_columns(false)
+        .with_layout(PartitionLayout::ValueOnly);
+    write_partitioned_dataset_with_options(
+        &mut df,
+        &rootdir,
+        &options,
+        &ParquetWriteOptions::default(),
+    )?;
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            partition_scheme: Some("{year}/{month}".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(rootdir.join("**/*.parquet"), args)?
+        .filter(col("date").gt_eq(lit(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())))
+        .sort(["v"], Default::default())
+        .collect()?;
+
+    assert_eq!(out.schema().get("date"), Some(&DataType::Date));
+    let expected = df!(
+        "v" => [1, 2],
+        "date" => [
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        ]
+    )?;
+    assert!(out.equals(&expected));
+
+    // Named placeholders and explicit formats.
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            partition_scheme: Some("{ts:%Y}/{ts:%m}".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(rootdir.join("**/*.parquet"), args)?
+        .filter(col("v").eq(lit(3)))
+        .collect()?;
+    let expected = df!(
+        "v" => [3],
+        "ts" => [NaiveDate::from_ymd_opt(2023, 12, 1).unwrap()]
+    )?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
@@ -35,3 +35,79 @@ fn test_lazy_agg() {
     let min = new.column("min").unwrap();
     assert_eq!(min, &Column::new("min".into(), [0.1f64, 0.01, 0.1]));
 }
+
+#[test]
+#[cfg(all(feature = "dtype-decimal", feature = "round_series"))]
+fn test_decimal_exact_arithmetic_and_aggregations() -> PolarsResult<()> {
+    let a = Int128Chunked::new("a".into(), &[100, 200, 245])
+        .into_decimal(Some(10), 2)?
+        .into_column();
+    let b = Int128Chunked::new("b".into(), &[30, 30, 70])
+        .into_decimal(Some(5), 1)?
+        .into_column();
+    let g = Column::new("g".into(), [1, 1, 2]);
+    let df = DataFrame::new(vec![g, a, b])?;
+
+    let lf = df.clone().lazy().select([
+        col("a").sum().alias("sum"),
+        col("a").mean().alias("mean"),
+        (col("a") / col("b")).first().alias("div"),
+        (col("a") * col("b")).last().alias("mul"),
+        col("a").round(1).last().alias("round"),
+    ]);
+    let schema = lf.clone().collect_schema()?;
+    let out = lf.collect()?;
+    assert_eq!(&out.schema(), schema.as_ref());
+
+    let expected = [
+        (
+            "sum",
+            DataType::Decimal(Some(38), Some(2)),
+            AnyValue::Decimal(545, 2),
+        ),
+        (
+            "mean",
+            DataType::Decimal(Some(38), Some(6)),
+            AnyValue::Decimal(1816667, 6),
+        ),
+        (
+            "div",
+            DataType::Decimal(Some(17), Some(8)),
+            AnyValue::Decimal(33333333, 8),
+        ),
+        (
+            "mul",
+            DataType::Decimal(Some(16), Some(3)),
+            AnyValue::Decimal(17150, 3),
+        ),
+        (
+            "round",
+            DataType::Decimal(Some(10), Some(1)),
+            AnyValue::Decimal(25, 1),
+        ),
+    ];
+    for (name, dtype, value) in expected {
+        let c = out.column(name)?;
+        assert_eq!(c.dtype(), &dtype, "{name}");
+        assert_eq!(c.get(0)?, value, "{name}");
+    }
+
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("a").mean()])
+        .sort(["g"], Default::default())
+        .collect()?;
+    let mean = out.column("a")?;
+    assert_eq!(mean.dtype(), &DataType::Decimal(Some(38), Some(6)));
+    assert_eq!(mean.get(0)?, AnyValue::Decimal(1500000, 6));
+    assert_eq!(mean.get(1)?, AnyValue::Decimal(2450000, 6));
+
+    // Overflows raise instead of wrapping around.
+    let big = Int128Chunked::new("big".into(), &[9 * 10i128.pow(37); 2])
+        .into_decimal(Some(38), 0)?
+        .into_series();
+    assert!(big.sum_reduce().is_err());
+    assert!((&big + &big).is_err());
+    Ok(())
+}
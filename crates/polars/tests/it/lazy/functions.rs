@@ -24,3 +24,103 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_namespace() -> PolarsResult<()> {
+    let df = df![
+        "keys" => [
+            Series::new("".into(), ["a", "b"]),
+            Series::new("".into(), ["c"]),
+            Series::new("".into(), ["b"]),
+        ],
+        "values" => [
+            Series::new("".into(), [1, 2]),
+            Series::new("".into(), [3]),
+            Series::new("".into(), [4]),
+        ],
+        "key" => ["a", "a", "b"],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_column(map_from_lists(col("keys"), col("values")).alias("m"))
+        .select([
+            col("m").map_().get(lit("b")).alias("get"),
+            col("m")
+                .map_()
+                .contains_key(col("key"))
+                .alias("contains_key"),
+            col("m").map_().keys().alias("keys"),
+            col("m").map_().values().alias("values"),
+        ])
+        .collect()?;
+    let expected = df![
+        "get" => [Some(2), None, Some(4)],
+        "contains_key" => [true, false, true],
+        "keys" => df.column("keys")?.as_materialized_series().clone(),
+        "values" => df.column("values")?.as_materialized_series().clone(),
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Every map needs as many keys as values.
+    let out = df
+        .lazy()
+        .select([map_from_lists(
+            col("keys"),
+            col("values").list().head(lit(0)),
+        )])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "rolling_window_by", feature = "cov", feature = "moment"))]
+fn test_rolling_by_skew_corr_cov() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1.0, 4.0, 2.0, 9.0, 3.0, 8.0, 1.0],
+        "b" => [2.0, 3.0, 5.0, 4.0, 1.0, 7.0, 6.0],
+        "idx" => [0i64, 1, 2, 3, 4, 5, 6],
+    ]?;
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::parse("3i"),
+        min_periods: 3,
+        closed_window: ClosedWindow::Right,
+        fn_params: None,
+    };
+    let cov_options = RollingCovOptions {
+        window_size: 3,
+        min_periods: 3,
+        ddof: 1,
+    };
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .rolling_skew_by(col("idx"), false, options.clone())
+                .alias("skew_by"),
+            col("a").rolling_skew(3, false).alias("skew"),
+            rolling_corr_by(col("a"), col("b"), col("idx"), options.clone(), 1).alias("corr_by"),
+            rolling_corr(col("a"), col("b"), cov_options).alias("corr"),
+            rolling_cov_by(col("a"), col("b"), col("idx"), options, 1).alias("cov_by"),
+            rolling_cov(col("a"), col("b"), cov_options).alias("cov"),
+        ])
+        .collect()?;
+
+    for (by, fixed) in [("skew_by", "skew"), ("corr_by", "corr"), ("cov_by", "cov")] {
+        let by = out.column(by)?.f64()?;
+        let fixed = out.column(fixed)?.f64()?;
+        assert_eq!(by.null_count(), 2);
+        for (l, r) in by.into_iter().zip(fixed) {
+            match (l, r) {
+                (None, None) => {},
+                (Some(l), Some(r)) => assert!((l - r).abs() < 1e-10, "{l} != {r}"),
+                _ => panic!("null mismatch"),
+            }
+        }
+    }
+    Ok(())
+}
@@ -61,3 +61,148 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by",
+    feature = "business"
+))]
+fn test_group_by_dynamic_business_days() -> PolarsResult<()> {
+    // Fri 2024-01-05 through Tue 2024-01-09.
+    let dates = DateChunked::from_naive_date(
+        "dt".into(),
+        (5..=9).map(|d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap()),
+    );
+    let df = df![
+        "dt" => dates.into_series(),
+        "a" => [1, 2, 3, 4, 5]
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1bd"),
+                period: Duration::parse("2bd"),
+                offset: Duration::parse("0bd"),
+                include_boundaries: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()])
+        .collect()?;
+
+    let a = out.column("a")?.i32()?;
+    assert_eq!(Vec::from(a), &[Some(10), Some(9), Some(5)]);
+    let upper = out.column("_upper_boundary")?.date()?;
+    let expected = DateChunked::from_naive_date(
+        "".into(),
+        [9, 10, 11].map(|d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap()),
+    );
+    assert_eq!(Vec::from(upper.physical()), Vec::from(expected.physical()));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "offset_by",
+    feature = "business"
+))]
+fn test_offset_by_business_days_with_calendar() -> PolarsResult<()> {
+    let date = |d| NaiveDate::from_ymd_opt(2024, 12, d).unwrap();
+    // Tue 2024-12-24 and Fri 2024-12-27, with Christmas holidays in between.
+    let dates = DateChunked::from_naive_date("dt".into(), [date(24), date(27)]);
+    let df = df!["dt" => dates.into_series()]?;
+    let calendar = BusinessCalendar::default()
+        .with_holiday_set("christmas".into(), &[20082, 20083])
+        .with_half_days(&[20081]);
+
+    let out = df
+        .lazy()
+        .select([
+            col("dt")
+                .dt()
+                .offset_by_with_options(
+                    lit("1bd"),
+                    OffsetByOptions {
+                        calendar: Some(calendar.clone()),
+                        ..Default::default()
+                    },
+                )
+                .alias("next"),
+            col("dt").dt().offset_by(lit("1bd")).alias("default"),
+            col("dt").dt().is_half_day(calendar).alias("half_day"),
+        ])
+        .collect()?;
+
+    let expected = DateChunked::from_naive_date("".into(), [date(27), date(30)]);
+    let next = out.column("next")?.date()?;
+    assert_eq!(Vec::from(next.physical()), Vec::from(expected.physical()));
+    let expected = DateChunked::from_naive_date("".into(), [date(25), date(30)]);
+    let default = out.column("default")?.date()?;
+    assert_eq!(
+        Vec::from(default.physical()),
+        Vec::from(expected.physical())
+    );
+    let half_day = out.column("half_day")?.bool()?;
+    assert_eq!(Vec::from(half_day), &[Some(true), Some(false)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_fiscal_quarters() -> PolarsResult<()> {
+    let dates = DateChunked::from_naive_date(
+        "dt".into(),
+        [
+            (2023, 9, 30),
+            (2023, 10, 1),
+            (2023, 12, 31),
+            (2024, 1, 1),
+            (2024, 6, 15),
+        ]
+        .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap()),
+    );
+    let df = df![
+        "dt" => dates.into_series(),
+        "a" => [1, 2, 3, 4, 5]
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1fq"),
+                period: Duration::parse("1fq"),
+                offset: Duration::parse("0fq"),
+                include_boundaries: true,
+                fiscal_calendar: Some(FiscalCalendar::Monthly { start_month: 10 }),
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()])
+        .collect()?;
+
+    let a = out.column("a")?.i32()?;
+    assert_eq!(Vec::from(a), &[Some(1), Some(5), Some(4), Some(5)]);
+    let upper = out.column("_upper_boundary")?.date()?;
+    let expected = DateChunked::from_naive_date(
+        "".into(),
+        [(2023, 10, 1), (2024, 1, 1), (2024, 4, 1), (2024, 7, 1)]
+            .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap()),
+    );
+    assert_eq!(Vec::from(upper.physical()), Vec::from(expected.physical()));
+    Ok(())
+}
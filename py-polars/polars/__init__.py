@@ -48,13 +48,17 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
     Int16,
     Int32,
     Int64,
+    Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -64,11 +68,14 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     Utf8,
 )
 from polars.expr import Expr
 from polars.functions import (
+    BusinessCalendar,
+    RetailCalendar,
     align_frames,
     all,
     all_horizontal,
@@ -136,7 +143,9 @@ from polars.functions import (
     reduce,
     repeat,
     rolling_corr,
+    rolling_corr_by,
     rolling_cov,
+    rolling_cov_by,
     select,
     set_random_seed,
     sql_expr,
@@ -233,13 +242,17 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
     "Int16",
     "Int32",
     "Int64",
+    "Int128",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -249,6 +262,7 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
     "Unknown",
     "Utf8",
     # polars.io
@@ -288,6 +302,10 @@ __all__ = [
     "using_string_cache",
     # polars.config
     "Config",
+    # polars.functions.business
+    "BusinessCalendar",
+    # polars.functions.fiscal
+    "RetailCalendar",
     # polars.functions.whenthen
     "when",
     # polars.functions
@@ -364,7 +382,9 @@ __all__ = [
     "quantile",
     "reduce",
     "rolling_corr",
+    "rolling_corr_by",
     "rolling_cov",
+    "rolling_cov_by",
     "select",
     "std",
     "struct",
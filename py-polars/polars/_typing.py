@@ -153,13 +153,19 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
-InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
+InterpolationMethod: TypeAlias = Literal[
+    "linear", "nearest", "previous", "next", "cubic", "pchip"
+]
+InterpolationLimitArea: TypeAlias = Literal["inside", "outside"]
+RetailPattern: TypeAlias = Literal["4-4-5", "4-5-4", "5-4-4"]
+RetailYearEnd: TypeAlias = Literal["last", "nearest"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
 RollingInterpolationMethod: TypeAlias = Literal[
     "nearest", "higher", "lower", "midpoint", "linear"
 ]  # QuantileInterpolOptions
+RollingRankMethod: TypeAlias = Literal["average", "min", "max"]  # RollingRankMethod
 ListToStructWidthStrategy: TypeAlias = Literal["first_non_null", "max_width"]
 
 # The following have no equivalent on the Rust side
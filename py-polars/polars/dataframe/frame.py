@@ -133,6 +133,7 @@ if TYPE_CHECKING:
 
     from polars import DataType, Expr, LazyFrame, Series
     from polars._typing import (
+        Ambiguous,
         AsofJoinStrategy,
         AvroCompression,
         ClosedInterval,
@@ -156,6 +157,7 @@ if TYPE_CHECKING:
         Label,
         MultiColSelector,
         MultiIndexSelector,
+        NonExistent,
         OneOrMoreDataTypes,
         Orientation,
         ParquetCompression,
@@ -176,6 +178,8 @@ if TYPE_CHECKING:
         UnstackDirection,
     )
     from polars._utils.various import NoDefault
+    from polars.functions.business import BusinessCalendar
+    from polars.functions.fiscal import RetailCalendar
     from polars.interchange.dataframe import PolarsDataFrame
     from polars.ml.torch import PolarsDataset
 
@@ -6324,6 +6328,10 @@ class DataFrame:
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        calendar: BusinessCalendar | None = None,
+        fiscal_calendar: int | RetailCalendar | None = None,
+        ambiguous: Ambiguous = "raise",
+        non_existent: NonExistent = "raise",
     ) -> DynamicGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -6394,6 +6402,27 @@ class DataFrame:
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        calendar
+            The :class:`BusinessCalendar` for windows in business days (e.g.
+            `every="1bd"`). Defaults to Monday to Friday, without holidays.
+        fiscal_calendar
+            The fiscal calendar for windows in fiscal units (e.g. `every="1fq"`):
+            either the month in which the fiscal year starts (1 = January, the
+            default) or a :class:`RetailCalendar`.
+        ambiguous
+            How to handle window boundaries that are ambiguous in the time zone of
+            `index_column`, such as `01:30` on the day clocks are set back:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: skip the window
+        non_existent
+            How to handle window boundaries that don't exist in the time zone of
+            `index_column`, such as `02:30` on the day clocks are set forward:
+
+            - `'raise'` (default): raise
+            - `'null'`: skip the window
 
         Returns
         -------
@@ -6441,19 +6470,34 @@ class DataFrame:
            - 1q    (1 calendar quarter)
            - 1y    (1 calendar year)
            - 1i    (1 index count)
+           - 1bd   (1 business day, see `calendar`)
+           - 1fp   (1 fiscal period, see `fiscal_calendar`)
+           - 1fq   (1 fiscal quarter)
+           - 1fy   (1 fiscal year)
 
            Or combine them:
            "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
 
            By "calendar day", we mean the corresponding time on the next day (which may
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
-           "calendar month", "calendar quarter", and "calendar year".
+           "calendar month", "calendar quarter", and "calendar year". If
+           `index_column` is time-zone-aware, windows in calendar units are defined in
+           local wall-clock time, e.g. `every="1d", offset="2h"` starts every window at
+           02:00 local time, also on days on which clocks change.
 
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
            - "10i"     # length 10
 
+           Windows in business days ("bd") can't be combined with other units. They
+           number the business days of `calendar`, with a day that is not a business
+           day belonging to the business day before it.
+
+           Windows in fiscal units ("fp", "fq", "fy") can't be combined with other
+           units either. They are aligned to the fiscal years of `fiscal_calendar`,
+           and their boundaries are at (local) midnight.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -6626,6 +6670,10 @@ class DataFrame:
             closed=closed,
             group_by=group_by,
             start_by=start_by,
+            calendar=calendar,
+            fiscal_calendar=fiscal_calendar,
+            ambiguous=ambiguous,
+            non_existent=non_existent,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -13,13 +13,17 @@ if TYPE_CHECKING:
 
     from polars import DataFrame
     from polars._typing import (
+        Ambiguous,
         ClosedInterval,
         IntoExpr,
         Label,
+        NonExistent,
         RollingInterpolationMethod,
         SchemaDict,
         StartBy,
     )
+    from polars.functions.business import BusinessCalendar
+    from polars.functions.fiscal import RetailCalendar
 
     if sys.version_info >= (3, 11):
         from typing import Self
@@ -910,6 +914,10 @@ class DynamicGroupBy:
         label: Label,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
         start_by: StartBy,
+        calendar: BusinessCalendar | None,
+        fiscal_calendar: int | RetailCalendar | None,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
     ) -> None:
         every = parse_as_duration_string(every)
         period = parse_as_duration_string(period)
@@ -925,6 +933,10 @@ class DynamicGroupBy:
         self.closed = closed
         self.group_by = group_by
         self.start_by = start_by
+        self.calendar = calendar
+        self.fiscal_calendar = fiscal_calendar
+        self.ambiguous = ambiguous
+        self.non_existent = non_existent
 
     def __iter__(self) -> Self:
         temp_col = "__POLARS_GB_GROUP_INDICES"
@@ -940,6 +952,10 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                calendar=self.calendar,
+                fiscal_calendar=self.fiscal_calendar,
+                ambiguous=self.ambiguous,
+                non_existent=self.non_existent,
             )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(no_optimization=True)
@@ -991,6 +1007,10 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                calendar=self.calendar,
+                fiscal_calendar=self.fiscal_calendar,
+                ambiguous=self.ambiguous,
+                non_existent=self.non_existent,
             )
             .agg(*aggs, **named_aggs)
             .collect(no_optimization=True)
@@ -1036,6 +1056,10 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                calendar=self.calendar,
+                fiscal_calendar=self.fiscal_calendar,
+                ambiguous=self.ambiguous,
+                non_existent=self.non_existent,
             )
             .map_groups(function, schema)
             .collect(no_optimization=True)
@@ -12,14 +12,18 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
     Int16,
     Int32,
     Int64,
+    Int128,
+    Interval,
     IntegerType,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -30,6 +34,7 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     Utf8,
 )
@@ -68,20 +73,25 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
+    "Int128",
     "Int16",
     "Int32",
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
     "Struct",
     "TemporalType",
     "Time",
+    "UInt128",
     "UInt16",
     "UInt32",
     "UInt64",
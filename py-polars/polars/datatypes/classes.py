@@ -319,6 +319,10 @@ class Int64(SignedIntegerType):
     """64-bit signed integer type."""
 
 
+class Int128(SignedIntegerType):
+    """128-bit signed integer type."""
+
+
 class UInt8(UnsignedIntegerType):
     """8-bit unsigned integer type."""
 
@@ -335,6 +339,18 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class UInt128(UnsignedIntegerType):
+    """128-bit unsigned integer type."""
+
+
+class Float16(FloatType):
+    """
+    16-bit half-precision floating point type.
+
+    Values are stored in half precision and upcast to `Float32` for computation.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -545,6 +561,18 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(TemporalType):
+    """
+    Data type representing a calendar-aware time interval.
+
+    Notes
+    -----
+    An interval consists of a number of months, a number of days and a number of
+    nanoseconds, which are applied in that order when added to a `Date` or
+    `Datetime`. Values are returned as `(months, days, nanoseconds)` tuples.
+    """
+
+
 class Categorical(DataType):
     """
     A categorical encoding of a set of strings.
@@ -857,6 +885,48 @@ class Array(NestedType):
         return self.size
 
 
+class Map(NestedType):
+    """
+    Key-value map type.
+
+    A map is stored as a list of `{key, value}` structs.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys.
+    value
+        The `DataType` of the values.
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
 class Field:
     """
     Definition of a single field within a `Struct` DataType.
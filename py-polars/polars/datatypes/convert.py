@@ -22,13 +22,17 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
     Int16,
     Int32,
     Int64,
+    Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -38,6 +42,7 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
 )
 from polars.dependencies import numpy as np
@@ -149,15 +154,19 @@ class _DataTypeMappings:
             Duration: "duration",
             Float32: "f32",
             Float64: "f64",
+            Int128: "i128",
             Int16: "i16",
             Int32: "i32",
             Int64: "i64",
             Int8: "i8",
+            Interval: "interval",
             List: "list",
+            Map: "map",
             Object: "object",
             String: "str",
             Struct: "struct",
             Time: "time",
+            UInt128: "u128",
             UInt16: "u16",
             UInt32: "u32",
             UInt64: "u64",
@@ -175,18 +184,23 @@ class _DataTypeMappings:
             Datetime: datetime,
             Decimal: PyDecimal,
             Duration: timedelta,
+            Float16: float,
             Float32: float,
             Float64: float,
+            Int128: int,
             Int16: int,
             Int32: int,
             Int64: int,
             Int8: int,
+            Interval: tuple,
             List: list,
+            Map: list,
             Null: None.__class__,
             Object: object,
             String: str,
             Struct: dict,
             Time: time,
+            UInt128: int,
             UInt16: int,
             UInt32: int,
             UInt64: int,
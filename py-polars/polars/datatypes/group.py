@@ -16,6 +16,7 @@ from polars.datatypes.classes import (
     Int16,
     Int32,
     Int64,
+    Int128,
     List,
     Struct,
     Time,
@@ -23,6 +24,7 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
 )
 
 if TYPE_CHECKING:
@@ -79,6 +81,7 @@ SIGNED_INTEGER_DTYPES: frozenset[PolarsIntegerType] = DataTypeGroup(
         Int16,
         Int32,
         Int64,
+        Int128,
     ]
 )
 UNSIGNED_INTEGER_DTYPES: frozenset[PolarsIntegerType] = DataTypeGroup(
@@ -87,6 +90,7 @@ UNSIGNED_INTEGER_DTYPES: frozenset[PolarsIntegerType] = DataTypeGroup(
         UInt16,
         UInt32,
         UInt64,
+        UInt128,
     ]
 )
 INTEGER_DTYPES: frozenset[PolarsIntegerType] = (
@@ -25,6 +25,8 @@ if TYPE_CHECKING:
         Roll,
         TimeUnit,
     )
+    from polars.functions.business import BusinessCalendar
+    from polars.functions.fiscal import RetailCalendar
 
 
 class ExprDateTimeNameSpace:
@@ -42,6 +44,7 @@ class ExprDateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: BusinessCalendar | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -74,6 +77,8 @@ class ExprDateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A :class:`BusinessCalendar` to use instead of `week_mask` and `holidays`.
 
         Returns
         -------
@@ -145,17 +150,159 @@ class ExprDateTimeNameSpace:
         └────────────┴─────────────────┘
         """
         n_pyexpr = parse_into_expression(n)
-        unix_epoch = dt.date(1970, 1, 1)
-        return wrap_expr(
-            self._pyexpr.dt_add_business_days(
-                n_pyexpr,
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
-                roll,
-            )
-        )
+        if calendar is None:
+            calendar = F.BusinessCalendar(week_mask, holidays)
+        return wrap_expr(self._pyexpr.dt_add_business_days(n_pyexpr, calendar, roll))
+
+    def is_business_day(self, calendar: BusinessCalendar | None = None) -> Expr:
+        """
+        Determine whether each date is a business day.
+
+        Datetimes are checked on their (local) date.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the business days. The default
+            is Monday to Friday, without holidays.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 1, 3), date(2020, 1, 4)]})
+        >>> df.with_columns(is_business_day=pl.col("date").dt.is_business_day())
+        shape: (2, 2)
+        ┌────────────┬─────────────────┐
+        │ date       ┆ is_business_day │
+        │ ---        ┆ ---             │
+        │ date       ┆ bool            │
+        ╞════════════╪═════════════════╡
+        │ 2020-01-03 ┆ true            │
+        │ 2020-01-04 ┆ false           │
+        └────────────┴─────────────────┘
+        """
+        if calendar is None:
+            calendar = F.BusinessCalendar()
+        return wrap_expr(self._pyexpr.dt_is_business_day(calendar))
+
+    def is_half_day(self, calendar: BusinessCalendar) -> Expr:
+        """
+        Determine whether each date is a half-day of the calendar.
+
+        Half-days are business days with shortened hours. Datetimes are checked on
+        their (local) date.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the half-days.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.BusinessCalendar(half_days=[date(2024, 12, 24)])
+        >>> df = pl.DataFrame({"date": [date(2024, 12, 23), date(2024, 12, 24)]})
+        >>> df.with_columns(is_half_day=pl.col("date").dt.is_half_day(calendar))
+        shape: (2, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ is_half_day │
+        │ ---        ┆ ---         │
+        │ date       ┆ bool        │
+        ╞════════════╪═════════════╡
+        │ 2024-12-23 ┆ false       │
+        │ 2024-12-24 ┆ true        │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_half_day(calendar))
+
+    def roll_forward(self, calendar: BusinessCalendar | None = None) -> Expr:
+        """
+        Move dates that are not business days forward to the next business day.
+
+        The time of day of datetimes is preserved.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the business days. The default
+            is Monday to Friday, without holidays.
+
+        Returns
+        -------
+        Expr
+            Expression of the same data type.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 1, 4), date(2020, 1, 6)]})
+        >>> df.with_columns(rolled=pl.col("date").dt.roll_forward())
+        shape: (2, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ rolled     │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2020-01-04 ┆ 2020-01-06 │
+        │ 2020-01-06 ┆ 2020-01-06 │
+        └────────────┴────────────┘
+        """
+        if calendar is None:
+            calendar = F.BusinessCalendar()
+        return wrap_expr(self._pyexpr.dt_roll_forward(calendar))
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
+    def roll_backward(self, calendar: BusinessCalendar | None = None) -> Expr:
+        """
+        Move dates that are not business days back to the previous business day.
+
+        The time of day of datetimes is preserved.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the business days. The default
+            is Monday to Friday, without holidays.
+
+        Returns
+        -------
+        Expr
+            Expression of the same data type.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 1, 4), date(2020, 1, 6)]})
+        >>> df.with_columns(rolled=pl.col("date").dt.roll_backward())
+        shape: (2, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ rolled     │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2020-01-04 ┆ 2020-01-03 │
+        │ 2020-01-06 ┆ 2020-01-06 │
+        └────────────┴────────────┘
+        """
+        if calendar is None:
+            calendar = F.BusinessCalendar()
+        return wrap_expr(self._pyexpr.dt_roll_backward(calendar))
+
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        fiscal_calendar: int | RetailCalendar | None = None,
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -170,6 +317,10 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        fiscal_calendar
+            The fiscal calendar for fiscal units such as `"1fq"`: either the month
+            in which the fiscal year starts (1 = January, the default) or a
+            :class:`RetailCalendar`.
 
         Notes
         -----
@@ -187,8 +338,11 @@ class ExprDateTimeNameSpace:
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
         - 1y    (1 calendar year)
+        - 1fp   (1 fiscal period)
+        - 1fq   (1 fiscal quarter)
+        - 1fy   (1 fiscal year)
 
-        These strings can be combined:
+        These strings can be combined, except for the fiscal units:
 
         - 3d12h4m25s # 3 days, 12 hours, 4 minutes, and 25 seconds
 
@@ -197,6 +351,9 @@ class ExprDateTimeNameSpace:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        Fiscal windows are aligned to the start of the fiscal year, and a Datetime
+        is truncated to local midnight.
+
         Returns
         -------
         Expr
@@ -274,11 +431,29 @@ class ExprDateTimeNameSpace:
         │ 2001-01-01 00:50:00 ┆ 2001-01-01 00:30:00 │
         │ 2001-01-01 01:00:00 ┆ 2001-01-01 01:00:00 │
         └─────────────────────┴─────────────────────┘
+
+        Fiscal units follow `fiscal_calendar`, here fiscal years starting in October:
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2024, 2, 29), date(2024, 10, 15)]})
+        >>> df.with_columns(
+        ...     fq=pl.col("date").dt.truncate("1fq", fiscal_calendar=10),
+        ...     fy=pl.col("date").dt.truncate("1fy", fiscal_calendar=10),
+        ... )
+        shape: (2, 3)
+        ┌────────────┬────────────┬────────────┐
+        │ date       ┆ fq         ┆ fy         │
+        │ ---        ┆ ---        ┆ ---        │
+        │ date       ┆ date       ┆ date       │
+        ╞════════════╪════════════╪════════════╡
+        │ 2024-02-29 ┆ 2024-01-01 ┆ 2023-10-01 │
+        │ 2024-10-15 ┆ 2024-10-01 ┆ 2024-10-01 │
+        └────────────┴────────────┴────────────┘
         """
         if isinstance(every, dt.timedelta):
             every = parse_as_duration_string(every)
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_truncate(every))
+        return wrap_expr(self._pyexpr.dt_truncate(every, fiscal_calendar))
 
     @unstable()
     def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
@@ -901,6 +1076,237 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_quarter())
 
+    def fiscal_year(self, start_month: int = 1) -> Expr:
+        """
+        Extract the fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        A fiscal year starts on the first day of `start_month` and is named after
+        the calendar year in which it ends. See :meth:`retail_year` for retail
+        (4-4-5) calendars.
+
+        Parameters
+        ----------
+        start_month
+            The month in which the fiscal year starts (1 = January).
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2023, 9, 30), date(2023, 10, 1), date(2024, 6, 15)]}
+        ... )
+        >>> df.with_columns(
+        ...     fiscal_year=pl.col("date").dt.fiscal_year(start_month=10),
+        ...     fiscal_quarter=pl.col("date").dt.fiscal_quarter(start_month=10),
+        ... )
+        shape: (3, 3)
+        ┌────────────┬─────────────┬────────────────┐
+        │ date       ┆ fiscal_year ┆ fiscal_quarter │
+        │ ---        ┆ ---         ┆ ---            │
+        │ date       ┆ i32         ┆ i8             │
+        ╞════════════╪═════════════╪════════════════╡
+        │ 2023-09-30 ┆ 2023        ┆ 4              │
+        │ 2023-10-01 ┆ 2024        ┆ 1              │
+        │ 2024-06-15 ┆ 2024        ┆ 3              │
+        └────────────┴─────────────┴────────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_year(start_month))
+
+    def fiscal_quarter(self, start_month: int = 1) -> Expr:
+        """
+        Extract the fiscal quarter from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter of the fiscal year starting in `start_month`, ranging
+        from 1 to 4.
+
+        Parameters
+        ----------
+        start_month
+            The month in which the fiscal year starts (1 = January).
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2023, 9, 30), date(2023, 10, 1), date(2024, 6, 15)]}
+        ... )
+        >>> df.with_columns(
+        ...     fiscal_quarter=pl.col("date").dt.fiscal_quarter(start_month=10)
+        ... )
+        shape: (3, 2)
+        ┌────────────┬────────────────┐
+        │ date       ┆ fiscal_quarter │
+        │ ---        ┆ ---            │
+        │ date       ┆ i8             │
+        ╞════════════╪════════════════╡
+        │ 2023-09-30 ┆ 4              │
+        │ 2023-10-01 ┆ 1              │
+        │ 2024-06-15 ┆ 3              │
+        └────────────┴────────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_quarter(start_month))
+
+    def retail_year(self, calendar: RetailCalendar) -> Expr:
+        """
+        Extract the retail fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        The year is named after the calendar year in which it ends.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar(end_month=12, week_end=6)
+        >>> df = pl.DataFrame({"date": [date(2024, 12, 28), date(2024, 12, 29)]})
+        >>> df.with_columns(retail_year=pl.col("date").dt.retail_year(calendar))
+        shape: (2, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ retail_year │
+        │ ---        ┆ ---         │
+        │ date       ┆ i32         │
+        ╞════════════╪═════════════╡
+        │ 2024-12-28 ┆ 2024        │
+        │ 2024-12-29 ┆ 2025        │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_year(calendar))
+
+    def retail_quarter(self, calendar: RetailCalendar) -> Expr:
+        """
+        Extract the retail fiscal quarter from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter of the retail year, ranging from 1 to 4.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar("4-4-5", end_month=12, week_end=6)
+        >>> df = pl.DataFrame({"date": [date(2024, 3, 30), date(2024, 3, 31)]})
+        >>> df.with_columns(quarter=pl.col("date").dt.retail_quarter(calendar))
+        shape: (2, 2)
+        ┌────────────┬─────────┐
+        │ date       ┆ quarter │
+        │ ---        ┆ ---     │
+        │ date       ┆ i8      │
+        ╞════════════╪═════════╡
+        │ 2024-03-30 ┆ 1       │
+        │ 2024-03-31 ┆ 2       │
+        └────────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_quarter(calendar))
+
+    def retail_period(self, calendar: RetailCalendar) -> Expr:
+        """
+        Extract the retail fiscal period from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the period of the retail year, ranging from 1 to 12. The periods
+        are 4 or 5 weeks long following the pattern of the calendar, and the extra
+        week of a 53-week year belongs to period 12.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar("4-4-5", end_month=12, week_end=6)
+        >>> df = pl.DataFrame({"date": [date(2024, 1, 27), date(2024, 1, 28)]})
+        >>> df.with_columns(period=pl.col("date").dt.retail_period(calendar))
+        shape: (2, 2)
+        ┌────────────┬────────┐
+        │ date       ┆ period │
+        │ ---        ┆ ---    │
+        │ date       ┆ i8     │
+        ╞════════════╪════════╡
+        │ 2024-01-27 ┆ 1      │
+        │ 2024-01-28 ┆ 2      │
+        └────────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_period(calendar))
+
+    def retail_week(self, calendar: RetailCalendar) -> Expr:
+        """
+        Extract the week of the retail fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Weeks are counted from the first day of the retail year and range from 1 to
+        53.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar(end_month=12, week_end=6)
+        >>> df = pl.DataFrame({"date": [date(2023, 12, 31), date(2024, 1, 7)]})
+        >>> df.with_columns(week=pl.col("date").dt.retail_week(calendar))
+        shape: (2, 2)
+        ┌────────────┬──────┐
+        │ date       ┆ week │
+        │ ---        ┆ ---  │
+        │ date       ┆ i8   │
+        ╞════════════╪══════╡
+        │ 2023-12-31 ┆ 1    │
+        │ 2024-01-07 ┆ 2    │
+        └────────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_week(calendar))
+
     def month(self) -> Expr:
         """
         Extract month from underlying Date representation.
@@ -2115,7 +2521,13 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_total_nanoseconds())
 
-    def offset_by(self, by: str | Expr) -> Expr:
+    def offset_by(
+        self,
+        by: str | Expr,
+        *,
+        calendar: BusinessCalendar | None = None,
+        fiscal_calendar: int | RetailCalendar | None = None,
+    ) -> Expr:
         """
         Offset this date by a relative time offset.
 
@@ -2139,11 +2551,29 @@ class ExprDateTimeNameSpace:
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
             - 1y    (1 calendar year)
+            - 1fp   (1 fiscal period)
+            - 1fq   (1 fiscal quarter)
+            - 1fy   (1 fiscal year)
+            - 1bd   (1 business day)
 
             By "calendar day", we mean the corresponding time on the next day (which may
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
             "calendar month", "calendar quarter", and "calendar year".
 
+            Business days can't be combined with other units. A date that is not a
+            business day counts as the first step, so Saturday offset by `"1bd"` is
+            Monday.
+
+            Fiscal units can't be combined with other units either. The day within
+            the fiscal period is kept, clamped to the end of the target period.
+        calendar
+            The :class:`BusinessCalendar` for business days such as `"3bd"`. The
+            default is Monday to Friday, without holidays.
+        fiscal_calendar
+            The fiscal calendar for fiscal units such as `"1fq"`: either the month
+            in which the fiscal year starts (1 = January, the default) or a
+            :class:`RetailCalendar`.
+
         Returns
         -------
         Expr
@@ -2198,7 +2628,7 @@ class ExprDateTimeNameSpace:
         └─────────────────────┴────────┴─────────────────────┘
         """
         by = parse_into_expression(by, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_offset_by(by))
+        return wrap_expr(self._pyexpr.dt_offset_by(by, calendar, fiscal_calendar))
 
     def month_start(self) -> Expr:
         """
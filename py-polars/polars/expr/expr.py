@@ -71,6 +71,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         ClosedInterval,
         FillNullStrategy,
+        InterpolationLimitArea,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
@@ -80,6 +81,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
+        RollingRankMethod,
         SearchSortedSide,
         SerializationFormat,
         TemporalLiteral,
@@ -6062,15 +6064,40 @@ class Expr:
 
         return self.map_batches(inspect, return_dtype=None, agg_list=True)
 
-    def interpolate(self, method: InterpolationMethod = "linear") -> Expr:
+    def interpolate(
+        self,
+        method: InterpolationMethod = "linear",
+        *,
+        limit: int | None = None,
+        limit_area: InterpolationLimitArea | None = "inside",
+    ) -> Expr:
         """
         Fill null values using interpolation.
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'pchip'}
             Interpolation method.
 
+            - 'linear': straight line between the neighbouring valid values.
+            - 'nearest': the closest of the neighbouring valid values.
+            - 'previous': the last valid value before the gap.
+            - 'next': the first valid value after the gap.
+            - 'cubic': natural cubic spline through all valid values.
+            - 'pchip': monotone piecewise cubic Hermite interpolation, which does
+              not overshoot the data.
+        limit
+            Maximum number of consecutive null values to fill. Filling starts next to
+            the valid value the fill is taken from; the rest of a longer gap stays
+            null.
+        limit_area : {'inside', 'outside'}, default 'inside'
+            Which null values to fill.
+
+            - 'inside': only nulls surrounded by valid values.
+            - 'outside': only nulls before the first or after the last valid value,
+              which get the first or last valid value respectively.
+            - None: no restriction.
+
         Examples
         --------
         Fill null values using linear interpolation.
@@ -6107,6 +6134,27 @@ class Expr:
         │ 3   ┆ 3.0 │
         └─────┴─────┘
 
+        Restrict how many and which null values are filled.
+
+        >>> df = pl.DataFrame({"a": [None, 1, None, None, 4, None]})
+        >>> df.select(
+        ...     previous=pl.col("a").interpolate("previous", limit=1),
+        ...     outside=pl.col("a").interpolate(limit_area="outside"),
+        ... )
+        shape: (6, 2)
+        ┌──────────┬─────────┐
+        │ previous ┆ outside │
+        │ ---      ┆ ---     │
+        │ i64      ┆ f64     │
+        ╞══════════╪═════════╡
+        │ null     ┆ 1.0     │
+        │ 1        ┆ 1.0     │
+        │ 1        ┆ null    │
+        │ null     ┆ null    │
+        │ 4        ┆ 4.0     │
+        │ null     ┆ 4.0     │
+        └──────────┴─────────┘
+
         Regrid data to a new grid.
 
         >>> df_original_grid = pl.DataFrame(
@@ -6137,16 +6185,50 @@ class Expr:
         │ 10          ┆ 20.0   │
         └─────────────┴────────┘
         """
-        return self._from_pyexpr(self._pyexpr.interpolate(method))
+        return self._from_pyexpr(
+            self._pyexpr.interpolate(method, limit, limit_area)
+        )
 
-    def interpolate_by(self, by: IntoExpr) -> Expr:
+    def interpolate_by(
+        self,
+        by: IntoExpr,
+        *,
+        method: InterpolationMethod = "linear",
+        limit: int | None = None,
+        limit_area: InterpolationLimitArea | None = "inside",
+    ) -> Expr:
         """
         Fill null values using interpolation based on another column.
 
+        The values of `by` are used as the coordinates of the data, so gaps of
+        irregularly sampled (e.g. time-stamped) data are interpolated by distance
+        rather than by position.
+
         Parameters
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'pchip'}
+            Interpolation method.
+
+            - 'linear': straight line between the neighbouring valid values.
+            - 'nearest': the closest of the neighbouring valid values.
+            - 'previous': the last valid value before the gap.
+            - 'next': the first valid value after the gap.
+            - 'cubic': natural cubic spline through all valid values.
+            - 'pchip': monotone piecewise cubic Hermite interpolation, which does
+              not overshoot the data.
+        limit
+            Maximum number of consecutive null values to fill. Filling starts next to
+            the valid value the fill is taken from; the rest of a longer gap stays
+            null.
+        limit_area : {'inside', 'outside'}, default 'inside'
+            Which null values to fill.
+
+            - 'inside': only nulls surrounded by valid values.
+            - 'outside': only nulls before the first or after the last valid value,
+              which get the first or last valid value respectively.
+            - None: no restriction.
 
         Examples
         --------
@@ -6170,9 +6252,28 @@ class Expr:
         │ null ┆ 7   ┆ 2.714286       │
         │ 3    ┆ 8   ┆ 3.0            │
         └──────┴─────┴────────────────┘
+
+        Fill null values with the value closest in `b`.
+
+        >>> df.with_columns(
+        ...     a_nearest=pl.col("a").interpolate_by("b", method="nearest")
+        ... )
+        shape: (4, 3)
+        ┌──────┬─────┬───────────┐
+        │ a    ┆ b   ┆ a_nearest │
+        │ ---  ┆ --- ┆ ---       │
+        │ i64  ┆ i64 ┆ i64       │
+        ╞══════╪═════╪═══════════╡
+        │ 1    ┆ 1   ┆ 1         │
+        │ null ┆ 2   ┆ 1         │
+        │ null ┆ 7   ┆ 3         │
+        │ 3    ┆ 8   ┆ 3         │
+        └──────┴─────┴───────────┘
         """
         by = parse_into_expression(by)
-        return self._from_pyexpr(self._pyexpr.interpolate_by(by))
+        return self._from_pyexpr(
+            self._pyexpr.interpolate_by(by, method, limit, limit_area)
+        )
 
     @unstable()
     def rolling_min_by(
@@ -8107,6 +8208,122 @@ class Expr:
             )
         )
 
+    @unstable()
+    def rolling_mad(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute a rolling median absolute deviation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The median absolute deviation of a window is the median of the absolute
+        differences between the values in the window and the median of the window.
+        Null values are ignored.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9, 3]})
+        >>> df.select(pl.col("a").rolling_mad(3))
+        shape: (5, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 1.0  │
+        │ 2.0  │
+        │ 1.0  │
+        └──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_mad(window_size, min_periods, center=center)
+        )
+
+    @unstable()
+    def rolling_rank(
+        self,
+        window_size: int,
+        method: RollingRankMethod = "average",
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute the rank of every value within its rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The rank is expressed as a percentile: the rank of the value divided by the
+        number of non-null values in the window, which gives a value in `(0, 1]`.
+        Null values get a null rank.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        method : {'average', 'min', 'max'}
+            The method used to assign ranks to tied values.
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9, 3]})
+        >>> df.select(pl.col("a").rolling_rank(3))
+        shape: (5, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ null     │
+        │ null     │
+        │ 0.666667 │
+        │ 1.0      │
+        │ 0.666667 │
+        └──────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_rank(method, window_size, min_periods, center=center)
+        )
+
     @unstable()
     def rolling_quantile(
         self,
@@ -8296,6 +8513,122 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
 
+    @unstable()
+    def rolling_skew_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        bias: bool = True,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Compute a rolling skew based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or a duration string, see :meth:`rolling_mean_by` for the
+            string language.
+        bias
+            If False, the calculations are corrected for statistical bias.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 4, 2, 9],
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 4), eager=True
+        ...         ),
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").rolling_skew_by("date", window_size="3d"))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ NaN      │
+        │ 0.0      │
+        │ 0.381802 │
+        │ 0.47033  │
+        └──────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_skew_by(by, window_size, bias, min_periods, closed)
+        )
+
+    @unstable()
+    def rolling_kurtosis(
+        self, window_size: int, *, fisher: bool = True, bias: bool = True
+    ) -> Expr:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").rolling_kurtosis(3))
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ -1.5 │
+        │ -1.5 │
+        └──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_kurtosis(window_size, fisher, bias)
+        )
+
     @unstable()
     def rolling_map(
         self,
@@ -24,10 +24,11 @@ from polars.functions.as_datatype import (
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import BusinessCalendar, business_day_count
 from polars.functions.col import col
 from polars.functions.eager import align_frames, concat
 from polars.functions.escape_regex import escape_regex
+from polars.functions.fiscal import RetailCalendar
 from polars.functions.lazy import (
     approx_n_unique,
     arctan2,
@@ -62,7 +63,9 @@ from polars.functions.lazy import (
     quantile,
     reduce,
     rolling_corr,
+    rolling_corr_by,
     rolling_cov,
+    rolling_cov_by,
     select,
     sql_expr,
     std,
@@ -160,7 +163,9 @@ __all__ = [
     "quantile",
     "reduce",
     "rolling_corr",
+    "rolling_corr_by",
     "rolling_cov",
+    "rolling_cov_by",
     "select",
     "set_random_seed",
     "std",
@@ -175,4 +180,8 @@ __all__ = [
     "sql_expr",
     # polars.functions.escape_regex
     "escape_regex",
+    # polars.functions.business
+    "BusinessCalendar",
+    # polars.functions.fiscal
+    "RetailCalendar",
 ]
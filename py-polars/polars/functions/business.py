@@ -11,17 +11,113 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
     import polars.polars as plr
 
 if TYPE_CHECKING:
-    from collections.abc import Iterable
+    from collections.abc import Iterable, Mapping
 
     from polars import Expr
     from polars._typing import IntoExprColumn
 
 
+class BusinessCalendar:
+    """
+    A reusable business-day calendar.
+
+    A date is a business day if its weekday is in `week_mask` and it is not a
+    holiday. The calendar can be passed to business-day functions such as
+    :func:`business_day_count`, :meth:`Expr.dt.add_business_days`,
+    :meth:`Expr.dt.is_business_day` and :meth:`Expr.dt.roll_forward`, and to
+    `group_by_dynamic` when grouping by business days (e.g. `every="1bd"`).
+
+    Parameters
+    ----------
+    week_mask
+        Which days of the week are business days. The default is Monday to Friday.
+    holidays
+        Holidays that are not business days.
+    holiday_sets
+        Named sets of holidays, for example one per exchange. These are combined
+        with `holidays`.
+    half_days
+        Business days with shortened hours. These still count as business days.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> calendar = pl.BusinessCalendar(
+    ...     holiday_sets={"christmas": [date(2024, 12, 25), date(2024, 12, 26)]},
+    ...     half_days=[date(2024, 12, 24)],
+    ... )
+    >>> df = pl.DataFrame(
+    ...     {"date": [date(2024, 12, 24), date(2024, 12, 25), date(2024, 12, 28)]}
+    ... )
+    >>> df.with_columns(
+    ...     business=pl.col("date").dt.is_business_day(calendar),
+    ...     next=pl.col("date").dt.roll_forward(calendar),
+    ... )
+    shape: (3, 3)
+    ┌────────────┬──────────┬────────────┐
+    │ date       ┆ business ┆ next       │
+    │ ---        ┆ ---      ┆ ---        │
+    │ date       ┆ bool     ┆ date       │
+    ╞════════════╪══════════╪════════════╡
+    │ 2024-12-24 ┆ true     ┆ 2024-12-24 │
+    │ 2024-12-25 ┆ false    ┆ 2024-12-27 │
+    │ 2024-12-28 ┆ false    ┆ 2024-12-30 │
+    └────────────┴──────────┴────────────┘
+    """
+
+    def __init__(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[date] = (),
+        *,
+        holiday_sets: Mapping[str, Iterable[date]] | None = None,
+        half_days: Iterable[date] = (),
+    ) -> None:
+        self.week_mask = tuple(week_mask)
+        if len(self.week_mask) != 7:
+            msg = f"`week_mask` must have 7 elements, got {len(self.week_mask)}"
+            raise ValueError(msg)
+        self.holidays = sorted(set(holidays))
+        self.holiday_sets = {
+            name: sorted(set(dates)) for name, dates in (holiday_sets or {}).items()
+        }
+        self.half_days = sorted(set(half_days))
+
+    def __repr__(self) -> str:
+        return (
+            f"BusinessCalendar(week_mask={self.week_mask!r}, "
+            f"holidays={self.holidays!r}, holiday_sets={self.holiday_sets!r}, "
+            f"half_days={self.half_days!r})"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, BusinessCalendar):
+            return NotImplemented
+        return self._to_epoch_days() == other._to_epoch_days()
+
+    def _to_epoch_days(
+        self,
+    ) -> tuple[tuple[bool, ...], list[int], list[tuple[str, list[int]]], list[int]]:
+        unix_epoch = date(1970, 1, 1)
+
+        def to_days(dates: Iterable[date]) -> list[int]:
+            return [(d - unix_epoch).days for d in dates]
+
+        return (
+            self.week_mask,
+            to_days(self.holidays),
+            [(name, to_days(dates)) for name, dates in self.holiday_sets.items()],
+            to_days(self.half_days),
+        )
+
+
 def business_day_count(
     start: date | IntoExprColumn,
     end: date | IntoExprColumn,
     week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
     holidays: Iterable[date] = (),
+    *,
+    calendar: BusinessCalendar | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -49,6 +145,8 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+    calendar
+        A :class:`BusinessCalendar` to use instead of `week_mask` and `holidays`.
 
     Returns
     -------
@@ -114,12 +212,6 @@ def business_day_count(
     """
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
-    unix_epoch = date(1970, 1, 1)
-    return wrap_expr(
-        plr.business_day_count(
-            start_pyexpr,
-            end_pyexpr,
-            week_mask,
-            [(holiday - unix_epoch).days for holiday in holidays],
-        )
-    )
+    if calendar is None:
+        calendar = BusinessCalendar(week_mask, holidays)
+    return wrap_expr(plr.business_day_count(start_pyexpr, end_pyexpr, calendar))
@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+from typing import get_args
+
+from polars._typing import RetailPattern, RetailYearEnd
+
+
+class RetailCalendar:
+    """
+    A retail (4-4-5, 4-5-4 or 5-4-4) fiscal calendar.
+
+    Every fiscal year consists of 52 or 53 whole weeks, grouped into four quarters
+    of three periods. The periods are 4 or 5 weeks long following `pattern`, and
+    the extra week of a 53-week year is added to the last period. A fiscal year is
+    named after the calendar year in which it ends.
+
+    The calendar can be passed to :meth:`Expr.dt.retail_year`,
+    :meth:`Expr.dt.retail_period` and the other retail accessors, and as
+    `fiscal_calendar` to :meth:`Expr.dt.truncate`, :meth:`Expr.dt.offset_by` and
+    `group_by_dynamic` to work with fiscal units such as `"1fq"`.
+
+    Parameters
+    ----------
+    pattern : {'4-4-5', '4-5-4', '5-4-4'}
+        The lengths in weeks of the three periods of every quarter.
+    end_month
+        The month in which the fiscal year ends (1 = January).
+    week_end
+        The ISO weekday on which every week ends (1 = Monday, 7 = Sunday).
+    year_end : {'last', 'nearest'}
+        Whether the fiscal year ends on the last `week_end` weekday of `end_month`,
+        or on the `week_end` weekday nearest to the end of `end_month` (which may
+        fall in the first days of the next month).
+
+    Examples
+    --------
+    The National Retail Federation calendar, which ends on the Saturday nearest to
+    the end of January:
+
+    >>> from datetime import date
+    >>> calendar = pl.RetailCalendar(
+    ...     "4-5-4", end_month=1, week_end=6, year_end="nearest"
+    ... )
+    >>> df = pl.DataFrame(
+    ...     {"date": [date(2023, 1, 29), date(2023, 3, 5), date(2024, 2, 3)]}
+    ... )
+    >>> df.with_columns(
+    ...     year=pl.col("date").dt.retail_year(calendar),
+    ...     period=pl.col("date").dt.retail_period(calendar),
+    ...     week=pl.col("date").dt.retail_week(calendar),
+    ... )
+    shape: (3, 4)
+    ┌────────────┬──────┬────────┬──────┐
+    │ date       ┆ year ┆ period ┆ week │
+    │ ---        ┆ ---  ┆ ---    ┆ ---  │
+    │ date       ┆ i32  ┆ i8     ┆ i8   │
+    ╞════════════╪══════╪════════╪══════╡
+    │ 2023-01-29 ┆ 2024 ┆ 1      ┆ 1    │
+    │ 2023-03-05 ┆ 2024 ┆ 2      ┆ 6    │
+    │ 2024-02-03 ┆ 2024 ┆ 12     ┆ 53   │
+    └────────────┴──────┴────────┴──────┘
+    """
+
+    def __init__(
+        self,
+        pattern: RetailPattern = "4-4-5",
+        *,
+        end_month: int = 12,
+        week_end: int = 7,
+        year_end: RetailYearEnd = "last",
+    ) -> None:
+        if pattern not in get_args(RetailPattern):
+            msg = (
+                "`pattern` must be one of {'4-4-5', '4-5-4', '5-4-4'}, "
+                f"got {pattern!r}"
+            )
+            raise ValueError(msg)
+        if year_end not in get_args(RetailYearEnd):
+            msg = f"`year_end` must be one of {{'last', 'nearest'}}, got {year_end!r}"
+            raise ValueError(msg)
+        if not 1 <= end_month <= 12:
+            msg = f"`end_month` must be between 1 and 12, got {end_month}"
+            raise ValueError(msg)
+        if not 1 <= week_end <= 7:
+            msg = f"`week_end` must be between 1 and 7, got {week_end}"
+            raise ValueError(msg)
+        self.pattern = pattern
+        self.end_month = end_month
+        self.week_end = week_end
+        self.year_end = year_end
+
+    def __repr__(self) -> str:
+        return (
+            f"RetailCalendar({self.pattern!r}, end_month={self.end_month}, "
+            f"week_end={self.week_end}, year_end={self.year_end!r})"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, RetailCalendar):
+            return NotImplemented
+        return self._to_tuple() == other._to_tuple()
+
+    def _to_tuple(self) -> tuple[str, int, int, str]:
+        return (self.pattern, self.end_month, self.week_end, self.year_end)
@@ -2,11 +2,13 @@ from __future__ import annotations
 
 import contextlib
 from collections.abc import Sequence
+from datetime import timedelta
 from typing import TYPE_CHECKING, Any, Callable, overload
 
 import polars._reexport as pl
 import polars.functions as F
 from polars._utils.async_ import _AioDataFrameResult, _GeventDataFrameResult
+from polars._utils.convert import parse_as_duration_string
 from polars._utils.deprecation import deprecate_function, issue_deprecation_warning
 from polars._utils.parse import (
     parse_into_expression,
@@ -26,6 +28,7 @@ if TYPE_CHECKING:
 
     from polars import DataFrame, Expr, LazyFrame, Series
     from polars._typing import (
+        ClosedInterval,
         CorrelationMethod,
         EpochTimeUnit,
         IntoExpr,
@@ -2181,6 +2184,122 @@ def rolling_corr(
     )
 
 
+@unstable()
+def rolling_cov_by(
+    a: str | Expr,
+    b: str | Expr,
+    by: IntoExpr,
+    *,
+    window_size: timedelta | str,
+    min_periods: int = 1,
+    closed: ClosedInterval = "right",
+    ddof: int = 1,
+) -> Expr:
+    """
+    Compute the rolling covariance between two columns, based on another column.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+    (the default) means the windows will be:
+
+        - (t_0 - window_size, t_0]
+        - (t_1 - window_size, t_1]
+        - ...
+        - (t_n - window_size, t_n]
+
+    Parameters
+    ----------
+    a
+        Column name or Expression.
+    b
+        Column name or Expression.
+    by
+        Column to base the windows on. Must be of dtype Datetime, Date or an
+        integer type, and may not contain nulls.
+    window_size
+        The length of the window, given as a timedelta or a duration string such
+        as "2h" or "3d" (see :meth:`Expr.rolling_mean_by` for the string language).
+    min_periods
+        The number of values in the window that should be non-null before computing
+        a result.
+    closed : {'left', 'right', 'both', 'none'}
+        Define which sides of the temporal interval are closed (inclusive),
+        defaults to `'right'`.
+    ddof
+        Delta degrees of freedom. The divisor used in calculations
+        is `N - ddof`, where `N` represents the number of elements.
+    """
+    if isinstance(window_size, timedelta):
+        window_size = parse_as_duration_string(window_size)
+    a = parse_into_expression(a)
+    b = parse_into_expression(b)
+    by = parse_into_expression(by)
+    return wrap_expr(
+        plr.rolling_cov_by(a, b, by, window_size, min_periods, closed, ddof)
+    )
+
+
+@unstable()
+def rolling_corr_by(
+    a: str | Expr,
+    b: str | Expr,
+    by: IntoExpr,
+    *,
+    window_size: timedelta | str,
+    min_periods: int = 1,
+    closed: ClosedInterval = "right",
+    ddof: int = 1,
+) -> Expr:
+    """
+    Compute the rolling correlation between two columns, based on another column.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+    (the default) means the windows will be:
+
+        - (t_0 - window_size, t_0]
+        - (t_1 - window_size, t_1]
+        - ...
+        - (t_n - window_size, t_n]
+
+    Parameters
+    ----------
+    a
+        Column name or Expression.
+    b
+        Column name or Expression.
+    by
+        Column to base the windows on. Must be of dtype Datetime, Date or an
+        integer type, and may not contain nulls.
+    window_size
+        The length of the window, given as a timedelta or a duration string such
+        as "2h" or "3d" (see :meth:`Expr.rolling_mean_by` for the string language).
+    min_periods
+        The number of values in the window that should be non-null before computing
+        a result.
+    closed : {'left', 'right', 'both', 'none'}
+        Define which sides of the temporal interval are closed (inclusive),
+        defaults to `'right'`.
+    ddof
+        Delta degrees of freedom. The divisor used in calculations
+        is `N - ddof`, where `N` represents the number of elements.
+    """
+    if isinstance(window_size, timedelta):
+        window_size = parse_as_duration_string(window_size)
+    a = parse_into_expression(a)
+    b = parse_into_expression(b)
+    by = parse_into_expression(by)
+    return wrap_expr(
+        plr.rolling_corr_by(a, b, by, window_size, min_periods, closed, ddof)
+    )
+
+
 @overload
 def sql_expr(sql: str) -> Expr:  # type: ignore[overload-overlap]
     ...
@@ -96,6 +96,7 @@ if TYPE_CHECKING:
 
     from polars import DataFrame, DataType, Expr
     from polars._typing import (
+        Ambiguous,
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
@@ -109,6 +110,7 @@ if TYPE_CHECKING:
         JoinStrategy,
         JoinValidation,
         Label,
+        NonExistent,
         Orientation,
         PolarsDataType,
         PythonDataType,
@@ -120,6 +122,8 @@ if TYPE_CHECKING:
         UniqueKeepStrategy,
     )
     from polars.dependencies import numpy as np
+    from polars.functions.business import BusinessCalendar
+    from polars.functions.fiscal import RetailCalendar
 
     if sys.version_info >= (3, 10):
         from typing import Concatenate, ParamSpec
@@ -3695,6 +3699,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        calendar: BusinessCalendar | None = None,
+        fiscal_calendar: int | RetailCalendar | None = None,
+        ambiguous: Ambiguous = "raise",
+        non_existent: NonExistent = "raise",
     ) -> LazyGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -3765,6 +3773,27 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        calendar
+            The :class:`BusinessCalendar` for windows in business days (e.g.
+            `every="1bd"`). Defaults to Monday to Friday, without holidays.
+        fiscal_calendar
+            The fiscal calendar for windows in fiscal units (e.g. `every="1fq"`):
+            either the month in which the fiscal year starts (1 = January, the
+            default) or a :class:`RetailCalendar`.
+        ambiguous
+            How to handle window boundaries that are ambiguous in the time zone of
+            `index_column`, such as `01:30` on the day clocks are set back:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: skip the window
+        non_existent
+            How to handle window boundaries that don't exist in the time zone of
+            `index_column`, such as `02:30` on the day clocks are set forward:
+
+            - `'raise'` (default): raise
+            - `'null'`: skip the window
 
         Returns
         -------
@@ -3812,19 +3841,34 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            - 1q    (1 calendar quarter)
            - 1y    (1 calendar year)
            - 1i    (1 index count)
+           - 1bd   (1 business day, see `calendar`)
+           - 1fp   (1 fiscal period, see `fiscal_calendar`)
+           - 1fq   (1 fiscal quarter)
+           - 1fy   (1 fiscal year)
 
            Or combine them:
            "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
 
            By "calendar day", we mean the corresponding time on the next day (which may
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
-           "calendar month", "calendar quarter", and "calendar year".
+           "calendar month", "calendar quarter", and "calendar year". If
+           `index_column` is time-zone-aware, windows in calendar units are defined in
+           local wall-clock time, e.g. `every="1d", offset="2h"` starts every window at
+           02:00 local time, also on days on which clocks change.
 
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
            - "10i"     # length 10
 
+           Windows in business days ("bd") can't be combined with other units. They
+           number the business days of `calendar`, with a day that is not a business
+           day belonging to the business day before it.
+
+           Windows in fiscal units ("fp", "fq", "fy") can't be combined with other
+           units either. They are aligned to the fiscal years of `fiscal_calendar`,
+           and their boundaries are at (local) midnight.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -4014,6 +4058,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             closed,
             pyexprs_by,
             start_by,
+            calendar,
+            fiscal_calendar,
+            ambiguous,
+            non_existent,
         )
         return LazyGroupBy(lgb)
 
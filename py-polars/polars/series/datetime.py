@@ -22,6 +22,8 @@ if TYPE_CHECKING:
         TemporalLiteral,
         TimeUnit,
     )
+    from polars.functions.business import BusinessCalendar
+    from polars.functions.fiscal import RetailCalendar
     from polars.polars import PySeries
 
 
@@ -44,6 +46,7 @@ class DateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: BusinessCalendar | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -76,6 +79,8 @@ class DateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A :class:`BusinessCalendar` to use instead of `week_mask` and `holidays`.
 
         Returns
         -------
@@ -129,6 +134,127 @@ class DateTimeNameSpace:
         ]
         """
 
+    def is_business_day(self, calendar: BusinessCalendar | None = None) -> Series:
+        """
+        Determine whether each date is a business day.
+
+        Datetimes are checked on their (local) date.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the business days. The default
+            is Monday to Friday, without holidays.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2020, 1, 3), date(2020, 1, 4)])
+        >>> s.dt.is_business_day()
+        shape: (2,)
+        Series: 'date' [bool]
+        [
+                true
+                false
+        ]
+        """
+
+    def is_half_day(self, calendar: BusinessCalendar) -> Series:
+        """
+        Determine whether each date is a half-day of the calendar.
+
+        Half-days are business days with shortened hours. Datetimes are checked on
+        their (local) date.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the half-days.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.BusinessCalendar(half_days=[date(2024, 12, 24)])
+        >>> s = pl.Series("date", [date(2024, 12, 23), date(2024, 12, 24)])
+        >>> s.dt.is_half_day(calendar)
+        shape: (2,)
+        Series: 'date' [bool]
+        [
+                false
+                true
+        ]
+        """
+
+    def roll_forward(self, calendar: BusinessCalendar | None = None) -> Series:
+        """
+        Move dates that are not business days forward to the next business day.
+
+        The time of day of datetimes is preserved.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the business days. The default
+            is Monday to Friday, without holidays.
+
+        Returns
+        -------
+        Series
+            Series of the same data type.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2020, 1, 4), date(2020, 1, 6)])
+        >>> s.dt.roll_forward()
+        shape: (2,)
+        Series: 'date' [date]
+        [
+                2020-01-06
+                2020-01-06
+        ]
+        """
+
+    def roll_backward(self, calendar: BusinessCalendar | None = None) -> Series:
+        """
+        Move dates that are not business days back to the previous business day.
+
+        The time of day of datetimes is preserved.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`BusinessCalendar` that defines the business days. The default
+            is Monday to Friday, without holidays.
+
+        Returns
+        -------
+        Series
+            Series of the same data type.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2020, 1, 4), date(2020, 1, 6)])
+        >>> s.dt.roll_backward()
+        shape: (2,)
+        Series: 'date' [date]
+        [
+                2020-01-03
+                2020-01-06
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -558,6 +684,202 @@ class DateTimeNameSpace:
         ]
         """
 
+    def fiscal_year(self, start_month: int = 1) -> Series:
+        """
+        Extract the fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        A fiscal year starts on the first day of `start_month` and is named after
+        the calendar year in which it ends. See :meth:`retail_year` for retail
+        (4-4-5) calendars.
+
+        Parameters
+        ----------
+        start_month
+            The month in which the fiscal year starts (1 = January).
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2023, 9, 30), date(2023, 10, 1)])
+        >>> s.dt.fiscal_year(start_month=10)
+        shape: (2,)
+        Series: '' [i32]
+        [
+                2023
+                2024
+        ]
+        """
+
+    def fiscal_quarter(self, start_month: int = 1) -> Series:
+        """
+        Extract the fiscal quarter from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter of the fiscal year starting in `start_month`, ranging
+        from 1 to 4.
+
+        Parameters
+        ----------
+        start_month
+            The month in which the fiscal year starts (1 = January).
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2023, 9, 30), date(2023, 10, 1)])
+        >>> s.dt.fiscal_quarter(start_month=10)
+        shape: (2,)
+        Series: '' [i8]
+        [
+                4
+                1
+        ]
+        """
+
+    def retail_year(self, calendar: RetailCalendar) -> Series:
+        """
+        Extract the retail fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        The year is named after the calendar year in which it ends.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar(end_month=12, week_end=6)
+        >>> s = pl.Series([date(2024, 12, 28), date(2024, 12, 29)])
+        >>> s.dt.retail_year(calendar)
+        shape: (2,)
+        Series: '' [i32]
+        [
+                2024
+                2025
+        ]
+        """
+
+    def retail_quarter(self, calendar: RetailCalendar) -> Series:
+        """
+        Extract the retail fiscal quarter from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter of the retail year, ranging from 1 to 4.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar("4-4-5", end_month=12, week_end=6)
+        >>> s = pl.Series([date(2024, 3, 30), date(2024, 3, 31)])
+        >>> s.dt.retail_quarter(calendar)
+        shape: (2,)
+        Series: '' [i8]
+        [
+                1
+                2
+        ]
+        """
+
+    def retail_period(self, calendar: RetailCalendar) -> Series:
+        """
+        Extract the retail fiscal period from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the period of the retail year, ranging from 1 to 12. The periods
+        are 4 or 5 weeks long following the pattern of the calendar, and the extra
+        week of a 53-week year belongs to period 12.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar("4-4-5", end_month=12, week_end=6)
+        >>> s = pl.Series([date(2024, 1, 27), date(2024, 1, 28)])
+        >>> s.dt.retail_period(calendar)
+        shape: (2,)
+        Series: '' [i8]
+        [
+                1
+                2
+        ]
+        """
+
+    def retail_week(self, calendar: RetailCalendar) -> Series:
+        """
+        Extract the week of the retail fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Weeks are counted from the first day of the retail year and range from 1 to
+        53.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`RetailCalendar`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.RetailCalendar(end_month=12, week_end=6)
+        >>> s = pl.Series([date(2023, 12, 31), date(2024, 1, 7)])
+        >>> s.dt.retail_week(calendar)
+        shape: (2,)
+        Series: '' [i8]
+        [
+                1
+                2
+        ]
+        """
+
     def month(self) -> Series:
         """
         Extract the month from the underlying date representation.
@@ -1622,7 +1944,13 @@ class DateTimeNameSpace:
         ]
         """
 
-    def offset_by(self, by: str | Expr) -> Series:
+    def offset_by(
+        self,
+        by: str | Expr,
+        *,
+        calendar: BusinessCalendar | None = None,
+        fiscal_calendar: int | RetailCalendar | None = None,
+    ) -> Series:
         """
         Offset this date by a relative time offset.
 
@@ -1646,12 +1974,30 @@ class DateTimeNameSpace:
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
             - 1y    (1 calendar year)
+            - 1fp   (1 fiscal period)
+            - 1fq   (1 fiscal quarter)
+            - 1fy   (1 fiscal year)
+            - 1bd   (1 business day)
 
             By "calendar day", we mean the corresponding time on the next day
             (which may not be 24 hours, due to daylight savings). Similarly for
             "calendar week", "calendar month", "calendar quarter", and
             "calendar year".
 
+            Business days can't be combined with other units. A date that is not a
+            business day counts as the first step, so Saturday offset by `"1bd"` is
+            Monday.
+
+            Fiscal units can't be combined with other units either. The day within
+            the fiscal period is kept, clamped to the end of the target period.
+        calendar
+            The :class:`BusinessCalendar` for business days such as `"3bd"`. The
+            default is Monday to Friday, without holidays.
+        fiscal_calendar
+            The fiscal calendar for fiscal units such as `"1fq"`: either the month
+            in which the fiscal year starts (1 = January, the default) or a
+            :class:`RetailCalendar`.
+
         Returns
         -------
         Series
@@ -1698,7 +2044,12 @@ class DateTimeNameSpace:
         ]
         """
 
-    def truncate(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
+    def truncate(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        fiscal_calendar: int | RetailCalendar | None = None,
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1713,6 +2064,10 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        fiscal_calendar
+            The fiscal calendar for fiscal units such as `"1fq"`: either the month
+            in which the fiscal year starts (1 = January, the default) or a
+            :class:`RetailCalendar`.
 
         Notes
         -----
@@ -1730,8 +2085,11 @@ class DateTimeNameSpace:
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
         - 1y    (1 calendar year)
+        - 1fp   (1 fiscal period)
+        - 1fq   (1 fiscal quarter)
+        - 1fy   (1 fiscal year)
 
-        These strings can be combined:
+        These strings can be combined, except for the fiscal units:
 
         - 3d12h4m25s # 3 days, 12 hours, 4 minutes, and 25 seconds
 
@@ -1739,6 +2097,9 @@ class DateTimeNameSpace:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        Fiscal windows are aligned to the start of the fiscal year, and a Datetime
+        is truncated to local midnight.
+
         Returns
         -------
         Series
@@ -124,6 +124,7 @@ if TYPE_CHECKING:
         ClosedInterval,
         ComparisonOperator,
         FillNullStrategy,
+        InterpolationLimitArea,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
@@ -135,6 +136,7 @@ if TYPE_CHECKING:
         PythonLiteral,
         RankMethod,
         RollingInterpolationMethod,
+        RollingRankMethod,
         SearchSortedSide,
         SeriesBuffers,
         SingleIndexSelector,
@@ -5897,6 +5899,104 @@ class Series:
         ]
         """
 
+    @unstable()
+    def rolling_mad(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute a rolling median absolute deviation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The median absolute deviation of a window is the median of the absolute
+        differences between the values in the window and the median of the window.
+        Null values are ignored.
+
+        The window at a given row will include the row itself and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9, 3])
+        >>> s.rolling_mad(window_size=3)
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+                null
+                null
+                1.0
+                2.0
+                1.0
+        ]
+        """
+
+    @unstable()
+    def rolling_rank(
+        self,
+        window_size: int,
+        method: RollingRankMethod = "average",
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the rank of every value within its rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The rank is expressed as a percentile: the rank of the value divided by the
+        number of non-null values in the window, which gives a value in `(0, 1]`.
+        Null values get a null rank.
+
+        The window at a given row will include the row itself and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        method : {'average', 'min', 'max'}
+            The method used to assign ranks to tied values, see
+            :meth:`Expr.rolling_rank`.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9, 3])
+        >>> s.rolling_rank(window_size=3)
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+                null
+                null
+                0.666667
+                1.0
+                0.666667
+        ]
+        """
+
     @unstable()
     def rolling_quantile(
         self,
@@ -5999,6 +6099,43 @@ class Series:
         (0.38180177416060584, 0.47033046033698594)
         """
 
+    @unstable()
+    def rolling_kurtosis(
+        self, window_size: int, *, fisher: bool = True, bias: bool = True
+    ) -> Series:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> pl.Series([1, 4, 2, 9]).rolling_kurtosis(3)
+        shape: (4,)
+        Series: '' [f64]
+        [
+                null
+                null
+                -1.5
+                -1.5
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -6178,15 +6315,40 @@ class Series:
         ]
         """
 
-    def interpolate(self, method: InterpolationMethod = "linear") -> Series:
+    def interpolate(
+        self,
+        method: InterpolationMethod = "linear",
+        *,
+        limit: int | None = None,
+        limit_area: InterpolationLimitArea | None = "inside",
+    ) -> Series:
         """
         Fill null values using interpolation.
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'pchip'}
             Interpolation method.
 
+            - 'linear': straight line between the neighbouring valid values.
+            - 'nearest': the closest of the neighbouring valid values.
+            - 'previous': the last valid value before the gap.
+            - 'next': the first valid value after the gap.
+            - 'cubic': natural cubic spline through all valid values.
+            - 'pchip': monotone piecewise cubic Hermite interpolation, which does
+              not overshoot the data.
+        limit
+            Maximum number of consecutive null values to fill. Filling starts next to
+            the valid value the fill is taken from; the rest of a longer gap stays
+            null.
+        limit_area : {'inside', 'outside'}, default 'inside'
+            Which null values to fill.
+
+            - 'inside': only nulls surrounded by valid values.
+            - 'outside': only nulls before the first or after the last valid value,
+              which get the first or last valid value respectively.
+            - None: no restriction.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, None, None, 5])
@@ -6200,9 +6362,29 @@ class Series:
             4.0
             5.0
         ]
+
+        Use a monotone spline for smooth data.
+
+        >>> s = pl.Series("a", [0.0, 1.0, None, 9.0])
+        >>> s.interpolate("pchip")
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+            0.0
+            1.0
+            3.875
+            9.0
+        ]
         """
 
-    def interpolate_by(self, by: IntoExpr) -> Series:
+    def interpolate_by(
+        self,
+        by: IntoExpr,
+        *,
+        method: InterpolationMethod = "linear",
+        limit: int | None = None,
+        limit_area: InterpolationLimitArea | None = "inside",
+    ) -> Series:
         """
         Fill null values using interpolation based on another column.
 
@@ -6210,6 +6392,27 @@ class Series:
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'pchip'}
+            Interpolation method.
+
+            - 'linear': straight line between the neighbouring valid values.
+            - 'nearest': the closest of the neighbouring valid values.
+            - 'previous': the last valid value before the gap.
+            - 'next': the first valid value after the gap.
+            - 'cubic': natural cubic spline through all valid values.
+            - 'pchip': monotone piecewise cubic Hermite interpolation, which does
+              not overshoot the data.
+        limit
+            Maximum number of consecutive null values to fill. Filling starts next to
+            the valid value the fill is taken from; the rest of a longer gap stays
+            null.
+        limit_area : {'inside', 'outside'}, default 'inside'
+            Which null values to fill.
+
+            - 'inside': only nulls surrounded by valid values.
+            - 'outside': only nulls before the first or after the last valid value,
+              which get the first or last valid value respectively.
+            - None: no restriction.
 
         Examples
         --------
@@ -208,6 +208,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_cov))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_corr_by))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_cov_by))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::reduce)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::repeat)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::spearman_rank_corr))
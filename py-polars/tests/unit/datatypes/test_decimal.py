@@ -4,6 +4,7 @@ import io
 import itertools
 import operator
 from dataclasses import dataclass
+from decimal import ROUND_HALF_UP
 from decimal import Decimal as D
 from math import ceil, floor
 from random import choice, randrange, seed
@@ -289,12 +290,8 @@ def test_decimal_series_value_arithmetic() -> None:
     assert out1.to_list() == [D("10.1"), D("20.1"), D("110.01")]
     assert out2.to_list() == [D("10.1"), D("20.1"), D("110.01")]
     assert out3.to_list() == [D("10.1001"), D("20.1001"), D("110.0101")]
-    assert out4.to_list() == [
-        D("0.066666"),
-        D("6.733333"),
-        D("66.673333"),
-    ]  # TODO: do we want floor instead of round?
-    assert out5.to_list() == [D("0.066666"), D("6.733333"), D("66.673333")]
+    assert out4.to_list() == [D("0.066667"), D("6.733333"), D("66.673333")]
+    assert out5.to_list() == [D("0.066667"), D("6.733333"), D("66.673333")]
     assert out6.to_list() == [D("-4.9"), D("5.1"), D("95.01")]
 
 
@@ -335,7 +332,7 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
+            "mean": [D("2277.582500")],
             "median": [55.055],
         }
     )
@@ -545,6 +542,14 @@ def test_decimal_round() -> None:
 
     for decimals in range(10):
         got_s = i_s.round(decimals)
-        expected_s = pl.Series("a", [round(v, decimals) for v in values], dtype)
+        if decimals < 2:
+            # rounding adds an integral digit to hold a possible carry
+            out_dtype = pl.Decimal(3 - 2 + decimals + 1, decimals)
+            exp = D(1).scaleb(-decimals)
+            rounded = [v.quantize(exp, rounding=ROUND_HALF_UP) for v in values]
+        else:
+            out_dtype = dtype
+            rounded = values
+        expected_s = pl.Series("a", rounded, out_dtype)
 
         assert_series_equal(got_s, expected_s)
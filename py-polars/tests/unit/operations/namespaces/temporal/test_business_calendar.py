@@ -0,0 +1,190 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+from zoneinfo import ZoneInfo
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def calendar() -> pl.BusinessCalendar:
+    return pl.BusinessCalendar(
+        holidays=[date(2025, 1, 1)],
+        holiday_sets={"christmas": [date(2024, 12, 25), date(2024, 12, 26)]},
+        half_days=[date(2024, 12, 24)],
+    )
+
+
+def test_is_business_day(calendar: pl.BusinessCalendar) -> None:
+    s = pl.date_range(date(2024, 12, 24), date(2025, 1, 2), eager=True)
+    result = s.dt.is_business_day(calendar)
+    expected = pl.Series(
+        "literal", [True, False, False, True, False, False, True, True, False, True]
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.is_business_day()
+    expected = pl.Series(
+        "literal", [True, True, True, True, False, False, True, True, True, True]
+    )
+    assert_series_equal(result, expected)
+
+
+def test_is_half_day(calendar: pl.BusinessCalendar) -> None:
+    s = pl.Series("a", [date(2024, 12, 23), date(2024, 12, 24), None])
+    result = s.dt.is_half_day(calendar)
+    expected = pl.Series("a", [False, True, None])
+    assert_series_equal(result, expected)
+
+    # Half-days that aren't business days don't count.
+    calendar = pl.BusinessCalendar(half_days=[date(2024, 12, 28)])
+    s = pl.Series("a", [datetime(2024, 12, 28, 10)])
+    assert s.dt.is_half_day(calendar).to_list() == [False]
+
+
+def test_roll_forward_backward(calendar: pl.BusinessCalendar) -> None:
+    s = pl.Series("a", [date(2024, 12, 25), date(2024, 12, 28), date(2025, 1, 1)])
+    result = s.dt.roll_forward(calendar)
+    expected = pl.Series(
+        "a", [date(2024, 12, 27), date(2024, 12, 30), date(2025, 1, 2)]
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.roll_backward(calendar)
+    expected = pl.Series(
+        "a", [date(2024, 12, 24), date(2024, 12, 27), date(2024, 12, 31)]
+    )
+    assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("time_zone", [None, "Europe/Amsterdam"])
+def test_roll_forward_datetime(
+    calendar: pl.BusinessCalendar, time_zone: str | None
+) -> None:
+    tzinfo = ZoneInfo(time_zone) if time_zone is not None else None
+    s = pl.Series(
+        "a",
+        [datetime(2024, 12, 28, 10, tzinfo=tzinfo)],
+        dtype=pl.Datetime("us", time_zone),
+    )
+    result = s.dt.roll_forward(calendar)
+    expected = pl.Series(
+        "a",
+        [datetime(2024, 12, 30, 10, tzinfo=tzinfo)],
+        dtype=pl.Datetime("us", time_zone),
+    )
+    assert_series_equal(result, expected)
+    assert s.dt.is_business_day(calendar).to_list() == [False]
+
+
+def test_business_functions_with_calendar(calendar: pl.BusinessCalendar) -> None:
+    df = pl.DataFrame({"start": [date(2024, 12, 24), date(2024, 12, 27)]})
+    result = df.select(
+        added=pl.col("start").dt.add_business_days(2, calendar=calendar),
+        count=pl.business_day_count("start", date(2025, 1, 2), calendar=calendar),
+    )
+    expected = pl.DataFrame(
+        {
+            "added": [date(2024, 12, 30), date(2024, 12, 31)],
+            "count": pl.Series([4, 3], dtype=pl.Int32),
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_business_calendar_invalid_week_mask() -> None:
+    with pytest.raises(ValueError, match="must have 7 elements"):
+        pl.BusinessCalendar(week_mask=(True, False))
+
+    calendar = pl.BusinessCalendar(week_mask=(False,) * 7)
+    with pytest.raises(ComputeError, match="at least one business day"):
+        pl.Series([date(2024, 1, 1)]).dt.is_business_day(calendar)
+
+
+def test_business_calendar_eq(calendar: pl.BusinessCalendar) -> None:
+    other = pl.BusinessCalendar(
+        holidays=[date(2025, 1, 1)],
+        holiday_sets={"christmas": [date(2024, 12, 26), date(2024, 12, 25)]},
+        half_days=[date(2024, 12, 24)],
+    )
+    assert calendar == other
+    assert calendar != pl.BusinessCalendar()
+
+
+def test_offset_by_business_days() -> None:
+    s = pl.Series(
+        "a", [date(2024, 1, 3), date(2024, 1, 5), date(2024, 1, 6), date(2024, 1, 6)]
+    )
+    result = s.dt.offset_by(pl.Series(["3bd", "1bd", "1bd", "-1bd"]))
+    expected = pl.Series(
+        "a", [date(2024, 1, 8), date(2024, 1, 8), date(2024, 1, 8), date(2024, 1, 5)]
+    )
+    assert_series_equal(result, expected)
+
+    s = pl.Series("a", [datetime(2024, 1, 5, 12)])
+    result = s.dt.offset_by("2bd")
+    assert result.to_list() == [datetime(2024, 1, 9, 12)]
+
+
+def test_offset_by_business_days_with_calendar(calendar: pl.BusinessCalendar) -> None:
+    s = pl.Series("a", [date(2024, 12, 24), date(2024, 12, 31)])
+    result = s.dt.offset_by("1bd", calendar=calendar)
+    expected = pl.Series("a", [date(2024, 12, 27), date(2025, 1, 2)])
+    assert_series_equal(result, expected)
+
+    result = s.dt.offset_by("-1bd", calendar=calendar)
+    expected = pl.Series("a", [date(2024, 12, 23), date(2024, 12, 30)])
+    assert_series_equal(result, expected)
+
+
+def test_group_by_dynamic_business_days() -> None:
+    df = pl.DataFrame(
+        {
+            "date": pl.date_range(date(2024, 1, 5), date(2024, 1, 9), eager=True),
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+    result = df.group_by_dynamic("date", every="1bd").agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 5), date(2024, 1, 8), date(2024, 1, 9)],
+            "value": [6, 4, 5],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by_dynamic(
+        "date", every="1bd", period="2bd", include_boundaries=True
+    ).agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "_lower_boundary": [date(2024, 1, 5), date(2024, 1, 8), date(2024, 1, 9)],
+            "_upper_boundary": [date(2024, 1, 9), date(2024, 1, 10), date(2024, 1, 11)],
+            "date": [date(2024, 1, 5), date(2024, 1, 8), date(2024, 1, 9)],
+            "value": [10, 9, 5],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    calendar = pl.BusinessCalendar(holidays=[date(2024, 1, 8)])
+    result = df.group_by_dynamic("date", every="1bd", calendar=calendar).agg(
+        pl.col("value").sum()
+    )
+    expected = pl.DataFrame(
+        {"date": [date(2024, 1, 5), date(2024, 1, 9)], "value": [10, 5]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_business_days_invalid() -> None:
+    df = pl.DataFrame({"date": [date(2024, 1, 5)], "value": [1]})
+    with pytest.raises(InvalidOperationError, match="cannot be combined"):
+        df.group_by_dynamic("date", every="1bd1h").agg(pl.col("value").sum())
+    with pytest.raises(InvalidOperationError, match="must be in business days"):
+        df.group_by_dynamic("date", every="1bd", period="2d").agg(
+            pl.col("value").sum()
+        )
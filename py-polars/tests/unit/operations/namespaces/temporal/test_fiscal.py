@@ -0,0 +1,157 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def nrf_calendar() -> pl.RetailCalendar:
+    return pl.RetailCalendar("4-5-4", end_month=1, week_end=6, year_end="nearest")
+
+
+def test_fiscal_year_quarter() -> None:
+    s = pl.Series("a", [date(2023, 9, 30), date(2023, 10, 1), date(2024, 6, 15)])
+    assert_series_equal(
+        s.dt.fiscal_year(10), pl.Series("a", [2023, 2024, 2024], dtype=pl.Int32)
+    )
+    assert_series_equal(
+        s.dt.fiscal_quarter(10), pl.Series("a", [4, 1, 3], dtype=pl.Int8)
+    )
+    # With the default start month the fiscal year is the calendar year.
+    assert_series_equal(s.dt.fiscal_year(), s.dt.year())
+    assert_series_equal(s.dt.fiscal_quarter(), s.dt.quarter())
+
+
+def test_fiscal_year_local_date() -> None:
+    s = pl.Series([datetime(2023, 10, 1, 1)]).dt.replace_time_zone("Europe/Amsterdam")
+    assert s.dt.fiscal_year(10).item() == 2024
+    assert s.dt.fiscal_quarter(10).item() == 1
+
+
+def test_retail_accessors(nrf_calendar: pl.RetailCalendar) -> None:
+    s = pl.Series(
+        "a",
+        [
+            date(2023, 1, 28),
+            date(2023, 1, 29),
+            date(2023, 3, 5),
+            date(2024, 2, 3),
+            date(2024, 2, 4),
+        ],
+    )
+    assert s.dt.retail_year(nrf_calendar).to_list() == [2023, 2024, 2024, 2024, 2025]
+    assert s.dt.retail_quarter(nrf_calendar).to_list() == [4, 1, 1, 4, 1]
+    assert s.dt.retail_period(nrf_calendar).to_list() == [12, 1, 2, 12, 1]
+    assert s.dt.retail_week(nrf_calendar).to_list() == [52, 1, 6, 53, 1]
+
+
+def test_retail_calendar_4_4_5() -> None:
+    calendar = pl.RetailCalendar(week_end=6)
+    s = pl.Series([date(2023, 12, 30), date(2023, 12, 31), date(2024, 12, 28)])
+    assert s.dt.retail_year(calendar).to_list() == [2023, 2024, 2024]
+    assert s.dt.retail_period(calendar).to_list() == [12, 1, 12]
+
+
+def test_retail_calendar_invalid() -> None:
+    with pytest.raises(ValueError, match="`pattern` must be one of"):
+        pl.RetailCalendar("4-4-4")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="`year_end` must be one of"):
+        pl.RetailCalendar(year_end="first")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="`week_end` must be between 1 and 7"):
+        pl.RetailCalendar(week_end=0)
+    assert pl.RetailCalendar() == pl.RetailCalendar("4-4-5", end_month=12)
+
+
+def test_fiscal_year_invalid_start_month() -> None:
+    s = pl.Series([date(2024, 1, 1)])
+    with pytest.raises(InvalidOperationError, match="`start_month` must be between"):
+        s.dt.fiscal_year(13)
+
+
+def test_truncate_fiscal(nrf_calendar: pl.RetailCalendar) -> None:
+    s = pl.Series("a", [date(2024, 2, 29), date(2024, 10, 15)])
+    result = s.dt.truncate("1fq", fiscal_calendar=10)
+    expected = pl.Series("a", [date(2024, 1, 1), date(2024, 10, 1)])
+    assert_series_equal(result, expected)
+
+    result = s.dt.truncate("1fy", fiscal_calendar=10)
+    expected = pl.Series("a", [date(2023, 10, 1), date(2024, 10, 1)])
+    assert_series_equal(result, expected)
+
+    s = pl.Series([datetime(2024, 2, 29, 12)])
+    assert s.dt.truncate("1fq", fiscal_calendar=10).item() == datetime(2024, 1, 1)
+
+    s = pl.Series([date(2023, 6, 1)])
+    result = s.dt.truncate("1fq", fiscal_calendar=nrf_calendar)
+    assert result.item() == date(2023, 4, 30)
+
+
+def test_offset_by_fiscal(nrf_calendar: pl.RetailCalendar) -> None:
+    s = pl.Series("a", [date(2024, 1, 31), date(2024, 3, 15)])
+    result = s.dt.offset_by("-1fq", fiscal_calendar=10)
+    expected = pl.Series("a", [date(2023, 10, 31), date(2023, 12, 15)])
+    assert_series_equal(result, expected)
+
+    s = pl.Series([datetime(2024, 1, 31, 12)])
+    assert s.dt.offset_by("1fp").item() == datetime(2024, 2, 29, 12)
+
+    s = pl.Series([date(2024, 2, 3)])
+    result = s.dt.offset_by("1fp", fiscal_calendar=nrf_calendar)
+    assert result.item() == date(2024, 3, 2)
+
+
+def test_group_by_dynamic_fiscal() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2023, 9, 30),
+                date(2023, 10, 1),
+                date(2023, 12, 31),
+                date(2024, 1, 1),
+                date(2024, 6, 15),
+            ],
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+    result = df.group_by_dynamic(
+        "date", every="1fq", fiscal_calendar=10, include_boundaries=True
+    ).agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "_lower_boundary": [
+                date(2023, 7, 1),
+                date(2023, 10, 1),
+                date(2024, 1, 1),
+                date(2024, 4, 1),
+            ],
+            "_upper_boundary": [
+                date(2023, 10, 1),
+                date(2024, 1, 1),
+                date(2024, 4, 1),
+                date(2024, 7, 1),
+            ],
+            "date": [
+                date(2023, 7, 1),
+                date(2023, 10, 1),
+                date(2024, 1, 1),
+                date(2024, 4, 1),
+            ],
+            "value": [1, 5, 4, 5],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fiscal_invalid() -> None:
+    df = pl.DataFrame({"date": [date(2024, 1, 1)], "value": [1]})
+    with pytest.raises(InvalidOperationError, match="cannot be combined"):
+        df.group_by_dynamic("date", every="1fq1d").agg(pl.col("value").sum())
+    with pytest.raises(InvalidOperationError, match="must be in fiscal units"):
+        df.group_by_dynamic("date", every="1fq", period="1d").agg(
+            pl.col("value").sum()
+        )
@@ -1252,3 +1252,97 @@ def test_window_size_validation() -> None:
 
     with pytest.raises(OverflowError, match=r"can't convert negative int to unsigned"):
         df.with_columns(trailing_min=pl.col("x").rolling_min(window_size=-3))
+
+
+def test_rolling_median_mad() -> None:
+    s = pl.Series("a", [1.0, None, 3.0, 7.0])
+    assert s.rolling_median(3, min_periods=1).to_list() == [1.0, 1.0, 2.0, 5.0]
+    assert s.rolling_mad(3, min_periods=1).to_list() == [0.0, 0.0, 1.0, 2.0]
+    assert s.rolling_mad(3).to_list() == [None, None, None, None]
+
+    s = pl.Series("a", np.random.randint(0, 20, 100), dtype=pl.Float64)
+    assert_series_equal(
+        s.rolling_median(7, center=True),
+        s.rolling_quantile(0.5, "linear", 7, center=True),
+    )
+    expected = s.rolling_map(
+        lambda w: (w - w.median()).abs().median(),  # type: ignore[operator]
+        window_size=7,
+    )
+    assert_series_equal(s.rolling_mad(7), expected)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("average", [1.0, 0.5, 0.5, 1.0, None]),
+        ("min", [1.0, 0.5, 1 / 3, 1.0, None]),
+        ("max", [1.0, 0.5, 2 / 3, 1.0, None]),
+    ],
+)
+def test_rolling_rank(method: str, expected: list[float | None]) -> None:
+    s = pl.Series("a", [3, 1, 1, 2, None])
+    result = s.rolling_rank(3, method, min_periods=1)  # type: ignore[arg-type]
+    assert_series_equal(result, pl.Series("a", expected))
+
+
+def test_rolling_rank_invalid_method() -> None:
+    with pytest.raises(ValueError, match="rolling rank `method` must be one of"):
+        pl.Series([1.0]).rolling_rank(1, "dense")  # type: ignore[arg-type]
+
+
+def test_rolling_kurtosis() -> None:
+    s = pl.Series("a", [1.0, 4.0, 2.0, 9.0, 3.0, 8.0, 1.0])
+    for fisher in (True, False):
+        for bias in (True, False):
+            result = s.rolling_kurtosis(5, fisher=fisher, bias=bias)
+            expected = s.rolling_map(
+                lambda w, fisher=fisher, bias=bias: w.kurtosis(  # type: ignore[misc]
+                    fisher=fisher, bias=bias
+                ),
+                window_size=5,
+            )
+            assert_series_equal(result, expected)
+
+
+def test_rolling_skew_by() -> None:
+    df = pl.DataFrame(
+        {"a": [1.0, 4.0, 2.0, 9.0, 3.0, 8.0, 1.0], "idx": range(7)},
+        schema_overrides={"idx": pl.Int64},
+    )
+    result = df.select(
+        pl.col("a").rolling_skew_by("idx", "3i", min_periods=3, bias=False)
+    )
+    expected = df.select(pl.col("a").rolling_skew(3, bias=False))
+    assert_frame_equal(result, expected)
+
+    # unsorted `by` column
+    result = df.reverse().select(
+        pl.col("a").rolling_skew_by("idx", "3i", min_periods=3)
+    )
+    expected = df.select(pl.col("a").rolling_skew(3)).reverse()
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("function", ["corr", "cov"])
+def test_rolling_corr_cov_by(function: str) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.0, 4.0, 2.0, 9.0, 3.0, 8.0, 1.0],
+            "b": [2.0, 3.0, 5.0, 4.0, 1.0, 7.0, 6.0],
+            "date": pl.date_range(date(2024, 1, 1), date(2024, 1, 7), eager=True),
+        }
+    )
+    fixed = getattr(pl, f"rolling_{function}")
+    by = getattr(pl, f"rolling_{function}_by")
+    result = df.select(by("a", "b", "date", window_size="3d", min_periods=3))
+    expected = df.select(fixed("a", "b", window_size=3))
+    assert_frame_equal(result, expected)
+
+    result = df.select(
+        by("a", "b", "date", window_size=timedelta(days=3), min_periods=3, ddof=0)
+    )
+    if function == "cov":
+        expected = df.select(fixed("a", "b", window_size=3, ddof=0))
+    # the correlation does not depend on the degrees of freedom
+    assert_frame_equal(result, expected)
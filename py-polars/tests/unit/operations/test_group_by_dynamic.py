@@ -790,6 +790,72 @@ def test_group_by_dynamic_monthly_crossing_dst() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_group_by_dynamic_wall_clock_offset_crossing_dst() -> None:
+    df = pl.DataFrame(
+        {"time": [datetime(2024, 3, 31, 12), datetime(2024, 4, 1, 12)], "value": [1, 2]}
+    ).with_columns(pl.col("time").dt.replace_time_zone("Europe/Amsterdam"))
+    result = df.group_by_dynamic("time", every="1d", offset="3h").agg(
+        pl.col("value").sum()
+    )
+    expected = pl.DataFrame(
+        {"time": [datetime(2024, 3, 31, 3), datetime(2024, 4, 1, 3)], "value": [1, 2]}
+    ).with_columns(pl.col("time").dt.replace_time_zone("Europe/Amsterdam"))
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_ambiguous_window_boundary() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [datetime(2024, 10, 26, 12), datetime(2024, 10, 27, 12)],
+            "value": [1, 2],
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone("Europe/Amsterdam"))
+    with pytest.raises(ComputeError, match="is ambiguous"):
+        df.group_by_dynamic("time", every="1d", offset="2h30m").agg(
+            pl.col("value").sum()
+        )
+
+    result = df.group_by_dynamic(
+        "time", every="1d", offset="2h30m", ambiguous="earliest"
+    ).agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 10, 26, 0, 30, tzinfo=timezone.utc),
+                datetime(2024, 10, 27, 0, 30, tzinfo=timezone.utc),
+            ],
+            "value": [1, 2],
+        }
+    ).with_columns(pl.col("time").dt.convert_time_zone("Europe/Amsterdam"))
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_non_existent_window_boundary() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 3, 30, 12),
+                datetime(2024, 3, 31, 12),
+                datetime(2024, 4, 1, 12),
+            ],
+            "value": [1, 2, 3],
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone("Europe/Amsterdam"))
+    with pytest.raises(ComputeError, match="is non-existent"):
+        df.group_by_dynamic("time", every="1d", offset="2h30m").agg(
+            pl.col("value").sum()
+        )
+
+    # Windows that start or end at the non-existent 2024-03-31 02:30 are skipped.
+    result = df.group_by_dynamic(
+        "time", every="1d", offset="2h30m", non_existent="null"
+    ).agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {"time": [datetime(2024, 4, 1, 2, 30)], "value": [3]}
+    ).with_columns(pl.col("time").dt.replace_time_zone("Europe/Amsterdam"))
+    assert_frame_equal(result, expected)
+
+
 def test_group_by_dynamic_2d_9333() -> None:
     df = pl.DataFrame({"ts": [datetime(2000, 1, 1, 3)], "values": [10.0]})
     df = df.with_columns(pl.col("ts").set_sorted())
@@ -150,3 +150,90 @@ def test_interpolate_temporal_nearest(
     assert result.collect_schema()["a"] == input_dtype
     expected = pl.DataFrame({"a": output}, schema={"a": input_dtype})
     assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("method", "output"),
+    [
+        ("previous", [None, 1, 1, 1, 5, None]),
+        ("next", [None, 1, 5, 5, 5, None]),
+    ],
+)
+def test_interpolate_previous_next(method: str, output: list[int | None]) -> None:
+    df = pl.LazyFrame({"a": [None, 1, None, None, 5, None]})
+    result = df.select(pl.col("a").interpolate(method))  # type: ignore[arg-type]
+    assert result.collect_schema()["a"] == pl.Int64
+    assert_frame_equal(result.collect(), pl.DataFrame({"a": output}))
+
+
+def test_interpolate_limit_and_limit_area() -> None:
+    s = pl.Series("a", [None, 1, None, None, None, 5, None])
+
+    assert s.interpolate(limit=2).to_list() == [None, 1.0, 2.0, 3.0, None, 5.0, None]
+    assert s.interpolate("next", limit=1).to_list() == [
+        None,
+        1,
+        None,
+        None,
+        5,
+        5,
+        None,
+    ]
+    assert s.interpolate(limit_area="outside").to_list() == [
+        1.0,
+        1.0,
+        None,
+        None,
+        None,
+        5.0,
+        5.0,
+    ]
+    assert s.interpolate("previous", limit_area=None).to_list() == [
+        None,
+        1,
+        1,
+        1,
+        1,
+        5,
+        5,
+    ]
+    assert s.interpolate("nearest", limit=1, limit_area=None).to_list() == [
+        1,
+        1,
+        1,
+        None,
+        None,
+        5,
+        5,
+    ]
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [("cubic", 4.25), ("pchip", 3.875)],
+)
+@pytest.mark.parametrize("input_dtype", [pl.Int64, pl.Float64])
+def test_interpolate_spline(
+    method: str, expected: float, input_dtype: PolarsDataType
+) -> None:
+    df = pl.LazyFrame({"a": [0, 1, None, 9]}, schema={"a": input_dtype})
+    result = df.select(pl.col("a").interpolate(method))  # type: ignore[arg-type]
+    assert result.collect_schema()["a"] == pl.Float64
+    assert_frame_equal(result.collect(), pl.DataFrame({"a": [0.0, 1.0, expected, 9.0]}))
+
+
+def test_interpolate_pchip_monotone() -> None:
+    s = pl.Series([0.0, None, 1.0, None, 1.0, None, 5.0])
+    result = s.interpolate("pchip")
+    # PCHIP keeps flat stretches flat and never overshoots.
+    assert result[3] == 1.0
+    assert 0.0 < result[1] < 1.0  # type: ignore[operator]
+    assert 1.0 < result[5] < 5.0  # type: ignore[operator]
+    assert result.diff().drop_nulls().min() >= 0  # type: ignore[operator]
+
+
+def test_interpolate_invalid_method() -> None:
+    with pytest.raises(ValueError, match="interpolation `method` must be one of"):
+        pl.Series([1, None, 3]).interpolate("quadratic")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="interpolation `limit_area` must be one of"):
+        pl.Series([1, None, 3]).interpolate(limit_area="both")  # type: ignore[arg-type]
@@ -250,3 +250,62 @@ def test_interpolate_by_invalid() -> None:
         match="null values in `by` column are not yet supported in 'interpolate_by'",
     ):
         s.interpolate_by(by)
+
+
+@pytest.mark.parametrize(
+    ("method", "limit", "expected"),
+    [
+        ("linear", None, [3.0, 4.0, 1.0, 2.0]),
+        ("nearest", None, [4.0, 4.0, 1.0, 1.0]),
+        ("previous", None, [1.0, 4.0, 1.0, 1.0]),
+        ("previous", 1, [None, 4.0, 1.0, 1.0]),
+        ("next", None, [4.0, 4.0, 1.0, 4.0]),
+        ("next", 1, [4.0, 4.0, 1.0, None]),
+        ("cubic", None, [3.0, 4.0, 1.0, 2.0]),
+        ("pchip", None, [3.0, 4.0, 1.0, 2.0]),
+    ],
+)
+def test_interpolate_by_methods_unsorted(
+    method: str, limit: int | None, expected: list[float | None]
+) -> None:
+    df = pl.DataFrame({"a": [None, 4.0, 1.0, None], "b": [3, 4, 1, 2]})
+    result = df.select(
+        pl.col("a").interpolate_by(
+            "b",
+            method=method,  # type: ignore[arg-type]
+            limit=limit,
+        )
+    )
+    assert_frame_equal(result, pl.DataFrame({"a": expected}))
+
+
+def test_interpolate_by_time_weighted_spline() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 4),
+                date(2024, 1, 5),
+            ],
+            "value": [0.0, 1.0, None, 9.0],
+        }
+    )
+    # In days since the start the valid points are (0, 0), (1, 1) and (4, 9), and the
+    # missing value sits at day 3.
+    result = df.select(pl.col("value").interpolate_by("ts", method="pchip"))["value"]
+    evenly_spaced = df.select(pl.col("value").interpolate("pchip"))["value"]
+    assert result[2] != evenly_spaced[2]
+    assert 1.0 < result[2] < 9.0  # type: ignore[operator]
+
+
+def test_interpolate_by_limit_area() -> None:
+    df = pl.DataFrame({"a": [None, 1, None, 3, None], "b": [1, 2, 3, 4, 5]})
+    result = df.select(
+        inside=pl.col("a").interpolate_by("b", method="nearest"),
+        outside=pl.col("a").interpolate_by("b", method="nearest", limit_area="outside"),
+    )
+    expected = pl.DataFrame(
+        {"inside": [None, 1, 3, 3, None], "outside": [1, 1, None, 3, 3]}
+    )
+    assert_frame_equal(result, expected)